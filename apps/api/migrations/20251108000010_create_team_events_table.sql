@@ -0,0 +1,10 @@
+-- Create team_events table (append-only domain event log)
+CREATE TABLE team_events (
+    sequence BIGSERIAL PRIMARY KEY,
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    event_type VARCHAR(50) NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_team_events_team_id_sequence ON team_events(team_id, sequence);
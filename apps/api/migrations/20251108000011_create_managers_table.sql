@@ -0,0 +1,12 @@
+-- Create managers table (persisted manager agent configuration)
+CREATE TABLE managers (
+    id UUID PRIMARY KEY,
+    team_id UUID UNIQUE NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    model VARCHAR(100) NOT NULL,
+    temperature REAL NOT NULL,
+    max_tokens INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT positive_max_tokens CHECK (max_tokens > 0)
+);
+
+CREATE INDEX idx_managers_team_id ON managers(team_id);
@@ -0,0 +1,3 @@
+-- Store the currency of a team's budget alongside the amount
+ALTER TABLE teams
+    ADD COLUMN budget_currency VARCHAR(3) NOT NULL DEFAULT 'USD';
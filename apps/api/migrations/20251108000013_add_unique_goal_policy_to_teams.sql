@@ -0,0 +1,9 @@
+-- Opt-in uniqueness of mission goals within a company
+-- Teams created under the ENFORCE_UNIQUE_GOAL_PER_COMPANY policy are flagged;
+-- archived teams release their goal for reuse
+ALTER TABLE teams
+    ADD COLUMN enforce_unique_goal BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE UNIQUE INDEX idx_teams_company_goal_unique
+    ON teams (company_id, lower(goal))
+    WHERE enforce_unique_goal AND status <> 'archived';
@@ -0,0 +1,4 @@
+-- Track how much of a team's budget has been spent
+ALTER TABLE teams
+    ADD COLUMN amount_spent DECIMAL(12,2) NOT NULL DEFAULT 0,
+    ADD CONSTRAINT non_negative_spend CHECK (amount_spent >= 0);
@@ -0,0 +1,8 @@
+-- Create feature_flags table (per-company behaviour toggles)
+CREATE TABLE feature_flags (
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    flag_name VARCHAR(100) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT false,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (company_id, flag_name)
+);
@@ -0,0 +1,4 @@
+-- Free-form lowercase labels for categorizing teams (e.g. "marketing", "q3")
+ALTER TABLE teams ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_teams_tags ON teams USING GIN (tags);
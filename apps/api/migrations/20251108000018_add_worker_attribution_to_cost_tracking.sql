@@ -0,0 +1,3 @@
+-- Attribute recorded spend to the worker (and its specialization) that incurred it
+ALTER TABLE cost_tracking ADD COLUMN worker_id UUID;
+ALTER TABLE cost_tracking ADD COLUMN specialization VARCHAR(100);
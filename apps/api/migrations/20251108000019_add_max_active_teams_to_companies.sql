@@ -0,0 +1,4 @@
+-- Optional cap on a company's non-terminal (pending, planning, active) teams
+-- NULL means unlimited
+ALTER TABLE companies
+    ADD COLUMN max_active_teams INTEGER CHECK (max_active_teams >= 0);
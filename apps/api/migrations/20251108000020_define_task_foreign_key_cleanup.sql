@@ -0,0 +1,13 @@
+-- Make task foreign keys explicit about deletes so removing a team (which
+-- cascades to its members and tasks) or a single worker leaves no orphans:
+-- subtasks go with their parent, and assignments to a removed worker are cleared
+ALTER TABLE tasks
+    DROP CONSTRAINT tasks_parent_task_id_fkey,
+    ADD CONSTRAINT tasks_parent_task_id_fkey
+        FOREIGN KEY (parent_task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    DROP CONSTRAINT tasks_assigned_to_fkey,
+    ADD CONSTRAINT tasks_assigned_to_fkey
+        FOREIGN KEY (assigned_to) REFERENCES team_members(id) ON DELETE SET NULL,
+    DROP CONSTRAINT tasks_assigned_by_fkey,
+    ADD CONSTRAINT tasks_assigned_by_fkey
+        FOREIGN KEY (assigned_by) REFERENCES team_members(id) ON DELETE SET NULL;
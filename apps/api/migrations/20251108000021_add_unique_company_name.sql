@@ -0,0 +1,14 @@
+-- Company names identify companies for find-or-create lookups (seeding, tests)
+
+-- Existing duplicates keep the oldest company's name; the others get their
+-- ID appended (trimmed to fit VARCHAR(255)) rather than being merged
+UPDATE companies
+SET name = LEFT(companies.name, 216) || ' (' || companies.id || ')'
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY name ORDER BY created_at, id) AS position
+    FROM companies
+) ranked
+WHERE ranked.id = companies.id
+  AND ranked.position > 1;
+
+CREATE UNIQUE INDEX idx_companies_name_unique ON companies(name);
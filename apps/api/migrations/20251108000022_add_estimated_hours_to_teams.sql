@@ -0,0 +1,4 @@
+-- Hours the manager estimated the goal will take, recorded after goal analysis
+ALTER TABLE teams
+    ADD COLUMN estimated_hours REAL,
+    ADD CONSTRAINT positive_estimated_hours CHECK (estimated_hours IS NULL OR estimated_hours > 0);
@@ -0,0 +1,12 @@
+-- Create webhooks table (company-registered endpoints notified of team events)
+CREATE TABLE webhooks (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    secret VARCHAR(64) NOT NULL,
+    -- Event types to deliver; empty means every event
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhooks_company_id ON webhooks(company_id);
@@ -0,0 +1,2 @@
+-- Index team_events by type so the event log can be filtered by it
+CREATE INDEX idx_team_events_event_type_sequence ON team_events(event_type, sequence);
@@ -0,0 +1,2 @@
+-- Persist worker skills so they can change while a team runs
+ALTER TABLE team_members ADD COLUMN skills TEXT[] NOT NULL DEFAULT '{}';
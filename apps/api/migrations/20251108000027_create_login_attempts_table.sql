@@ -0,0 +1,7 @@
+-- Track consecutive failed logins per user for temporary lockouts
+CREATE TABLE login_attempts (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    failed_count INTEGER NOT NULL CHECK (failed_count > 0),
+    window_started_at TIMESTAMPTZ NOT NULL,
+    locked_until TIMESTAMPTZ
+);
@@ -0,0 +1,11 @@
+-- Track failed logins per email instead of per user, so addresses without
+-- an account are locked like registered ones. Existing rows only hold
+-- short-lived lockouts, so they are dropped rather than converted.
+DROP TABLE login_attempts;
+
+CREATE TABLE login_attempts (
+    email VARCHAR(255) PRIMARY KEY,
+    failed_count INTEGER NOT NULL CHECK (failed_count > 0),
+    window_started_at TIMESTAMPTZ NOT NULL,
+    locked_until TIMESTAMPTZ
+);
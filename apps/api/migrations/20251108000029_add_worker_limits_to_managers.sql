@@ -0,0 +1,7 @@
+-- Persist each manager's team size range; existing managers keep the
+-- defaults they have been running with.
+ALTER TABLE managers
+    ADD COLUMN min_workers INTEGER NOT NULL DEFAULT 3,
+    ADD COLUMN max_workers INTEGER NOT NULL DEFAULT 5,
+    ADD CONSTRAINT managers_worker_limits_check
+        CHECK (min_workers >= 1 AND min_workers <= max_workers);
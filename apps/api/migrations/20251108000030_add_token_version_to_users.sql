@@ -0,0 +1,4 @@
+-- Tokens carry the version they were minted at; bumping a user's version
+-- revokes every token issued before, e.g. when they move company
+ALTER TABLE users
+    ADD COLUMN token_version INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,15 @@
+-- Track outbox delivery attempts so failed messages are retried with
+-- backoff and eventually given up on instead of being marked sent.
+-- available_at doubles as the claim lease: a relay claiming a message
+-- pushes it into the future so other relays skip it.
+ALTER TABLE outbox
+    ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN available_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN last_error TEXT,
+    ADD COLUMN failed_at TIMESTAMPTZ;
+
+DROP INDEX idx_outbox_unsent;
+CREATE INDEX idx_outbox_unsent ON outbox(id) WHERE sent_at IS NULL AND failed_at IS NULL;
+
+-- Sent messages are pruned after a retention period
+CREATE INDEX idx_outbox_sent_at ON outbox(sent_at) WHERE sent_at IS NOT NULL;
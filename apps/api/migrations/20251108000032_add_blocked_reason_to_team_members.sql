@@ -0,0 +1,3 @@
+-- Record why a blocked worker stalled; NULL for workers that are not
+-- blocked and for rows written before reasons were stored
+ALTER TABLE team_members ADD COLUMN blocked_reason TEXT;
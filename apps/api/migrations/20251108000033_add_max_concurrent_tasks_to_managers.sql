@@ -0,0 +1,5 @@
+-- Persist how many worker tasks each manager runs at once; existing
+-- managers keep the default they have been running with.
+ALTER TABLE managers
+    ADD COLUMN max_concurrent_tasks INTEGER NOT NULL DEFAULT 3,
+    ADD CONSTRAINT managers_max_concurrent_tasks_check CHECK (max_concurrent_tasks >= 1);
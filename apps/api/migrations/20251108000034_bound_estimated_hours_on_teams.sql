@@ -0,0 +1,6 @@
+-- Estimates are capped at a year (see MAX_ESTIMATED_HOURS); drop any
+-- larger ones recorded before the cap
+UPDATE teams SET estimated_hours = NULL WHERE estimated_hours > 8760;
+
+ALTER TABLE teams
+    ADD CONSTRAINT bounded_estimated_hours CHECK (estimated_hours IS NULL OR estimated_hours <= 8760);
@@ -0,0 +1,145 @@
+// Cancellation of in-flight agent work
+//
+// Each team's manager owns a `CancellationToken`. Long-running operations
+// race against it so cancelling the team stops them promptly.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::errors::{AgentError, AgentResult};
+
+/// Message carried by `AgentError::TaskExecutionFailed` for cancelled work
+pub const CANCELLED: &str = "cancelled";
+
+/// Runs `operation` until it finishes or `token` is cancelled
+///
+/// Returns `AgentError::TaskExecutionFailed("cancelled")` without polling
+/// `operation` if the token is already cancelled, and drops `operation` as
+/// soon as cancellation is requested.
+pub async fn run_cancellable<T, F>(token: &CancellationToken, operation: F) -> AgentResult<T>
+where
+    F: Future<Output = AgentResult<T>>,
+{
+    tokio::select! {
+        biased;
+        _ = token.cancelled() => Err(AgentError::TaskExecutionFailed(CANCELLED.to_string())),
+        result = operation => result,
+    }
+}
+
+/// Cancellation tokens of teams whose agents are running
+///
+/// Installed as an axum `Extension` so requests that stop a team reach its
+/// in-flight work: whoever runs a team's manager hands it [`token`] (see
+/// `ManagerAgent::with_cancellation_token`), and [`cancel`] triggers it.
+/// Cloning is cheap and clones share one registry.
+///
+/// [`token`]: TeamCancellations::token
+/// [`cancel`]: TeamCancellations::cancel
+#[derive(Debug, Clone, Default)]
+pub struct TeamCancellations {
+    tokens: Arc<Mutex<HashMap<Uuid, CancellationToken>>>,
+}
+
+impl TeamCancellations {
+    /// The token for `team_id`'s work, shared until it is cancelled or released
+    pub fn token(&self, team_id: Uuid) -> CancellationToken {
+        self.tokens
+            .lock()
+            .unwrap()
+            .entry(team_id)
+            .or_default()
+            .clone()
+    }
+
+    /// Stops `team_id`'s in-flight work
+    ///
+    /// Returns whether any work was registered for the team.
+    pub fn cancel(&self, team_id: Uuid) -> bool {
+        let token = self.tokens.lock().unwrap().remove(&team_id);
+        token.map(|token| token.cancel()).is_some()
+    }
+
+    /// Forgets `team_id`'s token once its work finished on its own
+    pub fn release(&self, team_id: Uuid) {
+        self.tokens.lock().unwrap().remove(&team_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn completes_when_not_cancelled() {
+        let token = CancellationToken::new();
+
+        let result = run_cancellable(&token, async { Ok(42) }).await;
+
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn already_cancelled_token_skips_the_operation() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = run_cancellable(&token, async { Ok(42) }).await;
+
+        assert!(matches!(result, Err(AgentError::TaskExecutionFailed(m)) if m == CANCELLED));
+    }
+
+    #[tokio::test]
+    async fn cancelling_stops_a_long_operation_promptly() {
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+
+        let long_task = async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        };
+        let result =
+            tokio::time::timeout(Duration::from_secs(1), run_cancellable(&token, long_task))
+                .await
+                .expect("cancelled task should stop well before the timeout");
+
+        assert!(matches!(result, Err(AgentError::TaskExecutionFailed(m)) if m == CANCELLED));
+    }
+
+    #[test]
+    fn cancelling_a_team_triggers_its_shared_token() {
+        let cancellations = TeamCancellations::default();
+        let team_id = Uuid::new_v4();
+        let running = cancellations.token(team_id);
+        let other = cancellations.token(Uuid::new_v4());
+
+        assert!(cancellations.cancel(team_id));
+
+        assert!(running.is_cancelled());
+        assert!(!other.is_cancelled());
+        // Nothing is left to cancel, and new work gets a fresh token
+        assert!(!cancellations.cancel(team_id));
+        assert!(!cancellations.token(team_id).is_cancelled());
+    }
+
+    #[test]
+    fn released_tokens_are_not_cancelled() {
+        let cancellations = TeamCancellations::default();
+        let team_id = Uuid::new_v4();
+        let finished = cancellations.token(team_id);
+
+        cancellations.release(team_id);
+
+        assert!(!cancellations.cancel(team_id));
+        assert!(!finished.is_cancelled());
+    }
+}
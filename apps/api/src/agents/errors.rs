@@ -6,8 +6,8 @@ pub enum AgentError {
     #[error("LLM API error: {0}")]
     LlmError(String),
 
-    #[error("Invalid team size: {0} (must be 3-5 workers)")]
-    InvalidTeamSize(usize),
+    #[error("Invalid team size: {size} (must be {min}-{max} workers)")]
+    InvalidTeamSize { size: usize, min: usize, max: usize },
 
     #[error("JSON parsing error: {0}")]
     JsonError(#[from] serde_json::Error),
@@ -0,0 +1,353 @@
+// LLM client abstraction
+//
+// Agents talk to language models through the `LlmClient` trait so the
+// provider can be swapped and tests can run without network access.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::json;
+
+use super::errors::{AgentError, AgentResult};
+
+/// Default Anthropic API endpoint
+pub const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
+
+/// Anthropic API version sent with every request
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Default price per input token, in USD
+pub const DEFAULT_INPUT_TOKEN_PRICE: Decimal = Decimal::from_parts(3, 0, 0, false, 6);
+
+/// Default price per output token, in USD
+pub const DEFAULT_OUTPUT_TOKEN_PRICE: Decimal = Decimal::from_parts(15, 0, 0, false, 6);
+
+/// Tokens consumed by one or more LLM calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct TokenUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+    }
+}
+
+/// A model's text together with the tokens it cost
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Completion {
+    pub text: String,
+    pub usage: TokenUsage,
+}
+
+/// Per-token prices used to turn `TokenUsage` into spend
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TokenPricing {
+    pub input_per_token: Decimal,
+    pub output_per_token: Decimal,
+}
+
+impl Default for TokenPricing {
+    fn default() -> Self {
+        Self {
+            input_per_token: DEFAULT_INPUT_TOKEN_PRICE,
+            output_per_token: DEFAULT_OUTPUT_TOKEN_PRICE,
+        }
+    }
+}
+
+impl TokenPricing {
+    /// Prices from `LLM_INPUT_TOKEN_PRICE` and `LLM_OUTPUT_TOKEN_PRICE`
+    ///
+    /// Unset or unparseable variables fall back to the defaults.
+    pub fn from_env() -> Self {
+        let price = |name: &str, default: Decimal| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<Decimal>().ok())
+                .filter(|price| !price.is_sign_negative())
+                .unwrap_or(default)
+        };
+
+        Self {
+            input_per_token: price("LLM_INPUT_TOKEN_PRICE", DEFAULT_INPUT_TOKEN_PRICE),
+            output_per_token: price("LLM_OUTPUT_TOKEN_PRICE", DEFAULT_OUTPUT_TOKEN_PRICE),
+        }
+    }
+
+    /// Cost of `usage` at these prices
+    pub fn cost(&self, usage: TokenUsage) -> Decimal {
+        Decimal::from(usage.input_tokens) * self.input_per_token
+            + Decimal::from(usage.output_tokens) * self.output_per_token
+    }
+}
+
+/// A language model provider that turns a system and user prompt into a
+/// completion
+#[async_trait]
+pub trait LlmClient: Send + Sync + fmt::Debug {
+    /// Request a single completion from `model`
+    ///
+    /// Returns the model's text and token usage, or `AgentError::LlmError`
+    /// if the provider call fails.
+    async fn complete(
+        &self,
+        model: &str,
+        system: &str,
+        user: &str,
+        temperature: f32,
+        max_tokens: u32,
+    ) -> AgentResult<Completion>;
+}
+
+/// `LlmClient` backed by the Anthropic Messages API
+#[derive(Clone)]
+pub struct AnthropicClient {
+    http: reqwest::Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl AnthropicClient {
+    /// Create a client authenticated with `api_key`
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            api_key: api_key.into(),
+            base_url: ANTHROPIC_API_URL.to_string(),
+        }
+    }
+
+    /// Create a client using the `ANTHROPIC_API_KEY` variable
+    ///
+    /// A missing key is not an error here; requests fail with
+    /// `AgentError::LlmError` until one is configured.
+    pub fn from_env() -> Self {
+        Self::new(std::env::var("ANTHROPIC_API_KEY").unwrap_or_default())
+    }
+
+    /// Send requests to a different endpoint (e.g. a proxy)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+impl fmt::Debug for AnthropicClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never print the API key
+        f.debug_struct("AnthropicClient")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Subset of the Messages API response that carries the completion
+#[derive(Deserialize)]
+struct MessagesResponse {
+    content: Vec<ContentBlock>,
+    #[serde(default)]
+    usage: TokenUsage,
+}
+
+#[derive(Deserialize)]
+struct ContentBlock {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[async_trait]
+impl LlmClient for AnthropicClient {
+    async fn complete(
+        &self,
+        model: &str,
+        system: &str,
+        user: &str,
+        temperature: f32,
+        max_tokens: u32,
+    ) -> AgentResult<Completion> {
+        if self.api_key.is_empty() {
+            return Err(AgentError::LlmError(
+                "ANTHROPIC_API_KEY is not set".to_string(),
+            ));
+        }
+
+        let response = self
+            .http
+            .post(format!("{}/v1/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&json!({
+                "model": model,
+                "system": system,
+                "temperature": temperature,
+                "max_tokens": max_tokens,
+                "messages": [{ "role": "user", "content": user }]
+            }))
+            .send()
+            .await
+            .map_err(|e| AgentError::LlmError(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AgentError::LlmError(format!(
+                "Anthropic API returned {}: {}",
+                status, body
+            )));
+        }
+
+        let message: MessagesResponse = response
+            .json()
+            .await
+            .map_err(|e| AgentError::LlmError(format!("Invalid response body: {}", e)))?;
+
+        Ok(Completion {
+            text: message
+                .content
+                .into_iter()
+                .filter(|block| block.kind == "text")
+                .map(|block| block.text)
+                .collect(),
+            usage: message.usage,
+        })
+    }
+}
+
+/// `LlmClient` that replays canned completions, for tests
+///
+/// Each call returns the next queued completion in order and fails with
+/// `AgentError::LlmError` once the queue is empty. Every completion reports
+/// the same token usage (zero unless set with `with_usage`). Models and
+/// prompts received are recorded for assertions.
+#[derive(Debug, Default)]
+pub struct MockLlmClient {
+    completions: Mutex<VecDeque<String>>,
+    usage: TokenUsage,
+    models: Mutex<Vec<String>>,
+    prompts: Mutex<Vec<(String, String)>>,
+}
+
+impl MockLlmClient {
+    /// Create a mock that returns `completions` in order
+    pub fn new<I, S>(completions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            completions: Mutex::new(completions.into_iter().map(Into::into).collect()),
+            usage: TokenUsage::default(),
+            models: Mutex::new(Vec::new()),
+            prompts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Report `usage` with every completion
+    pub fn with_usage(mut self, usage: TokenUsage) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// The models requested so far
+    pub fn models(&self) -> Vec<String> {
+        self.models.lock().unwrap().clone()
+    }
+
+    /// The `(system, user)` prompts received so far
+    pub fn prompts(&self) -> Vec<(String, String)> {
+        self.prompts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl LlmClient for MockLlmClient {
+    async fn complete(
+        &self,
+        model: &str,
+        system: &str,
+        user: &str,
+        _temperature: f32,
+        _max_tokens: u32,
+    ) -> AgentResult<Completion> {
+        self.models.lock().unwrap().push(model.to_string());
+        self.prompts
+            .lock()
+            .unwrap()
+            .push((system.to_string(), user.to_string()));
+
+        self.completions
+            .lock()
+            .unwrap()
+            .pop_front()
+            .map(|text| Completion {
+                text,
+                usage: self.usage,
+            })
+            .ok_or_else(|| AgentError::LlmError("No canned completion left".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn mock_returns_completions_in_order_then_fails() {
+        let client = MockLlmClient::new(["first", "second"]);
+
+        assert_eq!(
+            client.complete("m", "s", "u", 0.0, 10).await.unwrap().text,
+            "first"
+        );
+        assert_eq!(
+            client.complete("m", "s", "u", 0.0, 10).await.unwrap().text,
+            "second"
+        );
+        assert!(matches!(
+            client.complete("m", "s", "u", 0.0, 10).await,
+            Err(AgentError::LlmError(_))
+        ));
+        assert_eq!(client.prompts().len(), 3);
+        assert_eq!(client.models(), ["m", "m", "m"]);
+    }
+
+    #[tokio::test]
+    async fn anthropic_client_without_key_fails_fast() {
+        let client = AnthropicClient::new("");
+
+        let result = client.complete("m", "s", "u", 0.0, 10).await;
+
+        assert!(matches!(result, Err(AgentError::LlmError(_))));
+    }
+
+    #[test]
+    fn pricing_charges_input_and_output_tokens_separately() {
+        let pricing = TokenPricing {
+            input_per_token: Decimal::new(1, 5),
+            output_per_token: Decimal::new(3, 5),
+        };
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+        };
+
+        assert_eq!(pricing.cost(usage), Decimal::new(25, 3));
+    }
+
+    #[test]
+    fn anthropic_client_debug_hides_api_key() {
+        let client = AnthropicClient::new("sk-secret");
+
+        assert!(!format!("{:?}", client).contains("sk-secret"));
+    }
+}
@@ -1,18 +1,206 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+
 use uuid::Uuid;
 use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+use super::types::{
+    json_payload, DecomposedTask, GoalAnalysis, ReviewDecision, TaskOutput, TeamPlan, WorkerSpec,
+    WorkerStatus,
+};
+use super::cancellation::run_cancellable;
+use super::errors::{AgentError, AgentResult};
+use super::llm::{AnthropicClient, LlmClient, TokenPricing, TokenUsage};
+use super::prompts::library;
+use super::worker::{SkillMatching, WorkerAgent};
+use crate::domain::shared::Clock;
+use crate::domain::team::events::TeamEvent;
+use crate::domain::team::Team;
+
+/// Model used by newly created managers
+pub const DEFAULT_MODEL: &str = "claude-3-5-sonnet-20241022";
+
+/// Cheaper model used for dry-run planning
+pub const DRY_RUN_MODEL: &str = "claude-3-5-haiku-20241022";
 
-use super::types::{GoalAnalysis, WorkerSpec, ReviewDecision, TaskOutput};
-use super::errors::AgentResult;
+/// Default minimum number of workers in a formed team
+pub const DEFAULT_MIN_WORKERS: usize = 3;
+
+/// Default maximum number of workers in a formed team
+pub const DEFAULT_MAX_WORKERS: usize = 5;
+
+/// Default number of worker tasks `execute_tasks` runs at once
+pub const DEFAULT_MAX_CONCURRENT_TASKS: usize = 3;
+
+/// Worker tasks `execute_tasks` runs at once for new managers
+///
+/// Read from `MAX_CONCURRENT_TASKS`; unset, unparsable, or zero values
+/// fall back to [`DEFAULT_MAX_CONCURRENT_TASKS`].
+pub fn max_concurrent_tasks_from_env() -> usize {
+    parse_max_concurrent_tasks(std::env::var("MAX_CONCURRENT_TASKS").ok().as_deref())
+}
+
+fn parse_max_concurrent_tasks(value: Option<&str>) -> usize {
+    value
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|limit| *limit > 0)
+        .unwrap_or(DEFAULT_MAX_CONCURRENT_TASKS)
+}
+
+fn default_min_workers() -> usize {
+    DEFAULT_MIN_WORKERS
+}
+
+fn default_max_workers() -> usize {
+    DEFAULT_MAX_WORKERS
+}
+
+/// Team size range `form_team` must stay within
+///
+/// Always holds `1 <= min <= max`; deserializing limits that break this
+/// fails instead of producing an unusable manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawWorkerLimits")]
+pub struct WorkerLimits {
+    #[serde(rename = "min_workers")]
+    min: usize,
+    #[serde(rename = "max_workers")]
+    max: usize,
+}
+
+#[derive(Deserialize)]
+struct RawWorkerLimits {
+    #[serde(default = "default_min_workers")]
+    min_workers: usize,
+    #[serde(default = "default_max_workers")]
+    max_workers: usize,
+}
+
+impl WorkerLimits {
+    /// Allow teams of `min`..=`max` workers
+    ///
+    /// Returns `AgentError::ConfigError` if `min` is zero or greater than `max`.
+    pub fn new(min: usize, max: usize) -> AgentResult<Self> {
+        if min == 0 {
+            return Err(AgentError::ConfigError(
+                "min_workers must be at least 1".to_string(),
+            ));
+        }
+        if min > max {
+            return Err(AgentError::ConfigError(format!(
+                "min_workers ({}) cannot exceed max_workers ({})",
+                min, max
+            )));
+        }
+
+        Ok(Self { min, max })
+    }
+
+    /// Smallest team `form_team` may produce
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    /// Largest team `form_team` may produce
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl Default for WorkerLimits {
+    fn default() -> Self {
+        Self {
+            min: DEFAULT_MIN_WORKERS,
+            max: DEFAULT_MAX_WORKERS,
+        }
+    }
+}
+
+impl TryFrom<RawWorkerLimits> for WorkerLimits {
+    type Error = AgentError;
+
+    fn try_from(raw: RawWorkerLimits) -> AgentResult<Self> {
+        Self::new(raw.min_workers, raw.max_workers)
+    }
+}
+
+fn default_max_concurrent_tasks() -> usize {
+    max_concurrent_tasks_from_env()
+}
+
+fn default_llm_client() -> Arc<dyn LlmClient> {
+    Arc::new(AnthropicClient::from_env())
+}
+
+fn default_pricing() -> TokenPricing {
+    TokenPricing::for_model(DEFAULT_MODEL)
+}
 
 /// Manager Agent responsible for goal analysis, team formation,
 /// task decomposition, and worker coordination
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ManagerAgent {
     pub id: Uuid,
     pub team_id: Uuid,
     pub model: String,
     pub temperature: f32,
     pub max_tokens: u32,
+    /// Team size range `form_team` must stay within
+    #[serde(flatten)]
+    pub worker_limits: WorkerLimits,
+    /// Most worker tasks `execute_tasks` runs at once, across all calls
+    ///
+    /// Change it with `with_max_concurrent_tasks`; setting the field after
+    /// tasks have run does not resize the shared limit.
+    #[serde(default = "default_max_concurrent_tasks")]
+    pub max_concurrent_tasks: usize,
+    /// How task skills are matched against worker skills
+    #[serde(default)]
+    pub skill_matching: SkillMatching,
+    /// Model client used for LLM calls (not serialized; deserialized
+    /// managers get an Anthropic client for the default model)
+    #[serde(skip, default = "default_llm_client")]
+    pub llm: Arc<dyn LlmClient>,
+    /// Prices used to bill token usage (not serialized; deserialized
+    /// managers are billed at the default model's prices)
+    #[serde(skip, default = "default_pricing")]
+    pub pricing: TokenPricing,
+    /// Tokens used by this instance's LLM calls that have not been billed
+    /// to the team yet (clones start from zero)
+    #[serde(skip)]
+    pub(crate) unbilled_usage: Mutex<TokenUsage>,
+    /// Cancels the team's in-flight LLM calls and worker tasks
+    #[serde(skip)]
+    pub(crate) cancellation: CancellationToken,
+    /// Permits shared by every `execute_tasks` call on this manager and
+    /// its clones, sized from `max_concurrent_tasks` on first use
+    #[serde(skip)]
+    pub(crate) task_permits: Arc<OnceLock<Semaphore>>,
+}
+
+/// Clones share the cancellation token and the task limit, but each bills
+/// only the tokens its own calls used, so no usage is billed twice
+impl Clone for ManagerAgent {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            team_id: self.team_id,
+            model: self.model.clone(),
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+            worker_limits: self.worker_limits,
+            max_concurrent_tasks: self.max_concurrent_tasks,
+            skill_matching: self.skill_matching,
+            llm: self.llm.clone(),
+            pricing: self.pricing,
+            unbilled_usage: Mutex::default(),
+            cancellation: self.cancellation.clone(),
+            task_permits: self.task_permits.clone(),
+        }
+    }
 }
 
 impl ManagerAgent {
@@ -21,39 +209,196 @@ impl ManagerAgent {
         Self {
             id: Uuid::new_v4(),
             team_id,
-            model: "claude-3-5-sonnet-20241022".to_string(),
+            model: DEFAULT_MODEL.to_string(),
             temperature: 0.7,
             max_tokens: 4096,
+            worker_limits: WorkerLimits::default(),
+            max_concurrent_tasks: max_concurrent_tasks_from_env(),
+            skill_matching: SkillMatching::default(),
+            llm: default_llm_client(),
+            pricing: default_pricing(),
+            unbilled_usage: Mutex::default(),
+            cancellation: CancellationToken::new(),
+            task_permits: Arc::default(),
         }
     }
 
+    /// Use `llm` for this manager's model calls
+    pub fn with_llm_client(mut self, llm: Arc<dyn LlmClient>) -> Self {
+        self.llm = llm;
+        self
+    }
+
+    /// Plan with the cheaper [`DRY_RUN_MODEL`] instead of the default model
+    ///
+    /// For previewing a launch before committing budget; requests go
+    /// through the same LLM client, and usage is billed at the dry-run
+    /// model's prices.
+    pub fn with_dry_run_model(mut self) -> Self {
+        self.model = DRY_RUN_MODEL.to_string();
+        self.pricing = TokenPricing::for_model(DRY_RUN_MODEL);
+        self
+    }
+
+    /// Match task skills to worker skills with `matching`
+    ///
+    /// [`SkillMatching::Fuzzy`] restores substring matching for teams
+    /// whose specs use free-form skill descriptions.
+    pub fn with_skill_matching(mut self, matching: SkillMatching) -> Self {
+        self.skill_matching = matching;
+        self
+    }
+
+    /// Bill token usage at `pricing` instead of the configured prices
+    pub fn with_pricing(mut self, pricing: TokenPricing) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Cancel work through `token` instead of a token of its own
+    ///
+    /// Pass `TeamCancellations::token` so API requests that stop the team
+    /// reach this manager.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Token that is triggered when the team is cancelled
+    ///
+    /// Pass it to `WorkerAgent::execute_task` so worker tasks stop with
+    /// the rest of the team.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Cancel the team's in-flight work
+    ///
+    /// Operations running under this manager's token stop with
+    /// `AgentError::TaskExecutionFailed("cancelled")`, as do any started
+    /// afterwards.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    /// Tokens used since usage was last billed
+    pub fn unbilled_usage(&self) -> TokenUsage {
+        *self.unbilled_usage.lock().unwrap()
+    }
+
+    /// Charge unbilled token usage to `team`'s spend
+    ///
+    /// The usage is priced with `pricing` and passed to
+    /// `Team::record_spend`, then reset so it is billed only once.
+    /// Returns the event from `record_spend` if the spend failed the team
+    /// for exceeding its budget.
+    pub fn bill_usage(
+        &self,
+        team: &mut Team,
+        clock: &dyn Clock,
+    ) -> Result<Option<TeamEvent>, String> {
+        let usage = std::mem::take(&mut *self.unbilled_usage.lock().unwrap());
+        team.record_spend(self.pricing.cost(usage), clock)
+    }
+
+    /// Configure the allowed worker pool size for `form_team`
+    ///
+    /// Returns `AgentError::ConfigError` if `min` is zero or greater than `max`.
+    pub fn with_worker_limits(mut self, min: usize, max: usize) -> AgentResult<Self> {
+        self.worker_limits = WorkerLimits::new(min, max)?;
+        Ok(self)
+    }
+
+    /// Run at most `limit` worker tasks at once in `execute_tasks`
+    ///
+    /// The returned manager and its clones share a new limit, separate
+    /// from the one `self` had. Returns `AgentError::ConfigError` if
+    /// `limit` is zero.
+    pub fn with_max_concurrent_tasks(mut self, limit: usize) -> AgentResult<Self> {
+        if limit == 0 {
+            return Err(AgentError::ConfigError(
+                "max_concurrent_tasks must be at least 1".to_string(),
+            ));
+        }
+
+        self.max_concurrent_tasks = limit;
+        self.task_permits = Arc::default();
+        Ok(self)
+    }
+
+    /// Check that a proposed team fits the configured worker pool size
+    pub fn validate_team_size(&self, specs: &[WorkerSpec]) -> AgentResult<()> {
+        let size = specs.len();
+        let limits = self.worker_limits;
+        if size < limits.min() || size > limits.max() {
+            return Err(AgentError::InvalidTeamSize {
+                size,
+                min: limits.min(),
+                max: limits.max(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Analyze a user's goal and extract key information
     ///
-    /// This method uses Claude to understand the goal and break it down into:
+    /// This method asks the LLM client to understand the goal and break it
+    /// down into:
     /// - Core objective
     /// - Required subtasks
     /// - Needed specializations
     /// - Timeline estimate
     /// - Potential blockers
     /// - Success criteria
-    pub async fn analyze_goal(&self, _goal: &str) -> AgentResult<GoalAnalysis> {
-        // TODO: Implement with Claude API (US-303)
-        // For now, return a mock analysis
-        Ok(GoalAnalysis {
-            core_objective: "Placeholder goal analysis".to_string(),
-            subtasks: vec!["Subtask 1".to_string(), "Subtask 2".to_string()],
-            required_specializations: vec!["Coder".to_string(), "Tester".to_string()],
-            estimated_timeline_hours: 8.0,
-            potential_blockers: vec!["API rate limits".to_string()],
-            success_criteria: vec!["Tests pass".to_string()],
-        })
+    ///
+    /// The completion must contain a JSON object accepted by
+    /// `GoalAnalysis::from_llm_json`; any text around it is ignored.
+    pub async fn analyze_goal(&self, goal: &str) -> AgentResult<GoalAnalysis> {
+        let prompt = library::goal_analysis();
+        let variables = HashMap::from([("goal".to_string(), goal.to_string())]);
+
+        let user_prompt = prompt.render(&variables);
+        let completion = run_cancellable(
+            &self.cancellation,
+            self.llm.complete(
+                &self.model,
+                &prompt.system,
+                &user_prompt,
+                self.temperature,
+                self.max_tokens,
+            ),
+        )
+        .await?;
+        *self.unbilled_usage.lock().unwrap() += completion.usage;
+
+        GoalAnalysis::from_llm_json(json_payload(&completion.text))
+    }
+
+    /// Number of workers a goal calls for
+    ///
+    /// Complexity is the larger of the subtask count and the number of
+    /// distinct required specializations, clamped to the manager's
+    /// [`WorkerLimits`] (3-5 workers by default).
+    pub fn target_team_size(&self, analysis: &GoalAnalysis) -> usize {
+        let specializations: HashSet<&str> = analysis
+            .required_specializations
+            .iter()
+            .map(String::as_str)
+            .collect();
+        let complexity = analysis.subtasks.len().max(specializations.len());
+
+        complexity.clamp(self.worker_limits.min(), self.worker_limits.max())
     }
 
-    /// Form a team of 3-5 specialized workers based on goal analysis
-    pub async fn form_team(&self, _analysis: &GoalAnalysis) -> AgentResult<Vec<WorkerSpec>> {
+    /// Form a team of specialized workers based on goal analysis
+    ///
+    /// The team has [`ManagerAgent::target_team_size`] workers and is
+    /// validated against the manager's [`WorkerLimits`].
+    pub async fn form_team(&self, analysis: &GoalAnalysis) -> AgentResult<Vec<WorkerSpec>> {
         // TODO: Implement with Claude API (US-303)
-        // For now, return mock workers
-        Ok(vec![
+        // For now, draw mock workers from a fixed roster
+        let roster = [
             WorkerSpec {
                 specialization: "Coder".to_string(),
                 skills: vec!["Rust".to_string(), "API design".to_string()],
@@ -72,16 +417,207 @@ impl ManagerAgent {
                 responsibilities: vec!["Review code quality".to_string()],
                 required_tools: vec!["clippy".to_string()],
             },
-        ])
+            WorkerSpec {
+                specialization: "Researcher".to_string(),
+                skills: vec!["Research".to_string()],
+                responsibilities: vec!["Gather requirements".to_string()],
+                required_tools: vec![],
+            },
+            WorkerSpec {
+                specialization: "Writer".to_string(),
+                skills: vec!["Documentation".to_string()],
+                responsibilities: vec!["Write documentation".to_string()],
+                required_tools: vec![],
+            },
+        ];
+
+        let specs: Vec<WorkerSpec> = roster
+            .iter()
+            .cycle()
+            .take(self.target_team_size(analysis))
+            .cloned()
+            .collect();
+
+        self.validate_team_size(&specs)?;
+        Ok(specs)
+    }
+
+    /// Preview a launch: analyze `goal` and form a team for it
+    ///
+    /// Runs [`ManagerAgent::analyze_goal`] and [`ManagerAgent::form_team`]
+    /// only. No tasks are decomposed or executed and nothing is persisted;
+    /// the analysis call's tokens are left unbilled for the caller to
+    /// charge or discard.
+    pub async fn plan(&self, goal: &str) -> AgentResult<TeamPlan> {
+        let analysis = self.analyze_goal(goal).await?;
+        let workers = self.form_team(&analysis).await?;
+
+        Ok(TeamPlan { analysis, workers })
+    }
+
+    /// Plan `team`'s launch, billing it and recording the analysis'
+    /// estimate on it
+    ///
+    /// Like [`ManagerAgent::plan`], but the analysis call's tokens are
+    /// charged with [`ManagerAgent::bill_usage`], and the estimated timeline
+    /// is passed to `Team::record_estimate` so the team can project its
+    /// completion. Returns the plan with the event from billing, if the
+    /// spend failed the team. Persisting the team is left to the caller.
+    pub async fn plan_team(
+        &self,
+        team: &mut Team,
+        clock: &dyn Clock,
+    ) -> AgentResult<(TeamPlan, Option<TeamEvent>)> {
+        let plan = self.plan(team.goal()).await?;
+        let event = self
+            .bill_usage(team, clock)
+            .map_err(AgentError::TaskExecutionFailed)?;
+        team.record_estimate(plan.analysis.estimated_timeline_hours)
+            .map_err(AgentError::TaskExecutionFailed)?;
+
+        Ok((plan, event))
     }
 
     /// Decompose a goal into concrete, actionable tasks
-    /// TODO: Implement with Claude API (US-303)
-    /// For now, this is a stub that will be implemented when we integrate
-    /// with the task repository in Sprint 4
-    pub async fn decompose_goal(&self, _goal: &str) -> AgentResult<()> {
-        // Will return actual tasks when integrated with repository
-        Ok(())
+    ///
+    /// The completion must contain a JSON array accepted by
+    /// `DecomposedTask::from_llm_json`; any text around it is ignored.
+    /// Persisting the tasks is left to the caller.
+    pub async fn decompose_goal(&self, goal: &str) -> AgentResult<Vec<DecomposedTask>> {
+        let prompt = library::task_decomposition();
+        let variables = HashMap::from([("goal".to_string(), goal.to_string())]);
+
+        let user_prompt = prompt.render(&variables);
+        let completion = run_cancellable(
+            &self.cancellation,
+            self.llm.complete(
+                &self.model,
+                &prompt.system,
+                &user_prompt,
+                self.temperature,
+                self.max_tokens,
+            ),
+        )
+        .await?;
+        *self.unbilled_usage.lock().unwrap() += completion.usage;
+
+        DecomposedTask::from_llm_json(&completion.text)
+    }
+
+    /// Assign a task to the first idle worker able to handle it
+    ///
+    /// Workers whose required tools are not all in `available_tools` are
+    /// skipped. Returns the chosen worker's ID, `AgentError::ConfigError`
+    /// if every capable worker lacks a tool, or `AgentError::AgentNotFound`
+    /// if no idle worker has the required skills.
+    pub fn assign_task(
+        &self,
+        workers: &mut [WorkerAgent],
+        task_id: Uuid,
+        required_skills: &[String],
+        available_tools: &HashSet<String>,
+    ) -> AgentResult<Uuid> {
+        let mut tool_error = None;
+
+        for worker in workers.iter_mut().filter(|w| {
+            *w.get_status() == WorkerStatus::Idle
+                && w.can_handle_task_with(required_skills, self.skill_matching)
+        }) {
+            match worker.assign_task_with_tools(task_id, available_tools) {
+                Ok(()) => return Ok(worker.id),
+                Err(e @ AgentError::ConfigError(_)) => tool_error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(tool_error.unwrap_or_else(|| {
+            AgentError::AgentNotFound(format!("No idle worker can handle task {}", task_id))
+        }))
+    }
+
+    /// Move a task from its current worker to another idle worker
+    ///
+    /// Used when the assigned worker is blocked or has died. The previous
+    /// worker is released and the task goes to the first other idle worker
+    /// with the required skills. Returns the new worker's ID, or
+    /// `AgentError::AgentNotFound` if the task is not assigned or no
+    /// replacement exists (in which case no worker is changed).
+    pub fn reassign_task(
+        &self,
+        workers: &mut [WorkerAgent],
+        task_id: Uuid,
+        required_skills: &[String],
+    ) -> AgentResult<Uuid> {
+        let previous = workers
+            .iter()
+            .position(|w| {
+                w.assigned_task_id == Some(task_id) && *w.get_status() != WorkerStatus::Idle
+            })
+            .ok_or_else(|| {
+                AgentError::AgentNotFound(format!("No worker is assigned task {}", task_id))
+            })?;
+
+        let replacement = workers
+            .iter()
+            .enumerate()
+            .position(|(i, w)| {
+                i != previous
+                    && *w.get_status() == WorkerStatus::Idle
+                    && w.can_handle_task_with(required_skills, self.skill_matching)
+            })
+            .ok_or_else(|| {
+                AgentError::AgentNotFound(format!("No idle worker can take over task {}", task_id))
+            })?;
+
+        workers[previous].unassign_task();
+        workers[replacement].assign_task(task_id)?;
+
+        Ok(workers[replacement].id)
+    }
+
+    /// Execute the assigned task of every worker that has one
+    ///
+    /// At most `max_concurrent_tasks` tasks run at once, counting those
+    /// started by concurrent calls on this manager or its clones; the rest
+    /// queue until a running task finishes, so large teams do not exhaust
+    /// the LLM provider's rate limits. Tasks run under the manager's
+    /// cancellation token. Returns one result per assigned worker, in
+    /// worker order.
+    pub async fn execute_tasks(&self, workers: &mut [WorkerAgent]) -> Vec<AgentResult<TaskOutput>> {
+        let cancel = &self.cancellation;
+        let assigned = workers
+            .iter_mut()
+            .filter_map(|worker| worker.assigned_task_id.map(|task_id| (worker, task_id)));
+
+        self.run_limited(assigned, |(worker, task_id)| async move {
+            worker.execute_task(task_id, cancel).await
+        })
+        .await
+    }
+
+    /// Run `task` for each item, within the shared `max_concurrent_tasks`
+    /// limit
+    ///
+    /// Results are returned in item order.
+    async fn run_limited<I, F, Fut>(&self, items: I, task: F) -> Vec<Fut::Output>
+    where
+        I: IntoIterator,
+        F: Fn(I::Item) -> Fut,
+        Fut: Future,
+    {
+        // A deserialized limit of zero would never grant a permit
+        let permits = self
+            .task_permits
+            .get_or_init(|| Semaphore::new(self.max_concurrent_tasks.max(1)));
+
+        futures::future::join_all(items.into_iter().map(|item| {
+            let run = task(item);
+            async move {
+                let _permit = permits.acquire().await.expect("semaphore is never closed");
+                run.await
+            }
+        }))
+        .await
     }
 
     /// Review a worker's task output and provide feedback
@@ -94,11 +630,72 @@ impl ManagerAgent {
         // For now, approve everything
         Ok(ReviewDecision::Approved)
     }
+
+    /// Consolidate finished workers' outputs into one report
+    ///
+    /// Results are merged into an object keyed by task ID, logs are
+    /// concatenated in output order with a `[task_id]` prefix, and all
+    /// artifacts are collected. An output counts as failed when its result
+    /// is an object with an `error` key; those task IDs are listed under
+    /// `failed_tasks`. Returns `AgentError::TaskExecutionFailed` if two
+    /// outputs share a task ID, since their results would collide, or if
+    /// an artifact is invalid (see [`Artifact::validate`]).
+    ///
+    /// [`Artifact::validate`]: super::types::Artifact::validate
+    pub fn aggregate_reports(&self, outputs: &[TaskOutput]) -> AgentResult<serde_json::Value> {
+        let mut results = serde_json::Map::new();
+        let mut logs = Vec::new();
+        let mut artifacts = Vec::new();
+        let mut failed_tasks = Vec::new();
+
+        for output in outputs {
+            let task_id = output.task_id.to_string();
+            if results.contains_key(&task_id) {
+                return Err(AgentError::TaskExecutionFailed(format!(
+                    "Duplicate output for task {}",
+                    task_id
+                )));
+            }
+
+            for artifact in &output.artifacts {
+                artifact.validate().map_err(|e| {
+                    AgentError::TaskExecutionFailed(format!("Task {}: {}", task_id, e))
+                })?;
+            }
+
+            if output.result.get("error").is_some() {
+                failed_tasks.push(task_id.clone());
+            }
+            logs.extend(
+                output
+                    .logs
+                    .iter()
+                    .map(|line| format!("[{}] {}", task_id, line)),
+            );
+            artifacts.extend(output.artifacts.iter().cloned());
+            results.insert(task_id, output.result.clone());
+        }
+
+        Ok(serde_json::json!({
+            "team_id": self.team_id,
+            "summary": {
+                "total": outputs.len(),
+                "succeeded": outputs.len() - failed_tasks.len(),
+                "failed": failed_tasks.len(),
+            },
+            "failed_tasks": failed_tasks,
+            "results": results,
+            "logs": logs,
+            "artifacts": artifacts,
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::agents::llm::MockLlmClient;
+    use crate::agents::types::{Artifact, MAX_INLINE_ARTIFACT_BYTES};
 
     #[test]
     fn test_manager_agent_creation() {
@@ -111,15 +708,203 @@ mod tests {
         assert_eq!(manager.max_tokens, 4096);
     }
 
+    const CANNED_ANALYSIS: &str = r#"{
+        "core_objective": "Scrape product listings",
+        "subtasks": ["Fetch pages", "Parse listings"],
+        "required_specializations": ["Coder", "Tester"],
+        "estimated_timeline_hours": 8.0,
+        "potential_blockers": ["API rate limits"],
+        "success_criteria": ["Tests pass"]
+    }"#;
+
+    fn manager_with_completions(completions: &[&str]) -> (ManagerAgent, Arc<MockLlmClient>) {
+        let llm = Arc::new(MockLlmClient::new(completions.iter().copied()));
+        let manager = ManagerAgent::new(Uuid::new_v4()).with_llm_client(llm.clone());
+        (manager, llm)
+    }
+
     #[tokio::test]
     async fn test_analyze_goal_mock() {
-        let manager = ManagerAgent::new(Uuid::new_v4());
+        let (manager, llm) = manager_with_completions(&[CANNED_ANALYSIS]);
+
+        let analysis = manager.analyze_goal("Build a web scraper").await.unwrap();
+
+        assert_eq!(analysis.core_objective, "Scrape product listings");
+        assert_eq!(analysis.subtasks.len(), 2);
+        let prompts = llm.prompts();
+        assert!(prompts[0].1.contains("Goal: Build a web scraper"));
+        assert_eq!(llm.models(), [DEFAULT_MODEL]);
+    }
+
+    #[tokio::test]
+    async fn test_llm_calls_use_the_managers_model() {
+        let (mut manager, llm) = manager_with_completions(&[CANNED_ANALYSIS]);
+        manager.model = "claude-3-opus-20240229".to_string();
+
+        manager.analyze_goal("Build a web scraper").await.unwrap();
+
+        assert_eq!(llm.models(), ["claude-3-opus-20240229"]);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_goal_ignores_text_around_json() {
+        let completion = format!("Here is the analysis:\n```json\n{}\n```", CANNED_ANALYSIS);
+        let (manager, _) = manager_with_completions(&[&completion]);
+
+        let analysis = manager.analyze_goal("Build a web scraper").await.unwrap();
+
+        assert_eq!(analysis.required_specializations, ["Coder", "Tester"]);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_goal_rejects_invalid_completion() {
+        let (manager, _) = manager_with_completions(&["not json"]);
+
         let result = manager.analyze_goal("Build a web scraper").await;
 
-        assert!(result.is_ok());
-        let analysis = result.unwrap();
-        assert!(!analysis.core_objective.is_empty());
-        assert!(!analysis.subtasks.is_empty());
+        assert!(matches!(result, Err(AgentError::JsonError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_decompose_goal_parses_tasks() {
+        let completion = r#"[{
+            "title": "Fetch pages",
+            "description": "Download the listing pages",
+            "acceptance_criteria": ["Pages fetched", "Errors retried", "Results cached"],
+            "required_skills": ["Rust"],
+            "estimated_tokens": 3000
+        }]"#;
+        let (manager, llm) = manager_with_completions(&[completion]);
+
+        let tasks = manager.decompose_goal("Build a web scraper").await.unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Fetch pages");
+        let prompts = llm.prompts();
+        assert!(prompts[0].0.contains("JSON array"));
+        assert!(prompts[0].1.contains("Goal: Build a web scraper"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_goal_propagates_llm_errors() {
+        let (manager, _) = manager_with_completions(&[]);
+
+        let result = manager.analyze_goal("Build a web scraper").await;
+
+        assert!(matches!(result, Err(AgentError::LlmError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_manager_stops_analysis_and_worker_tasks() {
+        let (manager, llm) = manager_with_completions(&[CANNED_ANALYSIS]);
+        let mut worker = worker(&["Rust"], &[]);
+        let task_id = Uuid::new_v4();
+        worker.assign_task(task_id).unwrap();
+
+        manager.cancel();
+
+        let analysis = manager.analyze_goal("Build a web scraper").await;
+        assert!(matches!(analysis, Err(AgentError::TaskExecutionFailed(m)) if m == "cancelled"));
+        assert!(llm.prompts().is_empty());
+
+        let output = worker
+            .execute_task(task_id, &manager.cancellation_token())
+            .await;
+        assert!(matches!(output, Err(AgentError::TaskExecutionFailed(m)) if m == "cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_llm_usage_is_billed_to_team_spend() {
+        use crate::domain::shared::SystemClock;
+        use rust_decimal::Decimal;
+
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+        };
+        let llm = Arc::new(MockLlmClient::new([CANNED_ANALYSIS]).with_usage(usage));
+        let manager = ManagerAgent::new(Uuid::new_v4())
+            .with_llm_client(llm)
+            .with_pricing(TokenPricing {
+                input_per_token: Decimal::new(3, 6),
+                output_per_token: Decimal::new(15, 6),
+            });
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Build a web scraper".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        manager.analyze_goal("Build a web scraper").await.unwrap();
+        assert_eq!(manager.unbilled_usage(), usage);
+        manager.bill_usage(&mut team, &SystemClock).unwrap();
+
+        // 1000 * 0.000003 + 500 * 0.000015
+        assert_eq!(team.amount_spent(), Decimal::new(105, 4));
+        assert_eq!(manager.unbilled_usage(), TokenUsage::default());
+
+        // Already-billed usage is not charged twice
+        manager.bill_usage(&mut team, &SystemClock).unwrap();
+        assert_eq!(team.amount_spent(), Decimal::new(105, 4));
+    }
+
+    #[tokio::test]
+    async fn test_clones_bill_only_their_own_usage() {
+        use crate::domain::shared::SystemClock;
+
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+        };
+        let llm = Arc::new(MockLlmClient::new([CANNED_ANALYSIS]).with_usage(usage));
+        let manager = ManagerAgent::new(Uuid::new_v4()).with_llm_client(llm);
+        let preview = manager.clone();
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Build a web scraper".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        preview.analyze_goal("Build a web scraper").await.unwrap();
+        assert_eq!(preview.unbilled_usage(), usage);
+        assert_eq!(manager.unbilled_usage(), TokenUsage::default());
+        assert_eq!(preview.clone().unbilled_usage(), TokenUsage::default());
+
+        // Billing both charges the preview's tokens once
+        manager.bill_usage(&mut team, &SystemClock).unwrap();
+        preview.bill_usage(&mut team, &SystemClock).unwrap();
+        assert_eq!(team.amount_spent(), manager.pricing.cost(usage));
+    }
+
+    #[tokio::test]
+    async fn test_plan_team_bills_the_analysis() {
+        use crate::domain::shared::SystemClock;
+
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+        };
+        let llm = Arc::new(MockLlmClient::new([CANNED_ANALYSIS]).with_usage(usage));
+        let manager = ManagerAgent::new(Uuid::new_v4()).with_llm_client(llm);
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Build a web scraper".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        manager.plan_team(&mut team, &SystemClock).await.unwrap();
+
+        assert_eq!(team.amount_spent(), manager.pricing.cost(usage));
+        assert_eq!(manager.unbilled_usage(), TokenUsage::default());
     }
 
     #[tokio::test]
@@ -140,4 +925,517 @@ mod tests {
         let workers = result.unwrap();
         assert!(workers.len() >= 3 && workers.len() <= 5, "Should create 3-5 workers");
     }
+
+    fn empty_analysis() -> GoalAnalysis {
+        GoalAnalysis {
+            core_objective: "Test goal".to_string(),
+            subtasks: vec![],
+            required_specializations: vec![],
+            estimated_timeline_hours: 1.0,
+            potential_blockers: vec![],
+            success_criteria: vec![],
+        }
+    }
+
+    fn specs(count: usize) -> Vec<WorkerSpec> {
+        (0..count)
+            .map(|_| WorkerSpec {
+                specialization: "Coder".to_string(),
+                skills: vec![],
+                responsibilities: vec![],
+                required_tools: vec![],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_worker_limits_reject_min_greater_than_max() {
+        let result = ManagerAgent::new(Uuid::new_v4()).with_worker_limits(4, 3);
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+
+        let result = ManagerAgent::new(Uuid::new_v4()).with_worker_limits(0, 3);
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_deserialized_worker_limits_are_validated() {
+        let limits: WorkerLimits =
+            serde_json::from_str(r#"{"min_workers": 2, "max_workers": 4}"#).unwrap();
+        assert_eq!((limits.min(), limits.max()), (2, 4));
+
+        let limits: WorkerLimits = serde_json::from_str("{}").unwrap();
+        assert_eq!(limits, WorkerLimits::default());
+
+        let result = serde_json::from_str::<WorkerLimits>(r#"{"min_workers": 6}"#);
+        assert!(result.is_err());
+        let result = serde_json::from_str::<WorkerLimits>(r#"{"min_workers": 0}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_custom_worker_limits_enforced() {
+        let manager = ManagerAgent::new(Uuid::new_v4())
+            .with_worker_limits(2, 3)
+            .unwrap();
+
+        assert!(matches!(
+            manager.validate_team_size(&specs(1)),
+            Err(AgentError::InvalidTeamSize {
+                size: 1,
+                min: 2,
+                max: 3
+            })
+        ));
+        assert!(manager.validate_team_size(&specs(2)).is_ok());
+        assert!(manager.validate_team_size(&specs(3)).is_ok());
+        assert!(matches!(
+            manager.validate_team_size(&specs(4)),
+            Err(AgentError::InvalidTeamSize { size: 4, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_form_team_respects_custom_limits() {
+        let manager = ManagerAgent::new(Uuid::new_v4())
+            .with_worker_limits(2, 3)
+            .unwrap();
+        let workers = manager.form_team(&empty_analysis()).await.unwrap();
+        assert!(workers.len() >= 2 && workers.len() <= 3);
+
+        let strict = ManagerAgent::new(Uuid::new_v4())
+            .with_worker_limits(4, 5)
+            .unwrap();
+        let workers = strict.form_team(&empty_analysis()).await.unwrap();
+        assert_eq!(workers.len(), 4);
+    }
+
+    #[test]
+    fn test_max_concurrent_tasks_defaults_and_rejects_zero() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        assert_eq!(manager.max_concurrent_tasks, DEFAULT_MAX_CONCURRENT_TASKS);
+
+        let result = ManagerAgent::new(Uuid::new_v4()).with_max_concurrent_tasks(0);
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_run_limited_caps_concurrent_tasks() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        for limit in [1, DEFAULT_MAX_CONCURRENT_TASKS] {
+            let manager = ManagerAgent::new(Uuid::new_v4())
+                .with_max_concurrent_tasks(limit)
+                .unwrap();
+            let running = AtomicUsize::new(0);
+            let peak = AtomicUsize::new(0);
+
+            let results = manager
+                .run_limited(0..10, |i| {
+                    let (running, peak) = (&running, &peak);
+                    async move {
+                        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        i
+                    }
+                })
+                .await;
+
+            assert_eq!(results, (0..10).collect::<Vec<_>>());
+            assert_eq!(peak.load(Ordering::SeqCst), limit);
+        }
+    }
+
+    #[test]
+    fn test_max_concurrent_tasks_config_falls_back_to_default() {
+        assert_eq!(parse_max_concurrent_tasks(Some(" 8 ")), 8);
+        for value in [None, Some(""), Some("0"), Some("many"), Some("-2")] {
+            assert_eq!(
+                parse_max_concurrent_tasks(value),
+                DEFAULT_MAX_CONCURRENT_TASKS
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_limited_shares_its_limit_across_calls() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let manager = ManagerAgent::new(Uuid::new_v4())
+            .with_max_concurrent_tasks(2)
+            .unwrap();
+        let clone = manager.clone();
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let task = |_| {
+            let (running, peak) = (&running, &peak);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+            }
+        };
+
+        tokio::join!(
+            manager.run_limited(0..5, task),
+            clone.run_limited(0..5, task)
+        );
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_cancelling_the_team_reaches_its_manager() {
+        use crate::agents::cancellation::TeamCancellations;
+
+        let team_id = Uuid::new_v4();
+        let cancellations = TeamCancellations::default();
+        let manager =
+            ManagerAgent::new(team_id).with_cancellation_token(cancellations.token(team_id));
+
+        cancellations.cancel(team_id);
+
+        assert!(manager.cancellation_token().is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_execute_tasks_runs_every_assigned_worker() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers: Vec<WorkerAgent> = (0..5).map(|_| worker(&["Rust"], &[])).collect();
+        let task_ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        for (worker, task_id) in workers.iter_mut().zip(&task_ids) {
+            worker.assign_task(*task_id).unwrap();
+        }
+
+        let outputs = manager.execute_tasks(&mut workers).await;
+
+        let executed: Vec<Uuid> = outputs
+            .into_iter()
+            .map(|output| output.unwrap().task_id)
+            .collect();
+        assert_eq!(executed, task_ids);
+    }
+
+    fn analysis_with_subtasks(count: usize) -> GoalAnalysis {
+        GoalAnalysis {
+            subtasks: (0..count).map(|i| format!("Subtask {}", i)).collect(),
+            ..empty_analysis()
+        }
+    }
+
+    #[test]
+    fn test_target_team_size_clamps_small_goal_to_minimum() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+
+        assert_eq!(manager.target_team_size(&analysis_with_subtasks(2)), 3);
+    }
+
+    #[test]
+    fn test_target_team_size_clamps_large_goal_to_maximum() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+
+        assert_eq!(manager.target_team_size(&analysis_with_subtasks(10)), 5);
+    }
+
+    #[test]
+    fn test_target_team_size_counts_distinct_specializations() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let analysis = GoalAnalysis {
+            required_specializations: ["Coder", "Tester", "Writer", "Coder", "Researcher"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            ..analysis_with_subtasks(1)
+        };
+
+        assert_eq!(manager.target_team_size(&analysis), 4);
+    }
+
+    #[tokio::test]
+    async fn test_form_team_sizes_team_to_goal() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+
+        let workers = manager.form_team(&analysis_with_subtasks(4)).await.unwrap();
+
+        assert_eq!(workers.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_plan_analyzes_and_forms_team_with_one_llm_call() {
+        let (manager, llm) = manager_with_completions(&[CANNED_ANALYSIS]);
+
+        let plan = manager.plan("Build a web scraper").await.unwrap();
+
+        assert_eq!(plan.analysis.core_objective, "Scrape product listings");
+        assert_eq!(plan.workers.len(), 3);
+        assert_eq!(llm.prompts().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_plan_team_records_the_estimate() {
+        use crate::domain::shared::SystemClock;
+
+        let (manager, _) = manager_with_completions(&[CANNED_ANALYSIS]);
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Build a web scraper".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let (plan, event) = manager.plan_team(&mut team, &SystemClock).await.unwrap();
+
+        assert_eq!(plan.workers.len(), 3);
+        assert!(event.is_none());
+        assert_eq!(team.estimated_hours(), Some(8.0));
+    }
+
+    #[tokio::test]
+    async fn test_plan_team_rejects_an_unbounded_estimate() {
+        use crate::domain::shared::SystemClock;
+
+        let completion = CANNED_ANALYSIS.replace("8.0", "1000000.0");
+        let (manager, _) = manager_with_completions(&[&completion]);
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Build a web scraper".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let result = manager.plan_team(&mut team, &SystemClock).await;
+
+        assert!(matches!(result, Err(AgentError::TaskExecutionFailed(_))));
+        assert_eq!(team.estimated_hours(), None);
+    }
+
+    #[tokio::test]
+    async fn test_with_dry_run_model_switches_to_cheaper_model() {
+        let (manager, llm) = manager_with_completions(&[CANNED_ANALYSIS]);
+        let manager = manager.with_dry_run_model();
+
+        assert_eq!(manager.model, DRY_RUN_MODEL);
+        assert_eq!(manager.pricing, TokenPricing::for_model(DRY_RUN_MODEL));
+
+        // The injected client is kept; only the requested model changes
+        manager.analyze_goal("Build a web scraper").await.unwrap();
+        assert_eq!(llm.models(), [DRY_RUN_MODEL]);
+    }
+
+    fn worker(skills: &[&str], required_tools: &[&str]) -> WorkerAgent {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: skills.iter().map(|s| s.to_string()).collect(),
+            responsibilities: vec![],
+            required_tools: required_tools.iter().map(|t| t.to_string()).collect(),
+        };
+        WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap()
+    }
+
+    #[test]
+    fn test_assign_task_uses_the_managers_skill_matching() {
+        let required = vec!["go".to_string()];
+        let available = HashSet::new();
+        let mut workers = vec![worker(&["Django"], &[])];
+
+        let exact = ManagerAgent::new(Uuid::new_v4());
+        assert!(exact
+            .assign_task(&mut workers, Uuid::new_v4(), &required, &available)
+            .is_err());
+
+        let fuzzy = ManagerAgent::new(Uuid::new_v4()).with_skill_matching(SkillMatching::Fuzzy);
+        let assigned = fuzzy.assign_task(&mut workers, Uuid::new_v4(), &required, &available);
+        assert_eq!(assigned.unwrap(), workers[0].id);
+    }
+
+    #[test]
+    fn test_assign_task_skips_worker_with_unavailable_tool() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers = vec![
+            worker(&["Rust"], &["docker"]),
+            worker(&["Rust"], &["cargo"]),
+        ];
+        let available: HashSet<String> = ["cargo".to_string()].into_iter().collect();
+        let task_id = Uuid::new_v4();
+
+        let assigned = manager
+            .assign_task(&mut workers, task_id, &["Rust".to_string()], &available)
+            .unwrap();
+
+        assert_eq!(assigned, workers[1].id);
+        assert_eq!(workers[1].assigned_task_id, Some(task_id));
+        assert_eq!(workers[0].status, WorkerStatus::Idle);
+    }
+
+    #[test]
+    fn test_assign_task_fails_when_no_worker_has_tools() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers = vec![worker(&["Rust"], &["docker"])];
+
+        let result = manager.assign_task(
+            &mut workers,
+            Uuid::new_v4(),
+            &["Rust".to_string()],
+            &HashSet::new(),
+        );
+
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+        assert!(workers[0].assigned_task_id.is_none());
+    }
+
+    #[test]
+    fn test_assign_task_without_capable_worker() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers = vec![worker(&["Python"], &[])];
+
+        let result = manager.assign_task(
+            &mut workers,
+            Uuid::new_v4(),
+            &["Rust".to_string()],
+            &HashSet::new(),
+        );
+
+        assert!(matches!(result, Err(AgentError::AgentNotFound(_))));
+    }
+
+    #[test]
+    fn test_reassign_task_moves_task_to_idle_worker() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers = vec![worker(&["Rust"], &[]), worker(&["Rust"], &[])];
+        let task_id = Uuid::new_v4();
+        workers[0].assign_task(task_id).unwrap();
+        workers[0].block("build server down");
+
+        let new_worker = manager
+            .reassign_task(&mut workers, task_id, &["Rust".to_string()])
+            .unwrap();
+
+        assert_eq!(new_worker, workers[1].id);
+        assert_eq!(workers[1].assigned_task_id, Some(task_id));
+        assert_eq!(workers[1].status, WorkerStatus::Working);
+        assert!(workers[0].assigned_task_id.is_none());
+        assert_eq!(workers[0].blocked_reason(), Some("build server down"));
+    }
+
+    #[test]
+    fn test_reassign_task_without_replacement() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut workers = vec![worker(&["Rust"], &[]), worker(&["Python"], &[])];
+        let task_id = Uuid::new_v4();
+        workers[0].assign_task(task_id).unwrap();
+
+        let result = manager.reassign_task(&mut workers, task_id, &["Rust".to_string()]);
+
+        assert!(matches!(result, Err(AgentError::AgentNotFound(_))));
+        assert_eq!(workers[0].assigned_task_id, Some(task_id));
+        assert_eq!(workers[0].status, WorkerStatus::Working);
+        assert!(workers[1].assigned_task_id.is_none());
+    }
+
+    fn output(result: serde_json::Value, artifacts: &[&str], logs: &[&str]) -> TaskOutput {
+        TaskOutput {
+            task_id: Uuid::new_v4(),
+            worker_id: Uuid::new_v4(),
+            result,
+            artifacts: artifacts
+                .iter()
+                .map(|path| Artifact::file(*path, *path, None))
+                .collect(),
+            logs: logs.iter().map(|l| l.to_string()).collect(),
+            metadata: serde_json::json!({}),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_reports_counts_successes_and_failures() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let outputs = vec![
+            output(
+                serde_json::json!({"status": "completed"}),
+                &["main.rs"],
+                &["built"],
+            ),
+            output(
+                serde_json::json!({"error": "tests failed"}),
+                &[],
+                &["ran tests", "2 failed"],
+            ),
+            output(
+                serde_json::json!({"status": "completed"}),
+                &["README.md"],
+                &[],
+            ),
+        ];
+
+        let report = manager.aggregate_reports(&outputs).unwrap();
+
+        assert_eq!(report["summary"]["total"], 3);
+        assert_eq!(report["summary"]["succeeded"], 2);
+        assert_eq!(report["summary"]["failed"], 1);
+        assert_eq!(
+            report["failed_tasks"],
+            serde_json::json!([outputs[1].task_id.to_string()])
+        );
+        assert_eq!(
+            report["results"][outputs[1].task_id.to_string()]["error"],
+            "tests failed"
+        );
+        let artifact_names: Vec<_> = report["artifacts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|artifact| artifact["name"].clone())
+            .collect();
+        assert_eq!(artifact_names, ["main.rs", "README.md"]);
+        assert_eq!(report["logs"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            report["logs"][2],
+            format!("[{}] 2 failed", outputs[1].task_id)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_reports_with_no_outputs() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+
+        let report = manager.aggregate_reports(&[]).unwrap();
+
+        assert_eq!(report["summary"]["total"], 0);
+        assert_eq!(report["summary"]["failed"], 0);
+        assert!(report["results"].as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_reports_rejects_oversized_inline_artifacts() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let mut oversized = output(serde_json::json!({}), &[], &[]);
+        oversized.artifacts = vec![Artifact::inline(
+            "dump",
+            "x".repeat(MAX_INLINE_ARTIFACT_BYTES + 1),
+        )];
+
+        let result = manager.aggregate_reports(&[oversized]);
+
+        assert!(matches!(result, Err(AgentError::TaskExecutionFailed(_))));
+    }
+
+    #[test]
+    fn test_aggregate_reports_rejects_duplicate_task_ids() {
+        let manager = ManagerAgent::new(Uuid::new_v4());
+        let first = output(serde_json::json!({}), &[], &[]);
+        let second = TaskOutput {
+            task_id: first.task_id,
+            ..output(serde_json::json!({}), &[], &[])
+        };
+
+        let result = manager.aggregate_reports(&[first, second]);
+
+        assert!(matches!(result, Err(AgentError::TaskExecutionFailed(_))));
+    }
 }
@@ -7,13 +7,18 @@ pub mod manager;
 pub mod worker;
 pub mod types;
 pub mod errors;
+pub mod llm;
+pub mod cancellation;
 pub mod prompts;
 pub mod messages;
 pub mod events;
 pub mod state;
+pub mod pricing;
 
 // Re-export main types
-pub use manager::ManagerAgent;
-pub use worker::WorkerAgent;
-pub use types::{GoalAnalysis, WorkerSpec, TaskOutput};
+pub use manager::{ManagerAgent, WorkerLimits};
+pub use worker::{SkillMatching, WorkerAgent};
+pub use types::{Artifact, ArtifactKind, DecomposedTask, GoalAnalysis, TeamPlan, WorkerSpec, TaskOutput};
 pub use errors::AgentError;
+pub use pricing::ModelPricing;
+pub use llm::{AnthropicClient, Completion, LlmClient, MockLlmClient, TokenPricing, TokenUsage};
@@ -0,0 +1,217 @@
+// Model pricing
+//
+// Per-model token prices for cost accounting. A default table ships with
+// the binary; `MODEL_PRICING_PATH` names a JSON file whose entries add to
+// or replace it, so price changes need no rebuild.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::llm::TokenPricing;
+
+/// Pricing table embedded in the binary
+///
+/// Maps model names to USD prices per input and output token, written as
+/// strings so no precision is lost:
+/// ```json
+/// { "claude-3-5-sonnet-20241022": { "input_per_token": "0.000003", "output_per_token": "0.000015" } }
+/// ```
+pub const DEFAULT_MODEL_PRICING: &str = include_str!("model_pricing.json");
+
+/// Token prices per model name
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelPricing {
+    models: HashMap<String, TokenPricing>,
+}
+
+impl ModelPricing {
+    /// Parses a pricing table in the format of [`DEFAULT_MODEL_PRICING`]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let models: HashMap<String, TokenPricing> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid model pricing: {}", e))?;
+
+        if let Some((model, _)) = models.iter().find(|(_, rate)| {
+            rate.input_per_token.is_sign_negative() || rate.output_per_token.is_sign_negative()
+        }) {
+            return Err(format!("Negative price for model {}", model));
+        }
+
+        Ok(Self { models })
+    }
+
+    /// The default table with the entries of the JSON file at `path` on top
+    pub fn with_overrides_from(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        let mut pricing = Self::default();
+        pricing.models.extend(Self::from_json(&json)?.models);
+        Ok(pricing)
+    }
+
+    /// The default table, overridden by the file at `MODEL_PRICING_PATH`
+    ///
+    /// An unset or empty path means no overrides. A file that cannot be
+    /// read or parsed is logged and ignored, leaving the defaults in place.
+    pub fn from_env() -> Self {
+        Self::with_overrides_path(std::env::var("MODEL_PRICING_PATH").ok().as_deref())
+    }
+
+    fn with_overrides_path(path: Option<&str>) -> Self {
+        let Some(path) = path.map(str::trim).filter(|path| !path.is_empty()) else {
+            return Self::default();
+        };
+
+        Self::with_overrides_from(path).unwrap_or_else(|e| {
+            tracing::warn!(path = %path, "Ignoring model pricing overrides: {}", e);
+            Self::default()
+        })
+    }
+
+    /// The [`from_env`](Self::from_env) table, read once per process
+    pub fn configured() -> &'static Self {
+        static CONFIGURED: OnceLock<ModelPricing> = OnceLock::new();
+        CONFIGURED.get_or_init(Self::from_env)
+    }
+
+    /// Prices for `model`, or `None` (logged) if it is not in the table
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::agents::{ModelPricing, TokenUsage};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rate = ModelPricing::default()
+    ///     .pricing_for("claude-3-5-sonnet-20241022")
+    ///     .unwrap();
+    /// let usage = TokenUsage { input_tokens: 1000, output_tokens: 100 };
+    ///
+    /// assert_eq!(rate.cost(usage), Decimal::new(45, 4));
+    /// ```
+    pub fn pricing_for(&self, model: &str) -> Option<TokenPricing> {
+        let rate = self.models.get(model).copied();
+        if rate.is_none() {
+            tracing::warn!(model = %model, "No pricing configured for model");
+        }
+        rate
+    }
+}
+
+impl TokenPricing {
+    /// Prices billed for `model`'s tokens
+    ///
+    /// Taken from the [configured](ModelPricing::configured) table; models
+    /// missing from it fall back to [`TokenPricing::from_env`].
+    pub fn for_model(model: &str) -> Self {
+        ModelPricing::configured()
+            .pricing_for(model)
+            .unwrap_or_else(Self::from_env)
+    }
+}
+
+impl Default for ModelPricing {
+    fn default() -> Self {
+        Self::from_json(DEFAULT_MODEL_PRICING).expect("embedded model pricing is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::llm::TokenUsage;
+    use crate::agents::manager::{DEFAULT_MODEL, DRY_RUN_MODEL};
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn default_table_prices_the_models_agents_use() {
+        let pricing = ModelPricing::default();
+
+        assert_eq!(
+            pricing.pricing_for(DEFAULT_MODEL),
+            Some(TokenPricing::default())
+        );
+        assert!(pricing.pricing_for(DRY_RUN_MODEL).is_some());
+    }
+
+    #[test]
+    fn unknown_models_have_no_pricing() {
+        assert_eq!(ModelPricing::default().pricing_for("gpt-unknown"), None);
+    }
+
+    #[test]
+    fn overrides_file_replaces_and_adds_models() {
+        let path = std::env::temp_dir().join(format!("pricing-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"{
+                "claude-3-5-sonnet-20241022": { "input_per_token": "0.000002", "output_per_token": "0.00001" },
+                "custom-model": { "input_per_token": "0.000001", "output_per_token": "0.000002" }
+            }"#,
+        )
+        .unwrap();
+
+        let pricing = ModelPricing::with_overrides_from(&path);
+        std::fs::remove_file(&path).unwrap();
+        let pricing = pricing.unwrap();
+
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+        };
+        assert_eq!(
+            pricing.pricing_for(DEFAULT_MODEL).unwrap().cost(usage),
+            Decimal::new(7, 3)
+        );
+        assert_eq!(
+            pricing.pricing_for("custom-model").unwrap().cost(usage),
+            Decimal::new(2, 3)
+        );
+        assert!(pricing.pricing_for(DRY_RUN_MODEL).is_some());
+    }
+
+    #[test]
+    fn negative_and_malformed_prices_are_rejected() {
+        assert!(ModelPricing::from_json(
+            r#"{ "m": { "input_per_token": "-1", "output_per_token": "0" } }"#
+        )
+        .is_err());
+        assert!(ModelPricing::from_json(r#"{ "m": { "input_per_token": "0" } }"#).is_err());
+    }
+
+    #[test]
+    fn missing_overrides_file_is_an_error() {
+        assert!(ModelPricing::with_overrides_from("/nonexistent/pricing.json").is_err());
+    }
+
+    #[test]
+    fn empty_overrides_path_means_no_overrides() {
+        assert_eq!(
+            ModelPricing::with_overrides_path(Some("")),
+            ModelPricing::default()
+        );
+        assert_eq!(
+            ModelPricing::with_overrides_path(Some("  ")),
+            ModelPricing::default()
+        );
+        assert_eq!(
+            ModelPricing::with_overrides_path(None),
+            ModelPricing::default()
+        );
+    }
+
+    #[test]
+    fn models_are_billed_at_their_own_prices() {
+        let pricing = ModelPricing::default();
+
+        assert_eq!(
+            TokenPricing::for_model(DRY_RUN_MODEL),
+            pricing.pricing_for(DRY_RUN_MODEL).unwrap()
+        );
+        assert_ne!(
+            TokenPricing::for_model(DRY_RUN_MODEL),
+            TokenPricing::for_model(DEFAULT_MODEL)
+        );
+    }
+}
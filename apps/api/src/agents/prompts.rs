@@ -12,10 +12,15 @@ pub struct PromptTemplate {
 }
 
 impl PromptTemplate {
-    /// Render the user template with variables
-    pub fn render(&self, _variables: &std::collections::HashMap<String, String>) -> String {
-        // TODO: Implement variable substitution (US-303.12)
-        self.user_template.clone()
+    /// Render the user template, replacing each `{{name}}` with its variable
+    ///
+    /// Placeholders without a matching variable are left as-is.
+    pub fn render(&self, variables: &std::collections::HashMap<String, String>) -> String {
+        variables
+            .iter()
+            .fold(self.user_template.clone(), |rendered, (name, value)| {
+                rendered.replace(&format!("{{{{{}}}}}", name), value)
+            })
     }
 }
 
@@ -63,16 +68,17 @@ pub mod library {
     pub fn task_decomposition() -> PromptTemplate {
         PromptTemplate {
             name: "task_decomposition".to_string(),
-            version: "1.0.0".to_string(),
-            system: "You are breaking down a goal into concrete, actionable tasks."
+            version: "1.1.0".to_string(),
+            system: "You are breaking down a goal into concrete, actionable tasks. \
+                     Respond with a JSON array of tasks and nothing else."
                 .to_string(),
             user_template: "Goal: {{goal}}\n\n\
-                            For each task provide:\n\
-                            - Title\n\
-                            - Detailed description\n\
-                            - Acceptance criteria (3-5 checkable items)\n\
-                            - Required skills\n\
-                            - Estimated tokens/complexity"
+                            Return a JSON array where each task is an object with:\n\
+                            - \"title\": short task title (string)\n\
+                            - \"description\": detailed description (string)\n\
+                            - \"acceptance_criteria\": 3-5 checkable items (array of strings)\n\
+                            - \"required_skills\": skills needed (array of strings)\n\
+                            - \"estimated_tokens\": estimated tokens to complete (integer)"
                 .to_string(),
         }
     }
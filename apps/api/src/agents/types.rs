@@ -1,6 +1,44 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::errors::AgentResult;
+
+/// Fewest acceptance criteria a decomposed task may have
+pub const MIN_ACCEPTANCE_CRITERIA: usize = 3;
+
+/// Most acceptance criteria a decomposed task may have
+pub const MAX_ACCEPTANCE_CRITERIA: usize = 5;
+
+/// Largest inline artifact, in bytes; bigger outputs belong in a file or URL
+pub const MAX_INLINE_ARTIFACT_BYTES: usize = 64 * 1024;
+
+/// Deserialize the JSON value embedded in an LLM completion
+///
+/// Models often wrap JSON in prose or code fences, so only the span from
+/// the first `{` or `[` to the last matching closing bracket is parsed.
+/// Failures are reported as `AgentError::JsonError`.
+pub fn parse_llm_json<T: DeserializeOwned>(completion: &str) -> AgentResult<T> {
+    Ok(serde_json::from_str(json_payload(completion))?)
+}
+
+/// The JSON object or array inside `completion`, or all of it if none
+pub(crate) fn json_payload(completion: &str) -> &str {
+    let Some(start) = completion.find(['{', '[']) else {
+        return completion;
+    };
+    let close = if completion[start..].starts_with('{') {
+        '}'
+    } else {
+        ']'
+    };
+
+    match completion.rfind(close) {
+        Some(end) if start < end => &completion[start..=end],
+        _ => completion,
+    }
+}
+
 /// Analysis of a user's goal by the Manager Agent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoalAnalysis {
@@ -12,6 +50,84 @@ pub struct GoalAnalysis {
     pub success_criteria: Vec<String>,
 }
 
+impl GoalAnalysis {
+    /// Parse and validate a goal analysis returned by the LLM
+    ///
+    /// Beyond the shape enforced by serde, this requires a positive
+    /// timeline, at least one subtask, and only known specializations.
+    /// Violations are reported as `AgentError::JsonError`.
+    pub fn from_llm_json(json: &str) -> AgentResult<Self> {
+        let analysis: GoalAnalysis = serde_json::from_str(json)?;
+        analysis
+            .validate()
+            .map_err(<serde_json::Error as serde::de::Error>::custom)?;
+        Ok(analysis)
+    }
+
+    /// Check the invariants serde cannot express
+    fn validate(&self) -> Result<(), String> {
+        if self.estimated_timeline_hours <= 0.0 {
+            return Err(format!(
+                "estimated_timeline_hours must be positive, got {}",
+                self.estimated_timeline_hours
+            ));
+        }
+        if self.subtasks.is_empty() {
+            return Err("subtasks must not be empty".to_string());
+        }
+        for specialization in &self.required_specializations {
+            specialization.parse::<Specialization>()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A concrete task produced by decomposing a goal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecomposedTask {
+    pub title: String,
+    pub description: String,
+    pub acceptance_criteria: Vec<String>,
+    pub required_skills: Vec<String>,
+    pub estimated_tokens: u32,
+}
+
+impl DecomposedTask {
+    /// Parse and validate the task list returned by the LLM
+    ///
+    /// The completion must contain a JSON array of tasks (see
+    /// `parse_llm_json`). The list must not be empty and every task needs
+    /// 3-5 acceptance criteria. Violations are reported as
+    /// `AgentError::JsonError`.
+    pub fn from_llm_json(completion: &str) -> AgentResult<Vec<Self>> {
+        let tasks: Vec<DecomposedTask> = parse_llm_json(completion)?;
+        if tasks.is_empty() {
+            return Err(
+                <serde_json::Error as serde::de::Error>::custom("tasks must not be empty").into(),
+            );
+        }
+        for task in &tasks {
+            task.validate()
+                .map_err(<serde_json::Error as serde::de::Error>::custom)?;
+        }
+        Ok(tasks)
+    }
+
+    /// Check the invariants serde cannot express
+    fn validate(&self) -> Result<(), String> {
+        let criteria = self.acceptance_criteria.len();
+        if !(MIN_ACCEPTANCE_CRITERIA..=MAX_ACCEPTANCE_CRITERIA).contains(&criteria) {
+            return Err(format!(
+                "task \"{}\" must have {}-{} acceptance criteria, got {}",
+                self.title, MIN_ACCEPTANCE_CRITERIA, MAX_ACCEPTANCE_CRITERIA, criteria
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Specification for a worker agent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerSpec {
@@ -21,13 +137,91 @@ pub struct WorkerSpec {
     pub required_tools: Vec<String>,
 }
 
+/// What a team would look like if launched: the goal analysis and the
+/// workers proposed for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamPlan {
+    pub analysis: GoalAnalysis,
+    pub workers: Vec<WorkerSpec>,
+}
+
+/// Where an artifact's content lives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArtifactKind {
+    /// `location` is a path in the worker's workspace
+    File,
+    /// `location` is a URL
+    Url,
+    /// `location` is the content itself
+    Inline,
+}
+
+/// Something a worker produced while executing a task
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub name: String,
+    pub kind: ArtifactKind,
+    pub location: String,
+    pub size_bytes: Option<u64>,
+}
+
+impl Artifact {
+    /// A file in the worker's workspace
+    pub fn file(name: impl Into<String>, path: impl Into<String>, size_bytes: Option<u64>) -> Self {
+        Self {
+            name: name.into(),
+            kind: ArtifactKind::File,
+            location: path.into(),
+            size_bytes,
+        }
+    }
+
+    /// A resource at `url`, of unknown size
+    pub fn url(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: ArtifactKind::Url,
+            location: url.into(),
+            size_bytes: None,
+        }
+    }
+
+    /// Content carried in the artifact itself, sized from `content`
+    pub fn inline(name: impl Into<String>, content: impl Into<String>) -> Self {
+        let content = content.into();
+        Self {
+            name: name.into(),
+            kind: ArtifactKind::Inline,
+            size_bytes: Some(content.len() as u64),
+            location: content,
+        }
+    }
+
+    /// Check the invariants serde cannot express
+    ///
+    /// Inline content may be at most [`MAX_INLINE_ARTIFACT_BYTES`]; its
+    /// actual length is checked, not the reported `size_bytes`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.kind == ArtifactKind::Inline && self.location.len() > MAX_INLINE_ARTIFACT_BYTES {
+            return Err(format!(
+                "inline artifact \"{}\" is {} bytes, limit is {}",
+                self.name,
+                self.location.len(),
+                MAX_INLINE_ARTIFACT_BYTES
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Output from a worker's task execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskOutput {
     pub task_id: Uuid,
     pub worker_id: Uuid,
     pub result: serde_json::Value,
-    pub artifacts: Vec<String>,
+    pub artifacts: Vec<Artifact>,
     pub logs: Vec<String>,
     pub metadata: serde_json::Value,
 }
@@ -42,6 +236,16 @@ pub enum Specialization {
     Writer,
 }
 
+impl Specialization {
+    /// Whether workers in this role are useless without any skills
+    ///
+    /// Coders and testers are matched to tasks by skill; researchers,
+    /// reviewers, and writers can take general tasks.
+    pub fn requires_skills(&self) -> bool {
+        matches!(self, Specialization::Coder | Specialization::Tester)
+    }
+}
+
 impl std::fmt::Display for Specialization {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -54,12 +258,29 @@ impl std::fmt::Display for Specialization {
     }
 }
 
+impl std::str::FromStr for Specialization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Researcher" => Ok(Specialization::Researcher),
+            "Coder" => Ok(Specialization::Coder),
+            "Reviewer" => Ok(Specialization::Reviewer),
+            "Tester" => Ok(Specialization::Tester),
+            "Writer" => Ok(Specialization::Writer),
+            other => Err(format!("Unknown specialization: {}", other)),
+        }
+    }
+}
+
 /// Worker status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// A blocked worker records why it stalled, for debugging.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WorkerStatus {
     Idle,
     Working,
-    Blocked,
+    Blocked { reason: String },
 }
 
 /// Review decision from Manager Agent
@@ -69,3 +290,200 @@ pub enum ReviewDecision {
     RevisionRequested { feedback: String },
     Rejected { reason: String },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::errors::AgentError;
+    use serde_json::json;
+
+    fn analysis_json() -> serde_json::Value {
+        json!({
+            "core_objective": "Ship the API",
+            "subtasks": ["Design", "Implement"],
+            "required_specializations": ["Coder", "Tester"],
+            "estimated_timeline_hours": 8.0,
+            "potential_blockers": [],
+            "success_criteria": ["Tests pass"]
+        })
+    }
+
+    fn parse_error(value: serde_json::Value) -> String {
+        match GoalAnalysis::from_llm_json(&value.to_string()) {
+            Err(AgentError::JsonError(e)) => e.to_string(),
+            other => panic!("Expected JsonError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_valid_goal_analysis_parses() {
+        let analysis = GoalAnalysis::from_llm_json(&analysis_json().to_string()).unwrap();
+
+        assert_eq!(analysis.subtasks.len(), 2);
+        assert_eq!(analysis.estimated_timeline_hours, 8.0);
+    }
+
+    #[test]
+    fn test_missing_subtasks_rejected() {
+        let mut value = analysis_json();
+        value.as_object_mut().unwrap().remove("subtasks");
+
+        assert!(parse_error(value).contains("missing field `subtasks`"));
+    }
+
+    #[test]
+    fn test_empty_subtasks_rejected() {
+        let mut value = analysis_json();
+        value["subtasks"] = json!([]);
+
+        assert!(parse_error(value).contains("subtasks must not be empty"));
+    }
+
+    #[test]
+    fn test_negative_timeline_rejected() {
+        let mut value = analysis_json();
+        value["estimated_timeline_hours"] = json!(-2.5);
+
+        assert!(parse_error(value).contains("estimated_timeline_hours must be positive"));
+    }
+
+    #[test]
+    fn test_wrong_type_rejected() {
+        let mut value = analysis_json();
+        value["estimated_timeline_hours"] = json!("eight");
+
+        assert!(parse_error(value).contains("invalid type"));
+    }
+
+    #[test]
+    fn test_unknown_specialization_rejected() {
+        let mut value = analysis_json();
+        value["required_specializations"] = json!(["Coder", "Pirate"]);
+
+        assert!(parse_error(value).contains("Unknown specialization: Pirate"));
+    }
+
+    fn decomposition_json() -> serde_json::Value {
+        json!([
+            {
+                "title": "Fetch listing pages",
+                "description": "Download every product listing page",
+                "acceptance_criteria": [
+                    "All pages are fetched",
+                    "Failed requests are retried",
+                    "Responses are cached"
+                ],
+                "required_skills": ["Rust", "HTTP"],
+                "estimated_tokens": 4000
+            },
+            {
+                "title": "Parse listings",
+                "description": "Extract name and price from each page",
+                "acceptance_criteria": [
+                    "Names are extracted",
+                    "Prices are parsed as decimals",
+                    "Malformed pages are reported",
+                    "Output is valid JSON",
+                    "Parser has unit tests"
+                ],
+                "required_skills": ["Rust"],
+                "estimated_tokens": 6000
+            }
+        ])
+    }
+
+    fn decomposition_error(value: serde_json::Value) -> String {
+        match DecomposedTask::from_llm_json(&value.to_string()) {
+            Err(AgentError::JsonError(e)) => e.to_string(),
+            other => panic!("Expected JsonError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_valid_decomposition_parses() {
+        let completion = format!(
+            "Here are the tasks:\n```json\n{:#}\n```",
+            decomposition_json()
+        );
+
+        let tasks = DecomposedTask::from_llm_json(&completion).unwrap();
+
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].title, "Fetch listing pages");
+        assert_eq!(tasks[0].acceptance_criteria.len(), 3);
+        assert_eq!(tasks[1].required_skills, ["Rust"]);
+        assert_eq!(tasks[1].estimated_tokens, 6000);
+    }
+
+    #[test]
+    fn test_too_few_acceptance_criteria_rejected() {
+        let mut value = decomposition_json();
+        value[0]["acceptance_criteria"] = json!(["All pages are fetched", "Responses are cached"]);
+
+        assert!(decomposition_error(value)
+            .contains("task \"Fetch listing pages\" must have 3-5 acceptance criteria, got 2"));
+    }
+
+    #[test]
+    fn test_too_many_acceptance_criteria_rejected() {
+        let mut value = decomposition_json();
+        value[1]["acceptance_criteria"] = json!(["a", "b", "c", "d", "e", "f"]);
+
+        assert!(decomposition_error(value).contains("got 6"));
+    }
+
+    #[test]
+    fn test_empty_decomposition_rejected() {
+        assert!(decomposition_error(json!([])).contains("tasks must not be empty"));
+    }
+
+    #[test]
+    fn test_decomposition_missing_field_rejected() {
+        let mut value = decomposition_json();
+        value[0].as_object_mut().unwrap().remove("title");
+
+        assert!(decomposition_error(value).contains("missing field `title`"));
+    }
+
+    #[test]
+    fn test_artifact_kinds_serialize() {
+        let file = Artifact::file("main.rs", "src/main.rs", Some(120));
+        let url = Artifact::url("report", "https://example.com/report.pdf");
+        let inline = Artifact::inline("summary", "All tests pass");
+
+        assert_eq!(
+            serde_json::to_value(&file).unwrap(),
+            json!({"name": "main.rs", "kind": "File", "location": "src/main.rs", "size_bytes": 120})
+        );
+        assert_eq!(
+            serde_json::to_value(&url).unwrap(),
+            json!({
+                "name": "report",
+                "kind": "Url",
+                "location": "https://example.com/report.pdf",
+                "size_bytes": null
+            })
+        );
+        assert_eq!(inline.size_bytes, Some(14));
+
+        let round_trip: Artifact =
+            serde_json::from_value(serde_json::to_value(&inline).unwrap()).unwrap();
+        assert_eq!(round_trip, inline);
+    }
+
+    #[test]
+    fn test_inline_artifact_over_limit_rejected() {
+        let at_limit = Artifact::inline("blob", "a".repeat(MAX_INLINE_ARTIFACT_BYTES));
+        let over_limit = Artifact::inline("blob", "a".repeat(MAX_INLINE_ARTIFACT_BYTES + 1));
+
+        assert!(at_limit.validate().is_ok());
+        assert!(over_limit
+            .validate()
+            .unwrap_err()
+            .contains("inline artifact \"blob\""));
+        // Only inline content is limited
+        assert!(Artifact::file("big.bin", "out/big.bin", Some(u64::MAX))
+            .validate()
+            .is_ok());
+    }
+}
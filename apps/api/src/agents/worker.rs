@@ -1,12 +1,62 @@
+use std::collections::HashSet;
+
 use uuid::Uuid;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 
 use super::types::{WorkerSpec, WorkerStatus, TaskOutput, Specialization};
 use super::errors::{AgentError, AgentResult};
+use super::cancellation::run_cancellable;
+
+/// Current serialization schema version for `WorkerAgent`
+pub const WORKER_SCHEMA_VERSION: u32 = 2;
+
+/// Reason given to blocked workers loaded without a recorded reason
+pub const UNKNOWN_BLOCK_REASON: &str = "unknown (not recorded)";
+
+fn default_schema_version() -> u32 {
+    WORKER_SCHEMA_VERSION
+}
+
+/// How required skills are compared with a worker's skills
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SkillMatching {
+    /// Whole words, ignoring case: every word of the required skill must
+    /// appear in one of the worker's skills, so "Go" does not match "Django"
+    #[default]
+    Exact,
+    /// Substrings, ignoring case: "Go" matches "Django"
+    Fuzzy,
+}
+
+impl SkillMatching {
+    /// Whether a worker with skill `skill` satisfies `required`
+    pub fn matches(self, skill: &str, required: &str) -> bool {
+        match self {
+            SkillMatching::Exact => {
+                let tokens: HashSet<String> = skill_tokens(skill).collect();
+                let mut required = skill_tokens(required).peekable();
+                required.peek().is_some() && required.all(|token| tokens.contains(&token))
+            }
+            SkillMatching::Fuzzy => skill.to_lowercase().contains(&required.to_lowercase()),
+        }
+    }
+}
+
+/// Lowercased words of a skill, split on whitespace and commas
+fn skill_tokens(skill: &str) -> impl Iterator<Item = String> + '_ {
+    skill
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
 
 /// Worker Agent that executes specific tasks based on specialization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerAgent {
+    /// Schema version of the serialized form (payloads without it are v0)
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     pub id: Uuid,
     pub team_id: Uuid,
     pub specialization: Specialization,
@@ -19,17 +69,30 @@ pub struct WorkerAgent {
 
 impl WorkerAgent {
     /// Create a Worker Agent from a WorkerSpec
-    pub fn from_spec(team_id: Uuid, spec: &WorkerSpec) -> Self {
-        let specialization = match spec.specialization.as_str() {
-            "Researcher" => Specialization::Researcher,
-            "Coder" => Specialization::Coder,
-            "Reviewer" => Specialization::Reviewer,
-            "Tester" => Specialization::Tester,
-            "Writer" => Specialization::Writer,
-            _ => Specialization::Researcher, // Default
-        };
+    ///
+    /// Unknown specializations fall back to `Researcher`, logging a warning
+    /// with the unrecognized value. Roles that need skills to be useful
+    /// (see [`Specialization::requires_skills`]) fail with
+    /// `AgentError::ConfigError` when the spec lists none.
+    pub fn from_spec(team_id: Uuid, spec: &WorkerSpec) -> AgentResult<Self> {
+        let specialization: Specialization = spec.specialization.parse().unwrap_or_else(|_| {
+            tracing::warn!(
+                specialization = %spec.specialization,
+                "Unrecognized worker specialization, defaulting to Researcher"
+            );
+            Specialization::Researcher
+        });
+
+        let has_skill = spec.skills.iter().any(|skill| !skill.trim().is_empty());
+        if specialization.requires_skills() && !has_skill {
+            return Err(AgentError::ConfigError(format!(
+                "{} workers need at least one skill",
+                specialization
+            )));
+        }
 
-        Self {
+        Ok(Self {
+            schema_version: WORKER_SCHEMA_VERSION,
             id: Uuid::new_v4(),
             team_id,
             specialization,
@@ -38,7 +101,44 @@ impl WorkerAgent {
             required_tools: spec.required_tools.clone(),
             status: WorkerStatus::Idle,
             assigned_task_id: None,
+        })
+    }
+
+    /// Load a worker from persisted JSON, upgrading older payloads
+    ///
+    /// Payloads written before versioning (v0) have no `schema_version`
+    /// and are upgraded in place, as are v1 payloads whose blocked status
+    /// has no reason. Returns `AgentError::ConfigError` for
+    /// payloads from a newer schema than this build understands.
+    pub fn from_json(json: &str) -> AgentResult<Self> {
+        let mut payload: serde_json::Value = serde_json::from_str(json)?;
+        let version = payload
+            .get("schema_version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+
+        if version > u64::from(WORKER_SCHEMA_VERSION) {
+            return Err(AgentError::ConfigError(format!(
+                "Worker payload has schema version {}, newest supported is {}",
+                version, WORKER_SCHEMA_VERSION
+            )));
+        }
+
+        // v0 -> v1: same fields, only the version tag is added
+        // v1 -> v2: `"Blocked"` becomes `{"Blocked": {"reason": ...}}`
+        if version < 2 {
+            if let Some(object) = payload.as_object_mut() {
+                if object.get("status").and_then(serde_json::Value::as_str) == Some("Blocked") {
+                    object.insert(
+                        "status".to_string(),
+                        serde_json::json!({ "Blocked": { "reason": UNKNOWN_BLOCK_REASON } }),
+                    );
+                }
+                object.insert("schema_version".to_string(), WORKER_SCHEMA_VERSION.into());
+            }
         }
+
+        Ok(serde_json::from_value(payload)?)
     }
 
     /// Assign a task to this worker
@@ -54,22 +154,146 @@ impl WorkerAgent {
         Ok(())
     }
 
+    /// Assign a task only if every required tool is available
+    ///
+    /// Returns `AgentError::ConfigError` naming the missing tools otherwise.
+    pub fn assign_task_with_tools(
+        &mut self,
+        task_id: Uuid,
+        available: &HashSet<String>,
+    ) -> AgentResult<()> {
+        let missing = self.missing_tools(available);
+        if !missing.is_empty() {
+            return Err(AgentError::ConfigError(format!(
+                "Worker {} is missing required tools: {}",
+                self.id,
+                missing.join(", ")
+            )));
+        }
+
+        self.assign_task(task_id)
+    }
+
+    /// Release this worker's current task, returning its ID
+    ///
+    /// A working worker becomes idle again; a blocked worker stays blocked
+    /// so it is not handed new work until it recovers.
+    pub fn unassign_task(&mut self) -> Option<Uuid> {
+        if self.status == WorkerStatus::Working {
+            self.status = WorkerStatus::Idle;
+        }
+        self.assigned_task_id.take()
+    }
+
+    /// Mark this worker as blocked, recording why
+    ///
+    /// Any assigned task stays assigned so the manager can see what stalled
+    /// and reassign it.
+    pub fn block(&mut self, reason: impl Into<String>) {
+        self.status = WorkerStatus::Blocked {
+            reason: reason.into(),
+        };
+    }
+
+    /// Why this worker is blocked, if it is
+    pub fn blocked_reason(&self) -> Option<&str> {
+        match &self.status {
+            WorkerStatus::Blocked { reason } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Required tools that are not in `available`
+    pub fn missing_tools(&self, available: &HashSet<String>) -> Vec<String> {
+        self.required_tools
+            .iter()
+            .filter(|tool| !available.contains(*tool))
+            .cloned()
+            .collect()
+    }
+
     /// Get the current status of this worker
-    pub fn get_status(&self) -> WorkerStatus {
-        self.status
+    pub fn get_status(&self) -> &WorkerStatus {
+        &self.status
     }
 
     /// Check if this worker can handle a task based on required skills
+    ///
+    /// Skills are compared word by word (see [`SkillMatching::Exact`]).
     pub fn can_handle_task(&self, required_skills: &[String]) -> bool {
-        required_skills.iter().any(|req_skill| {
-            self.skills.iter().any(|skill| skill.to_lowercase().contains(&req_skill.to_lowercase()))
+        self.can_handle_task_with(required_skills, SkillMatching::Exact)
+    }
+
+    /// Check if this worker has any of `required_skills`, compared by `matching`
+    pub fn can_handle_task_with(
+        &self,
+        required_skills: &[String],
+        matching: SkillMatching,
+    ) -> bool {
+        required_skills.iter().any(|required| {
+            self.skills
+                .iter()
+                .any(|skill| matching.matches(skill, required))
         })
     }
 
+    /// Add a skill unless the worker already has it
+    ///
+    /// Skills are trimmed and compared case-insensitively, so adding a
+    /// duplicate (or a blank skill) is a no-op.
+    pub fn add_skill(&mut self, skill: String) {
+        let skill = skill.trim();
+        if skill.is_empty() || self.has_skill(skill) {
+            return;
+        }
+        self.skills.push(skill.to_string());
+    }
+
+    /// Remove a skill, matched case-insensitively; a no-op if absent
+    pub fn remove_skill(&mut self, skill: &str) {
+        let skill = skill.trim().to_lowercase();
+        self.skills.retain(|existing| existing.to_lowercase() != skill);
+    }
+
+    /// Remove then add skills, as [`remove_skill`](Self::remove_skill) and
+    /// [`add_skill`](Self::add_skill) do
+    ///
+    /// Fails with `AgentError::ConfigError`, leaving the skills unchanged,
+    /// if a role that needs skills would be left without any.
+    pub fn change_skills(&mut self, add: &[String], remove: &[String]) -> AgentResult<()> {
+        let previous = self.skills.clone();
+        for skill in remove {
+            self.remove_skill(skill);
+        }
+        for skill in add {
+            self.add_skill(skill.clone());
+        }
+
+        if self.specialization.requires_skills() && self.skills.is_empty() {
+            self.skills = previous;
+            return Err(AgentError::ConfigError(format!(
+                "{} workers need at least one skill",
+                self.specialization
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Whether the worker has `skill`, ignoring case
+    fn has_skill(&self, skill: &str) -> bool {
+        let skill = skill.to_lowercase();
+        self.skills.iter().any(|existing| existing.to_lowercase() == skill)
+    }
+
     /// Execute a task (stub implementation - will be fleshed out in Sprint 4)
+    ///
+    /// Stops with `AgentError::TaskExecutionFailed("cancelled")` when
+    /// `cancel` (normally the team manager's token) is triggered.
     pub async fn execute_task(
         &mut self,
         task_id: Uuid,
+        cancel: &CancellationToken,
     ) -> AgentResult<TaskOutput> {
         // TODO: Implement actual task execution in Sprint 4
         if self.assigned_task_id.is_none() {
@@ -78,22 +302,32 @@ impl WorkerAgent {
             ));
         }
 
-        // Mock output for now
-        Ok(TaskOutput {
-            task_id,
-            worker_id: self.id,
-            result: serde_json::json!({"status": "completed"}),
-            artifacts: vec![],
-            logs: vec!["Task executed successfully".to_string()],
-            metadata: serde_json::json!({}),
+        let worker_id = self.id;
+        run_cancellable(cancel, async move {
+            // Mock output for now
+            Ok(TaskOutput {
+                task_id,
+                worker_id,
+                result: serde_json::json!({"status": "completed"}),
+                artifacts: vec![],
+                logs: vec!["Task executed successfully".to_string()],
+                metadata: serde_json::json!({}),
+            })
         })
+        .await
     }
 
     /// Report progress to the Manager
+    ///
+    /// Blocked workers include the reason they are blocked.
     pub async fn report_progress(&self) -> AgentResult<String> {
+        let status = match &self.status {
+            WorkerStatus::Blocked { reason } => format!("Blocked ({})", reason),
+            status => format!("{:?}", status),
+        };
         Ok(format!(
-            "Worker {} ({}) - Status: {:?}, Task: {:?}",
-            self.id, self.specialization, self.status, self.assigned_task_id
+            "Worker {} ({}) - Status: {}, Task: {:?}",
+            self.id, self.specialization, status, self.assigned_task_id
         ))
     }
 }
@@ -112,7 +346,7 @@ mod tests {
             required_tools: vec!["cargo".to_string()],
         };
 
-        let worker = WorkerAgent::from_spec(team_id, &spec);
+        let worker = WorkerAgent::from_spec(team_id, &spec).unwrap();
 
         assert_eq!(worker.team_id, team_id);
         assert_eq!(worker.specialization, Specialization::Coder);
@@ -120,17 +354,124 @@ mod tests {
         assert_eq!(worker.skills.len(), 2);
     }
 
+    #[test]
+    fn test_coder_without_skills_is_rejected() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec![" ".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+
+        let result = WorkerAgent::from_spec(Uuid::new_v4(), &spec);
+
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_coder_with_skills_is_accepted() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+
+        assert!(WorkerAgent::from_spec(Uuid::new_v4(), &spec).is_ok());
+    }
+
+    #[test]
+    fn test_researcher_may_have_no_skills() {
+        let spec = WorkerSpec {
+            specialization: "Researcher".to_string(),
+            skills: vec![],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+
+        assert!(WorkerAgent::from_spec(Uuid::new_v4(), &spec).is_ok());
+    }
+
+    #[test]
+    fn test_add_skill_ignores_case_insensitive_duplicates() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        worker.add_skill("rust".to_string());
+        worker.add_skill("  ".to_string());
+        worker.add_skill(" SQL ".to_string());
+        worker.add_skill("sql".to_string());
+
+        assert_eq!(worker.skills, vec!["Rust".to_string(), "SQL".to_string()]);
+    }
+
+    #[test]
+    fn test_remove_skill_matches_case_insensitively() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string(), "SQL".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        worker.remove_skill("RUST");
+        worker.remove_skill("Go");
+
+        assert_eq!(worker.skills, vec!["SQL".to_string()]);
+    }
+
+    #[test]
+    fn test_change_skills_keeps_coders_skilled() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        let result = worker.change_skills(&[], &["rust".to_string()]);
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+        assert_eq!(worker.skills, vec!["Rust".to_string()]);
+
+        // Replacing the last skill is fine
+        worker
+            .change_skills(&["Go".to_string()], &["rust".to_string()])
+            .unwrap();
+        assert_eq!(worker.skills, vec!["Go".to_string()]);
+    }
+
+    #[test]
+    fn test_change_skills_may_empty_a_researcher() {
+        let spec = WorkerSpec {
+            specialization: "Researcher".to_string(),
+            skills: vec!["Papers".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        worker.change_skills(&[], &["papers".to_string()]).unwrap();
+        assert!(worker.skills.is_empty());
+    }
+
     #[test]
     fn test_assign_task() {
         let team_id = Uuid::new_v4();
         let spec = WorkerSpec {
             specialization: "Tester".to_string(),
-            skills: vec![],
+            skills: vec!["Integration testing".to_string()],
             responsibilities: vec![],
             required_tools: vec![],
         };
 
-        let mut worker = WorkerAgent::from_spec(team_id, &spec);
+        let mut worker = WorkerAgent::from_spec(team_id, &spec).unwrap();
         let task_id = Uuid::new_v4();
 
         let result = worker.assign_task(task_id);
@@ -149,10 +490,309 @@ mod tests {
             required_tools: vec![],
         };
 
-        let worker = WorkerAgent::from_spec(team_id, &spec);
+        let worker = WorkerAgent::from_spec(team_id, &spec).unwrap();
+
+        assert!(worker.can_handle_task(&["Rust".to_string()]));
+        assert!(worker.can_handle_task(&["Python".to_string()]));
+        assert!(!worker.can_handle_task(&["JavaScript".to_string()]));
+    }
+
+    fn worker_with_skills(skills: &[&str]) -> WorkerAgent {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: skills.iter().map(|skill| skill.to_string()).collect(),
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap()
+    }
+
+    #[test]
+    fn test_exact_matching_compares_whole_words() {
+        let django = worker_with_skills(&["Django"]);
+        let go = worker_with_skills(&["Go", "Rust"]);
+
+        assert!(!django.can_handle_task(&["Go".to_string()]));
+        assert!(go.can_handle_task(&["Go".to_string()]));
+        assert!(go.can_handle_task(&["rust".to_string()]));
+    }
+
+    #[test]
+    fn test_exact_matching_splits_on_whitespace_and_commas() {
+        let worker = worker_with_skills(&["Backend, Rust  async"]);
+
+        assert!(worker.can_handle_task(&["async rust".to_string()]));
+        assert!(!worker.can_handle_task(&["async python".to_string()]));
+        assert!(!worker.can_handle_task(&[" , ".to_string()]));
+    }
+
+    #[test]
+    fn test_fuzzy_matching_keeps_substring_behavior() {
+        let django = worker_with_skills(&["Django"]);
+
+        assert!(django.can_handle_task_with(&["go".to_string()], SkillMatching::Fuzzy));
+        assert!(!django.can_handle_task_with(&["rails".to_string()], SkillMatching::Fuzzy));
+    }
+
+    fn tools(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_missing_tools() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec!["cargo".to_string(), "git".to_string()],
+        };
+
+        let worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        assert!(worker
+            .missing_tools(&tools(&["cargo", "git", "npm"]))
+            .is_empty());
+        assert_eq!(
+            worker.missing_tools(&tools(&["cargo"])),
+            vec!["git".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_assign_task_refuses_unavailable_tools() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec!["cargo".to_string()],
+        };
+
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        let result = worker.assign_task_with_tools(Uuid::new_v4(), &tools(&["git"]));
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+        assert_eq!(worker.status, WorkerStatus::Idle);
+        assert!(worker.assigned_task_id.is_none());
+    }
+
+    #[test]
+    fn test_unassign_task_keeps_blocked_worker_blocked() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let task_id = Uuid::new_v4();
+
+        let mut working = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        working.assign_task(task_id).unwrap();
+        assert_eq!(working.unassign_task(), Some(task_id));
+        assert_eq!(working.status, WorkerStatus::Idle);
+
+        let mut blocked = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        blocked.assign_task(task_id).unwrap();
+        blocked.block("waiting on credentials");
+        assert_eq!(blocked.unassign_task(), Some(task_id));
+        assert_eq!(blocked.blocked_reason(), Some("waiting on credentials"));
+        assert!(blocked.assigned_task_id.is_none());
+    }
+
+    #[test]
+    fn test_v0_payload_loads_as_current_version() {
+        let json = serde_json::json!({
+            "id": Uuid::new_v4(),
+            "team_id": Uuid::new_v4(),
+            "specialization": "Coder",
+            "skills": ["Rust"],
+            "responsibilities": [],
+            "required_tools": [],
+            "status": "Idle",
+            "assigned_task_id": null
+        })
+        .to_string();
+
+        let direct: WorkerAgent = serde_json::from_str(&json).unwrap();
+        assert_eq!(direct.schema_version, WORKER_SCHEMA_VERSION);
+
+        let worker = WorkerAgent::from_json(&json).unwrap();
+        assert_eq!(worker.schema_version, WORKER_SCHEMA_VERSION);
+        assert_eq!(worker.specialization, Specialization::Coder);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_schema_version() {
+        let spec = WorkerSpec {
+            specialization: "Tester".to_string(),
+            skills: vec!["Integration testing".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+
+        let json = serde_json::to_string(&worker).unwrap();
+        assert!(json.contains(&format!("\"schema_version\":{}", WORKER_SCHEMA_VERSION)));
+
+        let loaded = WorkerAgent::from_json(&json).unwrap();
+        assert_eq!(loaded.id, worker.id);
+    }
+
+    #[test]
+    fn test_newer_schema_version_is_rejected() {
+        let spec = WorkerSpec {
+            specialization: "Tester".to_string(),
+            skills: vec!["Integration testing".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        worker.schema_version = WORKER_SCHEMA_VERSION + 1;
+
+        let json = serde_json::to_string(&worker).unwrap();
+        let result = WorkerAgent::from_json(&json);
+
+        assert!(matches!(result, Err(AgentError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_block_records_reason() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        assert_eq!(worker.blocked_reason(), None);
+
+        worker.block("rate limited by provider");
+
+        assert_eq!(
+            worker.status,
+            WorkerStatus::Blocked {
+                reason: "rate limited by provider".to_string()
+            }
+        );
+        assert_eq!(worker.blocked_reason(), Some("rate limited by provider"));
+        assert!(worker.assign_task(Uuid::new_v4()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_progress_report_includes_block_reason() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        worker.block("tests keep timing out");
+
+        let report = worker.report_progress().await.unwrap();
+
+        assert!(report.contains("Status: Blocked (tests keep timing out)"));
+    }
+
+    #[test]
+    fn test_block_reason_survives_round_trip() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        worker.block("disk full");
+
+        let json = serde_json::to_string(&worker).unwrap();
+        let loaded = WorkerAgent::from_json(&json).unwrap();
+
+        assert_eq!(loaded.blocked_reason(), Some("disk full"));
+    }
+
+    #[test]
+    fn test_v1_blocked_payload_gets_unknown_reason() {
+        let json = serde_json::json!({
+            "schema_version": 1,
+            "id": Uuid::new_v4(),
+            "team_id": Uuid::new_v4(),
+            "specialization": "Coder",
+            "skills": [],
+            "responsibilities": [],
+            "required_tools": [],
+            "status": "Blocked",
+            "assigned_task_id": null
+        })
+        .to_string();
+
+        let worker = WorkerAgent::from_json(&json).unwrap();
+
+        assert_eq!(worker.schema_version, WORKER_SCHEMA_VERSION);
+        assert_eq!(worker.blocked_reason(), Some(UNKNOWN_BLOCK_REASON));
+    }
+
+    fn capture_logs(f: impl FnOnce()) -> String {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, f);
+
+        let bytes = capture.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_unknown_specialization_warns_and_defaults_to_researcher() {
+        let spec = WorkerSpec {
+            specialization: "Astronaut".to_string(),
+            skills: vec![],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+        let mut worker = None;
+
+        let output =
+            capture_logs(|| worker = Some(WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap()));
+
+        assert_eq!(worker.unwrap().specialization, Specialization::Researcher);
+        assert!(output.contains("WARN"));
+        assert!(output.contains("specialization=Astronaut"));
+    }
+
+    #[test]
+    fn test_known_specialization_does_not_warn() {
+        let spec = WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        };
+
+        let output = capture_logs(|| {
+            WorkerAgent::from_spec(Uuid::new_v4(), &spec).unwrap();
+        });
 
-        assert!(worker.can_handle_task(&vec!["Rust".to_string()]));
-        assert!(worker.can_handle_task(&vec!["Python".to_string()]));
-        assert!(!worker.can_handle_task(&vec!["JavaScript".to_string()]));
+        assert!(output.is_empty());
     }
 }
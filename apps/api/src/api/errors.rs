@@ -1,15 +1,29 @@
 use axum::{
-    http::StatusCode,
+    extract::rejection::JsonRejection,
+    http::{header::RETRY_AFTER, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use serde_json::json;
 
+use crate::api::messages::{current_language, template, ErrorCode};
+use crate::domain::repositories::RepositoryError;
+use crate::domain::shared::DomainError;
+
+/// Seconds clients should wait before retrying after pool exhaustion
+pub const POOL_TIMEOUT_RETRY_AFTER_SECS: u64 = 5;
+
 /// API error type with HTTP status code and message
 #[derive(Debug)]
 pub struct ApiError {
     pub status: StatusCode,
     pub message: String,
+    /// Machine-readable error code for clients that branch on the failure
+    ///
+    /// Coded errors render a localized template instead of `message`.
+    pub code: Option<ErrorCode>,
+    /// Seconds to advertise in a `Retry-After` header
+    pub retry_after: Option<u64>,
 }
 
 impl ApiError {
@@ -18,9 +32,17 @@ impl ApiError {
         Self {
             status,
             message: message.into(),
+            code: None,
+            retry_after: None,
         }
     }
 
+    /// Attaches a machine-readable error code to the response body
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
     /// Creates a 400 Bad Request error
     pub fn bad_request(message: impl Into<String>) -> Self {
         Self::new(StatusCode::BAD_REQUEST, message)
@@ -31,11 +53,72 @@ impl ApiError {
         Self::new(StatusCode::UNAUTHORIZED, message)
     }
 
+    /// Creates a 403 Forbidden error
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, message)
+    }
+
     /// Creates a 404 Not Found error
     pub fn not_found(message: impl Into<String>) -> Self {
         Self::new(StatusCode::NOT_FOUND, message)
     }
 
+    /// Creates a 409 Conflict error
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, message)
+    }
+
+    /// Creates a 423 Locked error with a `Retry-After` header
+    pub fn locked(message: impl Into<String>, retry_after_secs: u64) -> Self {
+        let mut error = Self::new(StatusCode::LOCKED, message);
+        error.retry_after = Some(retry_after_secs);
+        error
+    }
+
+    /// Creates a 503 Service Unavailable error with a `Retry-After` hint
+    pub fn service_unavailable(message: impl Into<String>, retry_after_secs: u64) -> Self {
+        let mut error = Self::new(StatusCode::SERVICE_UNAVAILABLE, message);
+        error.retry_after = Some(retry_after_secs);
+        error
+    }
+
+    /// Creates a 429 Too Many Requests with a `Retry-After` header
+    pub fn too_many_requests(message: impl Into<String>, retry_after_secs: u64) -> Self {
+        let mut error = Self::new(StatusCode::TOO_MANY_REQUESTS, message);
+        error.retry_after = Some(retry_after_secs);
+        error
+    }
+
+    /// Maps a repository error message to an API error
+    ///
+    /// Repositories report failures as strings that embed the underlying
+    /// `sqlx::Error`. Pool exhaustion is transient, so it becomes a
+    /// retryable 503; anything else is a 500 prefixed with `context`.
+    pub fn repository(context: &str, error: impl Into<String>) -> Self {
+        let error = error.into();
+        if error.contains(&sqlx::Error::PoolTimedOut.to_string()) {
+            tracing::warn!("{}: {}", context, error);
+            return Self::service_unavailable(
+                "Service temporarily unavailable, please retry",
+                POOL_TIMEOUT_RETRY_AFTER_SECS,
+            )
+            .with_code(ErrorCode::ServiceUnavailable);
+        }
+
+        Self::internal_server_error(format!("{}: {}", context, error))
+    }
+
+    /// Maps a typed repository error to an API error
+    ///
+    /// A missing row is a 404, such as a team deleted between being loaded
+    /// and being written; query failures go through [`ApiError::repository`].
+    pub fn from_repository(context: &str, error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound { .. } => Self::not_found(error.to_string()),
+            RepositoryError::Database(e) => Self::repository(context, e),
+        }
+    }
+
     /// Creates a 500 Internal Server Error
     pub fn internal_server_error(message: impl Into<String>) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
@@ -43,12 +126,24 @@ impl ApiError {
 }
 
 impl IntoResponse for ApiError {
+    /// Renders the error body in the request's negotiated language
     fn into_response(self) -> Response {
-        let body = Json(json!({
-            "error": self.message
-        }));
+        let body = match self.code {
+            Some(code) => Json(json!({
+                "error": template(code, current_language()),
+                "code": code
+            })),
+            None => Json(json!({
+                "error": self.message
+            })),
+        };
 
-        (self.status, body).into_response()
+        let mut response = (self.status, body).into_response();
+        if let Some(secs) = self.retry_after {
+            response.headers_mut().insert(RETRY_AFTER, secs.into());
+        }
+
+        response
     }
 }
 
@@ -63,3 +158,105 @@ impl From<&str> for ApiError {
         Self::internal_server_error(message)
     }
 }
+
+impl DomainError {
+    /// Status reporting this error: 400 for validation, 409 for conflicts
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            DomainError::Validation(_) => StatusCode::BAD_REQUEST,
+            DomainError::Conflict(_) => StatusCode::CONFLICT,
+        }
+    }
+}
+
+impl From<DomainError> for ApiError {
+    fn from(error: DomainError) -> Self {
+        Self::new(error.status_code(), error.to_string())
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    /// Malformed request bodies are client errors, so report them as 400
+    fn from(rejection: JsonRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_timeout_maps_to_503_with_retry_after() {
+        let error = ApiError::repository(
+            "Database error",
+            format!("Failed to find team: {}", sqlx::Error::PoolTimedOut),
+        );
+
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            response.headers().get(RETRY_AFTER).unwrap(),
+            &POOL_TIMEOUT_RETRY_AFTER_SECS.to_string()
+        );
+    }
+
+    #[test]
+    fn other_repository_errors_map_to_500() {
+        let error = ApiError::repository("Database error", "Failed to find team: boom");
+
+        assert_eq!(error.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error.message, "Database error: Failed to find team: boom");
+        assert!(error.into_response().headers().get(RETRY_AFTER).is_none());
+    }
+
+    #[test]
+    fn missing_rows_map_to_404() {
+        let id = uuid::Uuid::new_v4();
+        let error = ApiError::from_repository(
+            "Failed to delete team",
+            RepositoryError::NotFound { entity: "Team", id },
+        );
+
+        assert_eq!(error.status, StatusCode::NOT_FOUND);
+        assert_eq!(error.message, format!("Team not found: {}", id));
+    }
+
+    #[test]
+    fn typed_query_failures_map_like_repository_messages() {
+        let error = ApiError::from_repository(
+            "Failed to delete team",
+            RepositoryError::Database("boom".to_string()),
+        );
+        assert_eq!(error.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error.message, "Failed to delete team: boom");
+
+        let error = ApiError::from_repository(
+            "Failed to delete team",
+            RepositoryError::Database(sqlx::Error::PoolTimedOut.to_string()),
+        );
+        assert_eq!(error.status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn domain_validation_errors_map_to_400() {
+        use crate::domain::team::Team;
+        use uuid::Uuid;
+
+        let error =
+            Team::new_now(Uuid::new_v4(), "  ".to_string(), Uuid::new_v4(), None).unwrap_err();
+
+        let error = ApiError::from(error);
+        assert_eq!(error.status, StatusCode::BAD_REQUEST);
+        assert_eq!(error.message, "Goal cannot be empty");
+    }
+
+    #[test]
+    fn domain_conflicts_map_to_409() {
+        let error = DomainError::Conflict("Team limit reached".to_string());
+
+        assert_eq!(error.status_code(), StatusCode::CONFLICT);
+        assert_eq!(ApiError::from(error).status, StatusCode::CONFLICT);
+    }
+}
@@ -1,76 +1,167 @@
-use axum::{extract::State, http::StatusCode, Json};
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, Extension, Json};
+use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::api::errors::ApiError;
-use crate::auth::jwt::create_token;
-use crate::auth::password::{hash_password, verify_password};
+use crate::api::login_dedup::LoginDeduplicator;
+use crate::api::messages::ErrorCode;
+use crate::api::middleware::auth::token_revoked;
+use crate::api::middleware::{ClientIp, InternalService};
+use crate::api::uuid_format;
+use crate::auth::jwt::{create_token_now, verify_token};
+use crate::auth::lockout::LoginLockout;
+use crate::auth::password::{
+    hash_password, validate_password_strength, verify_login, BcryptHasher,
+};
+use crate::auth::reset_token::{generate_reset_token, hash_reset_token, RESET_TOKEN_TTL_MINUTES};
+use crate::domain::repositories::password_reset_repository::{
+    PasswordResetRepository, PasswordResetToken,
+};
 use crate::domain::repositories::user_repository::{User, UserRepository};
-use crate::domain::user::value_objects::Email;
-use crate::infrastructure::repositories::PostgresUserRepository;
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::user::value_objects::{Email, UserRole};
+use crate::infrastructure::background_tasks::BackgroundTasks;
+use crate::infrastructure::captcha::CaptchaVerifier;
+use crate::infrastructure::repositories::{
+    PostgresLoginAttemptRepository, PostgresPasswordResetRepository, PostgresUserRepository,
+};
 
 /// Request body for user registration
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RegisterRequest {
     pub email: String,
     pub password: String,
     pub full_name: String,
     pub company_id: Uuid,
+    /// Token from the CAPTCHA widget; only required when a provider is configured
+    #[serde(default)]
+    pub captcha_token: Option<String>,
 }
 
 /// Response from successful registration
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct RegisterResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
     pub user_id: Uuid,
     pub message: String,
 }
 
 /// Request body for user login
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LoginRequest {
     pub email: String,
     pub password: String,
 }
 
 /// Response from successful login
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct LoginResponse {
     pub token: String,
+    #[serde(serialize_with = "uuid_format::serialize")]
     pub user_id: Uuid,
 }
 
+/// Request body for starting a password reset
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ForgotPasswordRequest {
+    pub email: String,
+}
+
+/// Request body for completing a password reset
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Request body for token introspection
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IntrospectRequest {
+    pub token: String,
+}
+
+/// Token introspection result (RFC 7662 style)
+///
+/// Inactive tokens carry only `active: false`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntrospectResponse {
+    pub active: bool,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "uuid_format::serialize_option"
+    )]
+    pub sub: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<usize>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "uuid_format::serialize_option"
+    )]
+    pub company_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, example = "member")]
+    pub role: Option<UserRole>,
+}
+
+/// Generic acknowledgement response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageResponse {
+    pub message: String,
+}
+
 /// Register a new user
 ///
 /// POST /api/auth/register
+///
+/// The CAPTCHA token is checked before anything else, so bots cannot
+/// use this endpoint to probe which emails are taken.
+#[utoipa::path(
+    post,
+    path = "/api/auth/register",
+    tag = "auth",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "User registered", body = RegisterResponse),
+        (status = 400, description = "Invalid input, email taken, or CAPTCHA failed", body = ErrorResponse),
+        (status = 429, description = "Too many registrations from this client", body = ErrorResponse),
+        (status = 503, description = "CAPTCHA provider unavailable", body = ErrorResponse),
+    )
+)]
 pub async fn register(
     State(pool): State<PgPool>,
+    Extension(captcha): Extension<Arc<dyn CaptchaVerifier>>,
     Json(req): Json<RegisterRequest>,
 ) -> Result<(StatusCode, Json<RegisterResponse>), ApiError> {
+    let human = captcha
+        .verify(req.captcha_token.as_deref())
+        .await
+        .map_err(|e| {
+            tracing::warn!("CAPTCHA verification unavailable: {}", e);
+            ApiError::service_unavailable("CAPTCHA verification unavailable, please retry", 5)
+        })?;
+    if !human {
+        return Err(ApiError::bad_request("CAPTCHA verification failed"));
+    }
+
     // Validate email
     let email = Email::new(&req.email)
         .map_err(|e| ApiError::bad_request(format!("Invalid email: {}", e)))?;
 
-    // Validate password (minimum 8 characters)
-    if req.password.len() < 8 {
-        return Err(ApiError::bad_request(
-            "Password must be at least 8 characters",
-        ));
-    }
+    // Validate password strength
+    validate_password_strength(&req.password).map_err(ApiError::bad_request)?;
 
     // Hash password
     let password_hash = hash_password(&req.password)
         .map_err(|e| ApiError::internal_server_error(format!("Failed to hash password: {}", e)))?;
 
     // Create user
-    let user = User {
-        id: Uuid::new_v4(),
-        company_id: req.company_id,
-        email,
-        password_hash,
-        full_name: req.full_name,
-        is_active: true,
-    };
+    let user = User::new(req.company_id, email, password_hash, &req.full_name)
+        .map_err(ApiError::bad_request)?;
 
     // Save to database
     let user_repo = PostgresUserRepository::new(pool);
@@ -78,7 +169,7 @@ pub async fn register(
         if e.contains("duplicate") || e.contains("unique") {
             ApiError::bad_request("Email already registered")
         } else {
-            ApiError::internal_server_error(format!("Failed to create user: {}", e))
+            ApiError::repository("Failed to create user", e)
         }
     })?;
 
@@ -94,10 +185,93 @@ pub async fn register(
 /// Login with email and password
 ///
 /// POST /api/auth/login
+///
+/// After too many consecutive wrong passwords the email is locked for a
+/// cooldown (see [`LoginLockout`]) and logins get 423 even with the right
+/// password. Failures are counted per email whether or not an account
+/// uses it, so a lockout does not reveal which emails are registered. A
+/// successful login resets the count.
+///
+/// Identical logins from the same client within a few seconds (see
+/// [`LoginDeduplicator`]) receive the token minted for the first, as long
+/// as the account is still active, unlocked, and its tokens not revoked.
+#[utoipa::path(
+    post,
+    path = "/api/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Logged in", body = LoginResponse),
+        (status = 400, description = "Invalid email", body = ErrorResponse),
+        (status = 401, description = "Wrong credentials or disabled account", body = ErrorResponse),
+        (status = 423, description = "Locked after repeated failed logins", body = ErrorResponse),
+    )
+)]
 pub async fn login(
     State(pool): State<PgPool>,
+    Extension(tasks): Extension<BackgroundTasks>,
+    Extension(lockout): Extension<LoginLockout>,
+    Extension(dedup): Extension<LoginDeduplicator>,
+    ClientIp(client): ClientIp,
     Json(req): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, ApiError> {
+    let response = dedup
+        .run(
+            &req.email,
+            &client,
+            &req.password,
+            || authenticate(pool.clone(), tasks, lockout.clone(), &req),
+            |cached| login_still_current(&pool, &lockout, &req.email, cached),
+        )
+        .await?;
+
+    Ok(Json(response))
+}
+
+/// Whether a login response remembered by [`LoginDeduplicator`] may be
+/// handed out again
+///
+/// It may not once the account was disabled or locked, or its token
+/// version moved past the cached token's (a company move or password
+/// reset), since then `authenticate` would no longer mint it. The reset
+/// case matters because the cache is keyed by the old password.
+async fn login_still_current(
+    pool: &PgPool,
+    lockout: &LoginLockout,
+    email: &str,
+    cached: LoginResponse,
+) -> Result<bool, ApiError> {
+    let user = PostgresUserRepository::new(pool.clone())
+        .find_by_id(cached.user_id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    if !user.is_some_and(|user| user.is_active) {
+        return Ok(false);
+    }
+
+    let attempts = PostgresLoginAttemptRepository::new(pool.clone())
+        .find(&email.trim().to_lowercase())
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    let now = lockout.clock.now();
+    if attempts.is_some_and(|attempts| lockout.policy.locked_for(&attempts, now).is_some()) {
+        return Ok(false);
+    }
+
+    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+    let Ok(claims) = verify_token(&cached.token, &secret) else {
+        return Ok(false);
+    };
+    Ok(!token_revoked(pool, &claims).await?)
+}
+
+/// Checks credentials and mints a token for [`login`]
+async fn authenticate(
+    pool: PgPool,
+    tasks: BackgroundTasks,
+    lockout: LoginLockout,
+    req: &LoginRequest,
+) -> Result<LoginResponse, ApiError> {
     // Validate email
     let email = Email::new(&req.email)
         .map_err(|e| ApiError::bad_request(format!("Invalid email: {}", e)))?;
@@ -107,40 +281,287 @@ pub async fn login(
     let user = user_repo
         .find_by_email(&email)
         .await
-        .map_err(|e| ApiError::internal_server_error(format!("Database error: {}", e)))?
-        .ok_or_else(|| ApiError::unauthorized("Invalid credentials"))?;
+        .map_err(|e| ApiError::repository("Database error", e))?;
 
-    // Check if user is active
-    if !user.is_active {
+    // Check if user is active, paying for the same bcrypt work as any other
+    // refused login so timing does not single out disabled accounts
+    if user.as_ref().is_some_and(|u| !u.is_active) {
+        verify_login(&BcryptHasher, &req.password, None).map_err(|e| {
+            ApiError::internal_server_error(format!("Password verification failed: {}", e))
+        })?;
         return Err(ApiError::unauthorized("Account is disabled"));
     }
 
-    // Verify password
-    let valid = verify_password(&req.password, &user.password_hash).map_err(|e| {
+    // Refuse locked emails before looking at the password
+    let attempt_repo = PostgresLoginAttemptRepository::new(pool.clone());
+    let attempt_key = email.as_str().trim().to_lowercase();
+    let now = lockout.clock.now();
+    let attempts = attempt_repo
+        .find(&attempt_key)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    if let Some(remaining) = attempts
+        .as_ref()
+        .and_then(|attempts| lockout.policy.locked_for(attempts, now))
+    {
+        // Round up so clients never retry while still locked
+        let retry_after = (remaining.num_milliseconds() as u64).div_ceil(1000);
+        return Err(ApiError::locked("Account temporarily locked", retry_after)
+            .with_code(ErrorCode::AccountLocked));
+    }
+
+    // Verify password (unknown users are checked against a dummy hash so
+    // timing does not reveal which emails are registered)
+    let stored_hash = user.as_ref().map(|u| u.password_hash.as_str());
+    let valid = verify_login(&BcryptHasher, &req.password, stored_hash).map_err(|e| {
         ApiError::internal_server_error(format!("Password verification failed: {}", e))
     })?;
 
-    if !valid {
+    let Some(user) = user.filter(|_| valid) else {
+        let policy = lockout.policy;
+        attempt_repo
+            .record_failure(&attempt_key, &move |current| {
+                policy.record_failure(current, now)
+            })
+            .await
+            .map_err(|e| ApiError::repository("Failed to record failed login", e))?;
         return Err(ApiError::unauthorized("Invalid credentials"));
+    };
+
+    if attempts.is_some() {
+        attempt_repo
+            .clear(&attempt_key)
+            .await
+            .map_err(|e| ApiError::repository("Database error", e))?;
     }
 
-    // Update last login
-    let _ = user_repo.update_last_login(user.id).await;
+    // Tokens are minted at the user's current version
+    let token_version = user_repo
+        .current_token_version(user.id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .unwrap_or_default();
+
+    // Update last login in the background so a slow write cannot delay
+    // the response
+    let user_id = user.id;
+    tasks.spawn(async move {
+        if let Err(e) = user_repo.update_last_login(user_id).await {
+            tracing::warn!("Failed to record last login for user {}: {}", user_id, e);
+        }
+    });
 
     // Create JWT token
     let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
-    let token = create_token(user.id, &secret)
+    let token = create_token_now(user.id, user.company_id, user.role, token_version, &secret)
         .map_err(|e| ApiError::internal_server_error(format!("Failed to create token: {}", e)))?;
 
-    Ok(Json(LoginResponse {
+    Ok(LoginResponse {
         token,
         user_id: user.id,
+    })
+}
+
+/// Report whether a token is valid, for internal services
+///
+/// POST /api/auth/introspect
+///
+/// Invalid, expired, or revoked tokens yield `active: false` rather than
+/// an error.
+#[utoipa::path(
+    post,
+    path = "/api/auth/introspect",
+    tag = "auth",
+    request_body = IntrospectRequest,
+    security(("internal_secret" = [])),
+    responses(
+        (status = 200, description = "Token state", body = IntrospectResponse),
+        (status = 401, description = "Missing or wrong internal secret", body = ErrorResponse),
+    )
+)]
+pub async fn introspect(
+    _internal: InternalService,
+    State(pool): State<PgPool>,
+    Json(req): Json<IntrospectRequest>,
+) -> Result<Json<IntrospectResponse>, ApiError> {
+    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+
+    let mut claims = verify_token(&req.token, &secret).ok();
+    if let Some(token) = &claims {
+        if token_revoked(&pool, token).await? {
+            claims = None;
+        }
+    }
+
+    let response = match claims {
+        Some(claims) => IntrospectResponse {
+            active: true,
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+            company_id: claims.company_id,
+            role: Some(claims.role),
+        },
+        None => IntrospectResponse {
+            active: false,
+            sub: None,
+            exp: None,
+            company_id: None,
+            role: None,
+        },
+    };
+
+    Ok(Json(response))
+}
+
+/// Request a password reset token
+///
+/// POST /api/auth/forgot-password
+///
+/// Always responds 200 so callers cannot probe which emails are registered.
+#[utoipa::path(
+    post,
+    path = "/api/auth/forgot-password",
+    tag = "auth",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset requested, whether or not the email exists", body = MessageResponse),
+    )
+)]
+pub async fn forgot_password(
+    State(pool): State<PgPool>,
+    Json(req): Json<ForgotPasswordRequest>,
+) -> Result<Json<MessageResponse>, ApiError> {
+    let response = Json(MessageResponse {
+        message: "If that email is registered, a reset link has been sent".to_string(),
+    });
+
+    let Ok(email) = Email::new(&req.email) else {
+        return Ok(response);
+    };
+
+    let user_repo = PostgresUserRepository::new(pool.clone());
+    let user = user_repo
+        .find_by_email(&email)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    let Some(user) = user.filter(|u| u.is_active) else {
+        return Ok(response);
+    };
+
+    let token = generate_reset_token();
+    let reset_repo = PostgresPasswordResetRepository::new(pool);
+    reset_repo
+        .create(PasswordResetToken {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            token_hash: hash_reset_token(&token),
+            expires_at: Utc::now() + Duration::minutes(RESET_TOKEN_TTL_MINUTES),
+        })
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    // TODO: Deliver the token by email once a mailer is integrated
+    tracing::info!("Password reset token issued for user {}", user.id);
+
+    Ok(response)
+}
+
+/// Complete a password reset with a previously issued token
+///
+/// POST /api/auth/reset-password
+#[utoipa::path(
+    post,
+    path = "/api/auth/reset-password",
+    tag = "auth",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password changed", body = MessageResponse),
+        (status = 400, description = "Invalid or expired token, or weak password", body = ErrorResponse),
+    )
+)]
+pub async fn reset_password(
+    State(pool): State<PgPool>,
+    Json(req): Json<ResetPasswordRequest>,
+) -> Result<Json<MessageResponse>, ApiError> {
+    // Validate password strength before burning the token
+    validate_password_strength(&req.new_password).map_err(ApiError::bad_request)?;
+
+    let password_hash = hash_password(&req.new_password)
+        .map_err(|e| ApiError::internal_server_error(format!("Failed to hash password: {}", e)))?;
+
+    let reset_repo = PostgresPasswordResetRepository::new(pool);
+    reset_repo
+        .reset_password(&hash_reset_token(&req.token), &password_hash)
+        .await
+        .map_err(|e| ApiError::repository("Failed to update password", e))?
+        .ok_or_else(|| ApiError::bad_request("Invalid or expired reset token"))?;
+
+    Ok(Json(MessageResponse {
+        message: "Password has been reset".to_string(),
     }))
 }
 
+/// Default time allowed for the readiness probe's database query
+const DEFAULT_HEALTH_DB_TIMEOUT_MS: u64 = 2000;
+
+/// Time allowed for the readiness probe's database query
+///
+/// Installed as an `Extension`; without one the probe waits
+/// `DEFAULT_HEALTH_DB_TIMEOUT_MS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadinessTimeout(pub std::time::Duration);
+
+impl ReadinessTimeout {
+    /// Reads `HEALTH_DB_TIMEOUT_MS`, falling back to the default when unset
+    /// or not a number
+    pub fn from_env() -> Self {
+        let timeout_ms = std::env::var("HEALTH_DB_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_HEALTH_DB_TIMEOUT_MS);
+
+        Self(std::time::Duration::from_millis(timeout_ms))
+    }
+}
+
+impl Default for ReadinessTimeout {
+    fn default() -> Self {
+        Self(std::time::Duration::from_millis(
+            DEFAULT_HEALTH_DB_TIMEOUT_MS,
+        ))
+    }
+}
+
 /// Health check endpoint
 ///
 /// GET /health
 pub async fn health_check() -> &'static str {
     "OK"
 }
+
+/// Readiness check endpoint
+///
+/// GET /health/ready
+///
+/// Responds 503 if the database cannot answer within the
+/// [`ReadinessTimeout`], so a stuck connection never hangs the probe.
+pub async fn readiness_check(
+    State(pool): State<PgPool>,
+    timeout: Option<Extension<ReadinessTimeout>>,
+) -> Result<&'static str, ApiError> {
+    let ReadinessTimeout(timeout) = timeout.map(|Extension(t)| t).unwrap_or_default();
+
+    let query = sqlx::query("SELECT 1").execute(&pool);
+    match tokio::time::timeout(timeout, query).await {
+        Ok(Ok(_)) => Ok("OK"),
+        Ok(Err(e)) => Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("Database unavailable: {}", e),
+        )),
+        Err(_) => Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("Database did not respond within {}ms", timeout.as_millis()),
+        )),
+    }
+}
@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use crate::api::errors::ApiError;
+use crate::api::middleware::TenantAdmin;
+use crate::api::pagination::Pagination;
+use crate::domain::repositories::team_event_repository::StoredTeamEvent;
+use crate::domain::repositories::TeamEventRepository;
+use crate::domain::team::events::TeamEvent;
+use crate::infrastructure::repositories::PostgresTeamEventRepository;
+
+/// Filter for the company event log; paging comes from [`Pagination`]
+#[derive(Debug, Deserialize)]
+pub struct EventLogQuery {
+    /// Event type, as a variant name (`Failed`) or stored name (`failed`)
+    #[serde(rename = "type")]
+    pub event_type: String,
+}
+
+/// List the caller's company's team events of one type (requires an admin)
+///
+/// GET /api/events?type=Failed&limit=&offset=
+///
+/// Events are returned oldest first across all of the company's teams.
+/// An unknown type is rejected with 400 and a non-admin caller with 403.
+pub async fn list_events(
+    TenantAdmin(company_id): TenantAdmin,
+    State(pool): State<PgPool>,
+    Pagination { limit, offset }: Pagination,
+    Query(query): Query<EventLogQuery>,
+) -> Result<Json<Vec<StoredTeamEvent>>, ApiError> {
+    let event_type = TeamEvent::parse_event_type(&query.event_type).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "Unknown event type '{}'; expected one of: {}",
+            query.event_type,
+            TeamEvent::EVENT_TYPES.join(", ")
+        ))
+    })?;
+
+    let events = PostgresTeamEventRepository::new(pool)
+        .find_by_type(company_id, event_type, limit, offset)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    Ok(Json(events))
+}
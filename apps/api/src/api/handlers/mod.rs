@@ -2,4 +2,8 @@
 // Adapters in the Hexagonal Architecture
 
 pub mod auth;
+pub mod events;
 pub mod teams;
+pub mod users;
+pub mod webhooks;
+pub mod workers;
@@ -1,37 +1,471 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    Json,
+    body::Body,
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
 };
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use crate::agents::cancellation::TeamCancellations;
+use crate::agents::types::{Specialization, WorkerStatus};
+use crate::agents::{ManagerAgent, WorkerAgent};
 use crate::api::errors::ApiError;
-use crate::api::middleware::JwtAuth;
-use crate::domain::repositories::TeamRepository;
-use crate::domain::team::Team;
-use crate::infrastructure::repositories::PostgresTeamRepository;
+use crate::api::messages::ErrorCode;
+use crate::api::middleware::{CompanyContext, Tenant, TenantAdmin};
+use crate::api::pagination::normalize_pagination;
+use crate::api::{timestamp_format, uuid_format};
+use crate::domain::repositories::team_event_repository::StoredTeamEvent;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::repositories::{
+    CompanyRepository, CostRepository, ManagerRepository, TeamEventRepository, TeamRepository,
+    WorkerRepository,
+};
+use crate::domain::shared::{Currency, Money, SystemClock};
+use crate::domain::team::events::TeamEvent;
+use crate::domain::team::value_objects::{normalize_tags, TeamStatus};
+use crate::domain::team::{Team, TeamExport, TeamSnapshot};
+use crate::infrastructure::database::ReadPool;
+use crate::infrastructure::event_logger::EventLogger;
+use crate::infrastructure::feature_flags::{FeatureFlags, UNIQUE_GOAL_PER_COMPANY};
+use crate::infrastructure::repositories::postgres_team_repository::UNIQUE_GOAL_INDEX;
+use crate::infrastructure::repositories::postgres_team_transfer as transfer;
+use crate::infrastructure::repositories::{
+    PostgresCompanyRepository, PostgresCostRepository, PostgresManagerRepository,
+    PostgresTeamEventRepository, PostgresTeamRepository, PostgresUserRepository,
+    PostgresWorkerRepository,
+};
+
+/// Loads a team owned by `company_id`, or fails with 404
+///
+/// Teams of other companies are reported as not found too, so callers
+/// cannot probe which team IDs exist.
+pub(crate) async fn load_team_or_404(
+    repo: &impl TeamRepository,
+    id: Uuid,
+    company_id: Uuid,
+) -> Result<Team, ApiError> {
+    repo.find_by_id(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .filter(|team| team.company_id() == company_id)
+        .ok_or_else(|| ApiError::not_found(format!("Team not found: {}", id)))
+}
+
+/// Loads a read-only snapshot of a team owned by `company_id`, or fails
+/// with 404 like [`load_team_or_404`]
+async fn load_snapshot_or_404(
+    repo: &impl TeamRepository,
+    id: Uuid,
+    company_id: Uuid,
+) -> Result<TeamSnapshot, ApiError> {
+    repo.find_snapshot_by_id(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .filter(|team| team.company_id == company_id)
+        .ok_or_else(|| ApiError::not_found(format!("Team not found: {}", id)))
+}
+
+/// Saves `team` and records `events` in one transaction
+///
+/// The events reach the event log and the outbox exactly when the team
+/// change is committed. Errors from the save itself go through
+/// `save_error` so callers can translate constraint violations.
+async fn save_with_events(
+    repo: &impl TeamRepository,
+    team: &Team,
+    events: &[TeamEvent],
+    save_error: impl FnOnce(String) -> ApiError,
+) -> Result<(), ApiError> {
+    let mut tx = repo
+        .begin()
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    tx.save(team).await.map_err(save_error)?;
+    tx.record_events(team.company_id(), events)
+        .await
+        .map_err(|e| ApiError::repository("Failed to record events", e))?;
+    tx.commit()
+        .await
+        .map_err(|e| ApiError::repository("Failed to save team", e))
+}
+
+/// Unique-goal policy switched on for every company at once
+///
+/// Installed as an `Extension`; without one (the default) the policy is
+/// decided per company by the `unique_goal_per_company` feature flag.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnforceUniqueGoal(pub bool);
+
+impl EnforceUniqueGoal {
+    /// Reads `ENFORCE_UNIQUE_GOAL_PER_COMPANY`; only `true` (in any case)
+    /// enables it
+    pub fn from_env() -> Self {
+        Self(
+            std::env::var("ENFORCE_UNIQUE_GOAL_PER_COMPANY")
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+        )
+    }
+}
+
+/// Returns whether goals must be unique within a company
+///
+/// Enabled for every company under [`EnforceUniqueGoal`], otherwise per
+/// company via the `unique_goal_per_company` feature flag.
+pub(crate) async fn unique_goal_policy_enabled(
+    flags: &FeatureFlags,
+    enforce: Option<Extension<EnforceUniqueGoal>>,
+    company_id: Uuid,
+) -> Result<bool, ApiError> {
+    let EnforceUniqueGoal(globally_enabled) = enforce.map(|Extension(e)| e).unwrap_or_default();
+    if globally_enabled {
+        return Ok(true);
+    }
+
+    flags
+        .is_enabled(company_id, UNIQUE_GOAL_PER_COMPANY)
+        .await
+        .map_err(|e| ApiError::repository("Failed to read feature flags", e))
+}
+
+/// Rejects a new team when the company is at its active team quota
+///
+/// Companies without a `max_active_teams` setting are unlimited. Only
+/// teams in a non-terminal status count toward the quota.
+async fn ensure_active_team_quota(pool: &PgPool, company_id: Uuid) -> Result<(), ApiError> {
+    ensure_active_team_room(pool, company_id, 1).await
+}
+
+/// Rejects `adding` new active teams when they would exceed the company's
+/// active team quota
+async fn ensure_active_team_room(
+    pool: &PgPool,
+    company_id: Uuid,
+    adding: i64,
+) -> Result<(), ApiError> {
+    let company_repo = PostgresCompanyRepository::new(pool.clone());
+    let Some(limit) = company_repo
+        .find_max_active_teams(company_id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+    else {
+        return Ok(());
+    };
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    let active = team_repo
+        .count_active(company_id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    if active + adding > limit {
+        return Err(
+            ApiError::forbidden("Company has reached its active team limit")
+                .with_code(ErrorCode::TeamQuotaExceeded),
+        );
+    }
+
+    Ok(())
+}
 
 /// Request body for creating a team
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTeamRequest {
     pub goal: String,
     pub company_id: Uuid,
     pub created_by: Uuid,
+    /// Accepts a JSON number or a quoted decimal string
+    #[serde(default, deserialize_with = "deserialize_budget")]
+    #[schema(value_type = Option<String>, example = "250.00")]
+    pub budget_limit: Option<Decimal>,
+    /// ISO 4217 code for `budget_limit` (defaults to USD)
+    pub budget_currency: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Maximum length of a team goal, in characters
+const MAX_GOAL_LENGTH: usize = 1000;
+
+/// Largest number of decimal places a budget may have (`DECIMAL(12,2)`)
+const MAX_BUDGET_SCALE: u32 = 2;
+
+/// Largest budget the `DECIMAL(12,2)` column can store (9,999,999,999.99)
+const MAX_BUDGET: Decimal = Decimal::from_parts(3_567_587_327, 232, 0, false, 2);
+
+/// Default cap on a team budget when `MAX_TEAM_BUDGET` is unset
+const DEFAULT_MAX_TEAM_BUDGET: Decimal = Decimal::from_parts(1_000_000, 0, 0, false, 0);
+
+/// Largest budget a team may be given
+///
+/// Installed as an `Extension`; without one the cap is
+/// `DEFAULT_MAX_TEAM_BUDGET`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxTeamBudget(pub Decimal);
+
+impl MaxTeamBudget {
+    /// Reads `MAX_TEAM_BUDGET`, falling back to the default when unset or
+    /// not a number, and never above what the budget column can store
+    pub fn from_env() -> Self {
+        let max = std::env::var("MAX_TEAM_BUDGET")
+            .ok()
+            .and_then(|value| value.parse::<Decimal>().ok())
+            .unwrap_or(DEFAULT_MAX_TEAM_BUDGET);
+
+        Self(max.min(MAX_BUDGET))
+    }
+}
+
+impl Default for MaxTeamBudget {
+    fn default() -> Self {
+        Self(DEFAULT_MAX_TEAM_BUDGET)
+    }
+}
+
+/// Checks a budget amount's precision and size
+fn validate_budget_amount(amount: Decimal, max_budget: MaxTeamBudget) -> Result<(), String> {
+    if amount.normalize().scale() > MAX_BUDGET_SCALE {
+        return Err(format!(
+            "Invalid budget: at most {} decimal places are allowed",
+            MAX_BUDGET_SCALE
+        ));
+    }
+    let MaxTeamBudget(max) = max_budget;
+    if amount > max {
+        return Err(format!("Invalid budget: must not exceed {}", max));
+    }
+    Ok(())
+}
+
+/// Deserializes an optional budget from a JSON number or decimal string
+///
+/// Non-numeric input fails with a message naming the offending value
+/// instead of serde's generic type error.
+fn deserialize_budget<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(number)) => parse_budget(&number.to_string())
+            .map(Some)
+            .map_err(D::Error::custom),
+        Some(serde_json::Value::String(text)) => parse_budget(text.trim())
+            .map(Some)
+            .map_err(D::Error::custom),
+        Some(other) => Err(D::Error::custom(format!(
+            "budget_limit must be a decimal number, got {}",
+            other
+        ))),
+    }
+}
+
+/// Parses budget text, accepting scientific notation for large JSON numbers
+fn parse_budget(text: &str) -> Result<Decimal, String> {
+    text.parse::<Decimal>()
+        .or_else(|_| Decimal::from_scientific(text))
+        .map_err(|_| format!("budget_limit must be a decimal number, got {:?}", text))
+}
+
+/// A `CreateTeamRequest` whose fields have all been checked
+///
+/// Built with [`ValidatedCreateTeam::validate`], which reports every
+/// invalid field at once rather than stopping at the first.
+/// `ValidatedCreateTeam::try_from` validates against the default
+/// [`MaxTeamBudget`].
+#[derive(Debug)]
+pub struct ValidatedCreateTeam {
+    pub company_id: Uuid,
+    pub created_by: Uuid,
+    /// Goal with surrounding whitespace removed
+    pub goal: String,
+    pub budget_limit: Option<Money>,
+    /// Normalized tags
+    pub tags: Vec<String>,
+}
+
+impl TryFrom<CreateTeamRequest> for ValidatedCreateTeam {
+    type Error = Vec<String>;
+
+    fn try_from(req: CreateTeamRequest) -> Result<Self, Self::Error> {
+        Self::validate(req, MaxTeamBudget::default())
+    }
+}
+
+impl ValidatedCreateTeam {
+    /// Checks every field of `req`, capping its budget at `max_budget`
+    pub fn validate(
+        req: CreateTeamRequest,
+        max_budget: MaxTeamBudget,
+    ) -> Result<Self, Vec<String>> {
+        let mut errors = Vec::new();
+
+        if req.company_id.is_nil() {
+            errors.push("company_id must not be nil".to_string());
+        }
+        if req.created_by.is_nil() {
+            errors.push("created_by must not be nil".to_string());
+        }
+
+        let goal = req.goal.trim().to_string();
+        if goal.is_empty() {
+            errors.push("Goal cannot be empty".to_string());
+        } else if goal.chars().count() > MAX_GOAL_LENGTH {
+            errors.push(format!(
+                "Goal must be at most {} characters",
+                MAX_GOAL_LENGTH
+            ));
+        }
+
+        let currency = match req.budget_currency.as_deref() {
+            Some(code) => code.parse::<Currency>().unwrap_or_else(|e| {
+                errors.push(e);
+                Currency::default()
+            }),
+            None => Currency::default(),
+        };
+
+        let mut budget_limit = None;
+        if let Some(amount) = req.budget_limit {
+            match validate_budget_amount(amount, max_budget).and_then(|()| {
+                Money::new(amount, currency).map_err(|e| format!("Invalid budget: {}", e))
+            }) {
+                Ok(money) => budget_limit = Some(money),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        let tags = normalize_tags(req.tags).unwrap_or_else(|e| {
+            errors.push(e);
+            Vec::new()
+        });
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Self {
+            company_id: req.company_id,
+            created_by: req.created_by,
+            goal,
+            budget_limit,
+            tags,
+        })
+    }
+}
+
+/// Request body for partially updating a team
+///
+/// Absent fields are left unchanged. `budget_limit: null` removes the budget.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTeamRequest {
+    #[serde(default)]
+    pub goal: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_present_budget")]
+    #[schema(value_type = Option<String>, nullable, example = "250.00")]
+    pub budget_limit: Option<Option<Decimal>>,
+}
+
+/// Wraps a present budget (including `null`) in `Some`, leaving absent as `None`
+fn deserialize_present_budget<'de, D>(deserializer: D) -> Result<Option<Option<Decimal>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_budget(deserializer).map(Some)
+}
+
+/// Request body for replacing a team's tags
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTeamTagsRequest {
+    pub tags: Vec<String>,
+}
+
+/// Request body for handing a team to another user
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TransferTeamRequest {
+    /// User who will own the team; must belong to the team's company
+    pub new_owner_id: Uuid,
+}
+
+/// Request body for deleting several teams at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkDeleteTeamsRequest {
+    pub team_ids: Vec<Uuid>,
+}
+
+/// Outcome of a bulk delete
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkDeleteTeamsResponse {
+    pub deleted: usize,
+    /// IDs that were not found or belong to another company
+    pub skipped: usize,
+    /// The skipped IDs, in request order
+    #[serde(serialize_with = "uuid_format::serialize_many")]
+    pub missing: Vec<Uuid>,
+}
+
+/// Largest number of teams a single batch create may contain
+const MAX_BATCH_TEAMS: usize = 100;
+
+/// Request body for creating several teams at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchCreateTeamsRequest {
+    pub teams: Vec<BatchCreateTeamEntry>,
+}
+
+/// One team in a batch create, owned by the caller and their company
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchCreateTeamEntry {
+    /// Team ID to use; generated when omitted
+    pub id: Option<Uuid>,
+    pub goal: String,
+    /// Accepts a JSON number or a quoted decimal string
+    #[serde(default, deserialize_with = "deserialize_budget")]
+    #[schema(value_type = Option<String>, example = "250.00")]
     pub budget_limit: Option<Decimal>,
+    /// ISO 4217 code for `budget_limit` (defaults to USD)
+    pub budget_currency: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Teams created by a batch, in request order
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchCreateTeamsResponse {
+    pub teams: Vec<TeamResponse>,
 }
 
 /// Response from team creation
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TeamResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
     pub id: Uuid,
+    #[serde(serialize_with = "uuid_format::serialize")]
     pub company_id: Uuid,
     pub goal: String,
     pub status: String,
+    #[serde(serialize_with = "uuid_format::serialize")]
     pub created_by: Uuid,
     pub budget_limit: Option<Decimal>,
+    #[schema(value_type = Option<String>, example = "USD")]
+    pub budget_currency: Option<Currency>,
+    pub tags: Vec<String>,
+    #[serde(serialize_with = "timestamp_format::serialize")]
+    pub created_at: DateTime<Utc>,
+    /// Projected finish time; only set for Active teams with an estimate
+    #[serde(serialize_with = "timestamp_format::serialize_option")]
+    pub estimated_completion: Option<DateTime<Utc>>,
 }
 
 impl From<&Team> for TeamResponse {
@@ -42,7 +476,165 @@ impl From<&Team> for TeamResponse {
             goal: team.goal().to_string(),
             status: format!("{:?}", team.status()),
             created_by: team.created_by(),
-            budget_limit: team.budget_limit(),
+            budget_limit: team.budget_limit().map(|b| b.amount()),
+            budget_currency: team.budget_limit().map(|b| b.currency()),
+            tags: team.tags().to_vec(),
+            created_at: team.created_at(),
+            estimated_completion: team
+                .estimated_completion()
+                .filter(|_| team.status() == TeamStatus::Active),
+        }
+    }
+}
+
+impl From<&TeamSnapshot> for TeamResponse {
+    fn from(snapshot: &TeamSnapshot) -> Self {
+        Self {
+            id: snapshot.id,
+            company_id: snapshot.company_id,
+            goal: snapshot.goal.clone(),
+            status: format!("{:?}", snapshot.status),
+            created_by: snapshot.created_by,
+            budget_limit: snapshot.budget_limit.map(|b| b.amount()),
+            budget_currency: snapshot.budget_limit.map(|b| b.currency()),
+            tags: snapshot.tags.clone(),
+            created_at: snapshot.created_at,
+            estimated_completion: snapshot
+                .estimated_completion()
+                .filter(|_| snapshot.status == TeamStatus::Active),
+        }
+    }
+}
+
+/// Team listing entry enriched with the creator's display name
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamListItemResponse {
+    #[serde(flatten)]
+    pub team: TeamResponse,
+    /// `None` if the creator's account no longer exists
+    pub created_by_name: Option<String>,
+}
+
+/// Query parameters for the team event feed
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TeamEventsQuery {
+    /// Return events with a sequence strictly greater than this cursor
+    pub after: Option<i64>,
+    /// Page size (default 50, clamped to 1-200)
+    pub limit: Option<i64>,
+}
+
+/// Query parameters for listing teams by creation time
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CreatedBetweenQuery {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+/// Query parameters for importing a team
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ImportTeamQuery {
+    /// Keep the exported IDs instead of generating new ones
+    #[serde(default)]
+    pub preserve_ids: bool,
+}
+
+/// One page of the team event feed
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamEventsResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub events: Vec<StoredTeamEvent>,
+    /// Cursor to pass as `after` for the next page
+    pub next_cursor: Option<i64>,
+    pub has_more: bool,
+}
+
+/// Team counts for a company dashboard
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamStatsResponse {
+    /// Number of teams per lowercase status name, including zeros
+    pub by_status: BTreeMap<String, i64>,
+    pub total: i64,
+}
+
+/// Where a team's budget went
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CostBreakdownResponse {
+    pub total_spent: Decimal,
+    /// Spend per worker specialization; team-level spend is "unattributed"
+    pub by_specialization: BTreeMap<String, Decimal>,
+    /// Budget left after the spend the budget is enforced against, `None`
+    /// if the team has no budget
+    ///
+    /// That spend can include usage not itemized above, so this is not
+    /// always the budget minus `total_spent`.
+    pub remaining: Option<Decimal>,
+}
+
+/// Manager Agent configuration for a team
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ManagerResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub id: Uuid,
+    pub model: String,
+    pub temperature: f32,
+    pub max_tokens: u32,
+}
+
+impl From<&ManagerAgent> for ManagerResponse {
+    fn from(manager: &ManagerAgent) -> Self {
+        Self {
+            id: manager.id,
+            model: manager.model.clone(),
+            temperature: manager.temperature,
+            max_tokens: manager.max_tokens,
+        }
+    }
+}
+
+/// A worker on a team's roster
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkerResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub id: Uuid,
+    #[schema(value_type = String, example = "Coder")]
+    pub specialization: Specialization,
+    pub status: WorkerStatusResponse,
+    #[serde(serialize_with = "uuid_format::serialize_option")]
+    pub assigned_task_id: Option<Uuid>,
+}
+
+/// A worker's status, in the same shape for every state
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkerStatusResponse {
+    /// `"Idle"`, `"Working"`, or `"Blocked"`
+    #[schema(example = "Blocked")]
+    pub state: String,
+    /// Why the worker is blocked, `None` unless it is
+    pub reason: Option<String>,
+}
+
+impl From<&WorkerStatus> for WorkerStatusResponse {
+    fn from(status: &WorkerStatus) -> Self {
+        let (state, reason) = match status {
+            WorkerStatus::Idle => ("Idle", None),
+            WorkerStatus::Working => ("Working", None),
+            WorkerStatus::Blocked { reason } => ("Blocked", Some(reason.clone())),
+        };
+        Self {
+            state: state.to_string(),
+            reason,
+        }
+    }
+}
+
+impl From<&WorkerAgent> for WorkerResponse {
+    fn from(worker: &WorkerAgent) -> Self {
+        Self {
+            id: worker.id,
+            specialization: worker.specialization,
+            status: WorkerStatusResponse::from(&worker.status),
+            assigned_task_id: worker.assigned_task_id,
         }
     }
 }
@@ -50,78 +642,1332 @@ impl From<&Team> for TeamResponse {
 /// Create a new team
 ///
 /// POST /api/teams
+#[utoipa::path(
+    post,
+    path = "/api/teams",
+    tag = "teams",
+    request_body = CreateTeamRequest,
+    responses(
+        (status = 201, description = "Team created", body = TeamResponse),
+        (status = 400, description = "Invalid fields, reported together", body = ErrorResponse),
+        (status = 403, description = "Company has reached its active team limit", body = ErrorResponse),
+        (status = 409, description = "A team with this goal already exists", body = ErrorResponse),
+    )
+)]
 pub async fn create_team(
     State(pool): State<PgPool>,
-    Json(req): Json<CreateTeamRequest>,
+    Extension(flags): Extension<FeatureFlags>,
+    enforce_unique_goal: Option<Extension<EnforceUniqueGoal>>,
+    max_budget: Option<Extension<MaxTeamBudget>>,
+    payload: Result<Json<CreateTeamRequest>, JsonRejection>,
 ) -> Result<(StatusCode, Json<TeamResponse>), ApiError> {
+    let Json(req) = payload?;
+    let max_budget = max_budget.map(|Extension(max)| max).unwrap_or_default();
+
+    // Validate every field before touching the aggregate
+    let req = ValidatedCreateTeam::validate(req, max_budget)
+        .map_err(|errors| ApiError::bad_request(errors.join("; ")))?;
+
     // Create team domain entity
-    let (team, _events) = Team::new(req.company_id, req.goal, req.created_by, req.budget_limit)
-        .map_err(ApiError::bad_request)?;
+    let (mut team, mut events) =
+        Team::new_now(req.company_id, req.goal, req.created_by, req.budget_limit)?;
+    if !req.tags.is_empty() {
+        events.push(team.set_tags(req.tags).map_err(ApiError::bad_request)?);
+    }
 
     // Save to database
-    let team_repo = PostgresTeamRepository::new(pool);
-    team_repo
-        .save(&team)
-        .await
-        .map_err(|e| ApiError::internal_server_error(format!("Failed to save team: {}", e)))?;
+    ensure_active_team_quota(&pool, team.company_id()).await?;
+    let unique_goal =
+        unique_goal_policy_enabled(&flags, enforce_unique_goal, team.company_id()).await?;
+    let team_repo = PostgresTeamRepository::new(pool).with_unique_goal(unique_goal);
+    save_with_events(&team_repo, &team, &events, |e| {
+        if e.contains(UNIQUE_GOAL_INDEX) {
+            ApiError::conflict("A team with this goal already exists")
+                .with_code(ErrorCode::DuplicateGoal)
+        } else {
+            ApiError::repository("Failed to save team", e)
+        }
+    })
+    .await?;
+    EventLogger.log(&events);
 
     Ok((StatusCode::CREATED, Json(TeamResponse::from(&team))))
 }
 
+/// Create several teams in one transaction (requires admin role)
+///
+/// POST /api/teams/batch
+///
+/// Entries are validated together and either all teams are created or
+/// none are. An entry whose `id` repeats an earlier entry's, or belongs to
+/// an existing team, fails the batch with a 409 naming its index.
+#[utoipa::path(
+    post,
+    path = "/api/teams/batch",
+    tag = "teams",
+    request_body = BatchCreateTeamsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Teams created", body = BatchCreateTeamsResponse),
+        (status = 400, description = "Empty or oversized batch, or invalid entries", body = ErrorResponse),
+        (status = 403, description = "Admin role required, or the company would exceed its active team limit", body = ErrorResponse),
+        (status = 409, description = "An entry's ID or goal collides with another entry or an existing team", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn batch_create_teams(
+    ctx: CompanyContext,
+    State(pool): State<PgPool>,
+    Extension(flags): Extension<FeatureFlags>,
+    enforce_unique_goal: Option<Extension<EnforceUniqueGoal>>,
+    max_budget: Option<Extension<MaxTeamBudget>>,
+    payload: Result<Json<BatchCreateTeamsRequest>, JsonRejection>,
+) -> Result<(StatusCode, Json<BatchCreateTeamsResponse>), ApiError> {
+    if !ctx.is_admin() {
+        return Err(ApiError::forbidden("Admin role required"));
+    }
+    let Json(req) = payload?;
+    let max_budget = max_budget.map(|Extension(max)| max).unwrap_or_default();
+
+    if req.teams.is_empty() {
+        return Err(ApiError::bad_request(
+            "Batch must contain at least one team",
+        ));
+    }
+    if req.teams.len() > MAX_BATCH_TEAMS {
+        return Err(ApiError::bad_request(format!(
+            "Batch may contain at most {} teams",
+            MAX_BATCH_TEAMS
+        )));
+    }
+
+    // Validate every entry before touching the database
+    let mut first_index_of: HashMap<Uuid, usize> = HashMap::new();
+    let mut teams = Vec::with_capacity(req.teams.len());
+    let mut errors = Vec::new();
+    for (index, entry) in req.teams.into_iter().enumerate() {
+        let id = entry.id.unwrap_or_else(Uuid::new_v4);
+        if let Some(first) = first_index_of.insert(id, index) {
+            return Err(ApiError::conflict(format!(
+                "Entry {} reuses the ID of entry {}",
+                index, first
+            )));
+        }
+
+        let validated = ValidatedCreateTeam::validate(
+            CreateTeamRequest {
+                goal: entry.goal,
+                company_id: ctx.company_id,
+                created_by: ctx.user_id,
+                budget_limit: entry.budget_limit,
+                budget_currency: entry.budget_currency,
+                tags: entry.tags,
+            },
+            max_budget,
+        );
+        match validated {
+            Ok(req) => teams.push((id, req)),
+            Err(entry_errors) => errors.extend(
+                entry_errors
+                    .into_iter()
+                    .map(|e| format!("Entry {}: {}", index, e)),
+            ),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ApiError::bad_request(errors.join("; ")));
+    }
+
+    let mut created = Vec::with_capacity(teams.len());
+    for (id, req) in teams {
+        let (mut team, mut events) = Team::new_with_id(
+            id,
+            req.company_id,
+            req.goal,
+            req.created_by,
+            req.budget_limit,
+            &SystemClock,
+        )?;
+        if !req.tags.is_empty() {
+            events.push(team.set_tags(req.tags).map_err(ApiError::bad_request)?);
+        }
+        created.push((team, events));
+    }
+
+    ensure_active_team_room(&pool, ctx.company_id, created.len() as i64).await?;
+    let unique_goal =
+        unique_goal_policy_enabled(&flags, enforce_unique_goal, ctx.company_id).await?;
+    let team_repo = PostgresTeamRepository::new(pool).with_unique_goal(unique_goal);
+
+    // Dropping the transaction on an error rolls back earlier entries
+    let mut tx = team_repo
+        .begin()
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    for (index, (team, events)) in created.iter().enumerate() {
+        tx.insert(team).await.map_err(|e| {
+            if e.contains(UNIQUE_GOAL_INDEX) {
+                ApiError::conflict("A team with this goal already exists")
+                    .with_code(ErrorCode::DuplicateGoal)
+            } else if e.contains("_pkey") {
+                ApiError::conflict(format!("Entry {} uses the ID of an existing team", index))
+            } else {
+                ApiError::repository("Failed to save team", e)
+            }
+        })?;
+        tx.record_events(ctx.company_id, events)
+            .await
+            .map_err(|e| ApiError::repository("Failed to record events", e))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|e| ApiError::repository("Failed to save teams", e))?;
+
+    for (_, events) in &created {
+        EventLogger.log(events);
+    }
+    tracing::info!(
+        "User {} created {} teams in company {}",
+        ctx.user_id,
+        created.len(),
+        ctx.company_id
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(BatchCreateTeamsResponse {
+            teams: created
+                .iter()
+                .map(|(team, _)| TeamResponse::from(team))
+                .collect(),
+        }),
+    ))
+}
+
 /// Get a team by ID (requires authentication)
 ///
 /// GET /api/teams/:id
+///
+/// Teams belonging to another company are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The team", body = TeamResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
 pub async fn get_team(
-    JwtAuth(user_id): JwtAuth,
+    Tenant(company_id): Tenant,
     State(pool): State<PgPool>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<TeamResponse>, ApiError> {
-    // Log the authenticated user (in production, you might check permissions here)
-    tracing::info!("User {} accessing team {}", user_id, id);
+    let team_repo = PostgresTeamRepository::new(pool);
+    let team = load_snapshot_or_404(&team_repo, id, company_id).await?;
+
+    Ok(Json(TeamResponse::from(&team)))
+}
+
+/// Partially update a team (requires authentication)
+///
+/// PATCH /api/teams/:id
+///
+/// Applies any subset of `goal` and `budget_limit` through the domain
+/// methods and returns the updated team. Teams belonging to another
+/// company are reported as not found.
+#[utoipa::path(
+    patch,
+    path = "/api/teams/{id}",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    request_body = UpdateTeamRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Updated team", body = TeamResponse),
+        (status = 400, description = "No updates, invalid values, or the team can no longer change", body = ErrorResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn update_team(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    max_budget: Option<Extension<MaxTeamBudget>>,
+    payload: Result<Json<UpdateTeamRequest>, JsonRejection>,
+) -> Result<Json<TeamResponse>, ApiError> {
+    let Json(req) = payload?;
+    let max_budget = max_budget.map(|Extension(max)| max).unwrap_or_default();
+    if req.goal.is_none() && req.budget_limit.is_none() {
+        return Err(ApiError::bad_request("No updates provided"));
+    }
 
     let team_repo = PostgresTeamRepository::new(pool);
-    let team = team_repo
-        .find_by_id(id)
+    let mut team = load_team_or_404(&team_repo, id, company_id).await?;
+
+    let mut events = Vec::new();
+    if let Some(goal) = req.goal {
+        events.push(team.update_goal(goal).map_err(ApiError::bad_request)?);
+    }
+    if let Some(amount) = req.budget_limit {
+        // Keep the existing budget's currency when changing the amount
+        let currency = team
+            .budget_limit()
+            .map(|b| b.currency())
+            .unwrap_or_default();
+        let budget_limit = amount
+            .map(|amount| {
+                validate_budget_amount(amount, max_budget)?;
+                Money::new(amount, currency).map_err(|e| format!("Invalid budget: {}", e))
+            })
+            .transpose()
+            .map_err(ApiError::bad_request)?;
+        events.push(
+            team.update_budget(budget_limit)
+                .map_err(ApiError::bad_request)?,
+        );
+    }
+
+    save_with_events(&team_repo, &team, &events, |e| {
+        ApiError::repository("Failed to save team", e)
+    })
+    .await?;
+    EventLogger.log(&events);
+
+    Ok(Json(TeamResponse::from(&team)))
+}
+
+/// Replace a team's tags
+///
+/// PUT /api/teams/:id/tags
+///
+/// Tags are lowercased and deduplicated; at most 10, each up to 50 characters.
+#[utoipa::path(
+    put,
+    path = "/api/teams/{id}/tags",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    request_body = UpdateTeamTagsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Team with its new tags", body = TeamResponse),
+        (status = 400, description = "Invalid tags or finished team", body = ErrorResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn set_team_tags(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateTeamTagsRequest>,
+) -> Result<Json<TeamResponse>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool);
+    let mut team = load_team_or_404(&team_repo, id, company_id).await?;
+
+    let events = vec![team.set_tags(req.tags).map_err(ApiError::bad_request)?];
+
+    save_with_events(&team_repo, &team, &events, |e| {
+        ApiError::repository("Failed to save team", e)
+    })
+    .await?;
+    EventLogger.log(&events);
+
+    Ok(Json(TeamResponse::from(&team)))
+}
+
+/// Transfer a team to another user (requires the owner or an admin)
+///
+/// POST /api/teams/:id/transfer
+///
+/// The new owner must be an active user of the team's company. Teams of
+/// another company are reported as not found.
+#[utoipa::path(
+    post,
+    path = "/api/teams/{id}/transfer",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    request_body = TransferTeamRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Team with its new owner", body = TeamResponse),
+        (status = 400, description = "New owner is not an active user of the company, or already owns the team", body = ErrorResponse),
+        (status = 403, description = "Caller is neither the owner nor an admin", body = ErrorResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn transfer_team_ownership(
+    ctx: CompanyContext,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<TransferTeamRequest>,
+) -> Result<Json<TeamResponse>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    let mut team = load_team_or_404(&team_repo, id, ctx.company_id).await?;
+    if team.created_by() != ctx.user_id && !ctx.is_admin() {
+        return Err(ApiError::forbidden(
+            "Only the team's owner or an admin can transfer it",
+        ));
+    }
+
+    let new_owner = PostgresUserRepository::new(pool)
+        .find_by_id(req.new_owner_id)
         .await
-        .map_err(|e| ApiError::internal_server_error(format!("Database error: {}", e)))?
-        .ok_or_else(|| ApiError::not_found(format!("Team not found: {}", id)))?;
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .filter(|user| user.company_id == team.company_id() && user.is_active);
+    if new_owner.is_none() {
+        return Err(ApiError::bad_request(format!(
+            "New owner {} is not an active user of this company",
+            req.new_owner_id
+        )));
+    }
+
+    let events = vec![team
+        .transfer_ownership(req.new_owner_id)
+        .map_err(ApiError::bad_request)?];
+
+    save_with_events(&team_repo, &team, &events, |e| {
+        ApiError::repository("Failed to save team", e)
+    })
+    .await?;
+    EventLogger.log(&events);
+
+    Ok(Json(TeamResponse::from(&team)))
+}
+
+/// Cancel a team and stop its running agents (requires authentication)
+///
+/// POST /api/teams/:id/cancel
+///
+/// The active team is marked failed with the reason "Cancelled" and its
+/// in-flight agent work is stopped. Teams of another company are reported
+/// as not found.
+#[utoipa::path(
+    post,
+    path = "/api/teams/{id}/cancel",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The cancelled team", body = TeamResponse),
+        (status = 400, description = "The team is not active", body = ErrorResponse),
+        (status = 403, description = "Caller is neither the owner nor an admin", body = ErrorResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn cancel_team(
+    ctx: CompanyContext,
+    State(pool): State<PgPool>,
+    Extension(cancellations): Extension<TeamCancellations>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TeamResponse>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool);
+    let mut team = load_team_or_404(&team_repo, id, ctx.company_id).await?;
+    if team.created_by() != ctx.user_id && !ctx.is_admin() {
+        return Err(ApiError::forbidden(
+            "Only the team's owner or an admin can cancel it",
+        ));
+    }
+
+    let events = vec![team
+        .fail("Cancelled".to_string(), &SystemClock)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?];
+
+    save_with_events(&team_repo, &team, &events, |e| {
+        ApiError::repository("Failed to save team", e)
+    })
+    .await?;
+    EventLogger.log(&events);
+    cancellations.cancel(id);
 
     Ok(Json(TeamResponse::from(&team)))
 }
 
-/// Get all teams for a company
+/// Get a page of a team's activity feed (requires authentication)
+///
+/// GET /api/teams/:id/events?after=&limit=
+///
+/// Keyset-paged by `after`, so `limit` follows [`normalize_pagination`]
+/// (default 50, clamped to 1-200) rather than [`crate::api::pagination::Pagination`].
+/// Teams belonging to another company are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}/events",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID"), TeamEventsQuery),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "One page of the team's events, oldest first", body = TeamEventsResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_team_events(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TeamEventsQuery>,
+) -> Result<Json<TeamEventsResponse>, ApiError> {
+    let (limit, after) = normalize_pagination(query.limit, query.after);
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    load_team_or_404(&team_repo, id, company_id).await?;
+
+    // Fetch one extra row to learn whether another page exists
+    let event_repo = PostgresTeamEventRepository::new(pool);
+    let mut events = event_repo
+        .list_after(id, after, limit + 1)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    let has_more = events.len() as i64 > limit;
+    events.truncate(limit as usize);
+    let next_cursor = events.last().map(|e| e.sequence);
+
+    Ok(Json(TeamEventsResponse {
+        events,
+        next_cursor,
+        has_more,
+    }))
+}
+
+/// Get a breakdown of a team's recorded spend (requires authentication)
+///
+/// GET /api/teams/:id/cost-breakdown
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}/cost-breakdown",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Spend breakdown", body = CostBreakdownResponse),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_cost_breakdown(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CostBreakdownResponse>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    let team = load_team_or_404(&team_repo, id, company_id).await?;
+
+    let cost_repo = PostgresCostRepository::new(pool);
+    let breakdown = cost_repo
+        .breakdown_by_specialization(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    let total_spent: Decimal = breakdown.iter().map(|cost| cost.total).sum();
+    let by_specialization = breakdown
+        .into_iter()
+        .map(|cost| {
+            let key = cost
+                .specialization
+                .unwrap_or_else(|| "unattributed".to_string());
+            (key, cost.total)
+        })
+        .collect();
+
+    Ok(Json(CostBreakdownResponse {
+        total_spent,
+        by_specialization,
+        remaining: team
+            .budget_limit()
+            .map(|budget| budget.amount() - team.amount_spent()),
+    }))
+}
+
+/// Get the Manager Agent formed for a team (requires authentication)
+///
+/// GET /api/teams/:id/manager
+///
+/// Teams belonging to another company are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}/manager",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The team's manager", body = ManagerResponse),
+        (status = 404, description = "Team not found or no manager formed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_team_manager(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ManagerResponse>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    load_team_or_404(&team_repo, id, company_id).await?;
+
+    let manager_repo = PostgresManagerRepository::new(pool);
+    let manager = manager_repo
+        .find_by_team(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .ok_or_else(|| ApiError::not_found(format!("No manager formed for team: {}", id)))?;
+
+    Ok(Json(ManagerResponse::from(&manager)))
+}
+
+/// Get the workers formed for a team (requires authentication)
+///
+/// GET /api/teams/:id/workers
+///
+/// Teams belonging to another company are reported as not found. A team
+/// that has not been formed yet has no workers.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}/workers",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The team's workers", body = Vec<WorkerResponse>),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_team_workers(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<WorkerResponse>>, ApiError> {
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    load_team_or_404(&team_repo, id, company_id).await?;
+
+    let worker_repo = PostgresWorkerRepository::new(pool);
+    let workers = worker_repo
+        .find_by_team(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    Ok(Json(workers.iter().map(WorkerResponse::from).collect()))
+}
+
+/// Collects `status` query values, accepting both repeated keys and
+/// comma-separated lists (`?status=pending,active&status=planning`)
+fn parse_status_filter(params: &[(String, String)]) -> Result<Vec<TeamStatus>, ApiError> {
+    params
+        .iter()
+        .filter(|(key, _)| key == "status")
+        .flat_map(|(_, value)| value.split(','))
+        .map(|status| {
+            status
+                .trim()
+                .parse::<TeamStatus>()
+                .map_err(ApiError::bad_request)
+        })
+        .collect()
+}
+
+/// Get all teams for a company (requires authentication)
+///
+/// GET /api/teams/company/:company_id?status=&tag=
 ///
-/// GET /api/teams/company/:company_id
+/// Callers may only list their own company's teams. An optional `status`
+/// filter restricts the listing to teams in any of the given statuses, and
+/// an optional `tag` filter to teams carrying that tag.
+#[utoipa::path(
+    get,
+    path = "/api/teams/company/{company_id}",
+    tag = "teams",
+    params(("company_id" = Uuid, Path, description = "Company ID"),
+        ("status" = Option<String>, Query, description = "Statuses to include; repeat or comma-separate"),
+        ("tag" = Option<String>, Query, description = "Only teams carrying this tag")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The company's teams", body = Vec<TeamListItemResponse>),
+        (status = 400, description = "Unknown status", body = ErrorResponse),
+        (status = 403, description = "Another company's teams", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
 pub async fn get_teams_by_company(
+    Tenant(tenant_id): Tenant,
     State(pool): State<PgPool>,
+    Extension(ReadPool(read_pool)): Extension<ReadPool>,
     Path(company_id): Path<Uuid>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<Json<Vec<TeamListItemResponse>>, ApiError> {
+    if company_id != tenant_id {
+        return Err(ApiError::forbidden("Cannot access another company's teams"));
+    }
+
+    let statuses = parse_status_filter(&params)?;
+    let tag = params
+        .iter()
+        .find(|(key, _)| key == "tag")
+        .map(|(_, value)| value.trim().to_lowercase());
+
+    let team_repo = PostgresTeamRepository::new(pool.clone()).with_read_pool(read_pool.clone());
+    let mut teams = match (&tag, statuses.is_empty()) {
+        (Some(tag), _) => team_repo.find_by_tag(company_id, tag).await,
+        (None, true) => team_repo.find_by_company(company_id).await,
+        (None, false) => team_repo.find_by_statuses(company_id, &statuses).await,
+    }
+    .map_err(|e| ApiError::repository("Database error", e))?;
+    if tag.is_some() && !statuses.is_empty() {
+        teams.retain(|team| statuses.contains(&team.status()));
+    }
+
+    // Resolve every creator name in one query
+    let mut creator_ids: Vec<Uuid> = teams.iter().map(|t| t.created_by()).collect();
+    creator_ids.sort();
+    creator_ids.dedup();
+
+    let user_repo = PostgresUserRepository::new(pool).with_read_pool(read_pool);
+    let creator_names: HashMap<Uuid, String> = user_repo
+        .find_by_ids(&creator_ids)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .into_iter()
+        .map(|user| (user.id, user.full_name))
+        .collect();
+
+    let responses = teams
+        .iter()
+        .map(|team| TeamListItemResponse {
+            team: TeamResponse::from(team),
+            created_by_name: creator_names.get(&team.created_by()).cloned(),
+        })
+        .collect();
+
+    Ok(Json(responses))
+}
+
+/// List the caller's company's teams created within a time range
+///
+/// GET /api/teams?from=&to=
+///
+/// Both bounds are inclusive RFC 3339 timestamps; `from` must not be after `to`.
+#[utoipa::path(
+    get,
+    path = "/api/teams",
+    tag = "teams",
+    params(CreatedBetweenQuery),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Teams created in the range", body = Vec<TeamResponse>),
+        (status = 400, description = "`from` is after `to`", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_teams_created_between(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Extension(ReadPool(read_pool)): Extension<ReadPool>,
+    Query(range): Query<CreatedBetweenQuery>,
 ) -> Result<Json<Vec<TeamResponse>>, ApiError> {
-    let team_repo = PostgresTeamRepository::new(pool);
+    if range.from > range.to {
+        return Err(ApiError::bad_request("`from` must not be after `to`"));
+    }
+
+    let team_repo = PostgresTeamRepository::new(pool).with_read_pool(read_pool);
     let teams = team_repo
-        .find_by_company(company_id)
+        .find_created_between(company_id, range.from, range.to)
         .await
-        .map_err(|e| ApiError::internal_server_error(format!("Database error: {}", e)))?;
+        .map_err(|e| ApiError::repository("Database error", e))?;
 
-    let responses = teams.iter().map(TeamResponse::from).collect();
+    Ok(Json(teams.iter().map(TeamResponse::from).collect()))
+}
 
-    Ok(Json(responses))
+/// Count a company's teams per status (requires authentication)
+///
+/// GET /api/teams/company/:company_id/stats
+#[utoipa::path(
+    get,
+    path = "/api/teams/company/{company_id}/stats",
+    tag = "teams",
+    params(("company_id" = Uuid, Path, description = "Company ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Team counts", body = TeamStatsResponse),
+        (status = 403, description = "Another company's teams", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn get_team_stats(
+    Tenant(tenant_id): Tenant,
+    State(pool): State<PgPool>,
+    Extension(ReadPool(read_pool)): Extension<ReadPool>,
+    Path(company_id): Path<Uuid>,
+) -> Result<Json<TeamStatsResponse>, ApiError> {
+    if company_id != tenant_id {
+        return Err(ApiError::forbidden("Cannot access another company's teams"));
+    }
+
+    let team_repo = PostgresTeamRepository::new(pool).with_read_pool(read_pool);
+    let counts = team_repo
+        .count_by_status(company_id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    Ok(Json(TeamStatsResponse {
+        total: counts.values().sum(),
+        by_status: counts
+            .into_iter()
+            .map(|(status, count)| (status.to_string(), count))
+            .collect(),
+    }))
 }
 
-/// Delete a team
+/// Header row of the team CSV export
+const TEAMS_CSV_HEADER: &str = "id,goal,status,created_at,budget_limit,spent\r\n";
+
+/// Export a company's teams as CSV (requires authentication)
+///
+/// GET /api/companies/:id/teams.csv
+///
+/// Callers may only export their own company's teams. Rows are streamed
+/// from the database as they are read, newest team first; a database
+/// error part-way through ends the download early.
+#[utoipa::path(
+    get,
+    path = "/api/companies/{company_id}/teams.csv",
+    tag = "teams",
+    params(("company_id" = Uuid, Path, description = "Company ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "CSV with one row per team", body = String, content_type = "text/csv"),
+        (status = 403, description = "Another company's teams", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn export_teams_csv(
+    Tenant(tenant_id): Tenant,
+    State(pool): State<PgPool>,
+    Extension(ReadPool(read_pool)): Extension<ReadPool>,
+    Path(company_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    if company_id != tenant_id {
+        return Err(ApiError::forbidden("Cannot access another company's teams"));
+    }
+
+    let rows = PostgresTeamRepository::new(pool)
+        .with_read_pool(read_pool)
+        .stream_by_company(company_id)
+        .map_ok(|team| teams_csv_row(&team))
+        .inspect_err(move |e| {
+            tracing::error!("Team CSV export for company {} failed: {}", company_id, e)
+        });
+    let header_row = stream::once(async { Ok(TEAMS_CSV_HEADER.to_string()) });
+    let body = Body::from_stream(header_row.chain(rows));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"teams.csv\"",
+            ),
+        ],
+        body,
+    ))
+}
+
+/// One CSV record for `team`, including the line terminator
+fn teams_csv_row(team: &Team) -> String {
+    format!(
+        "{},{},{},{},{},{}\r\n",
+        team.id(),
+        csv_field(team.goal()),
+        team.status(),
+        team.created_at().to_rfc3339(),
+        team.budget_limit()
+            .map(|budget| budget.amount().to_string())
+            .unwrap_or_default(),
+        team.amount_spent(),
+    )
+}
+
+/// Quotes a CSV field when it contains a comma, quote, or line break
+///
+/// Embedded quotes are doubled, per RFC 4180. Values a spreadsheet would
+/// evaluate as a formula (starting with `=`, `+`, `-`, or `@`) are prefixed
+/// with `'` so they open as text.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        Cow::Owned(format!("'{}", value))
+    } else {
+        Cow::Borrowed(value)
+    };
+
+    if value.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        value
+    }
+}
+
+/// Delete a team (requires authentication)
 ///
 /// DELETE /api/teams/:id
+///
+/// The team's in-flight agent work is stopped. Teams belonging to another
+/// company are reported as not found.
+#[utoipa::path(
+    delete,
+    path = "/api/teams/{id}",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "Team deleted"),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
 pub async fn delete_team(
+    Tenant(company_id): Tenant,
     State(pool): State<PgPool>,
+    Extension(cancellations): Extension<TeamCancellations>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, ApiError> {
     let team_repo = PostgresTeamRepository::new(pool);
-    team_repo.delete(id).await.map_err(|e| {
-        if e.contains("not found") {
-            ApiError::not_found(e)
-        } else {
-            ApiError::internal_server_error(format!("Failed to delete team: {}", e))
-        }
-    })?;
+    load_team_or_404(&team_repo, id, company_id).await?;
+
+    // A concurrent delete after the load above is still a 404
+    team_repo
+        .delete(id)
+        .await
+        .map_err(|e| ApiError::from_repository("Failed to delete team", e))?;
+    cancellations.cancel(id);
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Delete several teams in one transaction (requires admin)
+///
+/// POST /api/teams/bulk-delete
+///
+/// Only teams belonging to the caller's company are deleted; other IDs are
+/// counted as skipped and listed in `missing`. Deleted teams' in-flight
+/// agent work is stopped.
+#[utoipa::path(
+    post,
+    path = "/api/teams/bulk-delete",
+    tag = "teams",
+    request_body = BulkDeleteTeamsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Counts of deleted and skipped teams", body = BulkDeleteTeamsResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn bulk_delete_teams(
+    TenantAdmin(company_id): TenantAdmin,
+    State(pool): State<PgPool>,
+    Extension(cancellations): Extension<TeamCancellations>,
+    Json(req): Json<BulkDeleteTeamsRequest>,
+) -> Result<Json<BulkDeleteTeamsResponse>, ApiError> {
+    let mut team_ids = Vec::with_capacity(req.team_ids.len());
+    for id in req.team_ids {
+        if !team_ids.contains(&id) {
+            team_ids.push(id);
+        }
+    }
+
+    let team_repo = PostgresTeamRepository::new(pool);
+    let found: HashSet<Uuid> = team_repo
+        .find_by_ids(company_id, &team_ids)
+        .await
+        .map_err(|e| ApiError::repository("Failed to load teams", e))?
+        .iter()
+        .map(Team::id)
+        .collect();
+    let (existing, missing): (Vec<Uuid>, Vec<Uuid>) =
+        team_ids.into_iter().partition(|id| found.contains(id));
+
+    let deleted = team_repo
+        .delete_many(company_id, &existing)
+        .await
+        .map_err(|e| ApiError::repository("Failed to delete teams", e))?;
+    for team_id in &deleted {
+        cancellations.cancel(*team_id);
+    }
+
+    Ok(Json(BulkDeleteTeamsResponse {
+        deleted: deleted.len(),
+        skipped: missing.len(),
+        missing,
+    }))
+}
+
+/// Export a team with its workers, tasks, and events (requires authentication)
+///
+/// GET /api/teams/:id/export
+///
+/// Teams belonging to another company are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/api/teams/{id}/export",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Team ID")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The team with its manager, workers, tasks, and events", body = Object),
+        (status = 404, description = "Team not found in the caller's company", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn export_team(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TeamExport>, ApiError> {
+    let export = transfer::export_team(&pool, id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .filter(|export| export.team.company_id == company_id)
+        .ok_or_else(|| ApiError::not_found(format!("Team not found: {}", id)))?;
+
+    Ok(Json(export))
+}
+
+/// Recreate an exported team in the caller's company (requires admin)
+///
+/// POST /api/teams/import?preserve_ids=
+///
+/// The team, manager, workers, and tasks get new IDs unless
+/// `preserve_ids=true`, in which case the import is rejected with 409 if
+/// any of those IDs is already taken. The 409 does not say which ID, or
+/// where, so it reveals nothing about other companies. The importing
+/// admin becomes the team's creator. Imports count toward the active
+/// team quota and follow the company's unique-goal policy like new teams.
+/// Everything is stored in one transaction after the team's invariants
+/// are checked.
+#[utoipa::path(
+    post,
+    path = "/api/teams/import",
+    tag = "teams",
+    params(ImportTeamQuery),
+    request_body(content = Object, description = "A team export, as returned by the export endpoint"),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Imported team", body = TeamResponse),
+        (status = 400, description = "Malformed export or invalid team", body = ErrorResponse),
+        (status = 403, description = "Admin role required, or the company has reached its active team limit", body = ErrorResponse),
+        (status = 409, description = "Preserved IDs already in use, or a team with this goal already exists", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn import_team(
+    ctx: CompanyContext,
+    State(pool): State<PgPool>,
+    Extension(flags): Extension<FeatureFlags>,
+    enforce_unique_goal: Option<Extension<EnforceUniqueGoal>>,
+    Query(query): Query<ImportTeamQuery>,
+    payload: Result<Json<TeamExport>, JsonRejection>,
+) -> Result<(StatusCode, Json<TeamResponse>), ApiError> {
+    if !ctx.is_admin() {
+        return Err(ApiError::forbidden("Admin role required"));
+    }
+    let Json(export) = payload?;
+
+    let export = if query.preserve_ids {
+        export
+    } else {
+        export.with_new_ids()
+    };
+    let team = export
+        .validated_team(ctx.company_id, ctx.user_id)
+        .map_err(ApiError::bad_request)?;
+
+    let ids_taken = || ApiError::conflict("The export contains IDs that are already in use");
+    if query.preserve_ids {
+        let taken = transfer::find_taken_ids(&pool, &export.ids())
+            .await
+            .map_err(|e| ApiError::repository("Database error", e))?;
+        if !taken.is_empty() {
+            return Err(ids_taken());
+        }
+    }
+
+    if !team.status().is_terminal() {
+        ensure_active_team_quota(&pool, ctx.company_id).await?;
+    }
+    let unique_goal =
+        unique_goal_policy_enabled(&flags, enforce_unique_goal, ctx.company_id).await?;
+    transfer::import_team(&pool, &team, &export, unique_goal)
+        .await
+        .map_err(|e| {
+            if e.contains(UNIQUE_GOAL_INDEX) {
+                ApiError::conflict("A team with this goal already exists")
+                    .with_code(ErrorCode::DuplicateGoal)
+            } else if e.contains("_pkey") {
+                // An ID was taken after the check above
+                ids_taken()
+            } else {
+                ApiError::repository("Failed to import team", e)
+            }
+        })?;
+
+    tracing::info!(
+        "User {} imported team {} into company {}",
+        ctx.user_id,
+        team.id(),
+        ctx.company_id
+    );
+
+    Ok((StatusCode::CREATED, Json(TeamResponse::from(&team))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("Plain goal"), "Plain goal");
+        assert_eq!(csv_field("Scrape, parse"), "\"Scrape, parse\"");
+        assert_eq!(csv_field("Say \"hi\""), "\"Say \"\"hi\"\"\"");
+        assert_eq!(csv_field("Line\nbreak"), "\"Line\nbreak\"");
+    }
+
+    #[test]
+    fn csv_fields_that_look_like_formulas_open_as_text() {
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-2+3"), "'-2+3");
+        assert_eq!(csv_field("@SUM(A1:A2)"), "'@SUM(A1:A2)");
+        assert_eq!(csv_field("Sum = 3"), "Sum = 3");
+    }
+
+    fn request() -> CreateTeamRequest {
+        CreateTeamRequest {
+            goal: "Ship the release".to_string(),
+            company_id: Uuid::new_v4(),
+            created_by: Uuid::new_v4(),
+            budget_limit: Some(Decimal::new(10050, 2)),
+            budget_currency: None,
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn valid_request_is_trimmed_and_converted() {
+        let mut req = request();
+        req.goal = "  Ship the release \n".to_string();
+
+        let validated = ValidatedCreateTeam::try_from(req).unwrap();
+
+        assert_eq!(validated.goal, "Ship the release");
+        assert_eq!(
+            validated.budget_limit,
+            Some(Money::new(Decimal::new(10050, 2), Currency::Usd).unwrap())
+        );
+    }
+
+    #[test]
+    fn nil_ids_and_blank_goal_are_all_reported() {
+        let mut req = request();
+        req.company_id = Uuid::nil();
+        req.created_by = Uuid::nil();
+        req.goal = "   ".to_string();
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().any(|e| e.contains("company_id")));
+        assert!(errors.iter().any(|e| e.contains("created_by")));
+        assert!(errors.iter().any(|e| e.contains("Goal cannot be empty")));
+    }
+
+    #[test]
+    fn long_goal_and_bad_budget_precision_are_both_reported() {
+        let mut req = request();
+        req.goal = "x".repeat(MAX_GOAL_LENGTH + 1);
+        req.budget_limit = Some(Decimal::new(1001, 3));
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("at most 1000 characters")));
+        assert!(errors.iter().any(|e| e.contains("decimal places")));
+    }
+
+    #[test]
+    fn unknown_currency_and_negative_budget_are_both_reported() {
+        let mut req = request();
+        req.budget_currency = Some("XYZ".to_string());
+        req.budget_limit = Some(Decimal::from(-5));
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("must be positive")));
+    }
+
+    #[test]
+    fn tags_are_normalized_and_validated_with_other_fields() {
+        let mut req = request();
+        req.tags = vec!["Q3".to_string(), "q3".to_string()];
+        assert_eq!(ValidatedCreateTeam::try_from(req).unwrap().tags, vec!["q3"]);
+
+        let mut req = request();
+        req.goal = String::new();
+        req.tags = vec!["x".repeat(51)];
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("Tag must be at most")));
+    }
+
+    #[test]
+    fn budget_above_column_range_is_rejected() {
+        assert_eq!(MAX_BUDGET, Decimal::new(999_999_999_999, 2));
+        let mut req = request();
+        req.budget_limit = Some(Decimal::from(10_000_000_000i64));
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("must not exceed"));
+    }
+
+    #[test]
+    fn trailing_zeros_do_not_count_as_precision() {
+        let mut req = request();
+        req.budget_limit = Some(Decimal::new(100000, 4));
+
+        assert!(ValidatedCreateTeam::try_from(req).is_ok());
+    }
+
+    #[test]
+    fn team_response_serializes_ids_as_hyphenated_lowercase() {
+        let id = Uuid::parse_str("6BA7B810-9DAD-11D1-80B4-00C04FD430C8").unwrap();
+        let response = TeamResponse {
+            id,
+            company_id: id,
+            goal: "Ship the release".to_string(),
+            status: "Pending".to_string(),
+            created_by: id,
+            budget_limit: None,
+            budget_currency: None,
+            tags: Vec::new(),
+            created_at: Utc::now(),
+            estimated_completion: None,
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+
+        for field in ["id", "company_id", "created_by"] {
+            assert_eq!(json[field], "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        }
+    }
+
+    #[test]
+    fn team_response_serializes_created_at_as_utc_rfc3339() {
+        use chrono::TimeZone;
+
+        let team = TeamSnapshot {
+            id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            goal: "Ship the release".to_string(),
+            status: TeamStatus::Pending,
+            created_by: Uuid::new_v4(),
+            budget_limit: None,
+            tags: Vec::new(),
+            created_at: Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 5).unwrap(),
+            started_at: None,
+            estimated_hours: None,
+        };
+
+        let json = serde_json::to_value(TeamResponse::from(&team)).unwrap();
+
+        assert_eq!(json["created_at"], "2024-03-01T12:30:05.000Z");
+        assert!(json["estimated_completion"].is_null());
+    }
+
+    #[test]
+    fn team_response_projects_completion_for_active_teams_only() {
+        use chrono::TimeZone;
+
+        let started_at = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let mut team = TeamSnapshot {
+            id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            goal: "Ship the release".to_string(),
+            status: TeamStatus::Active,
+            created_by: Uuid::new_v4(),
+            budget_limit: None,
+            tags: Vec::new(),
+            created_at: started_at,
+            started_at: Some(started_at),
+            estimated_hours: Some(1.5),
+        };
+
+        let response = TeamResponse::from(&team);
+        assert_eq!(
+            response.estimated_completion,
+            Some(started_at + chrono::Duration::minutes(90))
+        );
+
+        team.status = TeamStatus::Completed;
+        assert_eq!(TeamResponse::from(&team).estimated_completion, None);
+    }
+
+    fn parse_request(budget: serde_json::Value) -> serde_json::Result<CreateTeamRequest> {
+        serde_json::from_value(serde_json::json!({
+            "company_id": Uuid::new_v4(),
+            "goal": "Ship the release",
+            "created_by": Uuid::new_v4(),
+            "budget_limit": budget,
+        }))
+    }
+
+    #[test]
+    fn budget_accepts_numbers_and_decimal_strings() {
+        let numeric = parse_request(serde_json::json!(250.5)).unwrap();
+        let quoted = parse_request(serde_json::json!(" 250.50 ")).unwrap();
+
+        assert_eq!(numeric.budget_limit, Some(Decimal::new(2505, 1)));
+        assert_eq!(quoted.budget_limit, Some(Decimal::new(25050, 2)));
+        assert!(ValidatedCreateTeam::try_from(quoted).is_ok());
+    }
+
+    #[test]
+    fn non_numeric_budget_is_a_clear_error() {
+        let error = parse_request(serde_json::json!("abc")).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("budget_limit must be a decimal number"));
+        assert!(parse_request(serde_json::json!(true)).is_err());
+    }
+
+    #[test]
+    fn budget_above_configured_maximum_is_rejected() {
+        let req = parse_request(serde_json::json!("1000000.01")).unwrap();
+
+        let errors = ValidatedCreateTeam::try_from(req).unwrap_err();
+
+        assert_eq!(errors, vec!["Invalid budget: must not exceed 1000000"]);
+    }
+
+    #[test]
+    fn budget_is_capped_by_injected_maximum() {
+        let max_budget = MaxTeamBudget(Decimal::new(500, 0));
+        let within = parse_request(serde_json::json!("500")).unwrap();
+        let above = parse_request(serde_json::json!("500.01")).unwrap();
+
+        assert!(ValidatedCreateTeam::validate(within, max_budget).is_ok());
+        assert_eq!(
+            ValidatedCreateTeam::validate(above, max_budget).unwrap_err(),
+            vec!["Invalid budget: must not exceed 500"]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::api::errors::ApiError;
+use crate::api::handlers::teams::{unique_goal_policy_enabled, EnforceUniqueGoal};
+use crate::api::messages::ErrorCode;
+use crate::api::middleware::{CompanyContext, InternalService, TenantAdmin};
+use crate::api::pagination::{Paginated, Pagination};
+use crate::api::{timestamp_format, uuid_format};
+use crate::domain::repositories::user_repository::{
+    normalize_full_name, TeamHandling, User, UserRepository,
+};
+use crate::domain::user::value_objects::{Email, UserRole};
+use crate::infrastructure::database::ReadPool;
+use crate::infrastructure::feature_flags::FeatureFlags;
+use crate::infrastructure::repositories::postgres_team_repository::UNIQUE_GOAL_INDEX;
+use crate::infrastructure::repositories::PostgresUserRepository;
+
+/// Filters for listing a company's users; paging comes from [`Pagination`]
+#[derive(Debug, Deserialize)]
+pub struct ListUsersQuery {
+    /// Only active (`true`) or inactive (`false`) users; both if omitted
+    pub active: Option<bool>,
+}
+
+/// Request body for updating a user's profile
+///
+/// Omitted fields are left unchanged.
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserRequest {
+    pub full_name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Request body for moving a user to another company
+#[derive(Debug, Deserialize)]
+pub struct ChangeCompanyRequest {
+    pub company_id: Uuid,
+    /// What happens to the teams the user created; defaults to keeping
+    /// them in the old company
+    #[serde(default)]
+    pub teams: TeamHandling,
+}
+
+/// User profile representation
+#[derive(Debug, Serialize)]
+pub struct UserResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub id: Uuid,
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub company_id: Uuid,
+    pub email: String,
+    pub full_name: String,
+    pub role: UserRole,
+    pub is_active: bool,
+    #[serde(serialize_with = "timestamp_format::serialize")]
+    pub created_at: DateTime<Utc>,
+    #[serde(serialize_with = "timestamp_format::serialize")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&User> for UserResponse {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            company_id: user.company_id,
+            email: user.email.to_string(),
+            full_name: user.full_name.clone(),
+            role: user.role,
+            is_active: user.is_active,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+/// List the caller's company's users, by full name (requires admin)
+///
+/// GET /api/users?limit=&offset=&active=
+///
+/// `limit` (1-100, default 20) and `offset` are validated by
+/// [`Pagination`]; out-of-range values are rejected with 400.
+pub async fn list_users(
+    TenantAdmin(company_id): TenantAdmin,
+    State(pool): State<PgPool>,
+    Extension(ReadPool(read_pool)): Extension<ReadPool>,
+    Pagination { limit, offset }: Pagination,
+    Query(query): Query<ListUsersQuery>,
+) -> Result<Json<Paginated<UserResponse>>, ApiError> {
+    let user_repo = PostgresUserRepository::new(pool).with_read_pool(read_pool);
+    let total = user_repo
+        .count_by_company(company_id, query.active)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+    let users = user_repo
+        .find_by_company_paginated(company_id, query.active, limit, offset)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    Ok(Json(Paginated {
+        items: users.iter().map(UserResponse::from).collect(),
+        total,
+        limit,
+        offset,
+    }))
+}
+
+/// Update a user's full name and/or email (requires authentication)
+///
+/// PATCH /api/users/:id
+///
+/// Users may edit their own profile; admins may edit anyone in their
+/// company. Users in other companies are reported as not found. An email
+/// already used by another account is rejected with 409.
+pub async fn update_user(
+    ctx: CompanyContext,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateUserRequest>,
+) -> Result<Json<UserResponse>, ApiError> {
+    if req.full_name.is_none() && req.email.is_none() {
+        return Err(ApiError::bad_request("No updates provided"));
+    }
+    if ctx.user_id != id && !ctx.is_admin() {
+        return Err(ApiError::forbidden("Cannot update another user's profile"));
+    }
+
+    let user_repo = PostgresUserRepository::new(pool);
+    let mut user = user_repo
+        .find_by_id(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .filter(|user| user.company_id == ctx.company_id)
+        .ok_or_else(|| ApiError::not_found(format!("User not found: {}", id)))?;
+
+    if let Some(full_name) = req.full_name {
+        user.full_name = normalize_full_name(&full_name).map_err(ApiError::bad_request)?;
+    }
+    if let Some(email) = req.email {
+        let email = Email::new(email)
+            .map_err(|e| ApiError::bad_request(format!("Invalid email: {}", e)))?;
+
+        let taken = user_repo
+            .find_by_email(&email)
+            .await
+            .map_err(|e| ApiError::repository("Database error", e))?
+            .is_some_and(|other| other.id != id);
+        if taken {
+            return Err(duplicate_email());
+        }
+        user.email = email;
+    }
+
+    user.updated_at = user_repo
+        .update_profile(user.id, &user.full_name, &user.email)
+        .await
+        .map_err(|e| {
+            // A concurrent registration can still claim the email
+            if e.contains("duplicate") || e.contains("unique") {
+                duplicate_email()
+            } else {
+                ApiError::repository("Failed to update user", e)
+            }
+        })?;
+
+    Ok(Json(UserResponse::from(&user)))
+}
+
+/// Move a user to another company (internal callers only)
+///
+/// PUT /api/users/:id/company
+///
+/// Restricted to platform operators through the internal service secret,
+/// since it crosses tenants. The user becomes a member of the new company.
+/// Unknown users and companies are reported as not found; a reassignment
+/// target outside the user's old company is rejected with 400. Moved teams
+/// must fit the new company's active team quota (403) and, where it
+/// enforces unique goals, must not repeat one of its goals (409). Tokens
+/// issued before the move are revoked.
+pub async fn change_user_company(
+    _: InternalService,
+    State(pool): State<PgPool>,
+    Extension(flags): Extension<FeatureFlags>,
+    enforce_unique_goal: Option<Extension<EnforceUniqueGoal>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ChangeCompanyRequest>,
+) -> Result<Json<UserResponse>, ApiError> {
+    let unique_goal =
+        unique_goal_policy_enabled(&flags, enforce_unique_goal, req.company_id).await?;
+    let user_repo = PostgresUserRepository::new(pool).with_unique_goal(unique_goal);
+    user_repo
+        .change_company(id, req.company_id, req.teams)
+        .await
+        .map_err(|e| {
+            if e.contains("not found") {
+                ApiError::not_found(e)
+            } else if e.contains("not a user of company") {
+                ApiError::bad_request(e)
+            } else if e.contains("active team limit") {
+                ApiError::forbidden("Company has reached its active team limit")
+                    .with_code(ErrorCode::TeamQuotaExceeded)
+            } else if e.contains(UNIQUE_GOAL_INDEX) {
+                ApiError::conflict("A team with this goal already exists")
+                    .with_code(ErrorCode::DuplicateGoal)
+            } else {
+                ApiError::repository("Failed to change company", e)
+            }
+        })?;
+    CompanyContext::forget(id);
+
+    let user = user_repo
+        .find_by_id(id)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?
+        .ok_or_else(|| ApiError::not_found(format!("User not found: {}", id)))?;
+
+    Ok(Json(UserResponse::from(&user)))
+}
+
+fn duplicate_email() -> ApiError {
+    ApiError::conflict("Email already registered").with_code(ErrorCode::DuplicateEmail)
+}
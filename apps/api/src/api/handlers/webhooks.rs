@@ -0,0 +1,139 @@
+use axum::{extract::State, http::StatusCode, Extension, Json};
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::errors::ApiError;
+use crate::api::middleware::TenantAdmin;
+use crate::api::{timestamp_format, uuid_format};
+use crate::domain::repositories::{Webhook, WebhookRepository};
+use crate::domain::team::events::TeamEvent;
+use crate::infrastructure::repositories::PostgresWebhookRepository;
+use crate::infrastructure::webhooks::WebhookUrlPolicy;
+
+/// Number of random bytes in a webhook secret (hex-encoded to 64 characters)
+const SECRET_BYTES: usize = 32;
+
+/// Request body for registering a webhook
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegisterWebhookRequest {
+    /// `http` or `https` URL that receives event POSTs; its host must
+    /// resolve to public addresses only
+    #[schema(example = "https://example.com/hooks/teams")]
+    pub url: String,
+    /// Event types to deliver, e.g. `["created", "failed"]`; all if empty
+    #[serde(default)]
+    pub event_types: Vec<String>,
+}
+
+/// A registered webhook, including the secret its deliveries are signed with
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    /// HMAC-SHA256 key for the `x-webhook-signature` header; only shown here
+    pub secret: String,
+    #[serde(serialize_with = "timestamp_format::serialize")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Checks each filter names a known event type, dropping duplicates
+fn validate_event_types(event_types: Vec<String>) -> Result<Vec<String>, String> {
+    let mut validated: Vec<String> = Vec::with_capacity(event_types.len());
+    for event_type in event_types {
+        if !TeamEvent::EVENT_TYPES.contains(&event_type.as_str()) {
+            return Err(format!("Unknown event type: {}", event_type));
+        }
+        if !validated.contains(&event_type) {
+            validated.push(event_type);
+        }
+    }
+
+    Ok(validated)
+}
+
+fn generate_secret() -> String {
+    let mut bytes = [0u8; SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Register a webhook for the caller's company (requires admin)
+///
+/// POST /api/webhooks
+///
+/// Matching team events are POSTed to `url` as JSON, signed with the
+/// returned secret. URLs reaching loopback, private, or link-local
+/// addresses are rejected unless their host is in
+/// `WEBHOOK_ALLOWED_HOSTS`.
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    request_body = RegisterWebhookRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Webhook registered", body = WebhookResponse),
+        (status = 400, description = "Invalid or non-public URL, or unknown event type", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+    )
+)]
+pub async fn register_webhook(
+    TenantAdmin(company_id): TenantAdmin,
+    State(pool): State<PgPool>,
+    Extension(url_policy): Extension<WebhookUrlPolicy>,
+    Json(req): Json<RegisterWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookResponse>), ApiError> {
+    let url = url_policy
+        .check(&req.url)
+        .await
+        .map_err(ApiError::bad_request)?
+        .to_string();
+    let event_types = validate_event_types(req.event_types).map_err(ApiError::bad_request)?;
+
+    let webhook = Webhook {
+        id: Uuid::new_v4(),
+        company_id,
+        url,
+        secret: generate_secret(),
+        event_types,
+        created_at: Utc::now(),
+    };
+
+    let webhook_repo = PostgresWebhookRepository::new(pool);
+    webhook_repo
+        .create(&webhook)
+        .await
+        .map_err(|e| ApiError::repository("Failed to save webhook", e))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookResponse {
+            id: webhook.id,
+            url: webhook.url,
+            event_types: webhook.event_types,
+            secret: webhook.secret,
+            created_at: webhook.created_at,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_event_types_rejects_unknown_and_drops_duplicates() {
+        let types = vec!["created".to_string(), "created".to_string()];
+        assert_eq!(validate_event_types(types).unwrap(), vec!["created"]);
+
+        let err = validate_event_types(vec!["deleted".to_string()]).unwrap_err();
+        assert!(err.contains("deleted"));
+    }
+}
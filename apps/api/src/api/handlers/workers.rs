@@ -0,0 +1,117 @@
+use axum::{
+    extract::{rejection::JsonRejection, Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::errors::ApiError;
+use crate::api::handlers::teams::load_team_or_404;
+use crate::api::middleware::Tenant;
+use crate::api::uuid_format;
+use crate::domain::repositories::WorkerRepository;
+use crate::infrastructure::repositories::{PostgresTeamRepository, PostgresWorkerRepository};
+
+/// Times a skill update is retried after losing a race with another one
+const SKILL_UPDATE_ATTEMPTS: usize = 3;
+
+/// Request body for changing some of a worker's skills
+///
+/// Removals are applied before additions; both match case-insensitively.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWorkerSkillsRequest {
+    /// Skills to add; ones the worker already has are ignored
+    #[serde(default)]
+    pub add: Vec<String>,
+    /// Skills to remove; ones the worker does not have are ignored
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+/// A worker's skills after an update
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkerSkillsResponse {
+    #[serde(serialize_with = "uuid_format::serialize")]
+    pub id: Uuid,
+    pub skills: Vec<String>,
+}
+
+/// Add and remove a worker's skills (requires authentication)
+///
+/// PATCH /api/workers/:id/skills
+///
+/// Workers on another company's team are reported as not found. Coders
+/// and testers must keep at least one skill. Concurrent updates are
+/// applied one after the other, never losing either.
+#[utoipa::path(
+    patch,
+    path = "/api/workers/{id}/skills",
+    tag = "teams",
+    params(("id" = Uuid, Path, description = "Worker ID")),
+    request_body = UpdateWorkerSkillsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "The worker's new skills", body = WorkerSkillsResponse),
+        (status = 400, description = "No changes, a blank skill, or a coder or tester left without skills", body = ErrorResponse),
+        (status = 404, description = "Worker not found in the caller's company", body = ErrorResponse),
+        (status = 409, description = "The skills kept changing concurrently", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    )
+)]
+pub async fn update_worker_skills(
+    Tenant(company_id): Tenant,
+    State(pool): State<PgPool>,
+    Path(id): Path<Uuid>,
+    payload: Result<Json<UpdateWorkerSkillsRequest>, JsonRejection>,
+) -> Result<Json<WorkerSkillsResponse>, ApiError> {
+    let Json(req) = payload?;
+    if req.add.is_empty() && req.remove.is_empty() {
+        return Err(ApiError::bad_request("No skill changes provided"));
+    }
+    if req.add.iter().any(|skill| skill.trim().is_empty()) {
+        return Err(ApiError::bad_request("Skills cannot be blank"));
+    }
+
+    let worker_repo = PostgresWorkerRepository::new(pool.clone());
+    let team_repo = PostgresTeamRepository::new(pool);
+    let not_found = || ApiError::not_found(format!("Worker not found: {}", id));
+
+    for _ in 0..SKILL_UPDATE_ATTEMPTS {
+        let mut worker = worker_repo
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApiError::repository("Database error", e))?
+            .ok_or_else(not_found)?;
+        load_team_or_404(&team_repo, worker.team_id, company_id)
+            .await
+            .map_err(|e| match e.status {
+                StatusCode::NOT_FOUND => not_found(),
+                _ => e,
+            })?;
+
+        let expected = worker.skills.clone();
+        worker
+            .change_skills(&req.add, &req.remove)
+            .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
+        // Saved only if no other update changed the skills since they were
+        // read; otherwise start over from the new skills
+        let saved = worker_repo
+            .update_skills(worker.id, &expected, &worker.skills)
+            .await
+            .map_err(|e| ApiError::repository("Failed to save worker", e))?;
+        if saved {
+            return Ok(Json(WorkerSkillsResponse {
+                id: worker.id,
+                skills: worker.skills,
+            }));
+        }
+    }
+
+    Err(ApiError::conflict(
+        "Worker skills changed concurrently, please retry",
+    ))
+}
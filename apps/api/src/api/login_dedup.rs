@@ -0,0 +1,249 @@
+// Login deduplication
+// Answers identical logins arriving within a short window with one token
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+use crate::api::errors::ApiError;
+use crate::api::handlers::auth::LoginResponse;
+
+/// Default seconds within which identical logins share a token
+pub const DEFAULT_LOGIN_DEDUP_WINDOW_SECS: u64 = 5;
+
+/// Tracked logins above which expired entries are pruned
+const PRUNE_THRESHOLD: usize = 1024;
+
+type Entry = (Instant, Arc<OnceCell<LoginResponse>>);
+
+/// Short-lived cache of successful logins keyed by email, client, and password
+///
+/// Clients that retry or double-submit a login get the token minted for the
+/// first request instead of a fresh one, and the repeat costs no password
+/// check or database writes. Concurrent identical logins wait for the first
+/// to finish. Failed logins are not remembered, and the password is part of
+/// the key so a wrong password never receives a cached token. A cached
+/// token is only handed out again after the caller confirms it is still
+/// current, so disabling, locking, or revoking an account takes effect at
+/// once. Cloning is cheap and clones share one cache.
+#[derive(Debug, Clone)]
+pub struct LoginDeduplicator {
+    window: Duration,
+    entries: Arc<Mutex<HashMap<[u8; 32], Entry>>>,
+}
+
+impl LoginDeduplicator {
+    /// Creates a deduplicator sharing tokens for `window`; zero disables it
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Window in seconds from `LOGIN_DEDUP_WINDOW_SECS`, else the default
+    pub fn from_env() -> Self {
+        let secs = std::env::var("LOGIN_DEDUP_WINDOW_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(DEFAULT_LOGIN_DEDUP_WINDOW_SECS);
+        Self::new(Duration::from_secs(secs))
+    }
+
+    /// Runs `login`, unless an identical login succeeded within the window
+    ///
+    /// A remembered response is returned only if `is_current` accepts it;
+    /// otherwise it is forgotten and `login` runs again. Emails are
+    /// compared ignoring case, as they are stored.
+    pub async fn run<F, Fut, C, CFut>(
+        &self,
+        email: &str,
+        client: &str,
+        password: &str,
+        login: F,
+        is_current: C,
+    ) -> Result<LoginResponse, ApiError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<LoginResponse, ApiError>>,
+        C: FnOnce(LoginResponse) -> CFut,
+        CFut: Future<Output = Result<bool, ApiError>>,
+    {
+        if self.window.is_zero() {
+            return login().await;
+        }
+
+        let key = key(email, client, password);
+        let mut cell = self.entry(key);
+        if let Some(cached) = cell.get().cloned() {
+            if is_current(cached.clone()).await? {
+                return Ok(cached);
+            }
+            self.forget(key, &cell);
+            cell = self.entry(key);
+        }
+        cell.get_or_try_init(login).await.cloned()
+    }
+
+    /// The cell shared by logins with `key`, fresh if the last one expired
+    fn entry(&self, key: [u8; 32]) -> Arc<OnceCell<LoginResponse>> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() > PRUNE_THRESHOLD {
+            entries.retain(|_, (started, _)| now.duration_since(*started) < self.window);
+        }
+
+        let (started, cell) = entries
+            .entry(key)
+            .or_insert_with(|| (now, Arc::new(OnceCell::new())));
+        if now.duration_since(*started) >= self.window {
+            *started = now;
+            *cell = Arc::new(OnceCell::new());
+        }
+        cell.clone()
+    }
+
+    /// Drops the entry for `key` unless a newer login already replaced `cell`
+    fn forget(&self, key: [u8; 32], cell: &Arc<OnceCell<LoginResponse>>) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries
+            .get(&key)
+            .is_some_and(|(_, current)| Arc::ptr_eq(current, cell))
+        {
+            entries.remove(&key);
+        }
+    }
+}
+
+impl Default for LoginDeduplicator {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_LOGIN_DEDUP_WINDOW_SECS))
+    }
+}
+
+/// Digest identifying a login, so no password is held in memory
+fn key(email: &str, client: &str, password: &str) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in [email.trim().to_lowercase().as_str(), client, password] {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    async fn login(
+        dedup: &LoginDeduplicator,
+        email: &str,
+        password: &str,
+        calls: &AtomicUsize,
+    ) -> Result<LoginResponse, ApiError> {
+        login_while(dedup, email, password, calls, true).await
+    }
+
+    /// Logs in, reporting any remembered token as `current` or not
+    async fn login_while(
+        dedup: &LoginDeduplicator,
+        email: &str,
+        password: &str,
+        calls: &AtomicUsize,
+        current: bool,
+    ) -> Result<LoginResponse, ApiError> {
+        dedup
+            .run(
+                email,
+                "203.0.113.1",
+                password,
+                || async {
+                    let call = calls.fetch_add(1, Ordering::SeqCst);
+                    Ok(LoginResponse {
+                        token: format!("token-{}", call),
+                        user_id: Uuid::nil(),
+                    })
+                },
+                |_| async move { Ok(current) },
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn identical_logins_share_one_token() {
+        let dedup = LoginDeduplicator::default();
+        let calls = AtomicUsize::new(0);
+
+        let first = login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        let second = login(&dedup, "A@B.co", "hunter22", &calls).await.unwrap();
+
+        assert_eq!(first.token, second.token);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn different_passwords_are_not_deduplicated() {
+        let dedup = LoginDeduplicator::default();
+        let calls = AtomicUsize::new(0);
+
+        login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        login(&dedup, "a@b.co", "hunter23", &calls).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_logins_are_not_remembered() {
+        let dedup = LoginDeduplicator::default();
+        let calls = AtomicUsize::new(0);
+
+        let failed = dedup
+            .run(
+                "a@b.co",
+                "203.0.113.1",
+                "hunter22",
+                || async { Err(ApiError::unauthorized("Invalid credentials")) },
+                |_| async { Ok(true) },
+            )
+            .await;
+        assert!(failed.is_err());
+
+        login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn stale_tokens_are_not_handed_out_again() {
+        let dedup = LoginDeduplicator::default();
+        let calls = AtomicUsize::new(0);
+
+        let first = login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        let second = login_while(&dedup, "a@b.co", "hunter22", &calls, false)
+            .await
+            .unwrap();
+        assert_ne!(first.token, second.token);
+
+        // The fresh login is remembered in its place
+        let third = login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        assert_eq!(third.token, second.token);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn logins_after_the_window_run_again() {
+        let dedup = LoginDeduplicator::new(Duration::from_millis(10));
+        let calls = AtomicUsize::new(0);
+
+        login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        login(&dedup, "a@b.co", "hunter22", &calls).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,149 @@
+// Localized error messages
+// Maps error codes to per-language templates for API error bodies
+
+use serde::Serialize;
+
+/// Machine-readable error codes clients can branch on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ErrorCode {
+    /// Another active team in the company already has this goal
+    DuplicateGoal,
+    /// Another user is already registered with this email
+    DuplicateEmail,
+    /// The company already has as many active teams as it is allowed
+    TeamQuotaExceeded,
+    /// A transient failure such as database pool exhaustion
+    ServiceUnavailable,
+    /// Writes are blocked while the API is in maintenance mode
+    Maintenance,
+    /// Logins are refused for a while after repeated failures
+    AccountLocked,
+}
+
+/// Languages error messages can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    /// Picks the best supported language from an `Accept-Language` header
+    ///
+    /// Tags are ranked by their `q` weight; only the primary subtag is
+    /// considered, so `es-MX` selects Spanish. Falls back to English when
+    /// nothing supported is listed.
+    pub fn from_accept_language(header: &str) -> Self {
+        let mut best: Option<(Language, f32)> = None;
+
+        for entry in header.split(',') {
+            let mut parts = entry.split(';');
+            let tag = parts.next().unwrap_or("").trim();
+            let weight = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let primary = tag.split('-').next().unwrap_or("").to_ascii_lowercase();
+            let language = match primary.as_str() {
+                "en" => Language::English,
+                "es" => Language::Spanish,
+                _ => continue,
+            };
+
+            if weight > 0.0 && best.is_none_or(|(_, w)| weight > w) {
+                best = Some((language, weight));
+            }
+        }
+
+        best.map(|(language, _)| language).unwrap_or_default()
+    }
+}
+
+tokio::task_local! {
+    static LANGUAGE: Language;
+}
+
+/// Runs `future` with `language` as the language for error responses
+pub async fn with_language<F: std::future::Future>(language: Language, future: F) -> F::Output {
+    LANGUAGE.scope(language, future).await
+}
+
+/// Language for the request being handled, English outside a request scope
+pub fn current_language() -> Language {
+    LANGUAGE.try_with(|language| *language).unwrap_or_default()
+}
+
+/// Message template for `code` in `language`
+pub fn template(code: ErrorCode, language: Language) -> &'static str {
+    match (code, language) {
+        (ErrorCode::DuplicateGoal, Language::English) => "A team with this goal already exists",
+        (ErrorCode::DuplicateGoal, Language::Spanish) => "Ya existe un equipo con este objetivo",
+        (ErrorCode::DuplicateEmail, Language::English) => "Email already registered",
+        (ErrorCode::DuplicateEmail, Language::Spanish) => "El correo ya está registrado",
+        (ErrorCode::TeamQuotaExceeded, Language::English) => {
+            "Company has reached its active team limit"
+        }
+        (ErrorCode::TeamQuotaExceeded, Language::Spanish) => {
+            "La empresa ha alcanzado su límite de equipos activos"
+        }
+        (ErrorCode::ServiceUnavailable, Language::English) => {
+            "Service temporarily unavailable, please retry"
+        }
+        (ErrorCode::ServiceUnavailable, Language::Spanish) => {
+            "Servicio no disponible temporalmente, vuelva a intentarlo"
+        }
+        (ErrorCode::Maintenance, Language::English) => {
+            "The API is in maintenance mode; writes are temporarily disabled"
+        }
+        (ErrorCode::Maintenance, Language::Spanish) => {
+            "La API está en mantenimiento; las escrituras están deshabilitadas temporalmente"
+        }
+        (ErrorCode::AccountLocked, Language::English) => {
+            "Account temporarily locked after too many failed logins"
+        }
+        (ErrorCode::AccountLocked, Language::Spanish) => {
+            "Cuenta bloqueada temporalmente tras demasiados inicios de sesión fallidos"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_or_unsupported_languages_default_to_english() {
+        assert_eq!(Language::from_accept_language(""), Language::English);
+        assert_eq!(
+            Language::from_accept_language("fr-FR, de"),
+            Language::English
+        );
+    }
+
+    #[test]
+    fn region_subtags_select_the_primary_language() {
+        assert_eq!(Language::from_accept_language("es-MX"), Language::Spanish);
+    }
+
+    #[test]
+    fn highest_weight_wins() {
+        assert_eq!(
+            Language::from_accept_language("en;q=0.5, es;q=0.9"),
+            Language::Spanish
+        );
+        assert_eq!(
+            Language::from_accept_language("es;q=0, en;q=0.1"),
+            Language::English
+        );
+    }
+
+    #[tokio::test]
+    async fn current_language_reads_the_scoped_value() {
+        assert_eq!(current_language(), Language::English);
+
+        let language = with_language(Language::Spanish, async { current_language() }).await;
+        assert_eq!(language, Language::Spanish);
+    }
+}
@@ -1,15 +1,21 @@
 use axum::{
     async_trait,
-    extract::FromRequestParts,
+    extract::{FromRef, FromRequestParts},
     http::{header::AUTHORIZATION, request::Parts},
 };
+use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::api::errors::ApiError;
-use crate::auth::jwt::verify_token;
+use crate::auth::jwt::{verify_token, Claims};
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::infrastructure::repositories::PostgresUserRepository;
 
 /// JWT authentication extractor for protected routes
 ///
+/// In local development, requests without an `Authorization` header can
+/// be authenticated as a fixed user; see [`DevAuthBypass`].
+///
 /// Usage:
 /// ```ignore
 /// use ghostpirates_api::api::middleware::auth::JwtAuth;
@@ -26,31 +32,155 @@ pub struct JwtAuth(pub Uuid);
 #[async_trait]
 impl<S> FromRequestParts<S> for JwtAuth
 where
+    PgPool: FromRef<S>,
     S: Send + Sync,
 {
     type Rejection = ApiError;
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        // Extract the authorization header
-        let auth_header = parts
-            .headers
-            .get(AUTHORIZATION)
-            .and_then(|value| value.to_str().ok())
-            .ok_or_else(|| ApiError::unauthorized("Missing authorization header"))?;
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            let DevAuthBypass(bypass_user) = parts
+                .extensions
+                .get::<DevAuthBypass>()
+                .copied()
+                .unwrap_or_default();
+            if let Some(user_id) = bypass_user {
+                return Ok(JwtAuth(user_id));
+            }
+        }
 
-        // Extract bearer token
-        let token = auth_header
-            .strip_prefix("Bearer ")
-            .ok_or_else(|| ApiError::unauthorized("Invalid authorization format. Use: Bearer <token>"))?;
+        let claims = verified_claims(parts, &PgPool::from_ref(state)).await?;
 
-        // Get JWT secret from environment
-        let secret = std::env::var("JWT_SECRET")
-            .unwrap_or_else(|_| "dev-secret-key".to_string());
+        Ok(JwtAuth(claims.sub))
+    }
+}
 
-        // Verify the token
-        let claims = verify_token(token, &secret)
-            .map_err(|e| ApiError::unauthorized(format!("Invalid token: {}", e)))?;
+/// User that unauthenticated requests act as during local development
+///
+/// Installed as an `Extension`; without one (the default) there is no
+/// bypass. Requests that send a token are always verified normally.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DevAuthBypass(pub Option<Uuid>);
 
-        Ok(JwtAuth(claims.sub))
+impl DevAuthBypass {
+    /// Reads `DEV_AUTH_BYPASS_USER`, honored only when `APP_ENV` is exactly
+    /// `development`
+    ///
+    /// In any other environment, including an unset `APP_ENV`, the
+    /// variable is ignored.
+    pub fn from_env() -> Self {
+        Self(dev_bypass_user_from(
+            std::env::var("APP_ENV").ok().as_deref(),
+            std::env::var("DEV_AUTH_BYPASS_USER").ok().as_deref(),
+        ))
+    }
+}
+
+fn dev_bypass_user_from(app_env: Option<&str>, bypass_user: Option<&str>) -> Option<Uuid> {
+    if app_env != Some("development") {
+        return None;
+    }
+
+    let bypass_user = bypass_user?.trim();
+    match bypass_user.parse() {
+        Ok(user_id) => Some(user_id),
+        Err(_) => {
+            tracing::warn!("Ignoring DEV_AUTH_BYPASS_USER, not a UUID: {}", bypass_user);
+            None
+        }
+    }
+}
+
+/// Extracts and verifies the bearer token claims from request parts
+fn claims_from_parts(parts: &Parts) -> Result<Claims, ApiError> {
+    // Extract the authorization header
+    let auth_header = parts
+        .headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| ApiError::unauthorized("Missing authorization header"))?;
+
+    // Extract bearer token
+    let token = auth_header.strip_prefix("Bearer ").ok_or_else(|| {
+        ApiError::unauthorized("Invalid authorization format. Use: Bearer <token>")
+    })?;
+
+    // Get JWT secret from environment
+    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+
+    // Verify the token
+    verify_token(token, &secret)
+        .map_err(|e| ApiError::unauthorized(format!("Invalid token: {}", e)))
+}
+
+/// Extracts the bearer token claims and rejects revoked tokens with 401
+pub(crate) async fn verified_claims(parts: &Parts, pool: &PgPool) -> Result<Claims, ApiError> {
+    let claims = claims_from_parts(parts)?;
+    if token_revoked(pool, &claims).await? {
+        return Err(ApiError::unauthorized("Token has been revoked"));
+    }
+
+    Ok(claims)
+}
+
+/// Whether the user's token version has moved past the one `claims` was
+/// minted with
+///
+/// Tokens of users without a row have nothing to revoke and are not
+/// revoked.
+pub(crate) async fn token_revoked(pool: &PgPool, claims: &Claims) -> Result<bool, ApiError> {
+    let current = PostgresUserRepository::new(pool.clone())
+        .current_token_version(claims.sub)
+        .await
+        .map_err(|e| ApiError::repository("Database error", e))?;
+
+    Ok(current.is_some_and(|version| claims.ver < version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+
+    const USER: &str = "7f5c2f4e-8a47-4d0b-9a0e-3c1f0d6b2a91";
+
+    #[test]
+    fn bypass_applies_only_in_development() {
+        let user_id: Uuid = USER.parse().unwrap();
+
+        assert_eq!(
+            dev_bypass_user_from(Some("development"), Some(USER)),
+            Some(user_id)
+        );
+        assert_eq!(dev_bypass_user_from(Some("production"), Some(USER)), None);
+        assert_eq!(dev_bypass_user_from(Some("Development"), Some(USER)), None);
+        assert_eq!(dev_bypass_user_from(None, Some(USER)), None);
+    }
+
+    #[test]
+    fn bypass_needs_a_valid_user_id() {
+        assert_eq!(dev_bypass_user_from(Some("development"), None), None);
+        assert_eq!(
+            dev_bypass_user_from(Some("development"), Some("admin")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn extractor_honors_an_installed_bypass_only() {
+        // Neither path reaches the database
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let user_id: Uuid = USER.parse().unwrap();
+
+        let mut parts = Request::new(()).into_parts().0;
+        parts.extensions.insert(DevAuthBypass(Some(user_id)));
+        let JwtAuth(authenticated) = JwtAuth::from_request_parts(&mut parts, &pool)
+            .await
+            .unwrap();
+        assert_eq!(authenticated, user_id);
+
+        let mut parts = Request::new(()).into_parts().0;
+        let result = JwtAuth::from_request_parts(&mut parts, &pool).await;
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,163 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{request::Parts, Extensions, HeaderMap},
+};
+
+/// How many reverse proxies in front of the API are trusted
+///
+/// Installed as an `Extension`. Each proxy appends the address it received
+/// the request from to `X-Forwarded-For`, so with `n` trusted proxies the
+/// `n`-th address from the right identifies the client; anything left of it
+/// was written by the client and can be spoofed. Without `X-Forwarded-For`,
+/// `X-Real-IP` is used. With no trusted proxies (the default, also when no
+/// extension is installed), only the socket's peer address counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrustProxy(pub usize);
+
+impl TrustProxy {
+    /// Reads `TRUST_PROXY`: a number of proxies, or `true`/`yes` for one
+    ///
+    /// Anything else trusts no proxy.
+    pub fn from_env() -> Self {
+        let value = std::env::var("TRUST_PROXY").unwrap_or_default();
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "true" | "yes" => Self(1),
+            _ => Self(value.parse().unwrap_or(0)),
+        }
+    }
+}
+
+/// Resolves the client address of a request
+///
+/// Proxy headers are read only under [`TrustProxy`], and values that are
+/// not IP addresses are ignored. Clients that cannot be identified share
+/// `"unknown"`.
+pub fn resolve_client_ip(headers: &HeaderMap, extensions: &Extensions) -> String {
+    let TrustProxy(hops) = extensions.get::<TrustProxy>().copied().unwrap_or_default();
+    if hops > 0 {
+        if let Some(ip) = forwarded_ip(headers, hops) {
+            return ip.to_string();
+        }
+    }
+
+    extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// The `hops`-th `X-Forwarded-For` address from the right, else `X-Real-IP`
+///
+/// A list shorter than `hops` did not pass through all trusted proxies, so
+/// none of it is believed.
+fn forwarded_ip(headers: &HeaderMap, hops: usize) -> Option<IpAddr> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    match header("x-forwarded-for") {
+        Some(value) => value
+            .rsplit(',')
+            .nth(hops - 1)
+            .and_then(|ip| ip.trim().parse().ok()),
+        None => header("x-real-ip").and_then(|ip| ip.trim().parse().ok()),
+    }
+}
+
+/// Extractor yielding the address resolved by [`resolve_client_ip`]
+///
+/// Never rejects.
+pub struct ClientIp(pub String);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(ClientIp(resolve_client_ip(
+            &parts.headers,
+            &parts.extensions,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn request(
+        trust: Option<TrustProxy>,
+        headers: &[(&'static str, &str)],
+    ) -> (HeaderMap, Extensions) {
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        let mut extensions = Extensions::new();
+        extensions.insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4321))));
+        if let Some(trust) = trust {
+            extensions.insert(trust);
+        }
+        (map, extensions)
+    }
+
+    #[test]
+    fn trusted_proxy_headers_identify_the_client() {
+        let (headers, extensions) = request(
+            Some(TrustProxy(1)),
+            &[("x-forwarded-for", "198.51.100.9, 203.0.113.7")],
+        );
+        assert_eq!(resolve_client_ip(&headers, &extensions), "203.0.113.7");
+
+        let (headers, extensions) = request(Some(TrustProxy(1)), &[("x-real-ip", "2001:db8::1")]);
+        assert_eq!(resolve_client_ip(&headers, &extensions), "2001:db8::1");
+    }
+
+    #[test]
+    fn addresses_added_by_the_client_are_skipped() {
+        // The client sent "198.51.100.9"; two trusted proxies appended theirs
+        let spoofed = [("x-forwarded-for", "198.51.100.9, 203.0.113.7, 10.0.0.2")];
+
+        let (headers, extensions) = request(Some(TrustProxy(2)), &spoofed);
+        assert_eq!(resolve_client_ip(&headers, &extensions), "203.0.113.7");
+
+        // Fewer addresses than trusted proxies: none of them is believed
+        let (headers, extensions) = request(Some(TrustProxy(4)), &spoofed);
+        assert_eq!(resolve_client_ip(&headers, &extensions), "10.0.0.1");
+    }
+
+    #[test]
+    fn trusted_proxy_falls_back_to_the_peer_for_malformed_headers() {
+        let (headers, extensions) =
+            request(Some(TrustProxy(1)), &[("x-forwarded-for", "not-an-ip")]);
+
+        assert_eq!(resolve_client_ip(&headers, &extensions), "10.0.0.1");
+    }
+
+    #[test]
+    fn proxy_headers_are_ignored_unless_trusted() {
+        let spoofed = [
+            ("x-forwarded-for", "203.0.113.7"),
+            ("x-real-ip", "203.0.113.8"),
+        ];
+
+        for trust in [None, Some(TrustProxy(0))] {
+            let (headers, extensions) = request(trust, &spoofed);
+            assert_eq!(resolve_client_ip(&headers, &extensions), "10.0.0.1");
+        }
+    }
+
+    #[test]
+    fn unidentified_clients_share_a_bucket() {
+        assert_eq!(
+            resolve_client_ip(&HeaderMap::new(), &Extensions::new()),
+            "unknown"
+        );
+    }
+}
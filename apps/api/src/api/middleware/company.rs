@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts},
+    http::request::Parts,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::api::errors::ApiError;
+use crate::api::middleware::auth::verified_claims;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::user::value_objects::UserRole;
+use crate::infrastructure::repositories::PostgresUserRepository;
+
+/// How long a loaded company and role are reused before re-querying
+pub const COMPANY_CONTEXT_TTL: Duration = Duration::from_secs(30);
+
+/// Caller identity with the company and role loaded from the database
+///
+/// Verifies the bearer token, then reads the user's company and role from
+/// the users table rather than trusting the token claims. Lookups are
+/// cached per user for [`COMPANY_CONTEXT_TTL`]; code that changes a user's
+/// company, role, or active flag must call [`CompanyContext::forget`] so
+/// the change applies at once. Invalid or revoked tokens and unknown or
+/// deactivated users are rejected with 401.
+///
+/// Handlers that only need the caller's company or admin role can keep
+/// using [`Tenant`](super::Tenant) and [`TenantAdmin`](super::TenantAdmin),
+/// which read the token claims without a database lookup. The claims cannot
+/// go stale: the only change to a user's company or role, a company move,
+/// revokes every token issued before it.
+///
+/// Usage:
+/// ```ignore
+/// use ghostpirates_api::api::middleware::CompanyContext;
+/// use ghostpirates_api::api::errors::ApiError;
+///
+/// async fn scoped_handler(ctx: CompanyContext) -> Result<String, ApiError> {
+///     Ok(format!("Hello company {}", ctx.company_id))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyContext {
+    pub user_id: Uuid,
+    pub company_id: Uuid,
+    pub role: UserRole,
+}
+
+impl CompanyContext {
+    /// Whether the caller is an admin of their company
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    /// Drops the cached context of `user_id`, so their next request
+    /// reloads it
+    pub fn forget(user_id: Uuid) {
+        cache().lock().unwrap().remove(&user_id);
+    }
+}
+
+/// Cached contexts keyed by user ID, with the time each was loaded
+fn cache() -> &'static Mutex<HashMap<Uuid, (CompanyContext, Instant)>> {
+    static CACHE: OnceLock<Mutex<HashMap<Uuid, (CompanyContext, Instant)>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CompanyContext
+where
+    PgPool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let user_id = verified_claims(parts, &PgPool::from_ref(state)).await?.sub;
+
+        let cached = cache()
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .filter(|(_, loaded_at)| loaded_at.elapsed() < COMPANY_CONTEXT_TTL)
+            .map(|(context, _)| *context);
+        if let Some(context) = cached {
+            return Ok(context);
+        }
+
+        let user = PostgresUserRepository::new(PgPool::from_ref(state))
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| ApiError::repository("Database error", e))?
+            .filter(|user| user.is_active)
+            .ok_or_else(|| ApiError::unauthorized("Unknown or inactive user"))?;
+        let context = CompanyContext {
+            user_id,
+            company_id: user.company_id,
+            role: user.role,
+        };
+
+        let mut cache = cache().lock().unwrap();
+        cache.retain(|_, (_, loaded_at)| loaded_at.elapsed() < COMPANY_CONTEXT_TTL);
+        cache.insert(user_id, (context, Instant::now()));
+
+        Ok(context)
+    }
+}
@@ -0,0 +1,66 @@
+use axum::{
+    body::Body,
+    extract::Request,
+    http::header::{CONTENT_LENGTH, CONTENT_TYPE},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::{json, Value};
+
+/// Request header opting in to enveloped success responses
+pub const ENVELOPE_HEADER: &str = "x-envelope";
+
+/// Middleware wrapping successful JSON responses as `{ "data", "meta" }`
+///
+/// Only requests sending `X-Envelope: true` are affected, so existing
+/// clients keep receiving bare objects. `data` holds the handler's JSON
+/// and `meta` the response status. Errors, which already carry an
+/// `{ "error" }` envelope, and non-JSON bodies such as CSV exports or
+/// empty 204s pass through unchanged.
+///
+/// Usage:
+/// ```ignore
+/// use axum::{middleware, Router};
+/// use ghostpirates_api::api::middleware::envelope_responses;
+///
+/// let app: Router = Router::new().layer(middleware::from_fn(envelope_responses));
+/// ```
+pub async fn envelope_responses(request: Request, next: Next) -> Response {
+    let requested = request
+        .headers()
+        .get(ENVELOPE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"));
+
+    let response = next.run(request).await;
+    if !requested || !response.status().is_success() || !is_json(&response) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!("Failed to buffer response for envelope: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let Ok(data) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let enveloped = json!({
+        "data": data,
+        "meta": { "status": parts.status.as_u16() },
+    });
+    parts.headers.remove(CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(enveloped.to_string()))
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+use crate::api::errors::ApiError;
+
+/// Header carrying the shared secret for internal service calls
+pub const INTERNAL_SECRET_HEADER: &str = "x-internal-secret";
+
+/// Shared secret internal callers must present
+///
+/// Installed as a request extension. Without one, or with an empty
+/// secret, every internal request is rejected.
+#[derive(Debug, Clone, Default)]
+pub struct InternalSecret(Option<Arc<str>>);
+
+impl InternalSecret {
+    /// Accept callers presenting `secret`; an empty secret accepts none
+    pub fn new(secret: &str) -> Self {
+        if secret.is_empty() {
+            return Self(None);
+        }
+
+        Self(Some(Arc::from(secret)))
+    }
+
+    /// The secret in `INTERNAL_SERVICE_SECRET`, if set
+    pub fn from_env() -> Self {
+        Self::new(&std::env::var("INTERNAL_SERVICE_SECRET").unwrap_or_default())
+    }
+}
+
+/// Extractor restricting a route to internal callers
+///
+/// Callers must send the [`InternalSecret`] in the `x-internal-secret`
+/// header. An mTLS-terminating proxy can inject the header for services it
+/// has authenticated. When no secret is configured every request is
+/// rejected, so internal routes are closed by default.
+///
+/// Usage:
+/// ```ignore
+/// use ghostpirates_api::api::middleware::InternalService;
+/// use ghostpirates_api::api::errors::ApiError;
+///
+/// async fn internal_handler(_: InternalService) -> Result<String, ApiError> {
+///     Ok("Hello sidecar".to_string())
+/// }
+/// ```
+pub struct InternalService;
+
+#[async_trait]
+impl<S> FromRequestParts<S> for InternalService
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let expected = parts
+            .extensions
+            .get::<InternalSecret>()
+            .and_then(|secret| secret.0.clone())
+            .ok_or_else(|| ApiError::forbidden("Internal endpoints are disabled"))?;
+
+        let provided = parts
+            .headers
+            .get(INTERNAL_SECRET_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| ApiError::unauthorized("Missing internal service secret"))?;
+
+        if !constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+            return Err(ApiError::unauthorized("Invalid internal service secret"));
+        }
+
+        Ok(InternalService)
+    }
+}
+
+/// Compares two byte strings without short-circuiting on the first mismatch
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,27 @@
+use axum::{extract::Request, http::header::ACCEPT_LANGUAGE, middleware::Next, response::Response};
+
+use crate::api::messages::{with_language, Language};
+
+/// Middleware choosing the language for error responses
+///
+/// Reads `Accept-Language` and runs the rest of the request with that
+/// language in scope, so `ApiError` bodies render localized templates.
+/// Requests without the header get English.
+///
+/// Usage:
+/// ```ignore
+/// use axum::{middleware, Router};
+/// use ghostpirates_api::api::middleware::negotiate_language;
+///
+/// let app: Router = Router::new().layer(middleware::from_fn(negotiate_language));
+/// ```
+pub async fn negotiate_language(request: Request, next: Next) -> Response {
+    let language = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Language::from_accept_language)
+        .unwrap_or_default();
+
+    with_language(language, next.run(request)).await
+}
@@ -0,0 +1,121 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::api::errors::ApiError;
+use crate::api::messages::ErrorCode;
+
+/// Seconds clients are told to wait before retrying a blocked write
+pub const MAINTENANCE_RETRY_AFTER_SECS: u64 = 60;
+
+/// Switch that blocks writes while the database is being migrated
+///
+/// Cloning is cheap and clones share one switch, so the instance given to
+/// [`maintenance_mode`] can be flipped at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceMode {
+    enabled: Arc<AtomicBool>,
+}
+
+impl MaintenanceMode {
+    /// Creates a switch in the given position
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(enabled)),
+        }
+    }
+
+    /// Switch turned on when `MAINTENANCE_MODE` is `true` or `1`
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("MAINTENANCE_MODE")
+            .map(|value| matches!(value.trim().to_lowercase().as_str(), "true" | "1"))
+            .unwrap_or(false);
+
+        Self::new(enabled)
+    }
+
+    /// Whether writes are currently blocked
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turns maintenance mode on or off for every clone
+    pub fn set(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+}
+
+/// Whether `method` changes state and is blocked during maintenance
+fn is_write(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+/// Middleware rejecting writes with 503 while `mode` is enabled
+///
+/// Reads and health checks pass through. Rejections carry a
+/// `Retry-After` header and the `Maintenance` error code.
+///
+/// Usage:
+/// ```ignore
+/// use axum::{middleware, Router};
+/// use ghostpirates_api::api::middleware::{maintenance_mode, MaintenanceMode};
+///
+/// let app: Router = Router::new()
+///     // ...routes
+///     .layer(middleware::from_fn_with_state(
+///         MaintenanceMode::from_env(),
+///         maintenance_mode,
+///     ));
+/// ```
+pub async fn maintenance_mode(
+    State(mode): State<MaintenanceMode>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let exempt = request.uri().path().starts_with("/health");
+    if mode.is_enabled() && is_write(request.method()) && !exempt {
+        return ApiError::service_unavailable(
+            "The API is in maintenance mode; writes are temporarily disabled",
+            MAINTENANCE_RETRY_AFTER_SECS,
+        )
+        .with_code(ErrorCode::Maintenance)
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_state_changing_methods_are_writes() {
+        assert!(is_write(&Method::POST));
+        assert!(is_write(&Method::PUT));
+        assert!(is_write(&Method::PATCH));
+        assert!(is_write(&Method::DELETE));
+        assert!(!is_write(&Method::GET));
+        assert!(!is_write(&Method::HEAD));
+        assert!(!is_write(&Method::OPTIONS));
+    }
+
+    #[test]
+    fn clones_share_the_switch() {
+        let mode = MaintenanceMode::default();
+        let clone = mode.clone();
+
+        clone.set(true);
+
+        assert!(mode.is_enabled());
+    }
+}
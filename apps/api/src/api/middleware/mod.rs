@@ -1,3 +1,21 @@
 pub mod auth;
+pub mod client_ip;
+pub mod company;
+pub mod envelope;
+pub mod internal;
+pub mod locale;
+pub mod maintenance;
+pub mod rate_limit;
+pub mod redaction;
+pub mod tenant;
 
-pub use auth::JwtAuth;
+pub use auth::{DevAuthBypass, JwtAuth};
+pub use company::CompanyContext;
+pub use envelope::envelope_responses;
+pub use internal::{InternalSecret, InternalService};
+pub use locale::negotiate_language;
+pub use maintenance::{maintenance_mode, MaintenanceMode};
+pub use client_ip::{ClientIp, TrustProxy};
+pub use rate_limit::{rate_limit, RateLimiter};
+pub use redaction::{log_requests, SensitiveFields};
+pub use tenant::{Tenant, TenantAdmin};
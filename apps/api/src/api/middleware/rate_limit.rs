@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use super::client_ip::resolve_client_ip;
+use crate::api::errors::ApiError;
+
+/// Default requests per minute a client may make to the API as a whole
+pub const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 300;
+
+/// Default registrations per hour a client may attempt
+pub const DEFAULT_REGISTER_RATE_LIMIT_PER_HOUR: u32 = 5;
+
+/// Tracked clients above which expired windows are pruned
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Fixed-window request limiter keyed by client IP
+///
+/// Each client may make `max_requests` requests per `window`; further
+/// requests are rejected until the window ends. Cloning is cheap and
+/// clones share one set of counters, so a limiter can be installed on as
+/// many routes as should share a budget.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    windows: Arc<Mutex<HashMap<String, (Instant, u32)>>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `max_requests` per `window` per client
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            max_requests,
+            window,
+            windows: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Limiter for all routes, per minute from `RATE_LIMIT_PER_MINUTE`
+    pub fn general_from_env() -> Self {
+        Self::new(
+            limit_from_env("RATE_LIMIT_PER_MINUTE", DEFAULT_RATE_LIMIT_PER_MINUTE),
+            Duration::from_secs(60),
+        )
+    }
+
+    /// Stricter limiter for registration, per hour from
+    /// `REGISTER_RATE_LIMIT_PER_HOUR`
+    pub fn registration_from_env() -> Self {
+        Self::new(
+            limit_from_env(
+                "REGISTER_RATE_LIMIT_PER_HOUR",
+                DEFAULT_REGISTER_RATE_LIMIT_PER_HOUR,
+            ),
+            Duration::from_secs(60 * 60),
+        )
+    }
+
+    /// Counts a request from `client`
+    ///
+    /// Returns the seconds until the client's window resets if the request
+    /// is over the limit.
+    pub fn check(&self, client: &str) -> Result<(), u64> {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap();
+        if windows.len() > PRUNE_THRESHOLD {
+            windows.retain(|_, (started, _)| now.duration_since(*started) < self.window);
+        }
+
+        let (started, count) = windows.entry(client.to_string()).or_insert((now, 0));
+        if now.duration_since(*started) >= self.window {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= self.max_requests {
+            let remaining = self.window.saturating_sub(now.duration_since(*started));
+            return Err(remaining.as_secs().max(1));
+        }
+
+        *count += 1;
+        Ok(())
+    }
+}
+
+/// Reads a positive limit from `name`, falling back to `default`
+fn limit_from_env(name: &str, default: u32) -> u32 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .filter(|limit| *limit > 0)
+        .unwrap_or(default)
+}
+
+/// Middleware rejecting clients over `limiter`'s budget with 429
+///
+/// Rejections carry a `Retry-After` header with the seconds left in the
+/// client's window. Clients are told apart by [`resolve_client_ip`], so
+/// proxy headers only count under `TrustProxy`.
+///
+/// Usage:
+/// ```ignore
+/// use axum::{middleware, routing::post, Router};
+/// use ghostpirates_api::api::middleware::{rate_limit, RateLimiter};
+///
+/// let app: Router = Router::new().route(
+///     "/api/auth/register",
+///     post(register).layer(middleware::from_fn_with_state(
+///         RateLimiter::registration_from_env(),
+///         rate_limit,
+///     )),
+/// );
+/// ```
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client = resolve_client_ip(request.headers(), request.extensions());
+
+    match limiter.check(&client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            tracing::warn!("Rate limit exceeded for client {}", client);
+            ApiError::too_many_requests("Too many requests, please retry later", retry_after)
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_requests_over_the_limit() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+
+        assert!(limiter.check("203.0.113.1").is_ok());
+        assert!(limiter.check("203.0.113.1").is_ok());
+        let retry_after = limiter.check("203.0.113.1").unwrap_err();
+
+        assert!((1..=60).contains(&retry_after));
+    }
+
+    #[test]
+    fn clients_have_separate_budgets() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+
+        assert!(limiter.check("203.0.113.1").is_ok());
+        assert!(limiter.check("203.0.113.2").is_ok());
+        assert!(limiter.check("203.0.113.1").is_err());
+    }
+
+    #[test]
+    fn budget_resets_after_the_window() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(10));
+
+        assert!(limiter.check("203.0.113.1").is_ok());
+        assert!(limiter.check("203.0.113.1").is_err());
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(limiter.check("203.0.113.1").is_ok());
+    }
+}
@@ -0,0 +1,314 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{Request, State},
+    http::{HeaderMap, Uri},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+
+/// Field and header names masked in logs unless `LOG_SENSITIVE_FIELDS` is set
+pub const DEFAULT_SENSITIVE_FIELDS: &[&str] = &["password", "token", "authorization"];
+
+/// Replacement logged in place of a sensitive value
+pub const REDACTED: &str = "***";
+
+/// Largest body, in bytes, that is buffered for logging
+pub const MAX_LOGGED_BODY_BYTES: u64 = 16 * 1024;
+
+/// Names of values that must never reach the logs
+///
+/// A JSON field or header is sensitive when its name contains one of these
+/// names, ignoring case, so `password` also covers `new_password`.
+#[derive(Debug, Clone)]
+pub struct SensitiveFields {
+    names: Arc<[String]>,
+}
+
+impl SensitiveFields {
+    /// Masks fields whose names contain any of `names`
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            names: names
+                .into_iter()
+                .map(|name| name.as_ref().trim().to_lowercase())
+                .filter(|name| !name.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Names from the comma-separated `LOG_SENSITIVE_FIELDS`, else the defaults
+    pub fn from_env() -> Self {
+        match std::env::var("LOG_SENSITIVE_FIELDS") {
+            Ok(names) if !names.trim().is_empty() => Self::new(names.split(',')),
+            _ => Self::default(),
+        }
+    }
+
+    /// Whether a field or header called `name` must be masked
+    pub fn is_sensitive(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.names.iter().any(|sensitive| name.contains(sensitive))
+    }
+
+    /// Masks sensitive fields at any depth of `value`
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::api::middleware::SensitiveFields;
+    /// use serde_json::json;
+    ///
+    /// let mut body = json!({ "email": "a@b.co", "password": "hunter22" });
+    /// SensitiveFields::default().redact_json(&mut body);
+    ///
+    /// assert_eq!(body, json!({ "email": "a@b.co", "password": "***" }));
+    /// ```
+    pub fn redact_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (name, field) in fields.iter_mut() {
+                    if self.is_sensitive(name) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact_json(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_json(item)),
+            _ => {}
+        }
+    }
+
+    /// Headers as `name: value` pairs with sensitive values masked
+    pub fn redact_headers(&self, headers: &HeaderMap) -> Vec<String> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.is_sensitive(name.as_str()) {
+                    REDACTED
+                } else {
+                    value.to_str().unwrap_or("<binary>")
+                };
+                format!("{}: {}", name, value)
+            })
+            .collect()
+    }
+
+    /// `uri` with the values of sensitive query parameters masked
+    ///
+    /// # Example
+    /// ```
+    /// use axum::http::Uri;
+    /// use ghostpirates_api::api::middleware::SensitiveFields;
+    ///
+    /// let uri: Uri = "/api/auth/reset?token=abc&lang=en".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     SensitiveFields::default().redact_uri(&uri),
+    ///     "/api/auth/reset?token=***&lang=en"
+    /// );
+    /// ```
+    pub fn redact_uri(&self, uri: &Uri) -> String {
+        let Some(query) = uri.query() else {
+            return uri.to_string();
+        };
+
+        let query: Vec<String> = query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if self.is_sensitive(name) => format!("{}={}", name, REDACTED),
+                _ => pair.to_string(),
+            })
+            .collect();
+        format!("{}?{}", uri.path(), query.join("&"))
+    }
+
+    /// A body as it may be logged
+    ///
+    /// JSON is redacted; anything else is summarized by size, since it
+    /// cannot be searched for sensitive fields.
+    fn describe_body(&self, bytes: &[u8]) -> String {
+        if bytes.is_empty() {
+            return String::new();
+        }
+        match serde_json::from_slice::<Value>(bytes) {
+            Ok(mut json) => {
+                self.redact_json(&mut json);
+                json.to_string()
+            }
+            Err(_) => format!("<{} bytes>", bytes.len()),
+        }
+    }
+}
+
+impl Default for SensitiveFields {
+    fn default() -> Self {
+        Self::new(DEFAULT_SENSITIVE_FIELDS)
+    }
+}
+
+/// Buffers `body` for logging if its size is known and small
+///
+/// Returns the body to pass on, and the bytes to log when buffered. If
+/// reading fails, the body passed on fails with the same error, so the
+/// reader sees the broken body rather than an empty one.
+async fn buffer_body(body: Body) -> (Body, Option<Bytes>) {
+    let small = body
+        .size_hint()
+        .upper()
+        .is_some_and(|size| size <= MAX_LOGGED_BODY_BYTES);
+    if !small {
+        return (body, None);
+    }
+
+    pass_on(axum::body::to_bytes(body, MAX_LOGGED_BODY_BYTES as usize).await)
+}
+
+/// The body and logged bytes for the outcome of buffering a body
+fn pass_on(read: Result<Bytes, axum::Error>) -> (Body, Option<Bytes>) {
+    match read {
+        Ok(bytes) => (Body::from(bytes.clone()), Some(bytes)),
+        Err(e) => {
+            let failed = futures::stream::once(async move { Err::<Bytes, _>(e) });
+            (Body::from_stream(failed), None)
+        }
+    }
+}
+
+/// Middleware logging requests and responses with sensitive values masked
+///
+/// URIs, headers, and JSON bodies are logged at debug level, with every
+/// query parameter, header, and field named in `fields` replaced by
+/// [`REDACTED`]. Bodies of unknown or large
+/// size are passed through untouched and not logged. Install it inside
+/// `TraceLayer` so the events land in the request's span.
+///
+/// Usage:
+/// ```ignore
+/// use axum::{middleware, Router};
+/// use ghostpirates_api::api::middleware::{log_requests, SensitiveFields};
+///
+/// let app: Router = Router::new()
+///     // ...routes
+///     .layer(middleware::from_fn_with_state(
+///         SensitiveFields::from_env(),
+///         log_requests,
+///     ));
+/// ```
+pub async fn log_requests(
+    State(fields): State<SensitiveFields>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let (body, logged) = buffer_body(body).await;
+    tracing::debug!(
+        method = %parts.method,
+        uri = %fields.redact_uri(&parts.uri),
+        headers = ?fields.redact_headers(&parts.headers),
+        body = %logged.map(|bytes| fields.describe_body(&bytes)).unwrap_or_default(),
+        "request"
+    );
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+
+    let (parts, body) = response.into_parts();
+    let (body, logged) = buffer_body(body).await;
+    tracing::debug!(
+        status = %parts.status,
+        headers = ?fields.redact_headers(&parts.headers),
+        body = %logged.map(|bytes| fields.describe_body(&bytes)).unwrap_or_default(),
+        "response"
+    );
+
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{header::AUTHORIZATION, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn nested_and_prefixed_fields_are_masked() {
+        let mut body = json!({
+            "new_password": "hunter22",
+            "users": [{ "email": "a@b.co", "Token": "abc" }],
+        });
+
+        SensitiveFields::default().redact_json(&mut body);
+
+        assert_eq!(
+            body,
+            json!({
+                "new_password": "***",
+                "users": [{ "email": "a@b.co", "Token": "***" }],
+            })
+        );
+    }
+
+    #[test]
+    fn sensitive_headers_are_masked() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer abc"));
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+
+        let logged = SensitiveFields::default().redact_headers(&headers);
+
+        assert_eq!(
+            logged,
+            ["authorization: ***", "content-type: application/json"]
+        );
+    }
+
+    #[test]
+    fn sensitive_query_parameters_are_masked() {
+        let fields = SensitiveFields::default();
+
+        let uri: Uri = "/reset?lang=en&reset_token=abc&flag".parse().unwrap();
+        assert_eq!(
+            fields.redact_uri(&uri),
+            "/reset?lang=en&reset_token=***&flag"
+        );
+
+        let uri: Uri = "/api/teams".parse().unwrap();
+        assert_eq!(fields.redact_uri(&uri), "/api/teams");
+    }
+
+    #[tokio::test]
+    async fn unreadable_bodies_stay_unreadable() {
+        let (body, logged) = pass_on(Err(axum::Error::new("connection reset")));
+
+        assert!(logged.is_none());
+        let error = axum::body::to_bytes(body, usize::MAX).await.unwrap_err();
+        assert!(error.to_string().contains("connection reset"));
+    }
+
+    #[tokio::test]
+    async fn buffered_bodies_are_passed_on_whole() {
+        let (body, logged) = buffer_body(Body::from("{\"a\":1}")).await;
+
+        assert_eq!(logged.as_deref(), Some(&b"{\"a\":1}"[..]));
+        let passed_on = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        assert_eq!(&passed_on[..], b"{\"a\":1}");
+    }
+
+    #[test]
+    fn configured_names_replace_the_defaults() {
+        let fields = SensitiveFields::new(["secret", " "]);
+
+        assert!(fields.is_sensitive("x-internal-secret"));
+        assert!(!fields.is_sensitive("password"));
+    }
+}
@@ -0,0 +1,80 @@
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts},
+    http::request::Parts,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::api::errors::ApiError;
+use crate::api::middleware::auth::verified_claims;
+use crate::domain::user::value_objects::UserRole;
+
+/// Tenant extractor yielding the caller's company ID
+///
+/// The company is taken from the verified JWT claims, never from the
+/// path or body, so handlers can scope queries to the caller's own data.
+/// Tokens without a `company_id` claim, and tokens revoked when the user
+/// moved company, are rejected with 401. Since moves revoke tokens, the
+/// claim is current without a database lookup; see
+/// [`CompanyContext`](super::CompanyContext) for handlers that need more.
+///
+/// Usage:
+/// ```ignore
+/// use ghostpirates_api::api::middleware::Tenant;
+/// use ghostpirates_api::api::errors::ApiError;
+///
+/// async fn scoped_handler(
+///     Tenant(company_id): Tenant,
+/// ) -> Result<String, ApiError> {
+///     Ok(format!("Hello company {}", company_id))
+/// }
+/// ```
+pub struct Tenant(pub Uuid);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Tenant
+where
+    PgPool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = verified_claims(parts, &PgPool::from_ref(state)).await?;
+
+        claims
+            .company_id
+            .map(Tenant)
+            .ok_or_else(|| ApiError::unauthorized("Token is missing company claim"))
+    }
+}
+
+/// Tenant extractor that additionally requires the admin role
+///
+/// Yields the caller's company ID like [`Tenant`], but rejects
+/// non-admin callers with 403. The role claim is trusted because a move,
+/// the only path that changes a role, revokes the token.
+pub struct TenantAdmin(pub Uuid);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for TenantAdmin
+where
+    PgPool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = verified_claims(parts, &PgPool::from_ref(state)).await?;
+        let company_id = claims
+            .company_id
+            .ok_or_else(|| ApiError::unauthorized("Token is missing company claim"))?;
+
+        if claims.role != UserRole::Admin {
+            return Err(ApiError::forbidden("Admin role required"));
+        }
+
+        Ok(TenantAdmin(company_id))
+    }
+}
@@ -3,4 +3,10 @@
 
 pub mod errors;
 pub mod handlers;
+pub mod login_dedup;
+pub mod messages;
 pub mod middleware;
+pub mod openapi;
+pub mod pagination;
+pub mod timestamp_format;
+pub mod uuid_format;
@@ -0,0 +1,121 @@
+// OpenAPI description
+// Machine-readable spec of the auth, team, and webhook endpoints
+
+use axum::Json;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi, ToSchema};
+
+use crate::api::handlers::{auth, teams, webhooks, workers};
+
+/// Body of every error response
+#[derive(Debug, ToSchema)]
+pub struct ErrorResponse {
+    /// Human-readable message; localized when the error has a code
+    pub error: String,
+    /// Stable machine-readable code, e.g. `duplicate_email`, if any
+    pub code: Option<String>,
+}
+
+/// Spec for the auth, team, and webhook endpoints
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Ghost Pirates API"),
+    paths(
+        auth::register,
+        auth::login,
+        auth::introspect,
+        auth::forgot_password,
+        auth::reset_password,
+        teams::create_team,
+        teams::batch_create_teams,
+        teams::get_teams_created_between,
+        teams::bulk_delete_teams,
+        teams::get_team,
+        teams::update_team,
+        teams::delete_team,
+        teams::set_team_tags,
+        teams::transfer_team_ownership,
+        teams::cancel_team,
+        teams::get_team_events,
+        teams::get_team_manager,
+        teams::get_team_workers,
+        teams::get_cost_breakdown,
+        teams::get_teams_by_company,
+        teams::get_team_stats,
+        teams::export_teams_csv,
+        teams::export_team,
+        teams::import_team,
+        workers::update_worker_skills,
+        webhooks::register_webhook,
+    ),
+    components(schemas(
+        ErrorResponse,
+        auth::RegisterRequest,
+        auth::RegisterResponse,
+        auth::LoginRequest,
+        auth::LoginResponse,
+        auth::IntrospectRequest,
+        auth::IntrospectResponse,
+        auth::ForgotPasswordRequest,
+        auth::ResetPasswordRequest,
+        auth::MessageResponse,
+        teams::CreateTeamRequest,
+        teams::BatchCreateTeamsRequest,
+        teams::BatchCreateTeamEntry,
+        teams::BatchCreateTeamsResponse,
+        teams::UpdateTeamRequest,
+        teams::UpdateTeamTagsRequest,
+        teams::TransferTeamRequest,
+        teams::BulkDeleteTeamsRequest,
+        teams::BulkDeleteTeamsResponse,
+        teams::TeamResponse,
+        teams::TeamListItemResponse,
+        teams::TeamStatsResponse,
+        teams::CostBreakdownResponse,
+        teams::ManagerResponse,
+        teams::WorkerResponse,
+        teams::WorkerStatusResponse,
+        teams::TeamEventsResponse,
+        workers::UpdateWorkerSkillsRequest,
+        workers::WorkerSkillsResponse,
+        webhooks::RegisterWebhookRequest,
+        webhooks::WebhookResponse,
+    )),
+    modifiers(&SecuritySchemes),
+    tags(
+        (name = "auth", description = "Registration, login, and password resets"),
+        (name = "teams", description = "Teams and their workers"),
+        (name = "webhooks", description = "Team event notifications"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Registers the `bearer_auth` (JWT) and `internal_secret` schemes
+/// referenced by the paths
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "internal_secret",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-internal-secret"))),
+        );
+    }
+}
+
+/// Serve the OpenAPI spec
+///
+/// GET /api/openapi.json
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
@@ -0,0 +1,207 @@
+// Pagination guardrails
+// Shared defaults, limits, extractor, and response envelope for list endpoints
+//
+// Offset-paged endpoints take the `Pagination` extractor (default 20, max
+// 100, out-of-range values rejected). Keyset-paged feeds, whose cursor is
+// not an offset, use `normalize_pagination` instead (default 50, max 200,
+// values clamped); new list endpoints should use `Pagination`.
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::api::errors::ApiError;
+
+/// Page size used when a request does not give a limit
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Largest page size a request may ask for
+pub const MAX_PAGE_SIZE: i64 = 200;
+
+/// Normalizes client-supplied keyset paging parameters
+///
+/// A missing limit becomes [`DEFAULT_PAGE_SIZE`]; any limit is clamped to
+/// `1..=MAX_PAGE_SIZE`. A missing or negative offset becomes 0. Keyset
+/// endpoints pass their cursor as the offset; offset-paged endpoints use
+/// [`Pagination`].
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::api::pagination::{normalize_pagination, MAX_PAGE_SIZE};
+///
+/// assert_eq!(normalize_pagination(Some(1_000_000), Some(-5)), (MAX_PAGE_SIZE, 0));
+/// ```
+pub fn normalize_pagination(limit: Option<i64>, offset: Option<i64>) -> (i64, i64) {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let offset = offset.unwrap_or(0).max(0);
+
+    (limit, offset)
+}
+
+/// Page size [`Pagination`] uses when a request does not give a limit
+pub const DEFAULT_LIMIT: i64 = 20;
+
+/// Largest page size [`Pagination`] accepts
+pub const MAX_LIMIT: i64 = 100;
+
+/// Validated `limit` and `offset` query parameters
+///
+/// A missing limit becomes [`DEFAULT_LIMIT`] and a missing offset 0.
+/// Unlike [`normalize_pagination`], out-of-range values are not clamped:
+/// a limit outside `1..=MAX_LIMIT` or a negative offset is rejected with
+/// 400, as is a value that is not an integer. Other query parameters are
+/// left for the handler's own `Query` extractor.
+///
+/// Usage:
+/// ```ignore
+/// use ghostpirates_api::api::pagination::Pagination;
+///
+/// async fn list_handler(Pagination { limit, offset }: Pagination) -> String {
+///     format!("{} items from {}", limit, offset)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Pagination {
+    /// Validates client-supplied paging parameters
+    pub fn new(limit: Option<i64>, offset: Option<i64>) -> Result<Self, String> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(format!(
+                "limit must be between 1 and {}, got {}",
+                MAX_LIMIT, limit
+            ));
+        }
+
+        let offset = offset.unwrap_or(0);
+        if offset < 0 {
+            return Err(format!("offset must not be negative, got {}", offset));
+        }
+
+        Ok(Self { limit, offset })
+    }
+}
+
+#[derive(Deserialize)]
+struct PaginationParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Pagination
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<PaginationParams>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| ApiError::bad_request(e.body_text()))?;
+
+        Pagination::new(params.limit, params.offset).map_err(ApiError::bad_request)
+    }
+}
+
+/// One page of a list endpoint's results
+///
+/// `total` counts every matching item, not just this page, so clients can
+/// tell how many pages remain.
+#[derive(Debug, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+
+    #[test]
+    fn missing_values_use_defaults() {
+        assert_eq!(normalize_pagination(None, None), (DEFAULT_PAGE_SIZE, 0));
+    }
+
+    #[test]
+    fn limit_is_clamped_to_the_maximum() {
+        assert_eq!(normalize_pagination(Some(1_000_000), None).0, MAX_PAGE_SIZE);
+        assert_eq!(
+            normalize_pagination(Some(MAX_PAGE_SIZE), None).0,
+            MAX_PAGE_SIZE
+        );
+        assert_eq!(normalize_pagination(Some(25), Some(10)), (25, 10));
+    }
+
+    #[test]
+    fn negative_and_zero_inputs_are_floored() {
+        assert_eq!(normalize_pagination(Some(0), None).0, 1);
+        assert_eq!(normalize_pagination(Some(-20), Some(-1)), (1, 0));
+    }
+
+    async fn extract(query: &str) -> Result<Pagination, ApiError> {
+        let request = axum::http::Request::builder()
+            .uri(format!("/items?{}", query))
+            .body(())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        Pagination::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn extractor_uses_defaults_for_missing_values() {
+        let page = extract("active=true").await.unwrap();
+
+        assert_eq!(
+            page,
+            Pagination {
+                limit: DEFAULT_LIMIT,
+                offset: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn extractor_accepts_the_bounds() {
+        assert_eq!(extract("limit=1&offset=0").await.unwrap().limit, 1);
+        assert_eq!(
+            extract("limit=100&offset=40").await.unwrap().limit,
+            MAX_LIMIT
+        );
+    }
+
+    #[tokio::test]
+    async fn extractor_rejects_a_limit_over_the_maximum() {
+        let err = extract("limit=101").await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert!(err.message.contains("limit must be between 1 and 100"));
+        assert!(extract("limit=0").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn extractor_rejects_a_negative_offset() {
+        let err = extract("offset=-1").await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+        assert!(err.message.contains("offset must not be negative"));
+    }
+
+    #[tokio::test]
+    async fn extractor_rejects_non_integer_values() {
+        let err = extract("limit=ten").await.unwrap_err();
+
+        assert_eq!(err.status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,53 @@
+// Timestamp wire format
+// Pins timestamps in response bodies to RFC 3339 in UTC with a `Z` suffix
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serializer;
+
+/// Serializes a timestamp as RFC 3339 with millisecond precision and a
+/// `Z` suffix, e.g. `2024-03-01T12:30:00.000Z`
+///
+/// Use with `#[serde(serialize_with = "timestamp_format::serialize")]` on
+/// response fields so every response renders timestamps the same way.
+pub fn serialize<S: Serializer>(at: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&at.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// [`serialize`] for optional timestamps; `None` becomes `null`
+///
+/// Use with `#[serde(serialize_with = "timestamp_format::serialize_option")]`.
+pub fn serialize_option<S: Serializer>(
+    at: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match at {
+        Some(at) => serialize(at, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Stamped {
+        #[serde(serialize_with = "serialize")]
+        at: DateTime<Utc>,
+        #[serde(serialize_with = "serialize_option")]
+        maybe: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn timestamps_serialize_as_rfc3339_utc() {
+        let at = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 5).unwrap()
+            + chrono::Duration::microseconds(123_456);
+
+        let json = serde_json::to_value(Stamped { at, maybe: None }).unwrap();
+
+        assert_eq!(json["at"], "2024-03-01T12:30:05.123Z");
+        assert!(json["maybe"].is_null());
+    }
+}
@@ -0,0 +1,87 @@
+// UUID wire format
+// Pins IDs in response bodies to lowercase hyphenated strings
+
+use serde::Serializer;
+use uuid::Uuid;
+
+/// Serializes a UUID as a lowercase hyphenated string
+///
+/// Use with `#[serde(serialize_with = "uuid_format::serialize")]` on
+/// response fields so the format does not depend on `uuid`'s serde
+/// behaviour or feature flags.
+pub fn serialize<S: Serializer>(id: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(id.hyphenated().encode_lower(&mut Uuid::encode_buffer()))
+}
+
+/// [`serialize`] for optional IDs; `None` becomes `null`
+///
+/// Use with `#[serde(serialize_with = "uuid_format::serialize_option")]`.
+pub fn serialize_option<S: Serializer>(
+    id: &Option<Uuid>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match id {
+        Some(id) => serialize(id, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// [`serialize`] for lists of IDs
+///
+/// Use with `#[serde(serialize_with = "uuid_format::serialize_many")]`.
+pub fn serialize_many<S: Serializer>(ids: &[Uuid], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(ids.iter().map(|id| id.hyphenated().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::handlers::auth::{IntrospectResponse, LoginResponse, RegisterResponse};
+
+    const ID: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+    #[test]
+    fn auth_responses_serialize_ids_as_hyphenated_lowercase() {
+        let id = Uuid::parse_str(&ID.to_uppercase()).unwrap();
+
+        let register = serde_json::to_value(RegisterResponse {
+            user_id: id,
+            message: "ok".to_string(),
+        })
+        .unwrap();
+        let login = serde_json::to_value(LoginResponse {
+            token: "token".to_string(),
+            user_id: id,
+        })
+        .unwrap();
+
+        assert_eq!(register["user_id"], ID);
+        assert_eq!(login["user_id"], ID);
+    }
+
+    #[test]
+    fn introspection_serializes_present_ids_and_omits_absent_ones() {
+        let id = Uuid::parse_str(&ID.to_uppercase()).unwrap();
+
+        let active = serde_json::to_value(IntrospectResponse {
+            active: true,
+            sub: Some(id),
+            exp: Some(0),
+            company_id: Some(id),
+            role: None,
+        })
+        .unwrap();
+        let inactive = serde_json::to_value(IntrospectResponse {
+            active: false,
+            sub: None,
+            exp: None,
+            company_id: None,
+            role: None,
+        })
+        .unwrap();
+
+        assert_eq!(active["sub"], ID);
+        assert_eq!(active["company_id"], ID);
+        assert_eq!(inactive, serde_json::json!({ "active": false }));
+    }
+}
@@ -1,21 +1,37 @@
 // JWT token creation and verification
 // Handles authentication tokens with 8-hour expiry
 
-use chrono::{Duration, Utc};
+use chrono::Duration;
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::shared::{Clock, SystemClock};
+use crate::domain::user::value_objects::UserRole;
+
 /// JWT claims structure
 ///
 /// # Fields
 /// * `sub` - Subject (user_id)
+/// * `company_id` - Company the user belongs to (absent in legacy tokens)
+/// * `role` - The user's role (legacy tokens are treated as members)
+/// * `ver` - The user's token version when the token was minted
 /// * `exp` - Expiry time (seconds since epoch)
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct Claims {
     /// User ID (subject)
     pub sub: Uuid,
+    /// Company (tenant) ID
+    #[serde(default)]
+    pub company_id: Option<Uuid>,
+    /// User role within the company
+    #[serde(default)]
+    pub role: UserRole,
+    /// Token version; tokens older than the user's current version are
+    /// revoked (legacy tokens have version 0)
+    #[serde(default)]
+    pub ver: i32,
     /// Expiry timestamp (seconds since epoch)
     pub exp: usize,
 }
@@ -24,7 +40,11 @@ pub struct Claims {
 ///
 /// # Arguments
 /// * `user_id` - The user's ID to include in the token
+/// * `company_id` - The user's company, used for tenant scoping
+/// * `role` - The user's role, used for admin-only operations
+/// * `token_version` - The user's current token version
 /// * `secret` - The secret key for signing (from environment)
+/// * `clock` - Source of the issue time the expiry is computed from
 ///
 /// # Returns
 /// * `Ok(String)` - The JWT token
@@ -33,22 +53,36 @@ pub struct Claims {
 /// # Token Properties
 /// - Expires after 8 hours
 /// - Signed with HS256 algorithm
-/// - Contains user_id in 'sub' claim
+/// - Contains user_id in 'sub' claim and the tenant in 'company_id'
 ///
 /// # Example
 /// ```
 /// use ghostpirates_api::auth::jwt::create_token;
+/// use ghostpirates_api::domain::shared::SystemClock;
+/// use ghostpirates_api::domain::user::value_objects::UserRole;
 /// use uuid::Uuid;
 ///
 /// let user_id = Uuid::new_v4();
+/// let company_id = Uuid::new_v4();
 /// let secret = "your-secret-key";
-/// let token = create_token(user_id, secret).expect("valid token");
+/// let token = create_token(user_id, company_id, UserRole::Member, 0, secret, &SystemClock)
+///     .expect("valid token");
 /// ```
 #[allow(dead_code)]
-pub fn create_token(user_id: Uuid, secret: &str) -> Result<String, String> {
-    let expiry = Utc::now() + Duration::hours(8);
+pub fn create_token(
+    user_id: Uuid,
+    company_id: Uuid,
+    role: UserRole,
+    token_version: i32,
+    secret: &str,
+    clock: &dyn Clock,
+) -> Result<String, String> {
+    let expiry = clock.now() + Duration::hours(8);
     let claims = Claims {
         sub: user_id,
+        company_id: Some(company_id),
+        role,
+        ver: token_version,
         exp: expiry.timestamp() as usize,
     };
 
@@ -60,6 +94,26 @@ pub fn create_token(user_id: Uuid, secret: &str) -> Result<String, String> {
     .map_err(|e| e.to_string())
 }
 
+/// Creates a JWT token for a user, issued now
+///
+/// Convenience for [`create_token`] with [`SystemClock`].
+pub fn create_token_now(
+    user_id: Uuid,
+    company_id: Uuid,
+    role: UserRole,
+    token_version: i32,
+    secret: &str,
+) -> Result<String, String> {
+    create_token(
+        user_id,
+        company_id,
+        role,
+        token_version,
+        secret,
+        &SystemClock,
+    )
+}
+
 /// Verifies and decodes a JWT token
 ///
 /// # Arguments
@@ -73,11 +127,14 @@ pub fn create_token(user_id: Uuid, secret: &str) -> Result<String, String> {
 /// # Example
 /// ```
 /// use ghostpirates_api::auth::jwt::{create_token, verify_token};
+/// use ghostpirates_api::domain::shared::SystemClock;
+/// use ghostpirates_api::domain::user::value_objects::UserRole;
 /// use uuid::Uuid;
 ///
 /// let user_id = Uuid::new_v4();
 /// let secret = "your-secret-key";
-/// let token = create_token(user_id, secret).unwrap();
+/// let token =
+///     create_token(user_id, Uuid::new_v4(), UserRole::Member, 0, secret, &SystemClock).unwrap();
 ///
 /// let claims = verify_token(&token, secret).expect("valid token");
 /// assert_eq!(claims.sub, user_id);
@@ -96,13 +153,16 @@ pub fn verify_token(token: &str, secret: &str) -> Result<Claims, String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::shared::{FixedClock, MockClock, SystemClock};
+    use chrono::{TimeZone, Utc};
 
     const TEST_SECRET: &str = "test-secret-key-for-unit-tests";
 
     #[test]
     fn create_and_verify_token() {
         let user_id = Uuid::new_v4();
-        let token = create_token(user_id, TEST_SECRET).expect("valid token");
+        let token = create_token_now(user_id, Uuid::new_v4(), UserRole::Member, 0, TEST_SECRET)
+            .expect("valid token");
 
         let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
         assert_eq!(claims.sub, user_id);
@@ -111,16 +171,81 @@ mod tests {
     #[test]
     fn token_contains_user_id() {
         let user_id = Uuid::new_v4();
-        let token = create_token(user_id, TEST_SECRET).expect("valid token");
+        let token = create_token(
+            user_id,
+            Uuid::new_v4(),
+            UserRole::Member,
+            0,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
 
         let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
         assert_eq!(claims.sub, user_id);
     }
 
+    #[test]
+    fn token_contains_company_id() {
+        let company_id = Uuid::new_v4();
+        let token = create_token(
+            Uuid::new_v4(),
+            company_id,
+            UserRole::Member,
+            0,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
+
+        let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
+        assert_eq!(claims.company_id, Some(company_id));
+    }
+
+    #[test]
+    fn token_contains_role() {
+        let token = create_token(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            UserRole::Admin,
+            0,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
+
+        let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
+        assert_eq!(claims.role, UserRole::Admin);
+    }
+
+    #[test]
+    fn token_contains_version() {
+        let token = create_token(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            UserRole::Member,
+            3,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
+
+        let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
+        assert_eq!(claims.ver, 3);
+    }
+
     #[test]
     fn wrong_secret_fails() {
         let user_id = Uuid::new_v4();
-        let token = create_token(user_id, TEST_SECRET).expect("valid token");
+        let token = create_token(
+            user_id,
+            Uuid::new_v4(),
+            UserRole::Member,
+            0,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
 
         let result = verify_token(&token, "wrong-secret");
         assert!(result.is_err());
@@ -135,7 +260,15 @@ mod tests {
     #[test]
     fn token_expiry_set() {
         let user_id = Uuid::new_v4();
-        let token = create_token(user_id, TEST_SECRET).expect("valid token");
+        let token = create_token(
+            user_id,
+            Uuid::new_v4(),
+            UserRole::Member,
+            0,
+            TEST_SECRET,
+            &SystemClock,
+        )
+        .expect("valid token");
 
         let claims = verify_token(&token, TEST_SECRET).expect("valid verification");
         let expiry_time = claims.exp as i64;
@@ -146,4 +279,55 @@ mod tests {
         assert!(expiry_time > now);
         assert!(expiry_time <= in_8_hours + 10); // 10 second buffer
     }
+
+    #[test]
+    fn token_expiry_is_eight_hours_after_clock_time() {
+        let issued_at = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+        let clock = MockClock::new(issued_at);
+
+        let token = create_token(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            UserRole::Member,
+            0,
+            TEST_SECRET,
+            &clock,
+        )
+        .expect("valid token");
+
+        // Decode without expiry validation since the fixed time is in the past
+        let mut validation = Validation::default();
+        validation.validate_exp = false;
+        let claims = decode::<Claims>(
+            &token,
+            &DecodingKey::from_secret(TEST_SECRET.as_ref()),
+            &validation,
+        )
+        .expect("valid token")
+        .claims;
+
+        assert_eq!(
+            claims.exp as i64,
+            (issued_at + Duration::hours(8)).timestamp()
+        );
+    }
+
+    #[test]
+    fn tokens_minted_at_the_same_time_are_identical() {
+        let clock = FixedClock(Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap());
+        let (user_id, company_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mint = || {
+            create_token(
+                user_id,
+                company_id,
+                UserRole::Member,
+                0,
+                TEST_SECRET,
+                &clock,
+            )
+        };
+
+        assert_eq!(mint().unwrap(), mint().unwrap());
+    }
 }
@@ -0,0 +1,221 @@
+// Failed login lockout
+// Temporarily refuses logins to an account after repeated wrong passwords
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::repositories::LoginAttempts;
+use crate::domain::shared::{Clock, SystemClock};
+
+/// Default consecutive failures that lock an account
+pub const DEFAULT_MAX_FAILURES: u32 = 5;
+
+/// Default seconds within which failures count towards a lockout
+pub const DEFAULT_WINDOW_SECS: u64 = 15 * 60;
+
+/// Default seconds an account stays locked
+pub const DEFAULT_COOLDOWN_SECS: u64 = 15 * 60;
+
+/// When failed logins lock an account, and for how long
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    /// Failures within `window` that lock the account; 0 disables lockouts
+    pub max_failures: u32,
+    pub window: Duration,
+    pub cooldown: Duration,
+}
+
+impl LockoutPolicy {
+    /// Policy from `LOGIN_LOCKOUT_MAX_FAILURES`, `LOGIN_LOCKOUT_WINDOW_SECS`,
+    /// and `LOGIN_LOCKOUT_COOLDOWN_SECS`
+    ///
+    /// Missing or unparsable values fall back to the defaults.
+    pub fn from_env() -> Self {
+        Self {
+            max_failures: env_or("LOGIN_LOCKOUT_MAX_FAILURES", DEFAULT_MAX_FAILURES),
+            window: Duration::seconds(
+                env_or("LOGIN_LOCKOUT_WINDOW_SECS", DEFAULT_WINDOW_SECS) as i64
+            ),
+            cooldown: Duration::seconds(
+                env_or("LOGIN_LOCKOUT_COOLDOWN_SECS", DEFAULT_COOLDOWN_SECS) as i64,
+            ),
+        }
+    }
+
+    /// How much longer `attempts` keeps the account locked at `now`
+    pub fn locked_for(&self, attempts: &LoginAttempts, now: DateTime<Utc>) -> Option<Duration> {
+        attempts
+            .locked_until
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+
+    /// The record after another failed login at `now`
+    ///
+    /// Failures outside the window, or from before an expired lockout,
+    /// no longer count. The failure that reaches `max_failures` locks the
+    /// account for `cooldown`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{Duration, Utc};
+    /// use ghostpirates_api::auth::lockout::LockoutPolicy;
+    ///
+    /// let policy = LockoutPolicy {
+    ///     max_failures: 2,
+    ///     window: Duration::minutes(15),
+    ///     cooldown: Duration::minutes(10),
+    /// };
+    /// let now = Utc::now();
+    ///
+    /// let first = policy.record_failure(None, now);
+    /// let second = policy.record_failure(Some(first), now);
+    /// assert_eq!(policy.locked_for(&second, now), Some(Duration::minutes(10)));
+    /// ```
+    pub fn record_failure(
+        &self,
+        previous: Option<LoginAttempts>,
+        now: DateTime<Utc>,
+    ) -> LoginAttempts {
+        let mut attempts = match previous {
+            Some(previous)
+                if now - previous.window_started_at < self.window
+                    && previous.locked_until.is_none_or(|until| until > now) =>
+            {
+                previous
+            }
+            _ => LoginAttempts {
+                failed_count: 0,
+                window_started_at: now,
+                locked_until: None,
+            },
+        };
+
+        attempts.failed_count = attempts.failed_count.saturating_add(1);
+        if self.max_failures > 0 && attempts.failed_count >= self.max_failures {
+            attempts.locked_until = Some(now + self.cooldown);
+        }
+
+        attempts
+    }
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failures: DEFAULT_MAX_FAILURES,
+            window: Duration::seconds(DEFAULT_WINDOW_SECS as i64),
+            cooldown: Duration::seconds(DEFAULT_COOLDOWN_SECS as i64),
+        }
+    }
+}
+
+/// Lockout policy and the clock it is judged by, shared by login requests
+///
+/// Installed as an `Extension`; tests pass a `MockClock` to step through
+/// a cooldown without waiting.
+#[derive(Clone)]
+pub struct LoginLockout {
+    pub policy: LockoutPolicy,
+    pub clock: Arc<dyn Clock>,
+}
+
+impl LoginLockout {
+    /// Creates a lockout judged by `clock`
+    pub fn new(policy: LockoutPolicy, clock: Arc<dyn Clock>) -> Self {
+        Self { policy, clock }
+    }
+
+    /// Lockout configured from the environment, on the system clock
+    pub fn from_env() -> Self {
+        Self::new(LockoutPolicy::from_env(), Arc::new(SystemClock))
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn policy() -> LockoutPolicy {
+        LockoutPolicy {
+            max_failures: 3,
+            window: Duration::minutes(15),
+            cooldown: Duration::minutes(10),
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    fn fail(policy: &LockoutPolicy, times: u32, at: DateTime<Utc>) -> LoginAttempts {
+        (0..times)
+            .fold(None, |attempts, _| {
+                Some(policy.record_failure(attempts, at))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn locks_after_max_failures_until_cooldown_ends() {
+        let policy = policy();
+
+        let attempts = fail(&policy, 2, start());
+        assert_eq!(policy.locked_for(&attempts, start()), None);
+
+        let attempts = policy.record_failure(Some(attempts), start());
+        assert_eq!(
+            policy.locked_for(&attempts, start() + Duration::minutes(4)),
+            Some(Duration::minutes(6))
+        );
+        assert_eq!(
+            policy.locked_for(&attempts, start() + Duration::minutes(10)),
+            None
+        );
+    }
+
+    #[test]
+    fn failures_outside_the_window_start_a_new_count() {
+        let policy = policy();
+        let attempts = fail(&policy, 2, start());
+
+        let later = start() + Duration::minutes(15);
+        let attempts = policy.record_failure(Some(attempts), later);
+
+        assert_eq!(attempts.failed_count, 1);
+        assert_eq!(attempts.window_started_at, later);
+    }
+
+    #[test]
+    fn failure_after_an_expired_lockout_starts_a_new_count() {
+        let policy = policy();
+        let attempts = fail(&policy, 3, start());
+
+        let after_cooldown = start() + Duration::minutes(11);
+        let attempts = policy.record_failure(Some(attempts), after_cooldown);
+
+        assert_eq!(attempts.failed_count, 1);
+        assert_eq!(policy.locked_for(&attempts, after_cooldown), None);
+    }
+
+    #[test]
+    fn zero_max_failures_never_locks() {
+        let policy = LockoutPolicy {
+            max_failures: 0,
+            ..policy()
+        };
+
+        let attempts = fail(&policy, 10, start());
+
+        assert_eq!(policy.locked_for(&attempts, start()), None);
+    }
+}
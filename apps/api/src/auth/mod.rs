@@ -2,4 +2,6 @@
 // Handles JWT and password management
 
 pub mod jwt;
+pub mod lockout;
 pub mod password;
+pub mod reset_token;
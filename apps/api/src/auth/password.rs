@@ -3,6 +3,9 @@
 
 use bcrypt::{hash, verify, DEFAULT_COST};
 
+/// Minimum accepted password length
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
 /// Hashes a password using bcrypt
 ///
 /// # Arguments
@@ -46,9 +49,112 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, String> {
     verify(password, hash).map_err(|e| e.to_string())
 }
 
+/// Bcrypt hash (at `DEFAULT_COST`) of a throwaway password
+///
+/// Verified against when a login names an unknown user, so that path costs
+/// the same bcrypt work as a wrong password for a real account.
+const DUMMY_PASSWORD_HASH: &str = "$2b$12$xLYhDTgypDxIvPkqmN1ZfOAPmg0d7gDrv5v/Ig1FPGKVY1xeMea6S";
+
+/// Password hash verification used by login
+///
+/// Abstracted so the login path can be exercised without real bcrypt work.
+pub trait PasswordHasher: Send + Sync {
+    /// Returns whether `password` matches `hash`
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, String>;
+}
+
+/// Production `PasswordHasher` backed by bcrypt
+pub struct BcryptHasher;
+
+impl PasswordHasher for BcryptHasher {
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, String> {
+        verify_password(password, hash)
+    }
+}
+
+/// Verifies login credentials without revealing whether the user exists
+///
+/// When `stored_hash` is `None` (unknown user) the password is still
+/// verified against a dummy hash so response timing matches the
+/// wrong-password path; the result is always `false`.
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::auth::password::{hash_password, verify_login, BcryptHasher};
+///
+/// let hash = hash_password("my_password").unwrap();
+/// assert!(verify_login(&BcryptHasher, "my_password", Some(&hash)).unwrap());
+/// assert!(!verify_login(&BcryptHasher, "my_password", None).unwrap());
+/// ```
+pub fn verify_login(
+    hasher: &dyn PasswordHasher,
+    password: &str,
+    stored_hash: Option<&str>,
+) -> Result<bool, String> {
+    match stored_hash {
+        Some(hash) => hasher.verify(password, hash),
+        None => {
+            hasher.verify(password, DUMMY_PASSWORD_HASH)?;
+            Ok(false)
+        }
+    }
+}
+
+/// Checks that a password meets the minimum strength requirements
+///
+/// # Arguments
+/// * `password` - The plaintext password to check
+///
+/// # Returns
+/// * `Ok(())` - If the password is acceptable
+/// * `Err(String)` - Human-readable reason the password was rejected
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::auth::password::validate_password_strength;
+///
+/// assert!(validate_password_strength("long-enough").is_ok());
+/// assert!(validate_password_strength("short").is_err());
+/// ```
+pub fn validate_password_strength(password: &str) -> Result<(), String> {
+    if password.len() < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Hasher that records how many verifications were performed
+    struct CountingHasher {
+        calls: AtomicUsize,
+    }
+
+    impl CountingHasher {
+        fn new() -> Self {
+            Self {
+                calls: AtomicUsize::new(0),
+            }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl PasswordHasher for CountingHasher {
+        fn verify(&self, password: &str, hash: &str) -> Result<bool, String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(password == hash)
+        }
+    }
 
     #[test]
     fn hash_and_verify_password() {
@@ -81,4 +187,45 @@ mod tests {
         assert!(verify_password(password, &hash1).unwrap());
         assert!(verify_password(password, &hash2).unwrap());
     }
+
+    #[test]
+    fn password_strength_minimum_length() {
+        assert!(validate_password_strength("12345678").is_ok());
+        assert!(validate_password_strength("1234567").is_err());
+    }
+
+    #[test]
+    fn unknown_user_still_runs_verification() {
+        let hasher = CountingHasher::new();
+
+        let valid = verify_login(&hasher, "any_password", None).unwrap();
+
+        assert!(!valid);
+        assert_eq!(hasher.calls(), 1);
+    }
+
+    #[test]
+    fn wrong_password_runs_verification() {
+        let hasher = CountingHasher::new();
+
+        let valid = verify_login(&hasher, "wrong_password", Some("stored")).unwrap();
+
+        assert!(!valid);
+        assert_eq!(hasher.calls(), 1);
+    }
+
+    #[test]
+    fn unknown_user_never_matches_dummy_hash() {
+        let hasher = CountingHasher::new();
+
+        let valid = verify_login(&hasher, DUMMY_PASSWORD_HASH, None).unwrap();
+
+        assert!(!valid);
+    }
+
+    #[test]
+    fn dummy_hash_is_valid_bcrypt_at_default_cost() {
+        assert!(!verify_password("not-the-dummy", DUMMY_PASSWORD_HASH).unwrap());
+        assert!(DUMMY_PASSWORD_HASH.starts_with(&format!("$2b${}$", DEFAULT_COST)));
+    }
 }
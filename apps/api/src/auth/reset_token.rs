@@ -0,0 +1,68 @@
+// Password reset token generation and hashing
+// Tokens are random, single-use, and only ever stored as SHA-256 digests
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Number of random bytes in a reset token (hex-encoded to 64 characters)
+const TOKEN_BYTES: usize = 32;
+
+/// Lifetime of a password reset token in minutes
+pub const RESET_TOKEN_TTL_MINUTES: i64 = 60;
+
+/// Generates a new random password reset token
+///
+/// # Returns
+/// * `String` - The plaintext token to hand to the user (never persisted)
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::auth::reset_token::generate_reset_token;
+///
+/// let token = generate_reset_token();
+/// assert_eq!(token.len(), 64);
+/// ```
+pub fn generate_reset_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Hashes a reset token for storage and lookup
+///
+/// SHA-256 is used instead of bcrypt because the token already carries
+/// 256 bits of entropy and must be looked up by its digest.
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::auth::reset_token::hash_reset_token;
+///
+/// assert_eq!(hash_reset_token("abc"), hash_reset_token("abc"));
+/// assert_ne!(hash_reset_token("abc"), hash_reset_token("abd"));
+/// ```
+pub fn hash_reset_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_unique() {
+        let token1 = generate_reset_token();
+        let token2 = generate_reset_token();
+
+        assert_ne!(token1, token2);
+        assert_eq!(token1.len(), TOKEN_BYTES * 2);
+    }
+
+    #[test]
+    fn hash_is_deterministic_and_not_plaintext() {
+        let token = generate_reset_token();
+        let hash = hash_reset_token(&token);
+
+        assert_eq!(hash, hash_reset_token(&token));
+        assert_ne!(hash, token);
+    }
+}
@@ -3,5 +3,6 @@
 // Domain is independent of infrastructure concerns
 
 pub mod repositories;
+pub mod shared;
 pub mod team;
 pub mod user;
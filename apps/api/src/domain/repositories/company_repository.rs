@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Repository trait for companies and their settings
+#[async_trait]
+pub trait CompanyRepository: Send + Sync {
+    /// Find the most non-terminal teams a company may have at once
+    ///
+    /// Returns `None` when the company is unlimited or does not exist.
+    async fn find_max_active_teams(&self, company_id: Uuid) -> Result<Option<i64>, String>;
+
+    /// Find the company named `name`, creating it if none exists
+    ///
+    /// Company names are unique, so concurrent callers get the same ID.
+    async fn find_or_create_by_name(&self, name: &str) -> Result<Uuid, String>;
+}
@@ -0,0 +1,39 @@
+use crate::agents::types::Specialization;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// One unit of spend incurred on behalf of a team
+#[derive(Debug, Clone)]
+pub struct CostEntry {
+    pub team_id: Uuid,
+    pub task_id: Option<Uuid>,
+    /// Worker that incurred the cost, `None` for team-level spend
+    pub worker_id: Option<Uuid>,
+    pub specialization: Option<Specialization>,
+    pub model_name: String,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_cost: Decimal,
+}
+
+/// Spend for one specialization
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecializationCost {
+    /// `None` for spend not attributed to a worker
+    pub specialization: Option<String>,
+    pub total: Decimal,
+}
+
+/// Repository trait for recorded spend
+#[async_trait]
+pub trait CostRepository: Send + Sync {
+    /// Record a cost entry
+    async fn record(&self, entry: &CostEntry) -> Result<(), String>;
+
+    /// Sum a team's recorded spend per worker specialization
+    async fn breakdown_by_specialization(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Vec<SpecializationCost>, String>;
+}
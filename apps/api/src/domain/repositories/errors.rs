@@ -0,0 +1,23 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors from repository operations that callers branch on
+///
+/// Lets adapters tell a missing row from a failed query without matching
+/// on message text.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RepositoryError {
+    /// No row with the given ID exists
+    #[error("{entity} not found: {id}")]
+    NotFound { entity: &'static str, id: Uuid },
+
+    /// The query itself failed
+    #[error("{0}")]
+    Database(String),
+}
+
+impl From<RepositoryError> for String {
+    fn from(error: RepositoryError) -> Self {
+        error.to_string()
+    }
+}
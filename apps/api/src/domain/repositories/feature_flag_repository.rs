@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Repository trait for per-company feature flags
+#[async_trait]
+pub trait FeatureFlagRepository: Send + Sync {
+    /// Find a company's setting for a flag
+    ///
+    /// Returns `None` if the company has never set the flag.
+    async fn find(&self, company_id: Uuid, flag_name: &str) -> Result<Option<bool>, String>;
+
+    /// Enable or disable a flag for a company
+    async fn set(&self, company_id: Uuid, flag_name: &str, enabled: bool) -> Result<(), String>;
+}
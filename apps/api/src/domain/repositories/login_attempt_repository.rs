@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Recent failed logins for one email address
+///
+/// Addresses are tracked whether or not an account uses them, so lockouts
+/// do not reveal which emails are registered. Addresses without failures
+/// since their last successful login have no record at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginAttempts {
+    /// Failures since `window_started_at`
+    pub failed_count: u32,
+    /// When the first failure counted in `failed_count` happened
+    pub window_started_at: DateTime<Utc>,
+    /// Logins are refused until this time, if set
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+/// Computes the record after a failure from the current one, if any
+pub type NextAttempts = dyn Fn(Option<LoginAttempts>) -> LoginAttempts + Send + Sync;
+
+/// Repository trait for failed login tracking
+///
+/// Emails are passed normalized (trimmed and lowercased) by callers.
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Find an email's failed login record
+    async fn find(&self, email: &str) -> Result<Option<LoginAttempts>, String>;
+
+    /// Record a failed login for `email`, returning the stored record
+    ///
+    /// `next` is applied to the current record while no other failure
+    /// for the same email can be recorded, so concurrent failures are
+    /// each counted.
+    async fn record_failure(
+        &self,
+        email: &str,
+        next: &NextAttempts,
+    ) -> Result<LoginAttempts, String>;
+
+    /// Forget an email's failed logins
+    async fn clear(&self, email: &str) -> Result<(), String>;
+}
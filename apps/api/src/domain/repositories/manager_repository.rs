@@ -0,0 +1,15 @@
+use crate::agents::ManagerAgent;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Repository trait for persisted Manager Agents
+///
+/// Each team has at most one manager.
+#[async_trait]
+pub trait ManagerRepository: Send + Sync {
+    /// Save a manager (insert or update its configuration)
+    async fn save(&self, manager: &ManagerAgent) -> Result<(), String>;
+
+    /// Find the manager formed for a team, if any
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Option<ManagerAgent>, String>;
+}
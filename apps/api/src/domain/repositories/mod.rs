@@ -1,4 +1,27 @@
+pub mod company_repository;
+pub mod cost_repository;
+pub mod errors;
+pub mod feature_flag_repository;
+pub mod login_attempt_repository;
+pub mod manager_repository;
+pub mod outbox_repository;
+pub mod password_reset_repository;
+pub mod task_repository;
+pub mod team_event_repository;
 pub mod team_repository;
 pub mod user_repository;
+pub mod webhook_repository;
+pub mod worker_repository;
 
-pub use team_repository::TeamRepository;
+pub use company_repository::CompanyRepository;
+pub use cost_repository::CostRepository;
+pub use errors::RepositoryError;
+pub use feature_flag_repository::FeatureFlagRepository;
+pub use login_attempt_repository::{LoginAttemptRepository, LoginAttempts};
+pub use manager_repository::ManagerRepository;
+pub use outbox_repository::{OutboxMessage, OutboxRepository};
+pub use task_repository::{TaskAssignment, TaskRepository};
+pub use team_event_repository::TeamEventRepository;
+pub use team_repository::{TeamRepository, TeamRepositoryTx};
+pub use webhook_repository::{Webhook, WebhookRepository};
+pub use worker_repository::WorkerRepository;
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A team event waiting in the outbox to be published
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    /// Monotonically increasing position in the outbox
+    pub id: i64,
+    pub company_id: Uuid,
+    pub event_type: String,
+    /// The serialized `TeamEvent`
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    /// Failed publication attempts so far
+    pub attempts: i32,
+}
+
+/// Repository trait for the transactional event outbox
+///
+/// Messages are written by [`TeamRepositoryTx::record_events`] in the same
+/// transaction as the team change that raised them, and read here by the
+/// relay that publishes them.
+///
+/// [`TeamRepositoryTx::record_events`]: super::TeamRepositoryTx::record_events
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claim up to `limit` unsent messages that are due, oldest first
+    ///
+    /// Claimed messages are hidden from other claims for `lease`, so
+    /// relays running side by side never publish the same message at
+    /// once. Messages neither marked sent nor failed within the lease are
+    /// claimed again.
+    async fn claim_unsent(&self, limit: i64, lease: Duration)
+        -> Result<Vec<OutboxMessage>, String>;
+
+    /// Mark messages as published so they are not claimed again
+    async fn mark_sent(&self, ids: &[i64]) -> Result<(), String>;
+
+    /// Record a failed publication of message `id`
+    ///
+    /// The message is claimed again from `retry_at`; with `None` it is
+    /// given up on and kept, unsent, for inspection.
+    async fn record_failure(
+        &self,
+        id: i64,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), String>;
+
+    /// Delete messages sent before `sent_before`, returning how many
+    async fn prune_sent(&self, sent_before: DateTime<Utc>) -> Result<u64, String>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Password reset token data for persistence
+///
+/// Only the SHA-256 digest of the token is stored; the plaintext token
+/// is handed to the user once and never persisted.
+#[derive(Debug, Clone)]
+pub struct PasswordResetToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Repository trait for password reset tokens
+#[async_trait]
+pub trait PasswordResetRepository: Send + Sync {
+    /// Store a newly minted reset token
+    async fn create(&self, token: PasswordResetToken) -> Result<Uuid, String>;
+
+    /// Mark an unused, unexpired token as used and set its user's password
+    ///
+    /// Both happen in one transaction, so a token is never burned without
+    /// the password changing, nor the password changed twice with one token.
+    /// The user's token version is bumped too, revoking every JWT issued
+    /// before the reset.
+    /// Returns the owning user's ID, or `None` (changing nothing) if the
+    /// token is unknown, expired, or has already been used.
+    async fn reset_password(
+        &self,
+        token_hash: &str,
+        password_hash: &str,
+    ) -> Result<Option<Uuid>, String>;
+}
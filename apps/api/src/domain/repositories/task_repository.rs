@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// A task handed to a worker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskAssignment {
+    pub task_id: Uuid,
+    pub worker_id: Uuid,
+}
+
+/// Repository trait for team tasks
+#[async_trait]
+pub trait TaskRepository: Send + Sync {
+    /// Assign a batch of tasks to workers atomically
+    ///
+    /// Each task moves to `assigned`. Fails without changing anything if
+    /// any task does not exist.
+    async fn assign_many(&self, assignments: &[TaskAssignment]) -> Result<(), String>;
+}
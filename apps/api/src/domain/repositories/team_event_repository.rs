@@ -0,0 +1,46 @@
+use crate::domain::team::events::TeamEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A persisted team event with its position in the event log
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredTeamEvent {
+    /// Monotonically increasing position in the event log
+    pub sequence: i64,
+    pub team_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Repository trait for the append-only team event log
+#[async_trait]
+pub trait TeamEventRepository: Send + Sync {
+    /// Append events to the log, returning their assigned sequence numbers
+    async fn append(&self, events: &[TeamEvent]) -> Result<Vec<i64>, String>;
+
+    /// List a team's events with sequence greater than `after_sequence`
+    ///
+    /// Uses keyset pagination so each page costs the same regardless of
+    /// how far into the log the cursor is. Results are ordered by sequence.
+    async fn list_after(
+        &self,
+        team_id: Uuid,
+        after_sequence: i64,
+        limit: i64,
+    ) -> Result<Vec<StoredTeamEvent>, String>;
+
+    /// List a company's events of one type, oldest first
+    ///
+    /// `event_type` is the stored name, e.g. `failed`; see
+    /// [`TeamEvent::parse_event_type`].
+    async fn find_by_type(
+        &self,
+        company_id: Uuid,
+        event_type: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<StoredTeamEvent>, String>;
+}
@@ -1,5 +1,10 @@
-use crate::domain::team::Team;
+use super::errors::RepositoryError;
+use crate::domain::team::events::TeamEvent;
+use crate::domain::team::value_objects::TeamStatus;
+use crate::domain::team::{Team, TeamSnapshot};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Repository trait for Team aggregate
@@ -14,13 +19,118 @@ pub trait TeamRepository: Send + Sync {
     /// Find a team by its ID
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Team>, String>;
 
+    /// Load a read-only snapshot of a team by its ID
+    ///
+    /// Cheaper than `find_by_id` for views that never mutate the team.
+    async fn find_snapshot_by_id(&self, id: Uuid) -> Result<Option<TeamSnapshot>, String>;
+
     /// Find all teams for a company
     async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Team>, String>;
 
+    /// Find a company's teams by ID in a single query
+    ///
+    /// IDs that do not exist or belong to another company are left out.
+    /// Teams are returned in the order their IDs are given.
+    async fn find_by_ids(&self, company_id: Uuid, ids: &[Uuid]) -> Result<Vec<Team>, String>;
+
+    /// Find a company's teams whose status is any of `statuses`
+    async fn find_by_statuses(
+        &self,
+        company_id: Uuid,
+        statuses: &[TeamStatus],
+    ) -> Result<Vec<Team>, String>;
+
+    /// Find a company's teams created between `from` and `to` (inclusive)
+    async fn find_created_between(
+        &self,
+        company_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Team>, String>;
+
+    /// Find teams of any company still in `status` that were created
+    /// before `older_than`, oldest first
+    ///
+    /// For cleanup jobs: a team stuck in `Pending` long after creation
+    /// points to a broken pipeline.
+    async fn find_stale(
+        &self,
+        status: TeamStatus,
+        older_than: DateTime<Utc>,
+    ) -> Result<Vec<Team>, String>;
+
+    /// Find a company's teams carrying `tag`
+    ///
+    /// Tags are stored lowercased, so `tag` should be normalized first.
+    async fn find_by_tag(&self, company_id: Uuid, tag: &str) -> Result<Vec<Team>, String>;
+
+    /// Count a company's teams that are not in a terminal status
+    async fn count_active(&self, company_id: Uuid) -> Result<i64, String>;
+
+    /// Count a company's teams per status
+    ///
+    /// Every status is present in the result, with zero for statuses that
+    /// have no teams.
+    async fn count_by_status(&self, company_id: Uuid) -> Result<HashMap<TeamStatus, i64>, String>;
+
     /// Find all teams created by a specific user
     #[allow(dead_code)]
     async fn find_by_creator(&self, user_id: Uuid) -> Result<Vec<Team>, String>;
 
     /// Delete a team by ID
-    async fn delete(&self, id: Uuid) -> Result<(), String>;
+    ///
+    /// The team's workers, tasks, events, costs, messages and manager are
+    /// removed with it by `ON DELETE CASCADE` foreign keys, so no orphan
+    /// rows remain. Fails with `RepositoryError::NotFound` if no team has
+    /// the ID.
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+
+    /// Delete a company's teams by ID in a single transaction
+    ///
+    /// IDs that do not exist or belong to another company are left alone.
+    /// Returns the IDs that were actually deleted.
+    async fn delete_many(&self, company_id: Uuid, ids: &[Uuid]) -> Result<Vec<Uuid>, String>;
+
+    /// Start a transaction for operations that must succeed or fail together
+    ///
+    /// Nothing written through the handle is visible to other callers
+    /// until `commit`; dropping it without committing rolls back.
+    async fn begin(&self) -> Result<Box<dyn TeamRepositoryTx>, String>;
+}
+
+/// Team repository operations running inside one transaction
+///
+/// Returned by [`TeamRepository::begin`]. Reads see the transaction's own
+/// uncommitted writes.
+#[async_trait]
+pub trait TeamRepositoryTx: Send {
+    /// Save a team (insert or update)
+    async fn save(&mut self, team: &Team) -> Result<(), String>;
+
+    /// Insert a new team, failing rather than overwriting if its ID is
+    /// already taken
+    async fn insert(&mut self, team: &Team) -> Result<(), String>;
+
+    /// Find a team by its ID
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Team>, String>;
+
+    /// Find all teams for a company
+    async fn find_by_company(&mut self, company_id: Uuid) -> Result<Vec<Team>, String>;
+
+    /// Append events to the team event log and queue them in the outbox
+    ///
+    /// Both writes commit or roll back with the team change that raised
+    /// the events, so no event is lost or published for a change that did
+    /// not happen. Returns the events' log sequence numbers.
+    async fn record_events(
+        &mut self,
+        company_id: Uuid,
+        events: &[TeamEvent],
+    ) -> Result<Vec<i64>, String>;
+
+    /// Make every write through this handle permanent
+    async fn commit(self: Box<Self>) -> Result<(), String>;
+
+    /// Discard every write through this handle
+    async fn rollback(self: Box<Self>) -> Result<(), String>;
 }
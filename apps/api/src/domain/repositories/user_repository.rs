@@ -1,5 +1,7 @@
-use crate::domain::user::value_objects::Email;
+use crate::domain::user::value_objects::{Email, UserRole};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 use uuid::Uuid;
 
 /// User data for persistence
@@ -13,6 +15,69 @@ pub struct User {
     pub password_hash: String,
     pub full_name: String,
     pub is_active: bool,
+    pub role: UserRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl User {
+    /// Creates a new active member with a normalized full name
+    ///
+    /// # Returns
+    /// * `Ok(User)` - If the full name is non-empty after normalization
+    /// * `Err(String)` - If the full name is blank
+    pub fn new(
+        company_id: Uuid,
+        email: Email,
+        password_hash: String,
+        full_name: &str,
+    ) -> Result<Self, String> {
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            company_id,
+            email,
+            password_hash,
+            full_name: normalize_full_name(full_name)?,
+            is_active: true,
+            role: UserRole::Member,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+/// Trims a full name and collapses internal whitespace runs to single spaces
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::domain::repositories::user_repository::normalize_full_name;
+///
+/// assert_eq!(normalize_full_name("  Alice   Smith ").unwrap(), "Alice Smith");
+/// assert!(normalize_full_name("   ").is_err());
+/// ```
+pub fn normalize_full_name(full_name: &str) -> Result<String, String> {
+    let normalized = full_name.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.is_empty() {
+        return Err("Full name cannot be empty".to_string());
+    }
+    Ok(normalized)
+}
+
+/// What happens to the teams a user created when they change company
+///
+/// Deserializes from `"keep"`, `"move"`, or `{"reassign_to": "<user id>"}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamHandling {
+    /// Leave the teams in the old company, still credited to the user
+    #[default]
+    Keep,
+    /// Move the teams to the new company along with the user
+    Move,
+    /// Detach the teams from the user by crediting them to another user
+    /// of the old company
+    ReassignTo(Uuid),
 }
 
 /// Repository trait for User aggregate
@@ -25,6 +90,13 @@ pub trait UserRepository: Send + Sync {
     #[allow(dead_code)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, String>;
 
+    /// Find all users whose ID is in `ids` with a single query
+    ///
+    /// Users are returned in the order their IDs appear in `ids`; IDs with
+    /// no matching user are skipped, so callers should key results by
+    /// `User::id` rather than by position.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, String>;
+
     /// Find a user by email address
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, String>;
 
@@ -32,6 +104,104 @@ pub trait UserRepository: Send + Sync {
     #[allow(dead_code)]
     async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<User>, String>;
 
+    /// Find one page of a company's users, ordered by full name
+    ///
+    /// `active` keeps only active (`Some(true)`) or inactive (`Some(false)`)
+    /// users; `None` keeps both.
+    async fn find_by_company_paginated(
+        &self,
+        company_id: Uuid,
+        active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, String>;
+
+    /// Count a company's users, filtered by `active` as in
+    /// `find_by_company_paginated`
+    async fn count_by_company(&self, company_id: Uuid, active: Option<bool>)
+        -> Result<i64, String>;
+
     /// Update user's last login timestamp
+    ///
+    /// Only touches `last_login`; logging in is not a profile change and
+    /// must not advance `updated_at`.
     async fn update_last_login(&self, user_id: Uuid) -> Result<(), String>;
+
+    /// Replace a user's full name and email, advancing `updated_at`
+    ///
+    /// Returns the stored `updated_at`. Fails with a unique-violation error
+    /// if the email belongs to another user.
+    async fn update_profile(
+        &self,
+        user_id: Uuid,
+        full_name: &str,
+        email: &Email,
+    ) -> Result<DateTime<Utc>, String>;
+
+    /// The user's current token version, or `None` for unknown users
+    ///
+    /// Tokens minted with an older version are revoked. Read from the
+    /// primary, so a revocation applies to the very next request.
+    async fn current_token_version(&self, user_id: Uuid) -> Result<Option<i32>, String>;
+
+    /// Move a user to another company in a single transaction
+    ///
+    /// The user joins `new_company_id` as a member, and the teams they
+    /// created in their old company are handled as `teams` says. Moving a
+    /// user to the company they are already in changes nothing. A move
+    /// bumps the user's token version, revoking the tokens that still
+    /// carry the old company.
+    ///
+    /// Moved teams count toward the new company's active team quota, and
+    /// are held to unique goals there when the repository enforces them.
+    ///
+    /// # Returns
+    /// * `Err(String)` - "User not found" or "Company not found" if either
+    ///   does not exist, "not a user of company" if a reassignment target is
+    ///   not an active user of the old company, "active team limit" if the
+    ///   moved teams would exceed the new company's quota, an error
+    ///   mentioning the unique goal index if a moved goal is taken, or a
+    ///   database error
+    async fn change_company(
+        &self,
+        user_id: Uuid,
+        new_company_id: Uuid,
+        teams: TeamHandling,
+    ) -> Result<(), String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email() -> Email {
+        Email::new("alice@example.com").unwrap()
+    }
+
+    #[test]
+    fn full_name_is_trimmed() {
+        let user = User::new(Uuid::new_v4(), email(), "hash".to_string(), "  Alice  ").unwrap();
+
+        assert_eq!(user.full_name, "Alice");
+    }
+
+    #[test]
+    fn full_name_internal_whitespace_is_collapsed() {
+        let user = User::new(
+            Uuid::new_v4(),
+            email(),
+            "hash".to_string(),
+            "Alice \t\n  Smith",
+        )
+        .unwrap();
+
+        assert_eq!(user.full_name, "Alice Smith");
+    }
+
+    #[test]
+    fn full_name_empty_after_trim_is_rejected() {
+        let result = User::new(Uuid::new_v4(), email(), "hash".to_string(), " \t\n ");
+
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// An endpoint a company registered to be notified of team events
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub url: String,
+    /// Key for the HMAC signature sent with every delivery
+    pub secret: String,
+    /// Event types to deliver; empty means every event
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Returns whether events of `event_type` should be delivered here
+    pub fn accepts(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type)
+    }
+}
+
+/// Repository trait for company webhooks
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Save a new webhook
+    async fn create(&self, webhook: &Webhook) -> Result<(), String>;
+
+    /// Find a company's webhooks that accept `event_type`
+    async fn find_for_event(
+        &self,
+        company_id: Uuid,
+        event_type: &str,
+    ) -> Result<Vec<Webhook>, String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(event_types: &[&str]) -> Webhook {
+        Webhook {
+            id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            url: "https://example.com/hook".to_string(),
+            secret: "secret".to_string(),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn empty_filter_accepts_every_event() {
+        assert!(webhook(&[]).accepts("created"));
+        assert!(webhook(&[]).accepts("tags_updated"));
+    }
+
+    #[test]
+    fn filter_accepts_only_listed_events() {
+        let hook = webhook(&["created", "failed"]);
+
+        assert!(hook.accepts("failed"));
+        assert!(!hook.accepts("started"));
+    }
+}
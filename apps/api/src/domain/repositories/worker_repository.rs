@@ -0,0 +1,34 @@
+use crate::agents::WorkerAgent;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Repository trait for a team's worker agents
+#[async_trait]
+pub trait WorkerRepository: Send + Sync {
+    /// Save a batch of workers atomically
+    ///
+    /// Either every worker is persisted or, on any failure, none are.
+    async fn save_many(&self, workers: &[WorkerAgent]) -> Result<(), String>;
+
+    /// Find a team's workers, oldest first
+    ///
+    /// Responsibilities and required tools are not persisted, so loaded
+    /// workers have none. A worker's assigned task is the task still in
+    /// progress that is assigned to it, if any.
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<WorkerAgent>, String>;
+
+    /// Find a worker by ID, loaded as by `find_by_team`
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WorkerAgent>, String>;
+
+    /// Replace a worker's skills if they are still `expected`
+    ///
+    /// Returns `false`, changing nothing, if the stored skills differ from
+    /// `expected` (another update got there first) or no worker has the
+    /// given ID.
+    async fn update_skills(
+        &self,
+        id: Uuid,
+        expected: &[String],
+        skills: &[String],
+    ) -> Result<bool, String>;
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Mutex;
+
+/// Source of the current time
+///
+/// Inject a `Clock` instead of calling `Utc::now()` directly so that
+/// time-dependent logic can be tested deterministically.
+pub trait Clock: Send + Sync {
+    /// Returns the current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Production clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same time
+///
+/// For tests that need one exact timestamp; use [`MockClock`] to step
+/// time forward between calls.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use ghostpirates_api::domain::shared::{Clock, FixedClock};
+///
+/// let at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+///
+/// assert_eq!(FixedClock(at).now(), at);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Test clock that returns a fixed, manually advanced time
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use ghostpirates_api::domain::shared::{Clock, MockClock};
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+/// let clock = MockClock::new(start);
+///
+/// clock.advance(Duration::minutes(5));
+/// assert_eq!(clock.now(), start + Duration::minutes(5));
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl MockClock {
+    /// Creates a clock frozen at `now`
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Moves the clock to `now`
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Moves the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn mock_clock_is_frozen_until_moved() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let clock = MockClock::new(start);
+
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start);
+
+        let later = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        clock.set(later);
+        assert_eq!(clock.now(), later);
+    }
+
+    #[test]
+    fn fixed_clock_never_moves() {
+        let at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let clock = FixedClock(at);
+
+        assert_eq!(clock.now(), at);
+        assert_eq!(clock.now(), at);
+    }
+
+    #[test]
+    fn system_clock_tracks_real_time() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+
+        assert!(now >= before);
+    }
+}
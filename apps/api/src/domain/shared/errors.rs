@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Errors raised when a domain rule rejects an operation
+///
+/// The variant tells adapters how to report the failure: validation errors
+/// are problems with the caller's input, conflicts clash with existing
+/// state and may succeed once that state changes.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DomainError {
+    /// The input breaks an invariant, e.g. a blank goal
+    #[error("{0}")]
+    Validation(String),
+
+    /// The operation clashes with existing state, e.g. a limit already reached
+    #[error("{0}")]
+    Conflict(String),
+}
+
+impl From<DomainError> for String {
+    fn from(error: DomainError) -> Self {
+        error.to_string()
+    }
+}
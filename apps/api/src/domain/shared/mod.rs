@@ -0,0 +1,10 @@
+// Shared kernel
+// Value objects and services used by more than one aggregate
+
+pub mod clock;
+pub mod errors;
+pub mod money;
+
+pub use clock::{Clock, FixedClock, MockClock, SystemClock};
+pub use errors::DomainError;
+pub use money::{Currency, Money};
@@ -0,0 +1,178 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Currencies accepted for budgets and costs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    /// United States dollar
+    #[default]
+    Usd,
+    /// Euro
+    Eur,
+    /// Pound sterling
+    Gbp,
+}
+
+impl Currency {
+    /// Returns the ISO 4217 code for this currency
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Gbp => "GBP",
+        }
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "USD" => Ok(Currency::Usd),
+            "EUR" => Ok(Currency::Eur),
+            "GBP" => Ok(Currency::Gbp),
+            other => Err(format!("Unsupported currency: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Money value object: a positive amount in a specific currency
+///
+/// # Invariants
+/// - Amount is strictly positive
+/// - Arithmetic is only defined between amounts of the same currency
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::domain::shared::{Currency, Money};
+/// use rust_decimal::Decimal;
+///
+/// let budget = Money::new(Decimal::from(100), Currency::Usd).expect("valid money");
+/// let top_up = Money::new(Decimal::from(50), Currency::Usd).unwrap();
+///
+/// assert_eq!(budget.checked_add(&top_up).unwrap().amount(), Decimal::from(150));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates a new Money value
+    ///
+    /// # Returns
+    /// * `Ok(Money)` - If the amount is positive
+    /// * `Err(String)` - If the amount is zero or negative
+    pub fn new(amount: Decimal, currency: Currency) -> Result<Self, String> {
+        if amount <= Decimal::ZERO {
+            return Err(format!("Amount must be positive, got {}", amount));
+        }
+
+        Ok(Self { amount, currency })
+    }
+
+    /// Returns the amount
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Adds two amounts of the same currency
+    pub fn checked_add(&self, other: &Money) -> Result<Money, String> {
+        self.ensure_same_currency(other)?;
+        Money::new(self.amount + other.amount, self.currency)
+    }
+
+    /// Subtracts an amount of the same currency
+    ///
+    /// Fails if the result would not be positive.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, String> {
+        self.ensure_same_currency(other)?;
+        Money::new(self.amount - other.amount, self.currency)
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), String> {
+        if self.currency != other.currency {
+            return Err(format!(
+                "Currency mismatch: {} vs {}",
+                self.currency, other.currency
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: i64) -> Money {
+        Money::new(Decimal::from(amount), Currency::Usd).unwrap()
+    }
+
+    #[test]
+    fn money_requires_positive_amount() {
+        assert!(Money::new(Decimal::ONE, Currency::Usd).is_ok());
+        assert!(Money::new(Decimal::ZERO, Currency::Usd).is_err());
+        assert!(Money::new(Decimal::from(-5), Currency::Eur).is_err());
+    }
+
+    #[test]
+    fn money_addition_same_currency() {
+        let sum = usd(100).checked_add(&usd(25)).unwrap();
+
+        assert_eq!(sum.amount(), Decimal::from(125));
+        assert_eq!(sum.currency(), Currency::Usd);
+    }
+
+    #[test]
+    fn money_subtraction_same_currency() {
+        let diff = usd(100).checked_sub(&usd(40)).unwrap();
+        assert_eq!(diff.amount(), Decimal::from(60));
+
+        // Result must stay positive
+        assert!(usd(40).checked_sub(&usd(40)).is_err());
+        assert!(usd(40).checked_sub(&usd(100)).is_err());
+    }
+
+    #[test]
+    fn money_rejects_cross_currency_arithmetic() {
+        let eur = Money::new(Decimal::from(10), Currency::Eur).unwrap();
+
+        let add = usd(100).checked_add(&eur);
+        let sub = usd(100).checked_sub(&eur);
+
+        assert!(add.unwrap_err().contains("Currency mismatch"));
+        assert!(sub.unwrap_err().contains("Currency mismatch"));
+    }
+
+    #[test]
+    fn currency_parsing() {
+        assert_eq!("usd".parse::<Currency>(), Ok(Currency::Usd));
+        assert_eq!("EUR".parse::<Currency>(), Ok(Currency::Eur));
+        assert!("XYZ".parse::<Currency>().is_err());
+        assert_eq!(Currency::Gbp.to_string(), "GBP");
+    }
+}
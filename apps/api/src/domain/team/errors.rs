@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+use super::value_objects::TeamStatus;
+
+/// Errors raised by Team lifecycle transitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TeamError {
+    /// The team is already in the requested status, so the call is a
+    /// no-op; clients retrying a transition can treat this as success
+    #[error("Team is already in {0:?} status")]
+    AlreadyInState(TeamStatus),
+
+    /// The team has finished and can no longer be changed
+    #[error("Team is in a terminal state ({0:?})")]
+    Terminal(TeamStatus),
+
+    /// The status machine does not allow the transition
+    #[error("Cannot {action} team in {from:?} status")]
+    InvalidTransition {
+        action: &'static str,
+        from: TeamStatus,
+    },
+
+    /// The action is switched off for the team's company
+    #[error("Cannot {0} teams: not enabled for this company")]
+    NotEnabled(&'static str),
+}
+
+impl From<TeamError> for String {
+    fn from(error: TeamError) -> Self {
+        error.to_string()
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain::shared::Money;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Domain events that occur within the Team aggregate
@@ -20,7 +22,8 @@ use uuid::Uuid;
 ///     created_by: Uuid::new_v4(),
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 #[allow(dead_code)]
 pub enum TeamEvent {
     /// Fired when a team is created
@@ -52,9 +55,79 @@ pub enum TeamEvent {
         #[allow(dead_code)]
         reason: String,
     },
+    /// Fired when a team's goal is changed
+    GoalUpdated {
+        /// ID of the updated team
+        team_id: Uuid,
+        /// The new objective
+        goal: String,
+    },
+    /// Fired when a team's budget is set, changed, or removed
+    BudgetUpdated {
+        /// ID of the updated team
+        team_id: Uuid,
+        /// The new budget, `None` if removed
+        budget_limit: Option<Money>,
+    },
+    /// Fired when a team's tags are replaced
+    TagsUpdated {
+        /// ID of the updated team
+        team_id: Uuid,
+        /// The normalized tags
+        tags: Vec<String>,
+    },
+    /// Fired when a team is handed to a new owner
+    OwnershipTransferred {
+        /// ID of the transferred team
+        team_id: Uuid,
+        /// User who owned the team before
+        previous_owner: Uuid,
+        /// User who owns the team now
+        new_owner: Uuid,
+    },
+    /// Fired when a completed team is reopened for follow-up work
+    Reopened {
+        /// ID of the reopened team
+        team_id: Uuid,
+        /// User who reopened the team
+        reopened_by: Uuid,
+    },
 }
 
 impl TeamEvent {
+    /// Every value [`TeamEvent::event_type`] can return
+    pub const EVENT_TYPES: [&'static str; 9] = [
+        "created",
+        "started",
+        "completed",
+        "failed",
+        "goal_updated",
+        "budget_updated",
+        "tags_updated",
+        "ownership_transferred",
+        "reopened",
+    ];
+
+    /// Resolves a client-supplied event type to its stored name
+    ///
+    /// Accepts the variant name (`GoalUpdated`) as well as the stored
+    /// name (`goal_updated`), ignoring case. Returns `None` for anything
+    /// that is not a known variant.
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::domain::team::events::TeamEvent;
+    ///
+    /// assert_eq!(TeamEvent::parse_event_type("Failed"), Some("failed"));
+    /// assert_eq!(TeamEvent::parse_event_type("Exploded"), None);
+    /// ```
+    pub fn parse_event_type(name: &str) -> Option<&'static str> {
+        let wanted = name.trim().replace('_', "").to_lowercase();
+        Self::EVENT_TYPES
+            .into_iter()
+            .find(|event_type| event_type.replace('_', "") == wanted)
+    }
+
     /// Returns the team_id for this event
     #[allow(dead_code)]
     pub fn team_id(&self) -> Uuid {
@@ -63,6 +136,26 @@ impl TeamEvent {
             TeamEvent::Started { team_id } => *team_id,
             TeamEvent::Completed { team_id } => *team_id,
             TeamEvent::Failed { team_id, .. } => *team_id,
+            TeamEvent::GoalUpdated { team_id, .. } => *team_id,
+            TeamEvent::BudgetUpdated { team_id, .. } => *team_id,
+            TeamEvent::TagsUpdated { team_id, .. } => *team_id,
+            TeamEvent::OwnershipTransferred { team_id, .. } => *team_id,
+            TeamEvent::Reopened { team_id, .. } => *team_id,
+        }
+    }
+
+    /// Returns the stable type name used when persisting this event
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            TeamEvent::Created { .. } => "created",
+            TeamEvent::Started { .. } => "started",
+            TeamEvent::Completed { .. } => "completed",
+            TeamEvent::Failed { .. } => "failed",
+            TeamEvent::GoalUpdated { .. } => "goal_updated",
+            TeamEvent::BudgetUpdated { .. } => "budget_updated",
+            TeamEvent::TagsUpdated { .. } => "tags_updated",
+            TeamEvent::OwnershipTransferred { .. } => "ownership_transferred",
+            TeamEvent::Reopened { .. } => "reopened",
         }
     }
 }
@@ -122,4 +215,31 @@ mod tests {
 
         assert_eq!(event.team_id(), cloned.team_id());
     }
+
+    #[test]
+    fn event_serializes_with_matching_type_tag() {
+        let event = TeamEvent::Failed {
+            team_id: Uuid::new_v4(),
+            reason: "Out of budget".to_string(),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.event_type());
+        assert_eq!(json["reason"], "Out of budget");
+    }
+
+    #[test]
+    fn parse_event_type_accepts_variant_and_stored_names() {
+        assert_eq!(TeamEvent::parse_event_type("Failed"), Some("failed"));
+        assert_eq!(
+            TeamEvent::parse_event_type("GoalUpdated"),
+            Some("goal_updated")
+        );
+        assert_eq!(
+            TeamEvent::parse_event_type("budget_updated"),
+            Some("budget_updated")
+        );
+        assert_eq!(TeamEvent::parse_event_type("Exploded"), None);
+        assert_eq!(TeamEvent::parse_event_type(""), None);
+    }
 }
@@ -0,0 +1,557 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::team::{check_estimate, Team};
+use super::value_objects::{normalize_tags, TeamStatus};
+use crate::agents::manager::{
+    DEFAULT_MAX_CONCURRENT_TASKS, DEFAULT_MAX_WORKERS, DEFAULT_MIN_WORKERS,
+};
+use crate::agents::WorkerLimits;
+use crate::domain::shared::{Currency, Money};
+
+/// Values of the `member_role` column type
+const MEMBER_ROLES: [&str; 2] = ["manager", "worker"];
+
+/// Values of the `member_status` column type
+const MEMBER_STATUSES: [&str; 4] = ["active", "idle", "busy", "offline"];
+
+/// Values of the `task_status` column type
+const TASK_STATUSES: [&str; 7] = [
+    "pending",
+    "assigned",
+    "in_progress",
+    "review",
+    "completed",
+    "failed",
+    "revision_requested",
+];
+
+/// Portable copy of a team with its workers, tasks, and event log
+///
+/// Produced by `GET /api/teams/:id/export` and accepted by
+/// `POST /api/teams/import` for backups and moving a team between
+/// companies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamExport {
+    pub team: ExportedTeam,
+    /// Absent in exports made before managers were exported
+    #[serde(default)]
+    pub manager: Option<ExportedManager>,
+    pub workers: Vec<ExportedWorker>,
+    pub tasks: Vec<ExportedTask>,
+    pub events: Vec<ExportedEvent>,
+}
+
+/// The team row of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedTeam {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub goal: String,
+    pub status: TeamStatus,
+    pub manager_agent_id: Option<Uuid>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub budget_limit: Option<Decimal>,
+    pub budget_currency: String,
+    pub amount_spent: Decimal,
+    pub tags: Vec<String>,
+    /// Absent in exports made before estimates were recorded
+    pub estimated_hours: Option<f32>,
+}
+
+/// The `managers` row of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedManager {
+    pub id: Uuid,
+    pub model: String,
+    pub temperature: f32,
+    pub max_tokens: i32,
+    /// Absent in exports made before worker limits were stored
+    #[serde(default = "default_min_workers")]
+    pub min_workers: i32,
+    #[serde(default = "default_max_workers")]
+    pub max_workers: i32,
+    /// Absent in exports made before task concurrency was stored
+    #[serde(default = "default_max_concurrent_tasks")]
+    pub max_concurrent_tasks: i32,
+}
+
+fn default_min_workers() -> i32 {
+    DEFAULT_MIN_WORKERS as i32
+}
+
+fn default_max_workers() -> i32 {
+    DEFAULT_MAX_WORKERS as i32
+}
+
+fn default_max_concurrent_tasks() -> i32 {
+    DEFAULT_MAX_CONCURRENT_TASKS as i32
+}
+
+/// A `team_members` row of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedWorker {
+    pub id: Uuid,
+    pub agent_id: Uuid,
+    pub role: String,
+    pub specialization: Option<String>,
+    pub status: String,
+    pub current_workload: i32,
+    pub max_concurrent_tasks: i32,
+    pub joined_at: DateTime<Utc>,
+    /// Absent in exports made before skills were persisted
+    #[serde(default)]
+    pub skills: Vec<String>,
+}
+
+/// A `tasks` row of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedTask {
+    pub id: Uuid,
+    pub parent_task_id: Option<Uuid>,
+    pub title: String,
+    pub description: String,
+    pub acceptance_criteria: serde_json::Value,
+    pub assigned_to: Option<Uuid>,
+    pub assigned_by: Option<Uuid>,
+    pub status: String,
+    pub start_time: Option<DateTime<Utc>>,
+    pub completion_time: Option<DateTime<Utc>>,
+    pub revision_count: i32,
+    pub max_revisions: i32,
+    pub input_data: Option<serde_json::Value>,
+    pub output_data: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A `team_events` entry of an export, in log order
+///
+/// Sequence numbers are not exported; imported events are appended to
+/// the log in the order given.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedEvent {
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TeamExport {
+    /// Rebuilds the team aggregate for `company_id`, checking invariants
+    ///
+    /// The goal must not be blank, the budget must be positive in a known
+    /// currency, spend must not be negative, tags must be valid, any
+    /// estimate must be positive, and the team cannot have completed before
+    /// it started. Every task's parent
+    /// and assignees must be part of the export, IDs must not repeat, and
+    /// roles and statuses must be ones the database accepts. A
+    /// `manager_agent_id` without a matching exported manager is dropped,
+    /// since the manager it named is not imported.
+    ///
+    /// # Returns
+    /// * `Ok(Team)` - The team as it will be stored, owned by `created_by`
+    /// * `Err(String)` - If any invariant is violated
+    pub fn validated_team(&self, company_id: Uuid, created_by: Uuid) -> Result<Team, String> {
+        let team = &self.team;
+        let goal = team.goal.trim();
+        if goal.is_empty() {
+            return Err("Goal cannot be empty".to_string());
+        }
+        let budget_limit = team
+            .budget_limit
+            .map(|amount| Money::new(amount, team.budget_currency.parse::<Currency>()?))
+            .transpose()?;
+        if team.amount_spent.is_sign_negative() {
+            return Err(format!(
+                "Spend cannot be negative, got {}",
+                team.amount_spent
+            ));
+        }
+        if let Some(hours) = team.estimated_hours {
+            check_estimate(hours)?;
+        }
+        self.check_rows()?;
+        self.check_references()?;
+        let manager_agent_id = team
+            .manager_agent_id
+            .filter(|id| self.manager.as_ref().is_some_and(|m| m.id == *id));
+
+        Team::try_from_persistence(
+            team.id,
+            company_id,
+            goal.to_string(),
+            team.status,
+            manager_agent_id,
+            created_by,
+            team.created_at,
+            team.started_at,
+            team.completed_at,
+            budget_limit,
+            team.amount_spent,
+            normalize_tags(team.tags.clone())?,
+            team.estimated_hours,
+        )
+    }
+
+    /// IDs of every row the import inserts under its own primary key
+    pub fn ids(&self) -> Vec<Uuid> {
+        std::iter::once(self.team.id)
+            .chain(self.manager.iter().map(|m| m.id))
+            .chain(self.workers.iter().map(|w| w.id))
+            .chain(self.tasks.iter().map(|t| t.id))
+            .collect()
+    }
+
+    /// Checks that IDs are unique and column values are storable
+    fn check_rows(&self) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        if let Some(id) = self.ids().into_iter().find(|id| !seen.insert(*id)) {
+            return Err(format!("Duplicate ID {}", id));
+        }
+        let mut agents = HashSet::new();
+        if let Some(worker) = self.workers.iter().find(|w| !agents.insert(w.agent_id)) {
+            return Err(format!("Duplicate agent ID {}", worker.agent_id));
+        }
+
+        if let Some(manager) = &self.manager {
+            if manager.max_tokens <= 0 {
+                return Err(format!(
+                    "Manager max_tokens must be positive, got {}",
+                    manager.max_tokens
+                ));
+            }
+            WorkerLimits::new(
+                usize::try_from(manager.min_workers).unwrap_or(0),
+                usize::try_from(manager.max_workers).unwrap_or(0),
+            )
+            .map_err(|e| format!("Manager worker limits are invalid: {}", e))?;
+            if manager.max_concurrent_tasks < 1 {
+                return Err(format!(
+                    "Manager max_concurrent_tasks must be positive, got {}",
+                    manager.max_concurrent_tasks
+                ));
+            }
+        }
+        for worker in &self.workers {
+            if !MEMBER_ROLES.contains(&worker.role.as_str()) {
+                return Err(format!(
+                    "Worker {} has unknown role {}",
+                    worker.id, worker.role
+                ));
+            }
+            if !MEMBER_STATUSES.contains(&worker.status.as_str()) {
+                return Err(format!(
+                    "Worker {} has unknown status {}",
+                    worker.id, worker.status
+                ));
+            }
+            if worker.current_workload < 0 || worker.current_workload > worker.max_concurrent_tasks
+            {
+                return Err(format!(
+                    "Worker {} workload {} is outside 0..={}",
+                    worker.id, worker.current_workload, worker.max_concurrent_tasks
+                ));
+            }
+        }
+        for task in &self.tasks {
+            if !TASK_STATUSES.contains(&task.status.as_str()) {
+                return Err(format!(
+                    "Task {} has unknown status {}",
+                    task.id, task.status
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that tasks only reference workers and tasks in the export
+    fn check_references(&self) -> Result<(), String> {
+        let workers: HashSet<Uuid> = self.workers.iter().map(|w| w.id).collect();
+        let tasks: HashSet<Uuid> = self.tasks.iter().map(|t| t.id).collect();
+
+        for task in &self.tasks {
+            if let Some(parent) = task.parent_task_id.filter(|id| !tasks.contains(id)) {
+                return Err(format!("Task {} has unknown parent {}", task.id, parent));
+            }
+            for worker in [task.assigned_to, task.assigned_by].into_iter().flatten() {
+                if !workers.contains(&worker) {
+                    return Err(format!(
+                        "Task {} references unknown worker {}",
+                        task.id, worker
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a copy with fresh IDs for the team, workers, and tasks
+    ///
+    /// References between them (task parents and assignees, worker agent
+    /// IDs, the manager and the team's `manager_agent_id`, and `team_id`
+    /// in event payloads) are
+    /// rewritten to match, so the copy can be imported next to the
+    /// original.
+    pub fn with_new_ids(&self) -> Self {
+        let mut ids: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut remap = |id: Uuid| *ids.entry(id).or_insert_with(Uuid::new_v4);
+
+        let team_id = remap(self.team.id);
+        let team = ExportedTeam {
+            id: team_id,
+            manager_agent_id: self.team.manager_agent_id.map(&mut remap),
+            ..self.team.clone()
+        };
+        let manager = self.manager.as_ref().map(|manager| ExportedManager {
+            id: remap(manager.id),
+            ..manager.clone()
+        });
+        let workers = self
+            .workers
+            .iter()
+            .map(|worker| ExportedWorker {
+                id: remap(worker.id),
+                agent_id: remap(worker.agent_id),
+                ..worker.clone()
+            })
+            .collect();
+        let tasks = self
+            .tasks
+            .iter()
+            .map(|task| ExportedTask {
+                id: remap(task.id),
+                parent_task_id: task.parent_task_id.map(&mut remap),
+                assigned_to: task.assigned_to.map(&mut remap),
+                assigned_by: task.assigned_by.map(&mut remap),
+                ..task.clone()
+            })
+            .collect();
+        let events = self
+            .events
+            .iter()
+            .map(|event| {
+                let mut payload = event.payload.clone();
+                if let Some(object) = payload.as_object_mut() {
+                    if object.contains_key("team_id") {
+                        object.insert("team_id".to_string(), team_id.to_string().into());
+                    }
+                }
+                ExportedEvent {
+                    payload,
+                    ..event.clone()
+                }
+            })
+            .collect();
+
+        Self {
+            team,
+            manager,
+            workers,
+            tasks,
+            events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn export() -> TeamExport {
+        let team_id = Uuid::new_v4();
+        let manager_id = Uuid::new_v4();
+        let worker_id = Uuid::new_v4();
+        let parent_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let task = |id: Uuid, parent_task_id: Option<Uuid>| ExportedTask {
+            id,
+            parent_task_id,
+            title: "Task".to_string(),
+            description: "Do it".to_string(),
+            acceptance_criteria: json!([]),
+            assigned_to: Some(worker_id),
+            assigned_by: None,
+            status: "assigned".to_string(),
+            start_time: None,
+            completion_time: None,
+            revision_count: 0,
+            max_revisions: 3,
+            input_data: None,
+            output_data: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        TeamExport {
+            team: ExportedTeam {
+                id: team_id,
+                company_id: Uuid::new_v4(),
+                goal: "Ship it".to_string(),
+                status: TeamStatus::Active,
+                manager_agent_id: Some(manager_id),
+                created_by: Uuid::new_v4(),
+                created_at: now,
+                started_at: Some(now),
+                completed_at: None,
+                budget_limit: Some(Decimal::new(100, 0)),
+                budget_currency: "USD".to_string(),
+                amount_spent: Decimal::new(5, 0),
+                tags: vec!["q3".to_string()],
+                estimated_hours: Some(6.0),
+            },
+            manager: Some(ExportedManager {
+                id: manager_id,
+                model: "claude-3-5-sonnet-20241022".to_string(),
+                temperature: 0.7,
+                max_tokens: 4096,
+                min_workers: 2,
+                max_workers: 4,
+                max_concurrent_tasks: 2,
+            }),
+            workers: vec![ExportedWorker {
+                id: worker_id,
+                agent_id: worker_id,
+                role: "worker".to_string(),
+                specialization: Some("Coder".to_string()),
+                status: "busy".to_string(),
+                current_workload: 1,
+                max_concurrent_tasks: 3,
+                joined_at: now,
+                skills: vec!["Rust".to_string()],
+            }],
+            tasks: vec![task(parent_id, None), task(Uuid::new_v4(), Some(parent_id))],
+            events: vec![ExportedEvent {
+                event_type: "started".to_string(),
+                payload: json!({ "type": "started", "team_id": team_id }),
+                occurred_at: now,
+            }],
+        }
+    }
+
+    #[test]
+    fn valid_export_rebuilds_team_for_new_owner() {
+        let export = export();
+        let company_id = Uuid::new_v4();
+        let created_by = Uuid::new_v4();
+
+        let team = export.validated_team(company_id, created_by).unwrap();
+
+        assert_eq!(team.id(), export.team.id);
+        assert_eq!(team.company_id(), company_id);
+        assert_eq!(team.created_by(), created_by);
+        assert_eq!(team.budget_limit().unwrap().amount(), Decimal::new(100, 0));
+        assert_eq!(team.estimated_hours(), Some(6.0));
+        assert_eq!(team.manager_agent_id(), export.team.manager_agent_id);
+    }
+
+    #[test]
+    fn manager_id_without_exported_manager_is_dropped() {
+        let mut export = export();
+        export.manager = None;
+
+        let team = export
+            .validated_team(Uuid::new_v4(), Uuid::new_v4())
+            .unwrap();
+
+        assert_eq!(team.manager_agent_id(), None);
+    }
+
+    #[test]
+    fn unstorable_rows_are_rejected() {
+        let mut bad_worker_status = export();
+        bad_worker_status.workers[0].status = "sleeping".to_string();
+        let mut bad_role = export();
+        bad_role.workers[0].role = "owner".to_string();
+        let mut bad_task_status = export();
+        bad_task_status.tasks[0].status = "done".to_string();
+        let mut duplicate_id = export();
+        duplicate_id.tasks[1].id = duplicate_id.workers[0].id;
+        let mut bad_manager = export();
+        bad_manager.manager.as_mut().unwrap().max_tokens = 0;
+        let mut bad_worker_limits = export();
+        bad_worker_limits.manager.as_mut().unwrap().min_workers = 5;
+        let mut bad_concurrency = export();
+        bad_concurrency
+            .manager
+            .as_mut()
+            .unwrap()
+            .max_concurrent_tasks = 0;
+
+        for export in [
+            bad_worker_status,
+            bad_role,
+            bad_task_status,
+            duplicate_id,
+            bad_manager,
+            bad_worker_limits,
+            bad_concurrency,
+        ] {
+            assert!(export
+                .validated_team(Uuid::new_v4(), Uuid::new_v4())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_team_is_rejected() {
+        let mut blank_goal = export();
+        blank_goal.team.goal = "  ".to_string();
+        let mut bad_budget = export();
+        bad_budget.team.budget_limit = Some(Decimal::ZERO);
+        let mut bad_timestamps = export();
+        bad_timestamps.team.completed_at = Some(bad_timestamps.team.created_at);
+        bad_timestamps.team.started_at =
+            Some(bad_timestamps.team.created_at + chrono::Duration::hours(1));
+        let mut bad_estimate = export();
+        bad_estimate.team.estimated_hours = Some(0.0);
+
+        for export in [blank_goal, bad_budget, bad_timestamps, bad_estimate] {
+            assert!(export
+                .validated_team(Uuid::new_v4(), Uuid::new_v4())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn dangling_task_references_are_rejected() {
+        let mut export = export();
+        export.tasks[0].assigned_by = Some(Uuid::new_v4());
+
+        let error = export
+            .validated_team(Uuid::new_v4(), Uuid::new_v4())
+            .unwrap_err();
+
+        assert!(error.contains("references unknown worker"));
+    }
+
+    #[test]
+    fn new_ids_keep_references_consistent() {
+        let original = export();
+
+        let copy = original.with_new_ids();
+
+        assert_ne!(copy.team.id, original.team.id);
+        assert_ne!(copy.workers[0].id, original.workers[0].id);
+        assert_eq!(copy.workers[0].agent_id, copy.workers[0].id);
+        assert_ne!(copy.tasks[0].id, original.tasks[0].id);
+        assert_eq!(copy.tasks[1].parent_task_id, Some(copy.tasks[0].id));
+        assert_eq!(copy.tasks[0].assigned_to, Some(copy.workers[0].id));
+        assert_eq!(copy.events[0].payload["team_id"], copy.team.id.to_string());
+        let manager = copy.manager.as_ref().unwrap();
+        assert_ne!(manager.id, original.manager.as_ref().unwrap().id);
+        assert_eq!(copy.team.manager_agent_id, Some(manager.id));
+        assert_eq!(copy.workers[0].skills, original.workers[0].skills);
+        assert!(copy.validated_team(Uuid::new_v4(), Uuid::new_v4()).is_ok());
+    }
+}
@@ -3,9 +3,15 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod errors;
 pub mod events;
+pub mod export;
+pub mod snapshot;
 pub mod team;
 pub mod value_objects;
 
 // Re-export main types for convenience
+pub use errors::TeamError;
+pub use export::TeamExport;
+pub use snapshot::TeamSnapshot;
 pub use team::Team;
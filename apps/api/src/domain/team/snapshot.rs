@@ -0,0 +1,33 @@
+use super::team::projected_completion;
+use super::value_objects::TeamStatus;
+use crate::domain::shared::Money;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Read-only view of a team
+///
+/// Carries just the fields needed to render a team, loaded directly from
+/// storage without reconstructing the `Team` aggregate. Use the aggregate
+/// for anything that mutates state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamSnapshot {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub goal: String,
+    pub status: TeamStatus,
+    pub created_by: Uuid,
+    pub budget_limit: Option<Money>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub estimated_hours: Option<f32>,
+}
+
+impl TeamSnapshot {
+    /// Projects when the team will finish, as [`Team::estimated_completion`]
+    ///
+    /// [`Team::estimated_completion`]: super::Team::estimated_completion
+    pub fn estimated_completion(&self) -> Option<DateTime<Utc>> {
+        projected_completion(self.started_at, self.estimated_hours)
+    }
+}
@@ -1,5 +1,7 @@
+use super::errors::TeamError;
 use super::events::TeamEvent;
-use super::value_objects::TeamStatus;
+use super::value_objects::{normalize_tags, TeamStatus};
+use crate::domain::shared::{Clock, DomainError, Money, SystemClock};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
@@ -11,12 +13,15 @@ use uuid::Uuid;
 ///
 /// # Invariants
 /// - Goal cannot be empty
-/// - Budget must be positive (if specified)
+/// - Budget must be positive (if specified, guaranteed by `Money`)
+/// - Amount spent is never negative
+/// - Estimated hours are positive (if recorded)
 /// - Status transitions must follow defined rules
 /// - Timestamps maintain chronological order
 ///
 /// # Example
 /// ```
+/// use ghostpirates_api::domain::shared::SystemClock;
 /// use ghostpirates_api::domain::team::Team;
 /// use uuid::Uuid;
 ///
@@ -25,6 +30,7 @@ use uuid::Uuid;
 ///     "Complete mission".to_string(),
 ///     Uuid::new_v4(),
 ///     None,
+///     &SystemClock,
 /// ).expect("valid team");
 ///
 /// assert_eq!(team.goal(), "Complete mission");
@@ -43,7 +49,10 @@ pub struct Team {
     created_at: DateTime<Utc>,
     started_at: Option<DateTime<Utc>>,
     completed_at: Option<DateTime<Utc>>,
-    budget_limit: Option<Decimal>,
+    budget_limit: Option<Money>,
+    amount_spent: Decimal,
+    tags: Vec<String>,
+    estimated_hours: Option<f32>,
 }
 
 #[allow(dead_code)]
@@ -54,45 +63,63 @@ impl Team {
     /// * `company_id` - The company this team belongs to
     /// * `goal` - The team's objective (cannot be empty)
     /// * `created_by` - ID of the user creating the team
-    /// * `budget_limit` - Optional budget limit (must be positive if specified)
+    /// * `budget_limit` - Optional budget limit
+    /// * `clock` - Source of the creation timestamp
     ///
     /// # Returns
     /// * `Ok((Team, Vec<TeamEvent>))` - New team and events generated
-    /// * `Err(String)` - If any invariant is violated
+    /// * `Err(DomainError::Validation)` - If any invariant is violated
     ///
     /// # Business Rules Enforced
-    /// - Goal must not be empty
-    /// - Budget must be positive (if provided)
+    /// - Goal is trimmed and must not be empty afterwards
     /// - Initial status is always Pending
     /// - Team generates a Created event
     pub fn new(
         company_id: Uuid,
         goal: String,
         created_by: Uuid,
-        budget_limit: Option<Decimal>,
-    ) -> Result<(Self, Vec<TeamEvent>), String> {
-        // Validate business rules
-        if goal.is_empty() {
-            return Err("Goal cannot be empty".to_string());
-        }
+        budget_limit: Option<Money>,
+        clock: &dyn Clock,
+    ) -> Result<(Self, Vec<TeamEvent>), DomainError> {
+        Self::new_with_id(
+            Uuid::new_v4(),
+            company_id,
+            goal,
+            created_by,
+            budget_limit,
+            clock,
+        )
+    }
 
-        if let Some(budget) = budget_limit {
-            if budget <= Decimal::ZERO {
-                return Err("Budget must be positive".to_string());
-            }
-        }
+    /// Creates a new Team aggregate with a caller-chosen ID
+    ///
+    /// Same rules as [`Team::new`]; the ID is not checked for uniqueness,
+    /// which is left to persistence.
+    pub fn new_with_id(
+        id: Uuid,
+        company_id: Uuid,
+        goal: String,
+        created_by: Uuid,
+        budget_limit: Option<Money>,
+        clock: &dyn Clock,
+    ) -> Result<(Self, Vec<TeamEvent>), DomainError> {
+        // Validate business rules
+        let goal = normalize_goal(goal).map_err(DomainError::Validation)?;
 
         let team = Self {
-            id: Uuid::new_v4(),
+            id,
             company_id,
             goal,
             status: TeamStatus::Pending,
             manager_agent_id: None,
             created_by,
-            created_at: Utc::now(),
+            created_at: clock.now(),
             started_at: None,
             completed_at: None,
             budget_limit,
+            amount_spent: Decimal::ZERO,
+            tags: Vec::new(),
+            estimated_hours: None,
         };
 
         let events = vec![TeamEvent::Created {
@@ -105,23 +132,35 @@ impl Team {
         Ok((team, events))
     }
 
+    /// Creates a new Team aggregate stamped with the current time
+    ///
+    /// Convenience for [`Team::new`] with [`SystemClock`]; tests that need
+    /// a known creation time pass their own clock to `new`.
+    pub fn new_now(
+        company_id: Uuid,
+        goal: String,
+        created_by: Uuid,
+        budget_limit: Option<Money>,
+    ) -> Result<(Self, Vec<TeamEvent>), DomainError> {
+        Self::new(company_id, goal, created_by, budget_limit, &SystemClock)
+    }
+
     /// Starts the team (transitions from Planning to Active)
     ///
     /// # Returns
     /// * `Ok(TeamEvent)` - Started event generated
-    /// * `Err(String)` - If team cannot transition from current status
+    /// * `Err(TeamError::AlreadyInState)` - If the team is already active
+    /// * `Err(TeamError::InvalidTransition)` - If team cannot transition from current status
     ///
     /// # Business Rules
     /// - Team must be in Planning status
     /// - Records the start timestamp
-    pub fn start(&mut self) -> Result<TeamEvent, String> {
+    pub fn start(&mut self, clock: &dyn Clock) -> Result<TeamEvent, TeamError> {
         let next_status = TeamStatus::Active;
-        if !self.status.can_transition_to(next_status) {
-            return Err(format!("Cannot start team in {:?} status", self.status));
-        }
+        self.check_transition("start", next_status)?;
 
         self.status = next_status;
-        self.started_at = Some(Utc::now());
+        self.started_at = Some(clock.now());
 
         Ok(TeamEvent::Started { team_id: self.id })
     }
@@ -130,16 +169,15 @@ impl Team {
     ///
     /// # Returns
     /// * `Ok(TeamEvent)` - Completed event generated
-    /// * `Err(String)` - If team cannot be completed from current status
+    /// * `Err(TeamError::AlreadyInState)` - If the team is already completed
+    /// * `Err(TeamError::InvalidTransition)` - If team cannot be completed from current status
     #[allow(dead_code)]
-    pub fn complete(&mut self) -> Result<TeamEvent, String> {
+    pub fn complete(&mut self, clock: &dyn Clock) -> Result<TeamEvent, TeamError> {
         let next_status = TeamStatus::Completed;
-        if !self.status.can_transition_to(next_status) {
-            return Err(format!("Cannot complete team in {:?} status", self.status));
-        }
+        self.check_transition("complete", next_status)?;
 
         self.status = next_status;
-        self.completed_at = Some(Utc::now());
+        self.completed_at = Some(clock.now());
 
         Ok(TeamEvent::Completed { team_id: self.id })
     }
@@ -148,19 +186,18 @@ impl Team {
     ///
     /// # Arguments
     /// * `reason` - Reason for failure
+    /// * `clock` - Source of the completion timestamp
     ///
     /// # Returns
     /// * `Ok(TeamEvent)` - Failed event generated
-    /// * `Err(String)` - If team cannot be marked failed from current status
-    #[allow(dead_code)]
-    pub fn fail(&mut self, reason: String) -> Result<TeamEvent, String> {
+    /// * `Err(TeamError::AlreadyInState)` - If the team has already failed
+    /// * `Err(TeamError::InvalidTransition)` - If team cannot be marked failed from current status
+    pub fn fail(&mut self, reason: String, clock: &dyn Clock) -> Result<TeamEvent, TeamError> {
         let next_status = TeamStatus::Failed;
-        if !self.status.can_transition_to(next_status) {
-            return Err(format!("Cannot fail team in {:?} status", self.status));
-        }
+        self.check_transition("fail", next_status)?;
 
         self.status = next_status;
-        self.completed_at = Some(Utc::now());
+        self.completed_at = Some(clock.now());
 
         Ok(TeamEvent::Failed {
             team_id: self.id,
@@ -168,6 +205,246 @@ impl Team {
         })
     }
 
+    /// Checks that `action` may move the team to `next`
+    ///
+    /// Repeating a transition the team has already made is reported as
+    /// `AlreadyInState` rather than as an invalid transition.
+    fn check_transition(&self, action: &'static str, next: TeamStatus) -> Result<(), TeamError> {
+        if self.status == next {
+            return Err(TeamError::AlreadyInState(next));
+        }
+        if !self.status.can_transition_to(next) {
+            return Err(TeamError::InvalidTransition {
+                action,
+                from: self.status,
+            });
+        }
+        Ok(())
+    }
+
+    /// Rejects mutations of a team that has finished
+    fn ensure_not_terminal(&self) -> Result<(), TeamError> {
+        if self.is_terminal() {
+            return Err(TeamError::Terminal(self.status));
+        }
+        Ok(())
+    }
+
+    /// Changes the team's goal
+    ///
+    /// # Returns
+    /// * `Ok(TeamEvent)` - GoalUpdated event generated
+    /// * `Err(String)` - If the goal is empty or the team has left planning
+    ///
+    /// # Business Rules
+    /// - Goal is trimmed and must not be empty afterwards
+    /// - Only Pending or Planning teams may change their goal
+    pub fn update_goal(&mut self, goal: String) -> Result<TeamEvent, String> {
+        self.ensure_not_terminal()?;
+        let goal = normalize_goal(goal)?;
+        if !matches!(self.status, TeamStatus::Pending | TeamStatus::Planning) {
+            return Err(format!(
+                "Cannot change goal of team in {:?} status",
+                self.status
+            ));
+        }
+
+        self.goal = goal.clone();
+
+        Ok(TeamEvent::GoalUpdated {
+            team_id: self.id,
+            goal,
+        })
+    }
+
+    /// Sets, changes, or removes the team's budget
+    ///
+    /// # Returns
+    /// * `Ok(TeamEvent)` - BudgetUpdated event generated
+    /// * `Err(String)` - If the team has already finished or the budget is
+    ///   below what has been spent
+    ///
+    /// # Business Rules
+    /// - Completed, Failed, and Archived teams cannot change their budget
+    /// - A budget cannot be lowered below the amount already spent;
+    ///   lowering it to exactly the spend is allowed
+    pub fn update_budget(&mut self, budget_limit: Option<Money>) -> Result<TeamEvent, String> {
+        self.ensure_not_terminal()?;
+        if let Some(budget) = budget_limit.filter(|b| b.amount() < self.amount_spent) {
+            return Err(format!(
+                "Budget {} is below the {} already spent",
+                budget.amount(),
+                self.amount_spent
+            ));
+        }
+
+        self.budget_limit = budget_limit;
+
+        Ok(TeamEvent::BudgetUpdated {
+            team_id: self.id,
+            budget_limit,
+        })
+    }
+
+    /// Replaces the team's tags
+    ///
+    /// # Returns
+    /// * `Ok(TeamEvent)` - TagsUpdated event with the normalized tags
+    /// * `Err(String)` - If the tags are invalid or the team has finished
+    ///
+    /// # Business Rules
+    /// - Tags are trimmed, lowercased, and deduplicated
+    /// - At most 10 tags, each at most 50 characters
+    /// - Completed, Failed, and Archived teams cannot change their tags
+    pub fn set_tags(&mut self, tags: Vec<String>) -> Result<TeamEvent, String> {
+        self.ensure_not_terminal()?;
+        self.tags = normalize_tags(tags)?;
+
+        Ok(TeamEvent::TagsUpdated {
+            team_id: self.id,
+            tags: self.tags.clone(),
+        })
+    }
+
+    /// Hands the team to another user of the same company
+    ///
+    /// The caller is responsible for checking that `new_owner` belongs to
+    /// the team's company; the aggregate only knows user IDs.
+    ///
+    /// # Returns
+    /// * `Ok(TeamEvent)` - OwnershipTransferred event generated
+    /// * `Err(String)` - If `new_owner` is nil or already owns the team
+    ///
+    /// # Business Rules
+    /// - Finished teams can still change owner, so a departing creator's
+    ///   history stays attached to someone who is still around
+    pub fn transfer_ownership(&mut self, new_owner: Uuid) -> Result<TeamEvent, String> {
+        if new_owner.is_nil() {
+            return Err("New owner must not be nil".to_string());
+        }
+        if new_owner == self.created_by {
+            return Err(format!("User {} already owns this team", new_owner));
+        }
+
+        let previous_owner = std::mem::replace(&mut self.created_by, new_owner);
+
+        Ok(TeamEvent::OwnershipTransferred {
+            team_id: self.id,
+            previous_owner,
+            new_owner,
+        })
+    }
+
+    /// Reopens a completed team for follow-up work
+    ///
+    /// Completed -> Active is not a regular lifecycle transition, so it is
+    /// only reachable through this method, and only for companies that
+    /// opted in.
+    ///
+    /// # Arguments
+    /// * `actor` - User reopening the team
+    /// * `enabled` - Whether the company's
+    ///   `feature_flags::REOPEN_COMPLETED_TEAMS` flag is on
+    ///
+    /// # Returns
+    /// * `Ok(TeamEvent)` - Reopened event generated
+    /// * `Err(String)` - If reopening is not enabled, `actor` is nil, or the
+    ///   team is not Completed
+    ///
+    /// # Business Rules
+    /// - Only Completed teams can be reopened; Archived and Failed teams
+    ///   stay closed
+    /// - Clears `completed_at` and keeps the original `started_at`
+    pub fn reopen(&mut self, actor: Uuid, enabled: bool) -> Result<TeamEvent, String> {
+        if !enabled {
+            return Err(TeamError::NotEnabled("reopen").into());
+        }
+        if actor.is_nil() {
+            return Err("Reopening user must not be nil".to_string());
+        }
+        match self.status {
+            TeamStatus::Completed => {}
+            TeamStatus::Active => return Err(TeamError::AlreadyInState(self.status).into()),
+            TeamStatus::Archived => return Err(TeamError::Terminal(self.status).into()),
+            from => {
+                return Err(TeamError::InvalidTransition {
+                    action: "reopen",
+                    from,
+                }
+                .into())
+            }
+        }
+
+        self.status = TeamStatus::Active;
+        self.completed_at = None;
+
+        Ok(TeamEvent::Reopened {
+            team_id: self.id,
+            reopened_by: actor,
+        })
+    }
+
+    /// Records spend against the team's budget
+    ///
+    /// # Arguments
+    /// * `amount` - Amount spent, in the budget's currency
+    ///
+    /// # Returns
+    /// * `Ok(Some(TeamEvent))` - Spend pushed an active team over budget and failed it
+    /// * `Ok(None)` - Spend recorded, no status change
+    /// * `Err(String)` - If the amount is negative
+    pub fn record_spend(
+        &mut self,
+        amount: Decimal,
+        clock: &dyn Clock,
+    ) -> Result<Option<TeamEvent>, String> {
+        if amount.is_sign_negative() {
+            return Err(format!("Spend cannot be negative, got {}", amount));
+        }
+
+        self.amount_spent += amount;
+
+        Ok(self.check_budget(clock))
+    }
+
+    /// Records how long goal analysis estimated the team's work will take
+    ///
+    /// # Arguments
+    /// * `hours` - `GoalAnalysis::estimated_timeline_hours` for the goal
+    ///
+    /// # Returns
+    /// * `Ok(())` - Estimate recorded, replacing any earlier one
+    /// * `Err(String)` - If the estimate is not positive, exceeds
+    ///   [`MAX_ESTIMATED_HOURS`], or the team has finished
+    pub fn record_estimate(&mut self, hours: f32) -> Result<(), String> {
+        self.ensure_not_terminal()?;
+        check_estimate(hours)?;
+
+        self.estimated_hours = Some(hours);
+        Ok(())
+    }
+
+    /// Fails an active team whose spend exceeds its budget
+    ///
+    /// # Returns
+    /// * `Some(TeamEvent)` - Failed event with reason "budget exceeded"
+    /// * `None` - Team is within budget, has no budget, or is not active
+    ///
+    /// # Business Rules
+    /// - Spending exactly the budget is allowed
+    /// - Only Active teams are auto-failed
+    pub fn check_budget(&mut self, clock: &dyn Clock) -> Option<TeamEvent> {
+        let over_budget = self
+            .budget_limit
+            .is_some_and(|budget| self.amount_spent > budget.amount());
+
+        if over_budget && self.status == TeamStatus::Active {
+            self.fail("budget exceeded".to_string(), clock).ok()
+        } else {
+            None
+        }
+    }
+
     // ===== Getters =====
 
     /// Returns the team's ID
@@ -185,6 +462,11 @@ impl Team {
         &self.goal
     }
 
+    /// Whether the team has finished and can no longer be changed
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+
     /// Returns the team's current status
     pub fn status(&self) -> TeamStatus {
         self.status
@@ -219,10 +501,32 @@ impl Team {
 
     /// Returns the budget limit if one was set
     #[allow(dead_code)]
-    pub fn budget_limit(&self) -> Option<Decimal> {
+    pub fn budget_limit(&self) -> Option<Money> {
         self.budget_limit
     }
 
+    /// Returns the total amount spent, in the budget's currency
+    pub fn amount_spent(&self) -> Decimal {
+        self.amount_spent
+    }
+
+    /// Returns the team's tags
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns the estimated duration in hours, if goal analysis recorded one
+    pub fn estimated_hours(&self) -> Option<f32> {
+        self.estimated_hours
+    }
+
+    /// Projects when the team will finish: its start time plus the estimate
+    ///
+    /// Returns `None` until the team has started and has an estimate.
+    pub fn estimated_completion(&self) -> Option<DateTime<Utc>> {
+        projected_completion(self.started_at, self.estimated_hours)
+    }
+
     /// Reconstructs a Team from persistence layer data
     ///
     /// This method bypasses business rules validation since the data
@@ -241,7 +545,10 @@ impl Team {
         created_at: DateTime<Utc>,
         started_at: Option<DateTime<Utc>>,
         completed_at: Option<DateTime<Utc>>,
-        budget_limit: Option<Decimal>,
+        budget_limit: Option<Money>,
+        amount_spent: Decimal,
+        tags: Vec<String>,
+        estimated_hours: Option<f32>,
     ) -> Self {
         Self {
             id,
@@ -254,20 +561,167 @@ impl Team {
             started_at,
             completed_at,
             budget_limit,
+            amount_spent,
+            tags,
+            estimated_hours,
+        }
+    }
+
+    /// Reconstructs a Team from persistence layer data, rejecting
+    /// inconsistent timestamps
+    ///
+    /// Same as [`Team::from_persistence`], but fails when `completed_at`
+    /// precedes `started_at` so a data bug cannot yield negative durations.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_persistence(
+        id: Uuid,
+        company_id: Uuid,
+        goal: String,
+        status: TeamStatus,
+        manager_agent_id: Option<Uuid>,
+        created_by: Uuid,
+        created_at: DateTime<Utc>,
+        started_at: Option<DateTime<Utc>>,
+        completed_at: Option<DateTime<Utc>>,
+        budget_limit: Option<Money>,
+        amount_spent: Decimal,
+        tags: Vec<String>,
+        estimated_hours: Option<f32>,
+    ) -> Result<Self, String> {
+        let team = Self::from_persistence(
+            id,
+            company_id,
+            goal,
+            status,
+            manager_agent_id,
+            created_by,
+            created_at,
+            started_at,
+            completed_at,
+            budget_limit,
+            amount_spent,
+            tags,
+            estimated_hours,
+        );
+        team.check_timestamps()?;
+        Ok(team)
+    }
+
+    /// Verifies the team was not completed before it started
+    pub fn check_timestamps(&self) -> Result<(), String> {
+        match (self.started_at, self.completed_at) {
+            (Some(started_at), Some(completed_at)) if completed_at < started_at => Err(format!(
+                "Team {} completed at {} before it started at {}",
+                self.id, completed_at, started_at
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Re-checks every invariant of the aggregate
+    ///
+    /// Constructors and transitions already uphold these, so this is a
+    /// self-check for teams rebuilt from elsewhere, e.g. by
+    /// [`Team::from_persistence`] or deserialized budgets. Checks that:
+    /// - the goal is not blank
+    /// - the budget, if any, is positive and the amount spent not negative
+    /// - estimated hours, if recorded, are positive
+    /// - the team did not start before it was created or complete before
+    ///   it started
+    /// - `completed_at` is set exactly when the status is terminal
+    pub fn validate(&self) -> Result<(), String> {
+        if self.goal.trim().is_empty() {
+            return Err(format!("Team {} has an empty goal", self.id));
+        }
+        if let Some(budget) = self.budget_limit {
+            if budget.amount() <= Decimal::ZERO {
+                return Err(format!(
+                    "Team {} has a non-positive budget of {}",
+                    self.id, budget
+                ));
+            }
+        }
+        if self.amount_spent < Decimal::ZERO {
+            return Err(format!(
+                "Team {} has a negative amount spent of {}",
+                self.id, self.amount_spent
+            ));
         }
+        if let Some(hours) = self.estimated_hours {
+            check_estimate(hours)?;
+        }
+
+        if let Some(started_at) = self.started_at {
+            if started_at < self.created_at {
+                return Err(format!(
+                    "Team {} started at {} before it was created at {}",
+                    self.id, started_at, self.created_at
+                ));
+            }
+        }
+        self.check_timestamps()?;
+
+        match (self.status.is_terminal(), self.completed_at) {
+            (true, None) => Err(format!(
+                "Team {} is {} but has no completion time",
+                self.id, self.status
+            )),
+            (false, Some(_)) => Err(format!(
+                "Team {} is {} but has a completion time",
+                self.id, self.status
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Trims surrounding whitespace from a goal, rejecting blank goals
+fn normalize_goal(goal: String) -> Result<String, String> {
+    let trimmed = goal.trim();
+    if trimmed.is_empty() {
+        return Err("Goal cannot be empty".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Largest estimate a team accepts, a year of round-the-clock work
+pub const MAX_ESTIMATED_HOURS: f32 = 8760.0;
+
+/// Rejects estimates that are not a positive number of hours up to
+/// [`MAX_ESTIMATED_HOURS`]
+pub(super) fn check_estimate(hours: f32) -> Result<(), String> {
+    if hours > 0.0 && hours <= MAX_ESTIMATED_HOURS {
+        Ok(())
+    } else {
+        Err(format!(
+            "Estimated hours must be positive and at most {}, got {}",
+            MAX_ESTIMATED_HOURS, hours
+        ))
     }
 }
 
+/// Adds an estimate in hours to a start time, to the millisecond
+pub(super) fn projected_completion(
+    started_at: Option<DateTime<Utc>>,
+    estimated_hours: Option<f32>,
+) -> Option<DateTime<Utc>> {
+    let millis = (f64::from(estimated_hours?) * 3_600_000.0).round() as i64;
+    started_at?.checked_add_signed(chrono::Duration::milliseconds(millis))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::shared::{Currency, FixedClock, MockClock, SystemClock};
+    use chrono::{Duration, TimeZone};
 
     #[test]
     fn create_team_with_valid_goal() {
         let company_id = Uuid::new_v4();
         let created_by = Uuid::new_v4();
 
-        let result = Team::new(company_id, "Test goal".to_string(), created_by, None);
+        let before = Utc::now();
+        let result = Team::new_now(company_id, "Test goal".to_string(), created_by, None);
 
         assert!(result.is_ok());
         let (team, events) = result.unwrap();
@@ -276,25 +730,84 @@ mod tests {
         assert_eq!(team.company_id(), company_id);
         assert_eq!(team.created_by(), created_by);
         assert_eq!(team.status(), TeamStatus::Pending);
+        assert!(team.created_at() >= before && team.created_at() <= Utc::now());
         assert_eq!(events.len(), 1);
     }
 
+    #[test]
+    fn create_team_with_chosen_id_uses_it_in_events() {
+        let id = Uuid::new_v4();
+
+        let (team, events) = Team::new_with_id(
+            id,
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(team.id(), id);
+        assert!(matches!(events[0], TeamEvent::Created { team_id, .. } if team_id == id));
+    }
+
     #[test]
     fn create_team_with_empty_goal_fails() {
-        let result = Team::new(Uuid::new_v4(), "".to_string(), Uuid::new_v4(), None);
+        let result = Team::new(
+            Uuid::new_v4(),
+            "".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        );
 
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Goal cannot be empty"));
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::Validation("Goal cannot be empty".to_string())
+        );
+    }
+
+    #[test]
+    fn create_team_with_whitespace_only_goal_fails() {
+        let result = Team::new(
+            Uuid::new_v4(),
+            " \t\n ".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::Validation("Goal cannot be empty".to_string())
+        );
+    }
+
+    #[test]
+    fn create_team_trims_padded_goal() {
+        let (team, events) = Team::new(
+            Uuid::new_v4(),
+            "  Build X ".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(team.goal(), "Build X");
+        assert!(matches!(&events[0], TeamEvent::Created { goal, .. } if goal == "Build X"));
     }
 
     #[test]
     fn create_team_with_valid_budget() {
-        let budget = Decimal::from(1000);
+        let budget = Money::new(Decimal::from(1000), Currency::Usd).unwrap();
         let result = Team::new(
             Uuid::new_v4(),
             "Test goal".to_string(),
             Uuid::new_v4(),
             Some(budget),
+            &SystemClock,
         );
 
         assert!(result.is_ok());
@@ -304,25 +817,16 @@ mod tests {
 
     #[test]
     fn create_team_with_zero_budget_fails() {
-        let result = Team::new(
-            Uuid::new_v4(),
-            "Test goal".to_string(),
-            Uuid::new_v4(),
-            Some(Decimal::ZERO),
-        );
+        // A zero budget cannot even be expressed as Money
+        let result = Money::new(Decimal::ZERO, Currency::Usd);
 
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Budget must be positive"));
+        assert!(result.unwrap_err().contains("must be positive"));
     }
 
     #[test]
     fn create_team_with_negative_budget_fails() {
-        let result = Team::new(
-            Uuid::new_v4(),
-            "Test goal".to_string(),
-            Uuid::new_v4(),
-            Some(Decimal::from(-100)),
-        );
+        let result = Money::new(Decimal::from(-100), Currency::Usd);
 
         assert!(result.is_err());
     }
@@ -333,7 +837,8 @@ mod tests {
         let created_by = Uuid::new_v4();
         let goal = "Test goal".to_string();
 
-        let (team, events) = Team::new(company_id, goal.clone(), created_by, None).unwrap();
+        let (team, events) =
+            Team::new(company_id, goal.clone(), created_by, None, &SystemClock).unwrap();
 
         assert_eq!(events.len(), 1);
         match &events[0] {
@@ -359,11 +864,12 @@ mod tests {
             "Test goal".to_string(),
             Uuid::new_v4(),
             None,
+            &SystemClock,
         )
         .unwrap();
 
         // Pending cannot transition directly to Active
-        let result = team.start();
+        let result = team.start(&SystemClock);
         assert!(result.is_err());
     }
 
@@ -372,9 +878,10 @@ mod tests {
         let company_id = Uuid::new_v4();
         let created_by = Uuid::new_v4();
         let goal = "Test goal".to_string();
-        let budget = Some(Decimal::from(500));
+        let budget = Some(Money::new(Decimal::from(500), Currency::Eur).unwrap());
 
-        let (team, _) = Team::new(company_id, goal.clone(), created_by, budget).unwrap();
+        let (team, _) =
+            Team::new(company_id, goal.clone(), created_by, budget, &SystemClock).unwrap();
 
         assert_eq!(team.company_id(), company_id);
         assert_eq!(team.goal(), goal);
@@ -385,4 +892,662 @@ mod tests {
         assert!(team.started_at().is_none());
         assert!(team.completed_at().is_none());
     }
+
+    /// Builds an active team with a 100 USD budget
+    fn active_team_with_budget() -> Team {
+        team_in_status(TeamStatus::Active)
+    }
+
+    /// Builds a team in `status` with a 100 USD budget
+    fn team_in_status(status: TeamStatus) -> Team {
+        Team::from_persistence(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            status,
+            None,
+            Uuid::new_v4(),
+            Utc::now(),
+            Some(Utc::now()),
+            None,
+            Some(Money::new(Decimal::from(100), Currency::Usd).unwrap()),
+            Decimal::ZERO,
+            Vec::new(),
+            None,
+        )
+    }
+
+    #[test]
+    fn spend_under_budget_keeps_team_active() {
+        let mut team = active_team_with_budget();
+
+        let event = team.record_spend(Decimal::from(99), &SystemClock).unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(team.amount_spent(), Decimal::from(99));
+        assert_eq!(team.status(), TeamStatus::Active);
+    }
+
+    #[test]
+    fn spend_at_budget_keeps_team_active() {
+        let mut team = active_team_with_budget();
+
+        let event = team.record_spend(Decimal::from(100), &SystemClock).unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(team.status(), TeamStatus::Active);
+    }
+
+    #[test]
+    fn spend_over_budget_fails_team() {
+        let mut team = active_team_with_budget();
+        team.record_spend(Decimal::from(60), &SystemClock).unwrap();
+
+        let event = team.record_spend(Decimal::from(41), &SystemClock).unwrap();
+
+        assert_eq!(team.status(), TeamStatus::Failed);
+        assert!(team.completed_at().is_some());
+        match event {
+            Some(TeamEvent::Failed { team_id, reason }) => {
+                assert_eq!(team_id, team.id());
+                assert_eq!(reason, "budget exceeded");
+            }
+            other => panic!("Expected Failed event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn over_budget_only_fails_active_teams() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            Some(Money::new(Decimal::from(10), Currency::Usd).unwrap()),
+            &SystemClock,
+        )
+        .unwrap();
+
+        let event = team.record_spend(Decimal::from(20), &SystemClock).unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(team.status(), TeamStatus::Pending);
+    }
+
+    #[test]
+    fn spend_without_budget_never_fails_team() {
+        let mut team = active_team_with_budget();
+        team.budget_limit = None;
+
+        let event = team
+            .record_spend(Decimal::from(1_000_000), &SystemClock)
+            .unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(team.status(), TeamStatus::Active);
+    }
+
+    #[test]
+    fn negative_spend_is_rejected() {
+        let mut team = active_team_with_budget();
+
+        assert!(team.record_spend(Decimal::from(-1), &SystemClock).is_err());
+        assert_eq!(team.amount_spent(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn update_goal_on_pending_team() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Old goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let event = team.update_goal("New goal".to_string()).unwrap();
+
+        assert_eq!(team.goal(), "New goal");
+        assert!(matches!(event, TeamEvent::GoalUpdated { goal, .. } if goal == "New goal"));
+    }
+
+    #[test]
+    fn update_goal_rejected_once_active() {
+        let mut team = active_team_with_budget();
+
+        assert!(team.update_goal("New goal".to_string()).is_err());
+        assert_eq!(team.goal(), "Test goal");
+    }
+
+    #[test]
+    fn update_goal_rejects_empty_goal() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Old goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        assert!(team.update_goal(String::new()).is_err());
+        assert!(team.update_goal("   ".to_string()).is_err());
+        assert_eq!(team.goal(), "Old goal");
+    }
+
+    #[test]
+    fn update_goal_trims_padded_goal() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Old goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let event = team.update_goal(" New goal\n".to_string()).unwrap();
+
+        assert_eq!(team.goal(), "New goal");
+        assert!(matches!(event, TeamEvent::GoalUpdated { goal, .. } if goal == "New goal"));
+    }
+
+    #[test]
+    fn update_budget_on_active_team() {
+        let mut team = active_team_with_budget();
+        let budget = Money::new(Decimal::from(250), Currency::Usd).unwrap();
+
+        let event = team.update_budget(Some(budget)).unwrap();
+
+        assert_eq!(team.budget_limit(), Some(budget));
+        assert!(
+            matches!(event, TeamEvent::BudgetUpdated { budget_limit, .. } if budget_limit == Some(budget))
+        );
+    }
+
+    #[test]
+    fn update_budget_rejects_lowering_below_spend() {
+        let mut team = active_team_with_budget();
+        team.record_spend(Decimal::from(60), &SystemClock).unwrap();
+        let lowered = Money::new(Decimal::from(59), Currency::Usd).unwrap();
+
+        let error = team.update_budget(Some(lowered)).unwrap_err();
+
+        assert!(error.contains("below the 60 already spent"));
+        assert_eq!(team.budget_limit().unwrap().amount(), Decimal::from(100));
+    }
+
+    #[test]
+    fn update_budget_allows_raising_or_matching_spend() {
+        let mut team = active_team_with_budget();
+        team.record_spend(Decimal::from(60), &SystemClock).unwrap();
+
+        for amount in [150, 60] {
+            let budget = Money::new(Decimal::from(amount), Currency::Usd).unwrap();
+            let event = team.update_budget(Some(budget)).unwrap();
+
+            assert_eq!(team.budget_limit(), Some(budget));
+            assert!(matches!(event, TeamEvent::BudgetUpdated { .. }));
+        }
+    }
+
+    #[test]
+    fn repeated_start_reports_already_in_state() {
+        let mut team = active_team_with_budget();
+
+        assert_eq!(
+            team.start(&SystemClock).unwrap_err(),
+            TeamError::AlreadyInState(TeamStatus::Active)
+        );
+    }
+
+    #[test]
+    fn repeated_complete_reports_already_in_state() {
+        let mut team = active_team_with_budget();
+        team.complete(&SystemClock).unwrap();
+        let completed_at = team.completed_at();
+
+        assert_eq!(
+            team.complete(&SystemClock).unwrap_err(),
+            TeamError::AlreadyInState(TeamStatus::Completed)
+        );
+        assert_eq!(team.completed_at(), completed_at);
+    }
+
+    #[test]
+    fn repeated_fail_reports_already_in_state() {
+        let mut team = active_team_with_budget();
+        team.fail("boom".to_string(), &SystemClock).unwrap();
+
+        assert_eq!(
+            team.fail("boom".to_string(), &SystemClock).unwrap_err(),
+            TeamError::AlreadyInState(TeamStatus::Failed)
+        );
+    }
+
+    #[test]
+    fn other_invalid_transitions_keep_their_message() {
+        let mut team = active_team_with_budget();
+        team.fail("boom".to_string(), &SystemClock).unwrap();
+
+        let error = team.complete(&SystemClock).unwrap_err();
+
+        assert_eq!(
+            error,
+            TeamError::InvalidTransition {
+                action: "complete",
+                from: TeamStatus::Failed
+            }
+        );
+        assert_eq!(error.to_string(), "Cannot complete team in Failed status");
+    }
+
+    #[test]
+    fn update_budget_rejected_once_finished() {
+        let mut team = active_team_with_budget();
+        team.complete(&SystemClock).unwrap();
+
+        assert!(team.update_budget(None).is_err());
+        assert!(team.budget_limit().is_some());
+    }
+
+    #[test]
+    fn terminal_teams_reject_mutations_uniformly() {
+        for status in [
+            TeamStatus::Completed,
+            TeamStatus::Failed,
+            TeamStatus::Archived,
+        ] {
+            let mut team = team_in_status(status);
+            let expected = TeamError::Terminal(status).to_string();
+
+            assert!(team.is_terminal());
+            assert_eq!(
+                team.update_goal("New goal".to_string()).unwrap_err(),
+                expected
+            );
+            assert_eq!(team.update_budget(None).unwrap_err(), expected);
+            assert_eq!(
+                team.set_tags(vec!["late".to_string()]).unwrap_err(),
+                expected
+            );
+            assert_eq!(team.goal(), "Test goal");
+            assert!(team.budget_limit().is_some());
+            assert!(team.tags().is_empty());
+        }
+    }
+
+    #[test]
+    fn non_terminal_teams_accept_mutations() {
+        for status in [
+            TeamStatus::Pending,
+            TeamStatus::Planning,
+            TeamStatus::Active,
+        ] {
+            let mut team = team_in_status(status);
+
+            assert!(!team.is_terminal());
+            assert!(team.update_budget(None).is_ok());
+            assert!(team.set_tags(vec!["open".to_string()]).is_ok());
+        }
+    }
+
+    fn fixed_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn created_at_comes_from_clock() {
+        let clock = MockClock::new(fixed_time());
+
+        let (team, _) = Team::new(
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &clock,
+        )
+        .unwrap();
+
+        assert_eq!(team.created_at(), fixed_time());
+    }
+
+    #[test]
+    fn transition_timestamps_come_from_clock() {
+        let clock = MockClock::new(fixed_time());
+        let mut team = Team::from_persistence(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            TeamStatus::Planning,
+            None,
+            Uuid::new_v4(),
+            fixed_time(),
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Vec::new(),
+            None,
+        );
+
+        clock.advance(Duration::minutes(10));
+        team.start(&clock).unwrap();
+        clock.advance(Duration::hours(2));
+        team.complete(&clock).unwrap();
+
+        assert_eq!(
+            team.started_at(),
+            Some(fixed_time() + Duration::minutes(10))
+        );
+        assert_eq!(
+            team.completed_at(),
+            Some(fixed_time() + Duration::minutes(130))
+        );
+    }
+
+    #[test]
+    fn transfer_ownership_changes_owner_and_emits_event() {
+        let mut team = team_in_status(TeamStatus::Completed);
+        let previous_owner = team.created_by();
+        let new_owner = Uuid::new_v4();
+
+        let event = team.transfer_ownership(new_owner).unwrap();
+
+        assert_eq!(team.created_by(), new_owner);
+        assert!(matches!(
+            event,
+            TeamEvent::OwnershipTransferred { previous_owner: p, new_owner: n, .. }
+                if p == previous_owner && n == new_owner
+        ));
+    }
+
+    #[test]
+    fn transfer_ownership_rejects_current_owner_and_nil() {
+        let mut team = team_in_status(TeamStatus::Active);
+        let owner = team.created_by();
+
+        assert!(team.transfer_ownership(owner).is_err());
+        assert!(team.transfer_ownership(Uuid::nil()).is_err());
+        assert_eq!(team.created_by(), owner);
+    }
+
+    #[test]
+    fn reopen_returns_completed_team_to_active() {
+        let mut team = team_in_status(TeamStatus::Active);
+        team.complete(&SystemClock).unwrap();
+        let started_at = team.started_at();
+        let actor = Uuid::new_v4();
+
+        let event = team.reopen(actor, true).unwrap();
+
+        assert_eq!(team.status(), TeamStatus::Active);
+        assert_eq!(team.completed_at(), None);
+        assert_eq!(team.started_at(), started_at);
+        assert!(team.validate().is_ok());
+        assert!(matches!(
+            event,
+            TeamEvent::Reopened { team_id, reopened_by }
+                if team_id == team.id() && reopened_by == actor
+        ));
+    }
+
+    #[test]
+    fn reopen_rejects_archived_and_unfinished_teams() {
+        for status in [
+            TeamStatus::Archived,
+            TeamStatus::Failed,
+            TeamStatus::Active,
+            TeamStatus::Pending,
+        ] {
+            let mut team = team_in_status(status);
+
+            assert!(
+                team.reopen(Uuid::new_v4(), true).is_err(),
+                "{status} reopened"
+            );
+            assert_eq!(team.status(), status);
+        }
+    }
+
+    #[test]
+    fn reopen_is_rejected_unless_enabled() {
+        let mut team = team_in_status(TeamStatus::Active);
+        team.complete(&SystemClock).unwrap();
+        let completed_at = team.completed_at();
+
+        let error = team.reopen(Uuid::new_v4(), false).unwrap_err();
+
+        assert_eq!(error, TeamError::NotEnabled("reopen").to_string());
+        assert_eq!(team.status(), TeamStatus::Completed);
+        assert_eq!(team.completed_at(), completed_at);
+    }
+
+    #[test]
+    fn start_and_complete_record_exact_clock_times() {
+        let started = fixed_time() + Duration::minutes(5);
+        let completed = fixed_time() + Duration::hours(3);
+        let mut team = team_in_status(TeamStatus::Planning);
+
+        team.start(&FixedClock(started)).unwrap();
+        team.complete(&FixedClock(completed)).unwrap();
+
+        assert_eq!(team.started_at(), Some(started));
+        assert_eq!(team.completed_at(), Some(completed));
+    }
+
+    #[test]
+    fn fail_records_exact_clock_time() {
+        let failed = fixed_time() + Duration::minutes(45);
+        let mut team = team_in_status(TeamStatus::Active);
+
+        team.fail("boom".to_string(), &FixedClock(failed)).unwrap();
+
+        assert_eq!(team.completed_at(), Some(failed));
+    }
+
+    #[test]
+    fn budget_failure_timestamp_comes_from_clock() {
+        let clock = MockClock::new(fixed_time());
+        let mut team = active_team_with_budget();
+
+        team.record_spend(Decimal::from(101), &clock).unwrap();
+
+        assert_eq!(team.status(), TeamStatus::Failed);
+        assert_eq!(team.completed_at(), Some(fixed_time()));
+    }
+
+    #[test]
+    fn set_tags_normalizes_and_emits_event() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let event = team
+            .set_tags(vec![
+                "Q3".to_string(),
+                "q3".to_string(),
+                "Growth".to_string(),
+            ])
+            .unwrap();
+
+        assert_eq!(team.tags(), ["q3".to_string(), "growth".to_string()]);
+        assert!(matches!(event, TeamEvent::TagsUpdated { tags, .. } if tags == team.tags()));
+    }
+
+    #[test]
+    fn set_tags_rejects_invalid_tags_without_changes() {
+        let mut team = active_team_with_budget();
+        team.set_tags(vec!["keep".to_string()]).unwrap();
+
+        assert!(team.set_tags(vec!["".to_string()]).is_err());
+        assert_eq!(team.tags(), ["keep".to_string()]);
+    }
+
+    /// Builds a completed team from persisted timestamps via the checked path
+    fn completed_team(
+        started_at: DateTime<Utc>,
+        completed_at: DateTime<Utc>,
+    ) -> Result<Team, String> {
+        Team::try_from_persistence(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            TeamStatus::Completed,
+            None,
+            Uuid::new_v4(),
+            started_at - Duration::hours(1),
+            Some(started_at),
+            Some(completed_at),
+            None,
+            Decimal::ZERO,
+            Vec::new(),
+            None,
+        )
+    }
+
+    #[test]
+    fn try_from_persistence_rejects_completion_before_start() {
+        let started_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        let result = completed_team(started_at, started_at - Duration::minutes(5));
+
+        assert!(result.unwrap_err().contains("before it started"));
+    }
+
+    #[test]
+    fn try_from_persistence_accepts_ordered_timestamps() {
+        let started_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        assert!(completed_team(started_at, started_at).is_ok());
+        assert!(completed_team(started_at, started_at + Duration::hours(2)).is_ok());
+    }
+
+    #[test]
+    fn validate_accepts_consistent_teams() {
+        let (team, _) = Team::new(
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            Some(Money::new(Decimal::from(100), Currency::Usd).unwrap()),
+            &SystemClock,
+        )
+        .unwrap();
+        assert_eq!(team.validate(), Ok(()));
+
+        let mut team = active_team_with_budget();
+        assert_eq!(team.validate(), Ok(()));
+        team.complete(&SystemClock).unwrap();
+        assert_eq!(team.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_empty_goal() {
+        let mut team = active_team_with_budget();
+        team.goal = "   ".to_string();
+
+        assert!(team.validate().unwrap_err().contains("empty goal"));
+    }
+
+    #[test]
+    fn validate_rejects_negative_budget() {
+        // Deserialization skips Money's own check
+        let budget: Money =
+            serde_json::from_value(serde_json::json!({ "amount": "-5", "currency": "USD" }))
+                .unwrap();
+        let mut team = active_team_with_budget();
+        team.budget_limit = Some(budget);
+
+        assert!(team.validate().unwrap_err().contains("non-positive budget"));
+    }
+
+    #[test]
+    fn validate_rejects_terminal_team_without_completion_time() {
+        let team = team_in_status(TeamStatus::Failed);
+
+        assert!(team.validate().unwrap_err().contains("no completion time"));
+    }
+
+    /// Builds an active team started at a fixed time
+    fn started_team(estimated_hours: Option<f32>) -> Team {
+        Team::from_persistence(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            TeamStatus::Active,
+            None,
+            Uuid::new_v4(),
+            fixed_time(),
+            Some(fixed_time()),
+            None,
+            None,
+            Decimal::ZERO,
+            Vec::new(),
+            estimated_hours,
+        )
+    }
+
+    #[test]
+    fn estimated_completion_adds_estimate_to_start() {
+        let mut team = started_team(None);
+        assert_eq!(team.estimated_completion(), None);
+
+        team.record_estimate(8.5).unwrap();
+
+        assert_eq!(team.estimated_hours(), Some(8.5));
+        assert_eq!(
+            team.estimated_completion(),
+            Some(fixed_time() + Duration::minutes(8 * 60 + 30))
+        );
+    }
+
+    #[test]
+    fn estimated_completion_needs_a_start_time() {
+        let (mut team, _) = Team::new(
+            Uuid::new_v4(),
+            "Test goal".to_string(),
+            Uuid::new_v4(),
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        team.record_estimate(2.0).unwrap();
+
+        assert_eq!(team.estimated_completion(), None);
+    }
+
+    #[test]
+    fn record_estimate_rejects_non_positive_hours() {
+        let mut team = started_team(Some(4.0));
+
+        for hours in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(team.record_estimate(hours).is_err());
+        }
+        assert_eq!(team.estimated_hours(), Some(4.0));
+    }
+
+    #[test]
+    fn record_estimate_is_bounded() {
+        let mut team = started_team(None);
+
+        assert!(team.record_estimate(MAX_ESTIMATED_HOURS + 1.0).is_err());
+        assert_eq!(team.estimated_hours(), None);
+
+        team.record_estimate(MAX_ESTIMATED_HOURS).unwrap();
+        assert_eq!(team.estimated_hours(), Some(MAX_ESTIMATED_HOURS));
+    }
+
+    #[test]
+    fn record_estimate_rejects_finished_teams() {
+        let mut team = team_in_status(TeamStatus::Completed);
+
+        assert!(team.record_estimate(1.0).is_err());
+        assert_eq!(team.estimated_hours(), None);
+    }
 }
@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 /// Pending -> Planning -> Active -> Completed
 ///                            └---> Failed -> Archived
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+///
+/// Completed teams may also be reopened to Active, but only explicitly
+/// through `Team::reopen`; `can_transition_to` does not allow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "team_status", rename_all = "lowercase")]
 pub enum TeamStatus {
     /// Team is pending creation/initialization
@@ -25,6 +28,16 @@ pub enum TeamStatus {
 }
 
 impl TeamStatus {
+    /// Every status, in lifecycle order
+    pub const ALL: [TeamStatus; 6] = [
+        TeamStatus::Pending,
+        TeamStatus::Planning,
+        TeamStatus::Active,
+        TeamStatus::Completed,
+        TeamStatus::Failed,
+        TeamStatus::Archived,
+    ];
+
     /// Checks if a transition from current status to next status is valid
     ///
     /// # Valid Transitions
@@ -54,6 +67,22 @@ impl TeamStatus {
                 | (Failed, Archived)
         )
     }
+
+    /// Whether the team has finished and no longer counts as active work
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::domain::team::value_objects::TeamStatus;
+    ///
+    /// assert!(TeamStatus::Failed.is_terminal());
+    /// assert!(!TeamStatus::Planning.is_terminal());
+    /// ```
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TeamStatus::Completed | TeamStatus::Failed | TeamStatus::Archived
+        )
+    }
 }
 
 impl std::fmt::Display for TeamStatus {
@@ -69,6 +98,86 @@ impl std::fmt::Display for TeamStatus {
     }
 }
 
+/// Allows binding `&[TeamStatus]` as a `team_status[]` query parameter
+impl sqlx::postgres::PgHasArrayType for TeamStatus {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_team_status")
+    }
+}
+
+impl std::str::FromStr for TeamStatus {
+    type Err = String;
+
+    /// Parses a lowercase status name as produced by `Display`
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::domain::team::value_objects::TeamStatus;
+    ///
+    /// assert_eq!("active".parse::<TeamStatus>(), Ok(TeamStatus::Active));
+    /// assert!("paused".parse::<TeamStatus>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(TeamStatus::Pending),
+            "planning" => Ok(TeamStatus::Planning),
+            "active" => Ok(TeamStatus::Active),
+            "completed" => Ok(TeamStatus::Completed),
+            "failed" => Ok(TeamStatus::Failed),
+            "archived" => Ok(TeamStatus::Archived),
+            other => Err(format!("Invalid team status: {}", other)),
+        }
+    }
+}
+
+/// Maximum number of tags on a team
+pub const MAX_TAGS: usize = 10;
+
+/// Maximum length of a single tag, in characters
+pub const MAX_TAG_LENGTH: usize = 50;
+
+/// Normalizes a team's tags
+///
+/// Tags are trimmed and lowercased, and duplicates are dropped (keeping
+/// the first occurrence).
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - Normalized tags
+/// * `Err(String)` - If a tag is empty or too long, or there are too many
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::domain::team::value_objects::normalize_tags;
+///
+/// let tags = normalize_tags(vec!["Q3".into(), " marketing ".into(), "q3".into()]).unwrap();
+/// assert_eq!(tags, vec!["q3", "marketing"]);
+/// ```
+pub fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            return Err("Tags cannot be empty".to_string());
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(format!(
+                "Tag must be at most {} characters: {}",
+                MAX_TAG_LENGTH, tag
+            ));
+        }
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+
+    if normalized.len() > MAX_TAGS {
+        return Err(format!("A team can have at most {} tags", MAX_TAGS));
+    }
+
+    Ok(normalized)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,6 +233,20 @@ mod tests {
         assert!(!TeamStatus::Archived.can_transition_to(TeamStatus::Pending));
     }
 
+    #[test]
+    fn finished_statuses_are_terminal() {
+        assert!(TeamStatus::Completed.is_terminal());
+        assert!(TeamStatus::Failed.is_terminal());
+        assert!(TeamStatus::Archived.is_terminal());
+    }
+
+    #[test]
+    fn unfinished_statuses_are_not_terminal() {
+        assert!(!TeamStatus::Pending.is_terminal());
+        assert!(!TeamStatus::Planning.is_terminal());
+        assert!(!TeamStatus::Active.is_terminal());
+    }
+
     #[test]
     fn status_display() {
         assert_eq!(TeamStatus::Pending.to_string(), "pending");
@@ -133,4 +256,46 @@ mod tests {
         assert_eq!(TeamStatus::Failed.to_string(), "failed");
         assert_eq!(TeamStatus::Archived.to_string(), "archived");
     }
+
+    #[test]
+    fn status_parse_round_trips_display() {
+        for status in TeamStatus::ALL {
+            assert_eq!(status.to_string().parse::<TeamStatus>(), Ok(status));
+        }
+    }
+
+    #[test]
+    fn status_parse_rejects_unknown() {
+        assert!("".parse::<TeamStatus>().is_err());
+        assert!("Active".parse::<TeamStatus>().is_err());
+        assert!("paused".parse::<TeamStatus>().is_err());
+    }
+
+    #[test]
+    fn tags_are_lowercased_trimmed_and_deduplicated() {
+        let tags = normalize_tags(vec![
+            "Marketing".to_string(),
+            "  q3 ".to_string(),
+            "MARKETING".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(tags, vec!["marketing".to_string(), "q3".to_string()]);
+    }
+
+    #[test]
+    fn tags_reject_empty_and_long_values() {
+        assert!(normalize_tags(vec!["  ".to_string()]).is_err());
+        assert!(normalize_tags(vec!["x".repeat(MAX_TAG_LENGTH)]).is_ok());
+        assert!(normalize_tags(vec!["x".repeat(MAX_TAG_LENGTH + 1)]).is_err());
+    }
+
+    #[test]
+    fn tags_limit_counts_distinct_tags() {
+        let distinct: Vec<String> = (0..=MAX_TAGS).map(|i| format!("tag{}", i)).collect();
+        assert!(normalize_tags(distinct).is_err());
+
+        let repeated = vec!["same".to_string(); MAX_TAGS + 5];
+        assert_eq!(normalize_tags(repeated).unwrap().len(), 1);
+    }
 }
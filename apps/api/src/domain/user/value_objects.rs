@@ -38,6 +38,23 @@ impl Email {
         }
     }
 
+    /// Wraps an email string without validating it
+    ///
+    /// Bypasses the rules enforced by [`Email::new`]. Only for rebuilding
+    /// stored values in repositories and for importing legacy data, where
+    /// an address that predates the current rules must still load.
+    ///
+    /// # Example
+    /// ```
+    /// use ghostpirates_api::domain::user::value_objects::Email;
+    ///
+    /// let email = Email::new_unchecked("legacy".to_string());
+    /// assert_eq!(email.as_str(), "legacy");
+    /// ```
+    pub fn new_unchecked(email: String) -> Self {
+        Email(email)
+    }
+
     /// Validates an email string
     ///
     /// # Validation Rules
@@ -61,6 +78,30 @@ impl fmt::Display for Email {
     }
 }
 
+/// Role of a user within their company
+///
+/// Admins may perform company-wide administrative operations
+/// such as bulk deletes. New users are members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+pub enum UserRole {
+    /// Regular company member
+    #[default]
+    Member,
+    /// Company administrator
+    Admin,
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserRole::Member => write!(f, "member"),
+            UserRole::Admin => write!(f, "admin"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +136,14 @@ mod tests {
         assert!(Email::new("").is_err());
     }
 
+    #[test]
+    fn new_unchecked_keeps_raw_value_that_new_rejects() {
+        let raw = "legacy-user";
+
+        assert!(Email::new(raw).is_err());
+        assert_eq!(Email::new_unchecked(raw.to_string()).as_str(), raw);
+    }
+
     #[test]
     fn email_display() {
         let email = Email::new("test@example.com").unwrap();
@@ -107,4 +156,18 @@ mod tests {
         let email2 = email1.clone();
         assert_eq!(email1, email2);
     }
+
+    #[test]
+    fn user_role_defaults_to_member() {
+        assert_eq!(UserRole::default(), UserRole::Member);
+    }
+
+    #[test]
+    fn user_role_serializes_lowercase() {
+        assert_eq!(
+            serde_json::to_string(&UserRole::Admin).unwrap(),
+            "\"admin\""
+        );
+        assert_eq!(UserRole::Member.to_string(), "member");
+    }
 }
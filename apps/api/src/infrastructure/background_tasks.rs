@@ -0,0 +1,76 @@
+// Background tasks
+// Tracks fire-and-forget work spawned by handlers so shutdown can wait for it
+
+use std::future::Future;
+use std::time::Duration;
+
+use tokio_util::task::TaskTracker;
+
+/// How long shutdown waits for background tasks before giving up on them
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Spawner for work that should not delay a response
+///
+/// Tasks run on the Tokio runtime like `tokio::spawn`, but are tracked so
+/// [`BackgroundTasks::shutdown`] can let them finish before the process
+/// exits. Cloning is cheap and clones share one tracker, so a single
+/// instance can be installed as an axum `Extension` for all handlers.
+#[derive(Debug, Clone, Default)]
+pub struct BackgroundTasks {
+    tracker: TaskTracker,
+}
+
+impl BackgroundTasks {
+    /// Creates an empty set of background tasks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `task` in the background
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tracker.spawn(task);
+    }
+
+    /// Stops accepting tasks and waits up to `timeout` for running ones
+    ///
+    /// Returns `false` if tasks were still running when the timeout hit;
+    /// they are dropped with the runtime.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.tracker.close();
+        tokio::time::timeout(timeout, self.tracker.wait())
+            .await
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn shutdown_waits_for_running_tasks() {
+        let tasks = BackgroundTasks::new();
+        let finished = Arc::new(AtomicBool::new(false));
+        let flag = finished.clone();
+        tasks.spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        assert!(tasks.shutdown(Duration::from_secs(1)).await);
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn shutdown_gives_up_after_the_timeout() {
+        let tasks = BackgroundTasks::new();
+        tasks.spawn(tokio::time::sleep(Duration::from_secs(60)));
+
+        assert!(!tasks.shutdown(Duration::from_millis(20)).await);
+    }
+}
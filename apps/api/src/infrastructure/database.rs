@@ -0,0 +1,192 @@
+// Database connection
+// Retries the initial connection so the API can boot before the database is ready
+
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tokio::time::Instant;
+
+/// Default total time spent retrying the initial connection
+pub const DEFAULT_CONNECT_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Pool that read-only handlers query instead of the primary
+///
+/// Installed as an `Extension`; it is the read replica when
+/// `DATABASE_REPLICA_URL` is set and the primary pool otherwise.
+#[derive(Clone)]
+pub struct ReadPool(pub PgPool);
+
+impl ReadPool {
+    /// Uses `replica` if configured, `primary` otherwise
+    pub fn new(primary: &PgPool, replica: Option<PgPool>) -> Self {
+        Self(replica.unwrap_or_else(|| primary.clone()))
+    }
+}
+
+/// URL of the read replica from `DATABASE_REPLICA_URL`
+///
+/// Unset or blank means there is no replica.
+pub fn replica_url_from_env() -> Option<String> {
+    std::env::var("DATABASE_REPLICA_URL")
+        .ok()
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+}
+
+/// Exponential backoff settings for [`connect_with_retry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Delay before the second attempt; doubled after each failure
+    pub initial_delay: Duration,
+    /// Longest delay between two attempts
+    pub max_delay: Duration,
+    /// Total time after which no further attempt is started
+    pub max_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+            max_wait: DEFAULT_CONNECT_MAX_WAIT,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy with `max_wait` from `DB_CONNECT_MAX_WAIT_SECS`
+    ///
+    /// Unset or unparseable values fall back to 30 seconds; `0` disables
+    /// retrying.
+    pub fn from_env() -> Self {
+        let max_wait = std::env::var("DB_CONNECT_MAX_WAIT_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_CONNECT_MAX_WAIT);
+
+        Self {
+            max_wait,
+            ..Self::default()
+        }
+    }
+}
+
+/// Calls `connect` until it succeeds or `policy.max_wait` runs out
+///
+/// Each failed attempt is logged along with the delay before the next one.
+/// Delays start at `policy.initial_delay` and double up to
+/// `policy.max_delay`. If the next delay would end past `max_wait`, the
+/// last error is returned instead.
+///
+/// # Example
+/// ```ignore
+/// let pool = connect_with_retry(&RetryPolicy::from_env(), || {
+///     PgPoolOptions::new().connect(&database_url)
+/// })
+/// .await?;
+/// ```
+pub async fn connect_with_retry<T, E, F, Fut>(policy: &RetryPolicy, mut connect: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Display,
+{
+    let started = Instant::now();
+    let mut delay = policy.initial_delay;
+    let mut attempt = 1;
+
+    loop {
+        let error = match connect().await {
+            Ok(connection) => return Ok(connection),
+            Err(e) => e,
+        };
+
+        if started.elapsed() + delay > policy.max_wait {
+            tracing::error!(
+                "Database connection attempt {} failed, giving up: {}",
+                attempt,
+                error
+            );
+            return Err(error);
+        }
+
+        tracing::warn!(
+            "Database connection attempt {} failed, retrying in {:?}: {}",
+            attempt,
+            delay,
+            error
+        );
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(policy.max_delay);
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn fast_policy(max_wait: Duration) -> RetryPolicy {
+        RetryPolicy {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            max_wait,
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_until_the_connector_succeeds() {
+        let attempts = Cell::new(0);
+
+        let result = connect_with_retry(&fast_policy(Duration::from_secs(5)), || {
+            attempts.set(attempts.get() + 1);
+            let attempt = attempts.get();
+            async move {
+                if attempt < 4 {
+                    Err("database is starting up")
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result, Ok(4));
+        assert_eq!(attempts.get(), 4);
+    }
+
+    #[tokio::test]
+    async fn gives_up_with_the_last_error_after_max_wait() {
+        let attempts = Cell::new(0);
+
+        let result: Result<(), String> =
+            connect_with_retry(&fast_policy(Duration::from_millis(20)), || {
+                attempts.set(attempts.get() + 1);
+                let attempt = attempts.get();
+                async move { Err(format!("refused #{}", attempt)) }
+            })
+            .await;
+
+        assert!(attempts.get() > 1);
+        assert_eq!(result, Err(format!("refused #{}", attempts.get())));
+    }
+
+    #[tokio::test]
+    async fn zero_max_wait_tries_once() {
+        let attempts = Cell::new(0);
+
+        let result: Result<(), &str> = connect_with_retry(&fast_policy(Duration::ZERO), || {
+            attempts.set(attempts.get() + 1);
+            async { Err("refused") }
+        })
+        .await;
+
+        assert_eq!(result, Err("refused"));
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -0,0 +1,169 @@
+// Tracing adapter for domain events
+// Emits one structured log line per TeamEvent
+
+use crate::domain::team::events::TeamEvent;
+
+/// Logs team lifecycle events via `tracing`
+///
+/// Each event is emitted at INFO with `target: "team_events"`, the
+/// `event_type`, the `team_id`, and the variant's other fields.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventLogger;
+
+impl EventLogger {
+    /// Logs every event in order
+    pub fn log(&self, events: &[TeamEvent]) {
+        for event in events {
+            self.log_event(event);
+        }
+    }
+
+    fn log_event(&self, event: &TeamEvent) {
+        let event_type = event.event_type();
+        match event {
+            TeamEvent::Created {
+                team_id,
+                company_id,
+                goal,
+                created_by,
+            } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                %company_id,
+                %created_by,
+                goal = goal.as_str(),
+                "team event"
+            ),
+            TeamEvent::Started { team_id } | TeamEvent::Completed { team_id } => {
+                tracing::info!(target: "team_events", event_type, %team_id, "team event")
+            }
+            TeamEvent::Failed { team_id, reason } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                reason = reason.as_str(),
+                "team event"
+            ),
+            TeamEvent::GoalUpdated { team_id, goal } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                goal = goal.as_str(),
+                "team event"
+            ),
+            TeamEvent::BudgetUpdated {
+                team_id,
+                budget_limit,
+            } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                budget_amount = ?budget_limit.map(|b| b.amount()),
+                budget_currency = ?budget_limit.map(|b| b.currency()),
+                "team event"
+            ),
+            TeamEvent::TagsUpdated { team_id, tags } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                ?tags,
+                "team event"
+            ),
+            TeamEvent::OwnershipTransferred {
+                team_id,
+                previous_owner,
+                new_owner,
+            } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                %previous_owner,
+                %new_owner,
+                "team event"
+            ),
+            TeamEvent::Reopened {
+                team_id,
+                reopened_by,
+            } => tracing::info!(
+                target: "team_events",
+                event_type,
+                %team_id,
+                %reopened_by,
+                "team event"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    /// Collects formatted tracing output in memory
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn capture_logs(f: impl FnOnce()) -> String {
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, f);
+
+        let bytes = capture.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn logs_created_event_with_team_id() {
+        let team_id = Uuid::new_v4();
+        let event = TeamEvent::Created {
+            team_id,
+            company_id: Uuid::new_v4(),
+            goal: "Test goal".to_string(),
+            created_by: Uuid::new_v4(),
+        };
+
+        let output = capture_logs(|| EventLogger.log(&[event]));
+
+        assert!(output.contains("team_events"));
+        assert!(output.contains("event_type=\"created\""));
+        assert!(output.contains(&format!("team_id={}", team_id)));
+        assert!(output.contains("goal=\"Test goal\""));
+    }
+
+    #[test]
+    fn logs_one_line_per_event() {
+        let team_id = Uuid::new_v4();
+        let events = [
+            TeamEvent::Started { team_id },
+            TeamEvent::Failed {
+                team_id,
+                reason: "budget exceeded".to_string(),
+            },
+        ];
+
+        let output = capture_logs(|| EventLogger.log(&events));
+
+        assert_eq!(output.lines().count(), 2);
+        assert!(output.contains("reason=\"budget exceeded\""));
+    }
+}
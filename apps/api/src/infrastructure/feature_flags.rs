@@ -0,0 +1,164 @@
+// Per-company feature flags
+// Reads toggles from the feature_flags table with a short-lived cache
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::FeatureFlagRepository;
+use crate::infrastructure::repositories::PostgresFeatureFlagRepository;
+
+/// Rejects a second team with the same goal within the company
+pub const UNIQUE_GOAL_PER_COMPANY: &str = "unique_goal_per_company";
+
+/// Lets completed teams be reopened for follow-up work (`Team::reopen`)
+pub const REOPEN_COMPLETED_TEAMS: &str = "reopen_completed_teams";
+
+/// How long a flag lookup is reused before the database is asked again
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+type CacheKey = (Uuid, String);
+
+/// Feature flag lookups for companies
+///
+/// Flags a company has never set are disabled. Cloning is cheap and clones
+/// share one cache, so a single instance can be installed as an axum
+/// `Extension` for all handlers.
+#[derive(Clone)]
+pub struct FeatureFlags {
+    repo: Arc<dyn FeatureFlagRepository>,
+    cache: Arc<Mutex<HashMap<CacheKey, (bool, Instant)>>>,
+    ttl: Duration,
+}
+
+impl FeatureFlags {
+    /// Creates a service backed by the given repository
+    pub fn new(repo: Arc<dyn FeatureFlagRepository>) -> Self {
+        Self {
+            repo,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            ttl: DEFAULT_CACHE_TTL,
+        }
+    }
+
+    /// Creates a service backed by the `feature_flags` table
+    pub fn postgres(pool: PgPool) -> Self {
+        Self::new(Arc::new(PostgresFeatureFlagRepository::new(pool)))
+    }
+
+    /// Sets how long lookups are cached
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns whether `flag` is enabled for the company
+    pub async fn is_enabled(&self, company_id: Uuid, flag: &str) -> Result<bool, String> {
+        let key = (company_id, flag.to_string());
+
+        if let Some((enabled, fetched_at)) = self.cache.lock().unwrap().get(&key) {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(*enabled);
+            }
+        }
+
+        let enabled = self.repo.find(company_id, flag).await?.unwrap_or(false);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(key, (enabled, Instant::now()));
+
+        Ok(enabled)
+    }
+
+    /// Enables or disables `flag` for the company
+    ///
+    /// The new value is visible immediately through this service; other
+    /// instances see it once their cached entry expires.
+    pub async fn set(&self, company_id: Uuid, flag: &str, enabled: bool) -> Result<(), String> {
+        self.repo.set(company_id, flag, enabled).await?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert((company_id, flag.to_string()), (enabled, Instant::now()));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// In-memory repository that counts lookups
+    #[derive(Default)]
+    struct InMemoryFlags {
+        flags: Mutex<HashMap<CacheKey, bool>>,
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl FeatureFlagRepository for InMemoryFlags {
+        async fn find(&self, company_id: Uuid, flag_name: &str) -> Result<Option<bool>, String> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .flags
+                .lock()
+                .unwrap()
+                .get(&(company_id, flag_name.to_string()))
+                .copied())
+        }
+
+        async fn set(
+            &self,
+            company_id: Uuid,
+            flag_name: &str,
+            enabled: bool,
+        ) -> Result<(), String> {
+            self.flags
+                .lock()
+                .unwrap()
+                .insert((company_id, flag_name.to_string()), enabled);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn unset_flag_is_disabled() {
+        let flags = FeatureFlags::new(Arc::new(InMemoryFlags::default()));
+
+        assert!(!flags.is_enabled(Uuid::new_v4(), "anything").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn lookups_are_cached_until_ttl_expires() {
+        let repo = Arc::new(InMemoryFlags::default());
+        let company_id = Uuid::new_v4();
+        let flags = FeatureFlags::new(repo.clone());
+
+        flags.is_enabled(company_id, "beta").await.unwrap();
+        flags.is_enabled(company_id, "beta").await.unwrap();
+        assert_eq!(repo.lookups.load(Ordering::SeqCst), 1);
+
+        let uncached = FeatureFlags::new(repo.clone()).with_ttl(Duration::ZERO);
+        uncached.is_enabled(company_id, "beta").await.unwrap();
+        uncached.is_enabled(company_id, "beta").await.unwrap();
+        assert_eq!(repo.lookups.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn set_refreshes_cached_value() {
+        let company_id = Uuid::new_v4();
+        let flags = FeatureFlags::new(Arc::new(InMemoryFlags::default()));
+
+        assert!(!flags.is_enabled(company_id, "beta").await.unwrap());
+        flags.set(company_id, "beta", true).await.unwrap();
+
+        assert!(flags.is_enabled(company_id, "beta").await.unwrap());
+    }
+}
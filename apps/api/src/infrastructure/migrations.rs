@@ -0,0 +1,52 @@
+// Embedded database migrations
+// Lets the API apply its own schema at startup when opted in
+
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::PgPool;
+
+/// Migrations from `migrations/`, embedded at compile time
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Returns whether migrations should run at startup
+///
+/// Enabled only when `RUN_MIGRATIONS=true`; production applies
+/// migrations out of band.
+pub fn run_on_startup() -> bool {
+    std::env::var("RUN_MIGRATIONS")
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Applies any pending migrations
+///
+/// # Returns
+/// * `Ok(Vec<i64>)` - Versions applied by this call (empty if up to date)
+/// * `Err(String)` - If a migration fails
+pub async fn run_migrations(pool: &PgPool) -> Result<Vec<i64>, String> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+    conn.ensure_migrations_table()
+        .await
+        .map_err(|e| format!("Failed to create migrations table: {}", e))?;
+    let already_applied: Vec<i64> = conn
+        .list_applied_migrations()
+        .await
+        .map_err(|e| format!("Failed to list applied migrations: {}", e))?
+        .into_iter()
+        .map(|m| m.version)
+        .collect();
+
+    MIGRATOR
+        .run(&mut *conn)
+        .await
+        .map_err(|e| format!("Migration failed: {}", e))?;
+
+    Ok(MIGRATOR
+        .iter()
+        .map(|m| m.version)
+        .filter(|version| !already_applied.contains(version))
+        .collect())
+}
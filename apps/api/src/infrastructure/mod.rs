@@ -2,4 +2,12 @@
 // Contains database adapters and external service integrations
 // Follows Hexagonal Architecture
 
+pub mod background_tasks;
+pub mod captcha;
+pub mod database;
+pub mod event_logger;
+pub mod feature_flags;
+pub mod migrations;
+pub mod outbox_relay;
 pub mod repositories;
+pub mod webhooks;
@@ -0,0 +1,494 @@
+// Outbox relay
+// Publishes team events queued in the outbox and marks them sent
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use futures::future::join_all;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+
+use crate::domain::repositories::{OutboxMessage, OutboxRepository};
+use crate::domain::team::events::TeamEvent;
+use crate::infrastructure::repositories::PostgresOutboxRepository;
+use crate::infrastructure::webhooks::WebhookDispatcher;
+
+/// How many messages are claimed per pass
+pub const DEFAULT_BATCH_SIZE: i64 = 100;
+
+/// How long the relay sleeps when the outbox is empty
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a claimed batch is hidden from other relays
+///
+/// Must outlast a pass; a relay that crashes mid-pass leaves its messages
+/// to be claimed again once the lease runs out.
+pub const DEFAULT_CLAIM_LEASE: Duration = Duration::from_secs(300);
+
+/// How many times a message is published before it is given up on
+pub const DEFAULT_MAX_ATTEMPTS: i32 = 8;
+
+/// Wait before a failed message is retried; doubled for each later failure
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Longest wait between retries of a failed message
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// How long sent messages are kept before they are pruned
+pub const DEFAULT_SENT_RETENTION: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// How often `run` prunes sent messages
+const PRUNE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Moves events from the outbox to their subscribers
+///
+/// Messages are marked sent only after the [`WebhookDispatcher`] delivered
+/// them to every matching webhook, so a crash in between publishes them
+/// again later: delivery is at-least-once. Failed messages are retried
+/// with exponential backoff and given up on after
+/// [`DEFAULT_MAX_ATTEMPTS`]; given-up messages stay in the outbox unsent.
+///
+/// Companies are published concurrently, each in outbox order, so one
+/// company's slow endpoint does not hold up the others.
+#[derive(Clone)]
+pub struct OutboxRelay {
+    outbox: Arc<dyn OutboxRepository>,
+    webhooks: WebhookDispatcher,
+    batch_size: i64,
+    poll_interval: Duration,
+    claim_lease: Duration,
+    max_attempts: i32,
+    retry_backoff: Duration,
+    sent_retention: Duration,
+}
+
+impl OutboxRelay {
+    /// Creates a relay reading from `outbox`
+    pub fn new(outbox: Arc<dyn OutboxRepository>, webhooks: WebhookDispatcher) -> Self {
+        Self {
+            outbox,
+            webhooks,
+            batch_size: DEFAULT_BATCH_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            claim_lease: DEFAULT_CLAIM_LEASE,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+            sent_retention: DEFAULT_SENT_RETENTION,
+        }
+    }
+
+    /// Creates a relay backed by the `outbox` and `webhooks` tables
+    pub fn postgres(pool: PgPool) -> Self {
+        Self::new(
+            Arc::new(PostgresOutboxRepository::new(pool.clone())),
+            WebhookDispatcher::postgres(pool),
+        )
+    }
+
+    /// Sets how often a message is published and how soon a failed one
+    /// is retried
+    pub fn with_retry(mut self, max_attempts: i32, retry_backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Publishes one batch of due messages
+    ///
+    /// Returns how many messages were claimed, whether they were sent,
+    /// scheduled for a retry, or given up on.
+    pub async fn relay_once(&self) -> Result<usize, String> {
+        let messages = self
+            .outbox
+            .claim_unsent(self.batch_size, self.claim_lease)
+            .await?;
+        let claimed = messages.len();
+
+        let mut by_company: BTreeMap<_, Vec<&OutboxMessage>> = BTreeMap::new();
+        for message in &messages {
+            by_company
+                .entry(message.company_id)
+                .or_default()
+                .push(message);
+        }
+        let outcomes = join_all(by_company.into_values().map(|messages| async move {
+            let mut outcomes = Vec::with_capacity(messages.len());
+            for message in messages {
+                outcomes.push((message, self.publish(message).await));
+            }
+            outcomes
+        }))
+        .await;
+
+        let mut sent = Vec::with_capacity(claimed);
+        for (message, outcome) in outcomes.into_iter().flatten() {
+            match outcome {
+                Ok(()) => sent.push(message.id),
+                Err(failure) => self.record_failure(message, failure).await?,
+            }
+        }
+        if !sent.is_empty() {
+            self.outbox.mark_sent(&sent).await?;
+        }
+
+        Ok(claimed)
+    }
+
+    /// Deletes messages sent longer ago than the retention period
+    pub async fn prune_sent(&self) -> Result<u64, String> {
+        let retention = chrono::Duration::from_std(self.sent_retention)
+            .map_err(|e| format!("Invalid outbox retention: {}", e))?;
+
+        self.outbox.prune_sent(Utc::now() - retention).await
+    }
+
+    /// Relays messages until `shutdown` is cancelled
+    ///
+    /// Full batches are followed immediately by the next one; otherwise
+    /// the relay waits for the poll interval. Sent messages are pruned
+    /// hourly. A pass in progress is finished before returning.
+    pub async fn run(self, shutdown: CancellationToken) {
+        let mut last_pruned: Option<Instant> = None;
+
+        while !shutdown.is_cancelled() {
+            if last_pruned.is_none_or(|at| at.elapsed() >= PRUNE_INTERVAL) {
+                match self.prune_sent().await {
+                    Ok(pruned) if pruned > 0 => {
+                        tracing::info!("Pruned {} sent outbox messages", pruned)
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Outbox pruning failed: {}", e),
+                }
+                last_pruned = Some(Instant::now());
+            }
+
+            match self.relay_once().await {
+                Ok(claimed) if claimed as i64 >= self.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Outbox relay failed: {}", e),
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(self.poll_interval) => {}
+                _ = shutdown.cancelled() => {}
+            }
+        }
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> Result<(), Failure> {
+        let event = serde_json::from_value::<TeamEvent>(message.payload.clone())
+            .map_err(|e| Failure::Unreadable(format!("Unreadable payload: {}", e)))?;
+
+        self.webhooks
+            .deliver_event(message.company_id, message.id, &event)
+            .await
+            .map_err(Failure::Delivery)
+    }
+
+    /// Schedules a retry of `message`, or gives up on it once it has used
+    /// its attempts or can never be published
+    async fn record_failure(
+        &self,
+        message: &OutboxMessage,
+        failure: Failure,
+    ) -> Result<(), String> {
+        let attempt = message.attempts + 1;
+        let (error, retryable) = match failure {
+            Failure::Unreadable(error) => (error, false),
+            Failure::Delivery(error) => (error, attempt < self.max_attempts),
+        };
+        let retry_at = if retryable {
+            let doublings = (attempt - 1).clamp(0, 16) as u32;
+            let backoff = self
+                .retry_backoff
+                .saturating_mul(1 << doublings)
+                .min(MAX_RETRY_BACKOFF);
+            chrono::Duration::from_std(backoff)
+                .ok()
+                .map(|backoff| Utc::now() + backoff)
+        } else {
+            None
+        };
+
+        match retry_at {
+            Some(_) => tracing::warn!(
+                "Outbox message {} failed (attempt {}), retrying: {}",
+                message.id,
+                attempt,
+                error
+            ),
+            None => tracing::error!(
+                "Giving up on outbox message {} after {} attempt(s): {}",
+                message.id,
+                attempt,
+                error
+            ),
+        }
+
+        self.outbox
+            .record_failure(message.id, &error, retry_at)
+            .await
+    }
+}
+
+/// Why a message could not be published
+enum Failure {
+    /// The payload no longer parses; retrying cannot help
+    Unreadable(String),
+    /// Delivery failed and may succeed later
+    Delivery(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::{Webhook, WebhookRepository};
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+    use tokio::sync::Notify;
+    use uuid::Uuid;
+
+    /// An outbox row and its delivery state
+    struct Row {
+        message: OutboxMessage,
+        available_at: DateTime<Utc>,
+        sent: bool,
+        failed: bool,
+    }
+
+    /// In-memory outbox
+    #[derive(Default)]
+    struct MemoryOutbox {
+        rows: Mutex<Vec<Row>>,
+    }
+
+    impl MemoryOutbox {
+        fn push(&self, id: i64, company_id: Uuid, payload: serde_json::Value) {
+            self.rows.lock().unwrap().push(Row {
+                message: OutboxMessage {
+                    id,
+                    company_id,
+                    event_type: "started".to_string(),
+                    payload,
+                    created_at: Utc::now(),
+                    attempts: 0,
+                },
+                available_at: Utc::now(),
+                sent: false,
+                failed: false,
+            });
+        }
+
+        /// `(sent, failed, attempts)` of the first row
+        fn state(&self) -> (bool, bool, i32) {
+            let rows = self.rows.lock().unwrap();
+            (rows[0].sent, rows[0].failed, rows[0].message.attempts)
+        }
+    }
+
+    #[async_trait]
+    impl OutboxRepository for MemoryOutbox {
+        async fn claim_unsent(
+            &self,
+            limit: i64,
+            lease: Duration,
+        ) -> Result<Vec<OutboxMessage>, String> {
+            let now = Utc::now();
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .iter_mut()
+                .filter(|row| !row.sent && !row.failed && row.available_at <= now)
+                .take(limit as usize)
+                .map(|row| {
+                    row.available_at = now + chrono::Duration::from_std(lease).unwrap();
+                    row.message.clone()
+                })
+                .collect())
+        }
+
+        async fn mark_sent(&self, ids: &[i64]) -> Result<(), String> {
+            for row in self.rows.lock().unwrap().iter_mut() {
+                if ids.contains(&row.message.id) {
+                    row.sent = true;
+                }
+            }
+            Ok(())
+        }
+
+        async fn record_failure(
+            &self,
+            id: i64,
+            _error: &str,
+            retry_at: Option<DateTime<Utc>>,
+        ) -> Result<(), String> {
+            for row in self.rows.lock().unwrap().iter_mut() {
+                if row.message.id == id {
+                    row.message.attempts += 1;
+                    match retry_at {
+                        Some(at) => row.available_at = at,
+                        None => row.failed = true,
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        async fn prune_sent(&self, _sent_before: DateTime<Utc>) -> Result<u64, String> {
+            let mut rows = self.rows.lock().unwrap();
+            let before = rows.len();
+            rows.retain(|row| !row.sent);
+            Ok((before - rows.len()) as u64)
+        }
+    }
+
+    struct NoWebhooks;
+
+    #[async_trait]
+    impl WebhookRepository for NoWebhooks {
+        async fn create(&self, _webhook: &Webhook) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn find_for_event(
+            &self,
+            _company_id: Uuid,
+            _event_type: &str,
+        ) -> Result<Vec<Webhook>, String> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Webhook lookups that always fail, so every delivery fails
+    struct BrokenWebhooks;
+
+    #[async_trait]
+    impl WebhookRepository for BrokenWebhooks {
+        async fn create(&self, _webhook: &Webhook) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn find_for_event(
+            &self,
+            _company_id: Uuid,
+            _event_type: &str,
+        ) -> Result<Vec<Webhook>, String> {
+            Err("webhooks unavailable".to_string())
+        }
+    }
+
+    /// Lookups for `slow` wait until a lookup for another company ran
+    struct SlowCompany {
+        slow: Uuid,
+        others_ran: Notify,
+    }
+
+    #[async_trait]
+    impl WebhookRepository for SlowCompany {
+        async fn create(&self, _webhook: &Webhook) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn find_for_event(
+            &self,
+            company_id: Uuid,
+            _event_type: &str,
+        ) -> Result<Vec<Webhook>, String> {
+            if company_id == self.slow {
+                self.others_ran.notified().await;
+            } else {
+                self.others_ran.notify_one();
+            }
+            Ok(Vec::new())
+        }
+    }
+
+    fn started() -> serde_json::Value {
+        let event = TeamEvent::Started {
+            team_id: Uuid::new_v4(),
+        };
+        serde_json::to_value(&event).unwrap()
+    }
+
+    fn relay(outbox: Arc<MemoryOutbox>, webhooks: Arc<dyn WebhookRepository>) -> OutboxRelay {
+        OutboxRelay::new(outbox, WebhookDispatcher::new(webhooks))
+    }
+
+    #[tokio::test]
+    async fn relay_once_marks_published_messages_sent() {
+        let outbox = Arc::new(MemoryOutbox::default());
+        outbox.push(1, Uuid::new_v4(), started());
+
+        let relay = relay(outbox.clone(), Arc::new(NoWebhooks));
+
+        assert_eq!(relay.relay_once().await, Ok(1));
+        assert_eq!(relay.relay_once().await, Ok(0));
+        assert_eq!(outbox.state(), (true, false, 0));
+
+        assert_eq!(relay.outbox.prune_sent(Utc::now()).await, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn unreadable_messages_do_not_block_the_outbox() {
+        let outbox = Arc::new(MemoryOutbox::default());
+        outbox.push(1, Uuid::new_v4(), serde_json::json!({ "type": "unknown" }));
+
+        let relay = relay(outbox.clone(), Arc::new(NoWebhooks));
+
+        // Given up on at once, but not reported as sent
+        assert_eq!(relay.relay_once().await, Ok(1));
+        assert_eq!(outbox.state(), (false, true, 1));
+        assert_eq!(relay.relay_once().await, Ok(0));
+    }
+
+    #[tokio::test]
+    async fn failed_messages_are_retried_then_given_up() {
+        let outbox = Arc::new(MemoryOutbox::default());
+        outbox.push(1, Uuid::new_v4(), started());
+
+        let relay = relay(outbox.clone(), Arc::new(BrokenWebhooks)).with_retry(2, Duration::ZERO);
+
+        assert_eq!(relay.relay_once().await, Ok(1));
+        assert_eq!(outbox.state(), (false, false, 1));
+
+        assert_eq!(relay.relay_once().await, Ok(1));
+        assert_eq!(outbox.state(), (false, true, 2));
+
+        assert_eq!(relay.relay_once().await, Ok(0));
+    }
+
+    #[tokio::test]
+    async fn failed_messages_wait_for_their_backoff() {
+        let outbox = Arc::new(MemoryOutbox::default());
+        outbox.push(1, Uuid::new_v4(), started());
+
+        let relay = relay(outbox.clone(), Arc::new(BrokenWebhooks));
+
+        assert_eq!(relay.relay_once().await, Ok(1));
+        assert_eq!(relay.relay_once().await, Ok(0));
+        assert_eq!(outbox.state(), (false, false, 1));
+    }
+
+    #[tokio::test]
+    async fn a_slow_company_does_not_hold_up_the_others() {
+        let slow = Uuid::from_u128(1);
+        let outbox = Arc::new(MemoryOutbox::default());
+        outbox.push(1, slow, started());
+        outbox.push(2, Uuid::from_u128(2), started());
+
+        let webhooks = Arc::new(SlowCompany {
+            slow,
+            others_ran: Notify::new(),
+        });
+        let relay = relay(outbox.clone(), webhooks);
+
+        // Publishing the companies one after the other would never finish
+        let claimed = tokio::time::timeout(Duration::from_secs(5), relay.relay_once())
+            .await
+            .expect("companies were not published concurrently");
+        assert_eq!(claimed, Ok(2));
+    }
+}
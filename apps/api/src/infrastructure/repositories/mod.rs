@@ -1,8 +1,34 @@
 // Repository implementations (data access layer)
 // Adapters that implement domain repository interfaces
 
+pub mod postgres_company_repository;
+pub mod postgres_cost_repository;
+pub mod postgres_feature_flag_repository;
+pub mod postgres_login_attempt_repository;
+pub mod postgres_manager_repository;
+pub mod postgres_outbox_repository;
+pub mod postgres_password_reset_repository;
+pub mod postgres_task_repository;
+pub mod postgres_team_event_repository;
+pub mod postgres_team_formation;
 pub mod postgres_team_repository;
+pub mod postgres_team_transfer;
 pub mod postgres_user_repository;
+pub mod postgres_webhook_repository;
+pub mod postgres_worker_repository;
 
-pub use postgres_team_repository::PostgresTeamRepository;
+pub use postgres_company_repository::PostgresCompanyRepository;
+pub use postgres_cost_repository::PostgresCostRepository;
+pub use postgres_feature_flag_repository::PostgresFeatureFlagRepository;
+pub use postgres_login_attempt_repository::PostgresLoginAttemptRepository;
+pub use postgres_manager_repository::PostgresManagerRepository;
+pub use postgres_outbox_repository::PostgresOutboxRepository;
+pub use postgres_password_reset_repository::PostgresPasswordResetRepository;
+pub use postgres_task_repository::PostgresTaskRepository;
+pub use postgres_team_event_repository::PostgresTeamEventRepository;
+pub use postgres_team_formation::save_team_formation;
+pub use postgres_team_repository::{PostgresTeamRepository, PostgresTeamRepositoryTx};
+pub use postgres_team_transfer::{export_team, import_team};
 pub use postgres_user_repository::PostgresUserRepository;
+pub use postgres_webhook_repository::PostgresWebhookRepository;
+pub use postgres_worker_repository::PostgresWorkerRepository;
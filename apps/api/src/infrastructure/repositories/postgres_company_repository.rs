@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::CompanyRepository;
+
+/// PostgreSQL implementation of CompanyRepository
+pub struct PostgresCompanyRepository {
+    pool: PgPool,
+}
+
+impl PostgresCompanyRepository {
+    /// Creates a new PostgresCompanyRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CompanyRepository for PostgresCompanyRepository {
+    async fn find_max_active_teams(&self, company_id: Uuid) -> Result<Option<i64>, String> {
+        let row = sqlx::query!(
+            r#"
+            SELECT max_active_teams
+            FROM companies
+            WHERE id = $1
+            "#,
+            company_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find company team quota: {}", e))?;
+
+        Ok(row.and_then(|r| r.max_active_teams).map(i64::from))
+    }
+
+    async fn find_or_create_by_name(&self, name: &str) -> Result<Uuid, String> {
+        let created = sqlx::query_scalar!(
+            r#"
+            INSERT INTO companies (name)
+            VALUES ($1)
+            ON CONFLICT (name) DO NOTHING
+            RETURNING id
+            "#,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create company: {}", e))?;
+
+        if let Some(id) = created {
+            return Ok(id);
+        }
+
+        // The name was taken, so the company already exists
+        sqlx::query_scalar!(
+            r#"
+            SELECT id FROM companies WHERE name = $1
+            "#,
+            name
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find company by name: {}", e))
+    }
+}
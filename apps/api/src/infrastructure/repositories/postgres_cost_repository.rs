@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::cost_repository::{CostEntry, SpecializationCost};
+use crate::domain::repositories::CostRepository;
+
+/// PostgreSQL implementation of CostRepository
+pub struct PostgresCostRepository {
+    pool: PgPool,
+}
+
+impl PostgresCostRepository {
+    /// Creates a new PostgresCostRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CostRepository for PostgresCostRepository {
+    async fn record(&self, entry: &CostEntry) -> Result<(), String> {
+        let input_tokens = i32::try_from(entry.input_tokens)
+            .map_err(|_| format!("Input token count out of range: {}", entry.input_tokens))?;
+        let output_tokens = i32::try_from(entry.output_tokens)
+            .map_err(|_| format!("Output token count out of range: {}", entry.output_tokens))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO cost_tracking (
+                team_id, task_id, worker_id, specialization,
+                model_name, input_tokens, output_tokens, total_cost
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            entry.team_id,
+            entry.task_id,
+            entry.worker_id,
+            entry.specialization.map(|s| s.to_string()),
+            entry.model_name,
+            input_tokens,
+            output_tokens,
+            entry.total_cost
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to record cost: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn breakdown_by_specialization(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Vec<SpecializationCost>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT specialization, SUM(total_cost) as "total!: Decimal"
+            FROM cost_tracking
+            WHERE team_id = $1
+            GROUP BY specialization
+            ORDER BY specialization
+            "#,
+            team_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load cost breakdown: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SpecializationCost {
+                specialization: r.specialization,
+                total: r.total,
+            })
+            .collect())
+    }
+}
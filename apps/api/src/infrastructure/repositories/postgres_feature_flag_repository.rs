@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::FeatureFlagRepository;
+
+/// PostgreSQL implementation of FeatureFlagRepository
+pub struct PostgresFeatureFlagRepository {
+    pool: PgPool,
+}
+
+impl PostgresFeatureFlagRepository {
+    /// Creates a new PostgresFeatureFlagRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for PostgresFeatureFlagRepository {
+    async fn find(&self, company_id: Uuid, flag_name: &str) -> Result<Option<bool>, String> {
+        let row = sqlx::query!(
+            r#"
+            SELECT enabled
+            FROM feature_flags
+            WHERE company_id = $1 AND flag_name = $2
+            "#,
+            company_id,
+            flag_name
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find feature flag: {}", e))?;
+
+        Ok(row.map(|r| r.enabled))
+    }
+
+    async fn set(&self, company_id: Uuid, flag_name: &str, enabled: bool) -> Result<(), String> {
+        sqlx::query!(
+            r#"
+            INSERT INTO feature_flags (company_id, flag_name, enabled)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (company_id, flag_name) DO UPDATE SET
+                enabled = EXCLUDED.enabled,
+                updated_at = NOW()
+            "#,
+            company_id,
+            flag_name,
+            enabled
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to set feature flag: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::repositories::login_attempt_repository::NextAttempts;
+use crate::domain::repositories::{LoginAttemptRepository, LoginAttempts};
+
+/// PostgreSQL implementation of LoginAttemptRepository
+pub struct PostgresLoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    /// Creates a new PostgresLoginAttemptRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn find(&self, email: &str) -> Result<Option<LoginAttempts>, String> {
+        let row = sqlx::query!(
+            r#"
+            SELECT failed_count, window_started_at, locked_until
+            FROM login_attempts
+            WHERE email = $1
+            "#,
+            email
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find login attempts: {}", e))?;
+
+        Ok(row.map(|r| LoginAttempts {
+            failed_count: r.failed_count.max(0) as u32,
+            window_started_at: r.window_started_at,
+            locked_until: r.locked_until,
+        }))
+    }
+
+    async fn record_failure(
+        &self,
+        email: &str,
+        next: &NextAttempts,
+    ) -> Result<LoginAttempts, String> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Serializes failures for one email until commit, including the
+        // first, when there is no row to lock yet
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))",
+            format!("login_attempts:{}", email)
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to lock login attempts: {}", e))?;
+
+        let current = sqlx::query!(
+            r#"
+            SELECT failed_count, window_started_at, locked_until
+            FROM login_attempts
+            WHERE email = $1
+            "#,
+            email
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to find login attempts: {}", e))?
+        .map(|r| LoginAttempts {
+            failed_count: r.failed_count.max(0) as u32,
+            window_started_at: r.window_started_at,
+            locked_until: r.locked_until,
+        });
+
+        let attempts = next(current);
+        sqlx::query!(
+            r#"
+            INSERT INTO login_attempts (email, failed_count, window_started_at, locked_until)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (email) DO UPDATE
+            SET failed_count = EXCLUDED.failed_count,
+                window_started_at = EXCLUDED.window_started_at,
+                locked_until = EXCLUDED.locked_until
+            "#,
+            email,
+            i32::try_from(attempts.failed_count).unwrap_or(i32::MAX),
+            attempts.window_started_at,
+            attempts.locked_until
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to save login attempts: {}", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to save login attempts: {}", e))?;
+
+        Ok(attempts)
+    }
+
+    async fn clear(&self, email: &str) -> Result<(), String> {
+        sqlx::query!("DELETE FROM login_attempts WHERE email = $1", email)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to clear login attempts: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::agents::{AnthropicClient, ManagerAgent, SkillMatching, TokenPricing, WorkerLimits};
+use crate::domain::repositories::ManagerRepository;
+
+/// PostgreSQL implementation of ManagerRepository
+pub struct PostgresManagerRepository {
+    pool: PgPool,
+}
+
+impl PostgresManagerRepository {
+    /// Creates a new PostgresManagerRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Upserts `manager`'s configuration on `conn`
+///
+/// Shared with [`super::save_team_formation`] so the manager can be saved
+/// in the caller's transaction.
+pub(crate) async fn upsert_manager(
+    conn: &mut PgConnection,
+    manager: &ManagerAgent,
+) -> Result<(), String> {
+    sqlx::query!(
+        r#"
+        INSERT INTO managers (
+            id, team_id, model, temperature, max_tokens,
+            min_workers, max_workers, max_concurrent_tasks
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (id) DO UPDATE SET
+            model = EXCLUDED.model,
+            temperature = EXCLUDED.temperature,
+            max_tokens = EXCLUDED.max_tokens,
+            min_workers = EXCLUDED.min_workers,
+            max_workers = EXCLUDED.max_workers,
+            max_concurrent_tasks = EXCLUDED.max_concurrent_tasks
+        "#,
+        manager.id,
+        manager.team_id,
+        manager.model,
+        manager.temperature,
+        manager.max_tokens as i32,
+        column(manager.worker_limits.min())?,
+        column(manager.worker_limits.max())?,
+        column(manager.max_concurrent_tasks)?
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Failed to save manager: {}", e))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ManagerRepository for PostgresManagerRepository {
+    async fn save(&self, manager: &ManagerAgent) -> Result<(), String> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+        upsert_manager(&mut conn, manager).await
+    }
+
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Option<ManagerAgent>, String> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                id, team_id, model, temperature, max_tokens,
+                min_workers, max_workers, max_concurrent_tasks
+            FROM managers
+            WHERE team_id = $1
+            "#,
+            team_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find manager by team: {}", e))?;
+
+        let Some(r) = row else {
+            return Ok(None);
+        };
+        let worker_limits = WorkerLimits::new(
+            usize::try_from(r.min_workers).unwrap_or(0),
+            usize::try_from(r.max_workers).unwrap_or(0),
+        )
+        .map_err(|e| format!("Stored manager {} is invalid: {}", r.id, e))?;
+        let max_concurrent_tasks = usize::try_from(r.max_concurrent_tasks)
+            .ok()
+            .filter(|limit| *limit > 0)
+            .ok_or_else(|| format!("Stored manager {} has no task concurrency", r.id))?;
+
+        Ok(Some(ManagerAgent {
+            id: r.id,
+            team_id: r.team_id,
+            temperature: r.temperature,
+            max_tokens: r.max_tokens as u32,
+            worker_limits,
+            max_concurrent_tasks,
+            skill_matching: SkillMatching::default(),
+            llm: Arc::new(AnthropicClient::from_env()),
+            pricing: TokenPricing::for_model(&r.model),
+            unbilled_usage: Default::default(),
+            cancellation: Default::default(),
+            task_permits: Default::default(),
+            model: r.model,
+        }))
+    }
+}
+
+/// Convert a worker or task limit to its INTEGER column value
+fn column(limit: usize) -> Result<i32, String> {
+    i32::try_from(limit).map_err(|_| format!("Limit {} is too large to store", limit))
+}
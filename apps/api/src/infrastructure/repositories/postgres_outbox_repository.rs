@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::{OutboxMessage, OutboxRepository};
+use crate::domain::team::events::TeamEvent;
+
+/// PostgreSQL implementation of OutboxRepository
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutboxRepository {
+    /// Creates a new PostgresOutboxRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Queues `events` of one company in the outbox on `conn`
+///
+/// Shared with [`super::PostgresTeamRepositoryTx`] so events are queued in
+/// the caller's transaction.
+pub(crate) async fn enqueue_events(
+    conn: &mut PgConnection,
+    company_id: Uuid,
+    events: &[TeamEvent],
+) -> Result<(), String> {
+    for event in events {
+        let payload = serde_json::to_value(event)
+            .map_err(|e| format!("Failed to serialize team event: {}", e))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO outbox (company_id, event_type, payload)
+            VALUES ($1, $2, $3)
+            "#,
+            company_id,
+            event.event_type(),
+            payload
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to queue team event: {}", e))?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn claim_unsent(
+        &self,
+        limit: i64,
+        lease: Duration,
+    ) -> Result<Vec<OutboxMessage>, String> {
+        // SKIP LOCKED lets concurrent relays claim disjoint batches
+        let rows = sqlx::query!(
+            r#"
+            WITH due AS (
+                SELECT id
+                FROM outbox
+                WHERE sent_at IS NULL AND failed_at IS NULL AND available_at <= NOW()
+                ORDER BY id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE outbox
+            SET available_at = NOW() + make_interval(secs => $2)
+            FROM due
+            WHERE outbox.id = due.id
+            RETURNING outbox.id, outbox.company_id, outbox.event_type, outbox.payload,
+                outbox.created_at, outbox.attempts
+            "#,
+            limit,
+            lease.as_secs_f64()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to claim outbox messages: {}", e))?;
+
+        let mut messages: Vec<OutboxMessage> = rows
+            .into_iter()
+            .map(|r| OutboxMessage {
+                id: r.id,
+                company_id: r.company_id,
+                event_type: r.event_type,
+                payload: r.payload,
+                created_at: r.created_at,
+                attempts: r.attempts,
+            })
+            .collect();
+        // RETURNING does not keep the CTE's order
+        messages.sort_by_key(|message| message.id);
+
+        Ok(messages)
+    }
+
+    async fn mark_sent(&self, ids: &[i64]) -> Result<(), String> {
+        sqlx::query!(
+            r#"
+            UPDATE outbox
+            SET sent_at = NOW()
+            WHERE id = ANY($1) AND sent_at IS NULL
+            "#,
+            ids
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to mark outbox messages sent: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        id: i64,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), String> {
+        sqlx::query!(
+            r#"
+            UPDATE outbox
+            SET attempts = attempts + 1,
+                last_error = $2,
+                available_at = COALESCE($3, available_at),
+                failed_at = CASE WHEN $3::timestamptz IS NULL THEN NOW() END
+            WHERE id = $1 AND sent_at IS NULL
+            "#,
+            id,
+            error,
+            retry_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to record outbox failure: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn prune_sent(&self, sent_before: DateTime<Utc>) -> Result<u64, String> {
+        let result = sqlx::query!("DELETE FROM outbox WHERE sent_at < $1", sent_before)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to prune outbox: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::password_reset_repository::{
+    PasswordResetRepository, PasswordResetToken,
+};
+
+/// PostgreSQL implementation of PasswordResetRepository
+pub struct PostgresPasswordResetRepository {
+    pool: PgPool,
+}
+
+impl PostgresPasswordResetRepository {
+    /// Creates a new PostgresPasswordResetRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PostgresPasswordResetRepository {
+    async fn create(&self, token: PasswordResetToken) -> Result<Uuid, String> {
+        sqlx::query!(
+            r#"
+            INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            token.id,
+            token.user_id,
+            token.token_hash,
+            token.expires_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create password reset token: {}", e))?;
+
+        Ok(token.id)
+    }
+
+    async fn reset_password(
+        &self,
+        token_hash: &str,
+        password_hash: &str,
+    ) -> Result<Option<Uuid>, String> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let Some(row) = sqlx::query!(
+            r#"
+            UPDATE password_reset_tokens
+            SET used_at = NOW()
+            WHERE token_hash = $1
+              AND used_at IS NULL
+              AND expires_at > NOW()
+            RETURNING user_id
+            "#,
+            token_hash
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to consume password reset token: {}", e))?
+        else {
+            return Ok(None);
+        };
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE users
+            SET password_hash = $2,
+                token_version = token_version + 1,
+                updated_at = GREATEST(updated_at, NOW())
+            WHERE id = $1
+            "#,
+            row.user_id,
+            password_hash
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update password: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(format!("User not found: {}", row.user_id));
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(Some(row.user_id))
+    }
+}
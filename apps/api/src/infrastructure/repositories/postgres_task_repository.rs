@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::{TaskAssignment, TaskRepository};
+
+/// PostgreSQL implementation of TaskRepository
+pub struct PostgresTaskRepository {
+    pool: PgPool,
+}
+
+impl PostgresTaskRepository {
+    /// Creates a new PostgresTaskRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Applies `assignments` with a single statement on `conn`
+///
+/// Returns an error if any task was not updated; the caller must then
+/// roll back its transaction so no assignment is kept. Shared with
+/// [`super::save_team_formation`].
+pub(crate) async fn assign_tasks(
+    conn: &mut PgConnection,
+    assignments: &[TaskAssignment],
+) -> Result<(), String> {
+    let task_ids: Vec<Uuid> = assignments.iter().map(|a| a.task_id).collect();
+    let worker_ids: Vec<Uuid> = assignments.iter().map(|a| a.worker_id).collect();
+
+    let updated = sqlx::query!(
+        r#"
+        UPDATE tasks
+        SET assigned_to = a.worker_id, status = 'assigned', updated_at = NOW()
+        FROM UNNEST($1::uuid[], $2::uuid[]) AS a(task_id, worker_id)
+        WHERE tasks.id = a.task_id
+        "#,
+        &task_ids,
+        &worker_ids
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Failed to assign tasks: {}", e))?
+    .rows_affected();
+
+    if updated != assignments.len() as u64 {
+        return Err(format!(
+            "Failed to assign tasks: {} of {} tasks found",
+            updated,
+            assignments.len()
+        ));
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl TaskRepository for PostgresTaskRepository {
+    async fn assign_many(&self, assignments: &[TaskAssignment]) -> Result<(), String> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        assign_tasks(&mut tx, assignments).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit task assignments: {}", e))
+    }
+}
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::team_event_repository::{StoredTeamEvent, TeamEventRepository};
+use crate::domain::team::events::TeamEvent;
+
+/// PostgreSQL implementation of TeamEventRepository
+pub struct PostgresTeamEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresTeamEventRepository {
+    /// Creates a new PostgresTeamEventRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Appends `events` to the log on `conn`, returning their sequence numbers
+///
+/// Shared with [`super::PostgresTeamRepositoryTx`] so events can be
+/// recorded in the caller's transaction.
+pub(crate) async fn insert_events(
+    conn: &mut PgConnection,
+    events: &[TeamEvent],
+) -> Result<Vec<i64>, String> {
+    let mut sequences = Vec::with_capacity(events.len());
+
+    for event in events {
+        let payload = serde_json::to_value(event)
+            .map_err(|e| format!("Failed to serialize team event: {}", e))?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO team_events (team_id, event_type, payload)
+            VALUES ($1, $2, $3)
+            RETURNING sequence
+            "#,
+            event.team_id(),
+            event.event_type(),
+            payload
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to append team event: {}", e))?;
+
+        sequences.push(row.sequence);
+    }
+
+    Ok(sequences)
+}
+
+#[async_trait]
+impl TeamEventRepository for PostgresTeamEventRepository {
+    async fn append(&self, events: &[TeamEvent]) -> Result<Vec<i64>, String> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| format!("Failed to acquire connection: {}", e))?;
+
+        insert_events(&mut conn, events).await
+    }
+
+    async fn list_after(
+        &self,
+        team_id: Uuid,
+        after_sequence: i64,
+        limit: i64,
+    ) -> Result<Vec<StoredTeamEvent>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT sequence, team_id, event_type, payload, occurred_at
+            FROM team_events
+            WHERE team_id = $1 AND sequence > $2
+            ORDER BY sequence
+            LIMIT $3
+            "#,
+            team_id,
+            after_sequence,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list team events: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| StoredTeamEvent {
+                sequence: r.sequence,
+                team_id: r.team_id,
+                event_type: r.event_type,
+                payload: r.payload,
+                occurred_at: r.occurred_at,
+            })
+            .collect())
+    }
+
+    async fn find_by_type(
+        &self,
+        company_id: Uuid,
+        event_type: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<StoredTeamEvent>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT e.sequence, e.team_id, e.event_type, e.payload, e.occurred_at
+            FROM team_events e
+            JOIN teams t ON t.id = e.team_id
+            WHERE t.company_id = $1 AND e.event_type = $2
+            ORDER BY e.sequence
+            LIMIT $3 OFFSET $4
+            "#,
+            company_id,
+            event_type,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find team events by type: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| StoredTeamEvent {
+                sequence: r.sequence,
+                team_id: r.team_id,
+                event_type: r.event_type,
+                payload: r.payload,
+                occurred_at: r.occurred_at,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,34 @@
+use sqlx::PgPool;
+
+use crate::agents::{ManagerAgent, WorkerAgent};
+use crate::domain::repositories::TaskAssignment;
+
+use super::postgres_manager_repository::upsert_manager;
+use super::postgres_task_repository::assign_tasks;
+use super::postgres_worker_repository::upsert_workers;
+
+/// Persists a formed team's manager, workers, and task assignments
+/// atomically
+///
+/// Runs `ManagerRepository::save`, `WorkerRepository::save_many`, and
+/// `TaskRepository::assign_many` in one transaction, so either the manager
+/// and every worker and assignment are committed or none are.
+pub async fn save_team_formation(
+    pool: &PgPool,
+    manager: &ManagerAgent,
+    workers: &[WorkerAgent],
+    assignments: &[TaskAssignment],
+) -> Result<(), String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    upsert_manager(&mut tx, manager).await?;
+    upsert_workers(&mut tx, workers).await?;
+    assign_tasks(&mut tx, assignments).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit team formation: {}", e))
+}
@@ -1,18 +1,43 @@
+use async_stream::try_stream;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, TryStreamExt};
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool, Postgres, Transaction};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::domain::repositories::TeamRepository;
+use crate::domain::repositories::{RepositoryError, TeamRepository, TeamRepositoryTx};
+use crate::domain::shared::{Currency, Money};
+use crate::domain::team::events::TeamEvent;
 use crate::domain::team::value_objects::TeamStatus;
-use crate::domain::team::Team;
+use crate::domain::team::{Team, TeamSnapshot};
+
+use super::postgres_outbox_repository::enqueue_events;
+use super::postgres_team_event_repository::insert_events;
+
+/// Name of the partial unique index backing the unique-goal policy
+pub const UNIQUE_GOAL_INDEX: &str = "idx_teams_company_goal_unique";
 
 /// PostgreSQL implementation of TeamRepository
 ///
 /// Provides persistence for Team aggregates using SQLx for compile-time
-/// verified queries against PostgreSQL.
+/// verified queries against PostgreSQL. Queries that load whole teams
+/// share [`TEAM_COLUMNS`] and decode through [`TeamRow`] instead.
+///
+/// Columns added after the initial schema (`amount_spent`, `tags`) are
+/// read as nullable and default to zero / empty, so rows from a table
+/// whose backfill has not finished still load.
+///
+/// `find_*` and `count_*` queries run on a separate read pool, which is
+/// the primary pool unless [`with_read_pool`](Self::with_read_pool) points
+/// it at a replica; writes and transactions always use the primary.
+#[derive(Clone)]
 pub struct PostgresTeamRepository {
     pool: PgPool,
+    read_pool: PgPool,
+    enforce_unique_goal: bool,
+    check_timestamps: bool,
 }
 
 impl PostgresTeamRepository {
@@ -21,157 +46,520 @@ impl PostgresTeamRepository {
     /// # Arguments
     /// * `pool` - SQLx connection pool for PostgreSQL
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+            enforce_unique_goal: false,
+            check_timestamps: cfg!(debug_assertions),
+        }
+    }
+
+    /// Runs `find_*` and `count_*` queries on `read_pool`
+    ///
+    /// Replicas may lag the primary, so callers that write what they read
+    /// should keep reading from the primary.
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    /// Flags newly inserted teams so their goal must be unique among the
+    /// company's non-archived flagged teams
+    ///
+    /// Violations surface from `save` as an error mentioning
+    /// [`UNIQUE_GOAL_INDEX`].
+    pub fn with_unique_goal(mut self, enforce: bool) -> Self {
+        self.enforce_unique_goal = enforce;
+        self
+    }
+
+    /// Rejects loaded teams whose `completed_at` precedes `started_at`
+    ///
+    /// On by default in debug builds; release builds trust stored rows
+    /// unless this is enabled.
+    pub fn with_checked_timestamps(mut self, check: bool) -> Self {
+        self.check_timestamps = check;
+        self
+    }
+
+    /// Streams a company's teams, newest first, one row at a time
+    ///
+    /// Unlike `find_by_company` this never holds the whole result in
+    /// memory, so it suits exports of large companies. The stream owns a
+    /// handle to the pool and ends after the first error.
+    pub fn stream_by_company(&self, company_id: Uuid) -> BoxStream<'static, Result<Team, String>> {
+        let repo = self.clone();
+
+        Box::pin(try_stream! {
+            let sql = format!(
+                r#"
+                SELECT {TEAM_COLUMNS}
+                FROM teams
+                WHERE company_id = $1
+                ORDER BY created_at DESC
+                "#
+            );
+            let mut rows = sqlx::query_as::<_, TeamRow>(&sql)
+                .bind(company_id)
+                .fetch(&repo.read_pool)
+                .map_err(|e| format!("Failed to stream teams by company: {}", e));
+
+            while let Some(row) = rows.try_next().await? {
+                yield row.into_team(repo.check_timestamps)?;
+            }
+        })
     }
 }
 
-#[async_trait]
-impl TeamRepository for PostgresTeamRepository {
-    async fn save(&self, team: &Team) -> Result<(), String> {
-        sqlx::query!(
-            r#"
-            INSERT INTO teams (
-                id, company_id, goal, status, manager_agent_id,
-                created_by, created_at, started_at, completed_at, budget_limit
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            ON CONFLICT (id) DO UPDATE SET
-                goal = EXCLUDED.goal,
-                status = EXCLUDED.status,
-                manager_agent_id = EXCLUDED.manager_agent_id,
-                started_at = EXCLUDED.started_at,
-                completed_at = EXCLUDED.completed_at,
-                budget_limit = EXCLUDED.budget_limit
-            "#,
-            team.id(),
-            team.company_id(),
-            team.goal(),
-            team.status() as TeamStatus,
-            team.manager_agent_id(),
-            team.created_by(),
-            team.created_at(),
-            team.started_at(),
-            team.completed_at(),
-            team.budget_limit()
+/// Rebuilds a budget from its persisted amount and currency code
+fn budget_from_columns(amount: Option<Decimal>, currency: &str) -> Result<Option<Money>, String> {
+    amount
+        .map(|amount| Money::new(amount, currency.parse::<Currency>()?))
+        .transpose()
+        .map_err(|e| format!("Invalid budget from database: {}", e))
+}
+
+/// Columns selected into a [`TeamRow`]
+const TEAM_COLUMNS: &str = "
+    id, company_id, goal, status, manager_agent_id, created_by,
+    created_at, started_at, completed_at,
+    budget_limit, budget_currency, amount_spent, tags, estimated_hours
+";
+
+/// A `teams` row as selected by [`TEAM_COLUMNS`]
+///
+/// `amount_spent` and `tags` are read as nullable; see
+/// [`PostgresTeamRepository`].
+#[derive(sqlx::FromRow)]
+struct TeamRow {
+    id: Uuid,
+    company_id: Uuid,
+    goal: String,
+    status: TeamStatus,
+    manager_agent_id: Option<Uuid>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    budget_limit: Option<Decimal>,
+    budget_currency: String,
+    amount_spent: Option<Decimal>,
+    tags: Option<Vec<String>>,
+    estimated_hours: Option<f32>,
+}
+
+impl TeamRow {
+    /// Rebuilds the team, rejecting it if `check_timestamps` is set and its
+    /// `completed_at` precedes `started_at`
+    fn into_team(self, check_timestamps: bool) -> Result<Team, String> {
+        let team = Team::from_persistence(
+            self.id,
+            self.company_id,
+            self.goal,
+            self.status,
+            self.manager_agent_id,
+            self.created_by,
+            self.created_at,
+            self.started_at,
+            self.completed_at,
+            budget_from_columns(self.budget_limit, &self.budget_currency)?,
+            self.amount_spent.unwrap_or_default(),
+            self.tags.unwrap_or_default(),
+            self.estimated_hours,
+        );
+        if check_timestamps {
+            team.check_timestamps()
+                .map_err(|e| format!("Invalid team from database: {}", e))?;
+        }
+        Ok(team)
+    }
+}
+
+/// Inserts or updates `team` on `executor`
+///
+/// Shared by the pool-backed repository and [`PostgresTeamRepositoryTx`].
+async fn upsert_team<'e>(
+    executor: impl PgExecutor<'e>,
+    team: &Team,
+    enforce_unique_goal: bool,
+) -> Result<(), String> {
+    sqlx::query!(
+        r#"
+        INSERT INTO teams (
+            id, company_id, goal, status, manager_agent_id,
+            created_by, created_at, started_at, completed_at, budget_limit,
+            budget_currency, amount_spent, enforce_unique_goal, tags,
+            estimated_hours
         )
-        .execute(&self.pool)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (id) DO UPDATE SET
+            goal = EXCLUDED.goal,
+            status = EXCLUDED.status,
+            manager_agent_id = EXCLUDED.manager_agent_id,
+            created_by = EXCLUDED.created_by,
+            started_at = EXCLUDED.started_at,
+            completed_at = EXCLUDED.completed_at,
+            budget_limit = EXCLUDED.budget_limit,
+            budget_currency = EXCLUDED.budget_currency,
+            amount_spent = EXCLUDED.amount_spent,
+            tags = EXCLUDED.tags,
+            estimated_hours = EXCLUDED.estimated_hours
+        "#,
+        team.id(),
+        team.company_id(),
+        team.goal(),
+        team.status() as TeamStatus,
+        team.manager_agent_id(),
+        team.created_by(),
+        team.created_at(),
+        team.started_at(),
+        team.completed_at(),
+        team.budget_limit().map(|b| b.amount()),
+        team.budget_limit()
+            .map(|b| b.currency())
+            .unwrap_or_default()
+            .code(),
+        team.amount_spent(),
+        enforce_unique_goal,
+        team.tags(),
+        team.estimated_hours()
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| format!("Failed to save team: {}", e))?;
+
+    Ok(())
+}
+
+/// Inserts `team` on `executor`, never overwriting an existing row
+///
+/// A taken ID fails with an error naming the `teams_pkey` constraint.
+pub(crate) async fn insert_team<'e>(
+    executor: impl PgExecutor<'e>,
+    team: &Team,
+    enforce_unique_goal: bool,
+) -> Result<(), String> {
+    sqlx::query!(
+        r#"
+        INSERT INTO teams (
+            id, company_id, goal, status, manager_agent_id,
+            created_by, created_at, started_at, completed_at, budget_limit,
+            budget_currency, amount_spent, enforce_unique_goal, tags,
+            estimated_hours
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        "#,
+        team.id(),
+        team.company_id(),
+        team.goal(),
+        team.status() as TeamStatus,
+        team.manager_agent_id(),
+        team.created_by(),
+        team.created_at(),
+        team.started_at(),
+        team.completed_at(),
+        team.budget_limit().map(|b| b.amount()),
+        team.budget_limit()
+            .map(|b| b.currency())
+            .unwrap_or_default()
+            .code(),
+        team.amount_spent(),
+        enforce_unique_goal,
+        team.tags(),
+        team.estimated_hours()
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| format!("Failed to insert team: {}", e))?;
+
+    Ok(())
+}
+
+/// Loads a team by ID on `executor`
+async fn fetch_team_by_id<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+    check_timestamps: bool,
+) -> Result<Option<Team>, String> {
+    let sql = format!(
+        r#"
+        SELECT {TEAM_COLUMNS}
+        FROM teams
+        WHERE id = $1
+        "#
+    );
+    let row = sqlx::query_as::<_, TeamRow>(&sql)
+        .bind(id)
+        .fetch_optional(executor)
         .await
-        .map_err(|e| format!("Failed to save team: {}", e))?;
+        .map_err(|e| format!("Failed to find team by id: {}", e))?;
 
-        Ok(())
+    row.map(|row| row.into_team(check_timestamps)).transpose()
+}
+
+/// Loads a company's teams, newest first, on `executor`
+async fn fetch_teams_by_company<'e>(
+    executor: impl PgExecutor<'e>,
+    company_id: Uuid,
+    check_timestamps: bool,
+) -> Result<Vec<Team>, String> {
+    let sql = format!(
+        r#"
+        SELECT {TEAM_COLUMNS}
+        FROM teams
+        WHERE company_id = $1
+        ORDER BY created_at DESC
+        "#
+    );
+    let rows = sqlx::query_as::<_, TeamRow>(&sql)
+        .bind(company_id)
+        .fetch_all(executor)
+        .await
+        .map_err(|e| format!("Failed to find teams by company: {}", e))?;
+
+    rows.into_iter()
+        .map(|row| row.into_team(check_timestamps))
+        .collect()
+}
+
+#[async_trait]
+impl TeamRepository for PostgresTeamRepository {
+    async fn save(&self, team: &Team) -> Result<(), String> {
+        upsert_team(&self.pool, team, self.enforce_unique_goal).await
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Team>, String> {
+        fetch_team_by_id(&self.read_pool, id, self.check_timestamps).await
+    }
+
+    async fn find_snapshot_by_id(&self, id: Uuid) -> Result<Option<TeamSnapshot>, String> {
         let row = sqlx::query!(
             r#"
             SELECT
                 id, company_id, goal,
                 status as "status: TeamStatus",
-                manager_agent_id, created_by,
-                created_at, started_at, completed_at,
-                budget_limit as "budget_limit: Decimal"
+                created_by,
+                budget_limit as "budget_limit: Decimal",
+                budget_currency,
+                tags as "tags?",
+                created_at, started_at, estimated_hours
             FROM teams
             WHERE id = $1
             "#,
             id
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&self.read_pool)
         .await
-        .map_err(|e| format!("Failed to find team by id: {}", e))?;
+        .map_err(|e| format!("Failed to find team snapshot by id: {}", e))?;
 
-        Ok(row.map(|r| {
-            Team::from_persistence(
-                r.id,
-                r.company_id,
-                r.goal,
-                r.status,
-                r.manager_agent_id,
-                r.created_by,
-                r.created_at,
-                r.started_at,
-                r.completed_at,
-                r.budget_limit,
-            )
-        }))
+        row.map(|r| {
+            Ok(TeamSnapshot {
+                id: r.id,
+                company_id: r.company_id,
+                goal: r.goal,
+                status: r.status,
+                created_by: r.created_by,
+                budget_limit: budget_from_columns(r.budget_limit, &r.budget_currency)?,
+                tags: r.tags.unwrap_or_default(),
+                created_at: r.created_at,
+                started_at: r.started_at,
+                estimated_hours: r.estimated_hours,
+            })
+        })
+        .transpose()
     }
 
     async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Team>, String> {
+        fetch_teams_by_company(&self.read_pool, company_id, self.check_timestamps).await
+    }
+
+    async fn find_by_ids(&self, company_id: Uuid, ids: &[Uuid]) -> Result<Vec<Team>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let sql = format!(
+            r#"
+            SELECT {TEAM_COLUMNS}
+            FROM teams
+            WHERE company_id = $1 AND id = ANY($2)
+            ORDER BY array_position($2, id)
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(company_id)
+            .bind(ids)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find teams by ids: {}", e))?;
+
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
+    }
+
+    async fn find_by_statuses(
+        &self,
+        company_id: Uuid,
+        statuses: &[TeamStatus],
+    ) -> Result<Vec<Team>, String> {
+        let sql = format!(
+            r#"
+            SELECT {TEAM_COLUMNS}
+            FROM teams
+            WHERE company_id = $1 AND status = ANY($2)
+            ORDER BY created_at DESC
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(company_id)
+            .bind(statuses)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find teams by status: {}", e))?;
+
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
+    }
+
+    async fn find_created_between(
+        &self,
+        company_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Team>, String> {
+        let sql = format!(
+            r#"
+            SELECT {TEAM_COLUMNS}
+            FROM teams
+            WHERE company_id = $1 AND created_at BETWEEN $2 AND $3
+            ORDER BY created_at DESC
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(company_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find teams by creation time: {}", e))?;
+
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
+    }
+
+    async fn find_stale(
+        &self,
+        status: TeamStatus,
+        older_than: DateTime<Utc>,
+    ) -> Result<Vec<Team>, String> {
+        let sql = format!(
+            r#"
+            SELECT {TEAM_COLUMNS}
+            FROM teams
+            WHERE status = $1 AND created_at < $2
+            ORDER BY created_at ASC
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(status)
+            .bind(older_than)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find stale teams: {}", e))?;
+
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
+    }
+
+    async fn find_by_tag(&self, company_id: Uuid, tag: &str) -> Result<Vec<Team>, String> {
+        let sql = format!(
+            r#"
+            SELECT {TEAM_COLUMNS}
+            FROM teams
+            WHERE company_id = $1 AND tags @> ARRAY[$2]
+            ORDER BY created_at DESC
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(company_id)
+            .bind(tag)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find teams by tag: {}", e))?;
+
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
+    }
+
+    async fn count_active(&self, company_id: Uuid) -> Result<i64, String> {
+        let terminal: Vec<TeamStatus> = TeamStatus::ALL
+            .into_iter()
+            .filter(TeamStatus::is_terminal)
+            .collect();
+
+        sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM teams
+            WHERE company_id = $1 AND status <> ALL($2)
+            "#,
+            company_id,
+            &terminal as &[TeamStatus]
+        )
+        .fetch_one(&self.read_pool)
+        .await
+        .map_err(|e| format!("Failed to count active teams: {}", e))
+    }
+
+    async fn count_by_status(&self, company_id: Uuid) -> Result<HashMap<TeamStatus, i64>, String> {
         let rows = sqlx::query!(
             r#"
-            SELECT
-                id, company_id, goal,
-                status as "status: TeamStatus",
-                manager_agent_id, created_by,
-                created_at, started_at, completed_at,
-                budget_limit as "budget_limit: Decimal"
+            SELECT status as "status: TeamStatus", COUNT(*) as "count!"
             FROM teams
             WHERE company_id = $1
-            ORDER BY created_at DESC
+            GROUP BY status
             "#,
             company_id
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&self.read_pool)
         .await
-        .map_err(|e| format!("Failed to find teams by company: {}", e))?;
+        .map_err(|e| format!("Failed to count teams by status: {}", e))?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| {
-                Team::from_persistence(
-                    r.id,
-                    r.company_id,
-                    r.goal,
-                    r.status,
-                    r.manager_agent_id,
-                    r.created_by,
-                    r.created_at,
-                    r.started_at,
-                    r.completed_at,
-                    r.budget_limit,
-                )
-            })
-            .collect())
+        let mut counts: HashMap<TeamStatus, i64> =
+            TeamStatus::ALL.iter().map(|status| (*status, 0)).collect();
+        for row in rows {
+            counts.insert(row.status, row.count);
+        }
+
+        Ok(counts)
     }
 
     async fn find_by_creator(&self, user_id: Uuid) -> Result<Vec<Team>, String> {
-        let rows = sqlx::query!(
+        let sql = format!(
             r#"
-            SELECT
-                id, company_id, goal,
-                status as "status: TeamStatus",
-                manager_agent_id, created_by,
-                created_at, started_at, completed_at,
-                budget_limit as "budget_limit: Decimal"
+            SELECT {TEAM_COLUMNS}
             FROM teams
             WHERE created_by = $1
             ORDER BY created_at DESC
-            "#,
-            user_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| format!("Failed to find teams by creator: {}", e))?;
+            "#
+        );
+        let rows = sqlx::query_as::<_, TeamRow>(&sql)
+            .bind(user_id)
+            .fetch_all(&self.read_pool)
+            .await
+            .map_err(|e| format!("Failed to find teams by creator: {}", e))?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| {
-                Team::from_persistence(
-                    r.id,
-                    r.company_id,
-                    r.goal,
-                    r.status,
-                    r.manager_agent_id,
-                    r.created_by,
-                    r.created_at,
-                    r.started_at,
-                    r.completed_at,
-                    r.budget_limit,
-                )
-            })
-            .collect())
+        rows.into_iter()
+            .map(|row| row.into_team(self.check_timestamps))
+            .collect()
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
         let result = sqlx::query!(
             r#"
             DELETE FROM teams WHERE id = $1
@@ -180,12 +568,96 @@ impl TeamRepository for PostgresTeamRepository {
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| format!("Failed to delete team: {}", e))?;
+        .map_err(|e| RepositoryError::Database(format!("Failed to delete team: {}", e)))?;
 
         if result.rows_affected() == 0 {
-            return Err(format!("Team not found: {}", id));
+            return Err(RepositoryError::NotFound { entity: "Team", id });
         }
 
         Ok(())
     }
+
+    async fn delete_many(&self, company_id: Uuid, ids: &[Uuid]) -> Result<Vec<Uuid>, String> {
+        // A single statement runs in its own transaction
+        let rows = sqlx::query!(
+            r#"
+            DELETE FROM teams
+            WHERE company_id = $1 AND id = ANY($2)
+            RETURNING id
+            "#,
+            company_id,
+            ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to delete teams: {}", e))?;
+
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    async fn begin(&self) -> Result<Box<dyn TeamRepositoryTx>, String> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        Ok(Box::new(PostgresTeamRepositoryTx {
+            repo: self.clone(),
+            tx,
+        }))
+    }
+}
+
+/// Transactional handle returned by [`PostgresTeamRepository::begin`]
+///
+/// Uses the settings of the repository it was started from. Dropping the
+/// handle without committing rolls the transaction back.
+pub struct PostgresTeamRepositoryTx {
+    repo: PostgresTeamRepository,
+    tx: Transaction<'static, Postgres>,
+}
+
+#[async_trait]
+impl TeamRepositoryTx for PostgresTeamRepositoryTx {
+    async fn save(&mut self, team: &Team) -> Result<(), String> {
+        upsert_team(&mut *self.tx, team, self.repo.enforce_unique_goal).await
+    }
+
+    async fn insert(&mut self, team: &Team) -> Result<(), String> {
+        insert_team(&mut *self.tx, team, self.repo.enforce_unique_goal).await
+    }
+
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Team>, String> {
+        fetch_team_by_id(&mut *self.tx, id, self.repo.check_timestamps).await
+    }
+
+    async fn find_by_company(&mut self, company_id: Uuid) -> Result<Vec<Team>, String> {
+        fetch_teams_by_company(&mut *self.tx, company_id, self.repo.check_timestamps).await
+    }
+
+    async fn record_events(
+        &mut self,
+        company_id: Uuid,
+        events: &[TeamEvent],
+    ) -> Result<Vec<i64>, String> {
+        let sequences = insert_events(&mut self.tx, events).await?;
+        enqueue_events(&mut self.tx, company_id, events).await?;
+
+        Ok(sequences)
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), String> {
+        self.tx
+            .commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), String> {
+        self.tx
+            .rollback()
+            .await
+            .map_err(|e| format!("Failed to roll back transaction: {}", e))
+    }
 }
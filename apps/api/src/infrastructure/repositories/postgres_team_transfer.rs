@@ -0,0 +1,348 @@
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::team::export::{
+    ExportedEvent, ExportedManager, ExportedTask, ExportedTeam, ExportedWorker,
+};
+use crate::domain::team::value_objects::TeamStatus;
+use crate::domain::team::{Team, TeamExport};
+
+use super::postgres_team_repository::insert_team;
+
+/// Reads a team with its manager, workers, tasks, and event log
+///
+/// All rows are read in one transaction so the export is a consistent
+/// snapshot.
+///
+/// # Returns
+/// * `Ok(Some(TeamExport))` - The team and everything attached to it
+/// * `Ok(None)` - No team has this ID
+/// * `Err(String)` - If a query fails
+pub async fn export_team(pool: &PgPool, team_id: Uuid) -> Result<Option<TeamExport>, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    let Some(team) = sqlx::query!(
+        r#"
+        SELECT
+            id, company_id, goal,
+            status as "status: TeamStatus",
+            manager_agent_id, created_by,
+            created_at, started_at, completed_at,
+            budget_limit as "budget_limit: Decimal",
+            budget_currency,
+            amount_spent as "amount_spent?: Decimal",
+            tags as "tags?",
+            estimated_hours
+        FROM teams
+        WHERE id = $1
+        "#,
+        team_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to export team: {}", e))?
+    else {
+        return Ok(None);
+    };
+
+    let manager = sqlx::query!(
+        r#"
+        SELECT
+            id, model, temperature, max_tokens,
+            min_workers, max_workers, max_concurrent_tasks
+        FROM managers
+        WHERE team_id = $1
+        "#,
+        team_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to export manager: {}", e))?;
+
+    let workers = sqlx::query!(
+        r#"
+        SELECT
+            id, agent_id,
+            role::text as "role!",
+            specialization,
+            status::text as "status!",
+            current_workload, max_concurrent_tasks, joined_at, skills
+        FROM team_members
+        WHERE team_id = $1
+        ORDER BY joined_at, id
+        "#,
+        team_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to export workers: {}", e))?;
+
+    let tasks = sqlx::query!(
+        r#"
+        SELECT
+            id, parent_task_id, title, description, acceptance_criteria,
+            assigned_to, assigned_by,
+            status::text as "status!",
+            start_time, completion_time, revision_count, max_revisions,
+            input_data, output_data, created_at, updated_at
+        FROM tasks
+        WHERE team_id = $1
+        ORDER BY created_at, id
+        "#,
+        team_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to export tasks: {}", e))?;
+
+    let events = sqlx::query!(
+        r#"
+        SELECT event_type, payload, occurred_at
+        FROM team_events
+        WHERE team_id = $1
+        ORDER BY sequence
+        "#,
+        team_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to export events: {}", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to finish export: {}", e))?;
+
+    Ok(Some(TeamExport {
+        team: ExportedTeam {
+            id: team.id,
+            company_id: team.company_id,
+            goal: team.goal,
+            status: team.status,
+            manager_agent_id: team.manager_agent_id,
+            created_by: team.created_by,
+            created_at: team.created_at,
+            started_at: team.started_at,
+            completed_at: team.completed_at,
+            budget_limit: team.budget_limit,
+            budget_currency: team.budget_currency,
+            amount_spent: team.amount_spent.unwrap_or_default(),
+            tags: team.tags.unwrap_or_default(),
+            estimated_hours: team.estimated_hours,
+        },
+        manager: manager.map(|m| ExportedManager {
+            id: m.id,
+            model: m.model,
+            temperature: m.temperature,
+            max_tokens: m.max_tokens,
+            min_workers: m.min_workers,
+            max_workers: m.max_workers,
+            max_concurrent_tasks: m.max_concurrent_tasks,
+        }),
+        workers: workers
+            .into_iter()
+            .map(|w| ExportedWorker {
+                id: w.id,
+                agent_id: w.agent_id,
+                role: w.role,
+                specialization: w.specialization,
+                status: w.status,
+                current_workload: w.current_workload,
+                max_concurrent_tasks: w.max_concurrent_tasks,
+                joined_at: w.joined_at,
+                skills: w.skills,
+            })
+            .collect(),
+        tasks: tasks
+            .into_iter()
+            .map(|t| ExportedTask {
+                id: t.id,
+                parent_task_id: t.parent_task_id,
+                title: t.title,
+                description: t.description,
+                acceptance_criteria: t.acceptance_criteria,
+                assigned_to: t.assigned_to,
+                assigned_by: t.assigned_by,
+                status: t.status,
+                start_time: t.start_time,
+                completion_time: t.completion_time,
+                revision_count: t.revision_count,
+                max_revisions: t.max_revisions,
+                input_data: t.input_data,
+                output_data: t.output_data,
+                created_at: t.created_at,
+                updated_at: t.updated_at,
+            })
+            .collect(),
+        events: events
+            .into_iter()
+            .map(|e| ExportedEvent {
+                event_type: e.event_type,
+                payload: e.payload,
+                occurred_at: e.occurred_at,
+            })
+            .collect(),
+    }))
+}
+
+/// Returns which of `ids` already identify a team, manager, worker, or task
+///
+/// Every company's rows are searched, since IDs are global.
+pub async fn find_taken_ids(pool: &PgPool, ids: &[Uuid]) -> Result<Vec<Uuid>, String> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT id as "id!" FROM teams WHERE id = ANY($1)
+        UNION SELECT id FROM managers WHERE id = ANY($1)
+        UNION SELECT id FROM team_members WHERE id = ANY($1)
+        UNION SELECT id FROM tasks WHERE id = ANY($1)
+        "#,
+        ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to check IDs: {}", e))
+}
+
+/// Stores `team` with the manager, workers, tasks, and events of `export`
+///
+/// `team` is the validated aggregate built from `export` (see
+/// `TeamExport::validated_team`) and decides the stored company and
+/// creator. Everything is inserted in one transaction, so a failure
+/// leaves no partial team behind. Existing IDs are never overwritten:
+/// importing an ID that is already taken fails with an error naming the
+/// table's primary key. `enforce_unique_goal` flags the team like
+/// `PostgresTeamRepository::with_unique_goal`.
+pub async fn import_team(
+    pool: &PgPool,
+    team: &Team,
+    export: &TeamExport,
+    enforce_unique_goal: bool,
+) -> Result<(), String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    insert_team(&mut *tx, team, enforce_unique_goal).await?;
+
+    if let Some(manager) = &export.manager {
+        sqlx::query!(
+            r#"
+            INSERT INTO managers (
+                id, team_id, model, temperature, max_tokens,
+                min_workers, max_workers, max_concurrent_tasks
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            manager.id,
+            team.id(),
+            manager.model,
+            manager.temperature,
+            manager.max_tokens,
+            manager.min_workers,
+            manager.max_workers,
+            manager.max_concurrent_tasks
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to import manager {}: {}", manager.id, e))?;
+    }
+
+    for worker in &export.workers {
+        sqlx::query!(
+            r#"
+            INSERT INTO team_members (
+                id, team_id, agent_id, role, specialization, status,
+                current_workload, max_concurrent_tasks, joined_at, skills
+            )
+            VALUES (
+                $1, $2, $3, $4::text::member_role, $5, $6::text::member_status,
+                $7, $8, $9, $10
+            )
+            "#,
+            worker.id,
+            team.id(),
+            worker.agent_id,
+            worker.role,
+            worker.specialization,
+            worker.status,
+            worker.current_workload,
+            worker.max_concurrent_tasks,
+            worker.joined_at,
+            &worker.skills
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to import worker {}: {}", worker.id, e))?;
+    }
+
+    // Parents are linked after every task exists, so order does not matter
+    for task in &export.tasks {
+        sqlx::query!(
+            r#"
+            INSERT INTO tasks (
+                id, team_id, title, description, acceptance_criteria,
+                assigned_to, assigned_by, status, start_time, completion_time,
+                revision_count, max_revisions, input_data, output_data,
+                created_at, updated_at
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8::text::task_status, $9, $10,
+                $11, $12, $13, $14, $15, $16
+            )
+            "#,
+            task.id,
+            team.id(),
+            task.title,
+            task.description,
+            task.acceptance_criteria,
+            task.assigned_to,
+            task.assigned_by,
+            task.status,
+            task.start_time,
+            task.completion_time,
+            task.revision_count,
+            task.max_revisions,
+            task.input_data,
+            task.output_data,
+            task.created_at,
+            task.updated_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to import task {}: {}", task.id, e))?;
+    }
+    for task in export.tasks.iter().filter(|t| t.parent_task_id.is_some()) {
+        sqlx::query!(
+            "UPDATE tasks SET parent_task_id = $2 WHERE id = $1",
+            task.id,
+            task.parent_task_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to link task {}: {}", task.id, e))?;
+    }
+
+    for event in &export.events {
+        sqlx::query!(
+            r#"
+            INSERT INTO team_events (team_id, event_type, payload, occurred_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            team.id(),
+            event.event_type,
+            event.payload,
+            event.occurred_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to import event: {}", e))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit team import: {}", e))
+}
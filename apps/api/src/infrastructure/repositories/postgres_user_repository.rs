@@ -1,19 +1,44 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::domain::repositories::user_repository::{User, UserRepository};
-use crate::domain::user::value_objects::Email;
+use crate::domain::repositories::user_repository::{TeamHandling, User, UserRepository};
+use crate::domain::team::value_objects::TeamStatus;
+use crate::domain::user::value_objects::{Email, UserRole};
 
 /// PostgreSQL implementation of UserRepository
+///
+/// Like [`super::PostgresTeamRepository`], `find_*` and `count_*` queries
+/// run on the read pool and everything else on the primary.
 pub struct PostgresUserRepository {
     pool: PgPool,
+    read_pool: PgPool,
+    enforce_unique_goal: bool,
 }
 
 impl PostgresUserRepository {
     /// Creates a new PostgresUserRepository
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            read_pool: pool.clone(),
+            pool,
+            enforce_unique_goal: false,
+        }
+    }
+
+    /// Runs `find_*` and `count_*` queries on `read_pool`
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    /// Flags teams moved by `change_company` so their goal must be unique
+    /// in the new company, like
+    /// [`PostgresTeamRepository::with_unique_goal`](super::PostgresTeamRepository::with_unique_goal)
+    pub fn with_unique_goal(mut self, enforce: bool) -> Self {
+        self.enforce_unique_goal = enforce;
+        self
     }
 }
 
@@ -23,16 +48,20 @@ impl UserRepository for PostgresUserRepository {
         sqlx::query!(
             r#"
             INSERT INTO users (
-                id, company_id, email, password_hash, full_name, is_active
+                id, company_id, email, password_hash, full_name, is_active, role,
+                created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
             user.id,
             user.company_id,
             user.email.as_str(),
             user.password_hash,
             user.full_name,
-            user.is_active
+            user.is_active,
+            user.role as UserRole,
+            user.created_at,
+            user.updated_at
         )
         .execute(&self.pool)
         .await
@@ -44,93 +73,202 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, String> {
         let row = sqlx::query!(
             r#"
-            SELECT id, company_id, email, password_hash, full_name, is_active
+            SELECT id, company_id, email, password_hash, full_name, is_active,
+                role as "role: UserRole", created_at, updated_at
             FROM users
             WHERE id = $1
             "#,
             id
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&self.read_pool)
         .await
         .map_err(|e| format!("Failed to find user by id: {}", e))?;
 
-        Ok(row
-            .map(|r| {
-                Email::new(&r.email).map(|email| User {
-                    id: r.id,
-                    company_id: r.company_id,
-                    email,
-                    password_hash: r.password_hash,
-                    full_name: r.full_name,
-                    is_active: r.is_active,
-                })
-            })
-            .transpose()
-            .map_err(|e| format!("Invalid email from database: {}", e))?)
+        Ok(row.map(|r| User {
+            id: r.id,
+            company_id: r.company_id,
+            email: Email::new_unchecked(r.email),
+            password_hash: r.password_hash,
+            full_name: r.full_name,
+            is_active: r.is_active,
+            role: r.role,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }))
     }
 
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, String> {
         let row = sqlx::query!(
             r#"
-            SELECT id, company_id, email, password_hash, full_name, is_active
+            SELECT id, company_id, email, password_hash, full_name, is_active,
+                role as "role: UserRole", created_at, updated_at
             FROM users
             WHERE email = $1
             "#,
             email.as_str()
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&self.read_pool)
         .await
         .map_err(|e| format!("Failed to find user by email: {}", e))?;
 
-        Ok(row
-            .map(|r| {
-                Email::new(&r.email).map(|email| User {
-                    id: r.id,
-                    company_id: r.company_id,
-                    email,
-                    password_hash: r.password_hash,
-                    full_name: r.full_name,
-                    is_active: r.is_active,
-                })
+        Ok(row.map(|r| User {
+            id: r.id,
+            company_id: r.company_id,
+            email: Email::new_unchecked(r.email),
+            password_hash: r.password_hash,
+            full_name: r.full_name,
+            is_active: r.is_active,
+            role: r.role,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }))
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, company_id, email, password_hash, full_name, is_active,
+                role as "role: UserRole", created_at, updated_at
+            FROM users
+            WHERE id = ANY($1)
+            ORDER BY array_position($1, id)
+            "#,
+            ids
+        )
+        .fetch_all(&self.read_pool)
+        .await
+        .map_err(|e| format!("Failed to find users by ids: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| User {
+                id: r.id,
+                company_id: r.company_id,
+                email: Email::new_unchecked(r.email),
+                password_hash: r.password_hash,
+                full_name: r.full_name,
+                is_active: r.is_active,
+                role: r.role,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
             })
-            .transpose()
-            .map_err(|e| format!("Invalid email from database: {}", e))?)
+            .collect())
     }
 
     async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<User>, String> {
         let rows = sqlx::query!(
             r#"
-            SELECT id, company_id, email, password_hash, full_name, is_active
+            SELECT id, company_id, email, password_hash, full_name, is_active,
+                role as "role: UserRole", created_at, updated_at
             FROM users
             WHERE company_id = $1
             ORDER BY full_name
             "#,
             company_id
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&self.read_pool)
         .await
         .map_err(|e| format!("Failed to find users by company: {}", e))?;
 
-        rows.into_iter()
-            .map(|r| {
-                Email::new(&r.email).map(|email| User {
-                    id: r.id,
-                    company_id: r.company_id,
-                    email,
-                    password_hash: r.password_hash,
-                    full_name: r.full_name,
-                    is_active: r.is_active,
-                })
+        // Addresses that fail today's rules still load (see
+        // `Email::new_unchecked`), so one legacy row cannot empty the
+        // listing; they are reported so they can be cleaned up
+        for r in rows
+            .iter()
+            .filter(|r| Email::new(r.email.as_str()).is_err())
+        {
+            tracing::warn!(
+                user_id = %r.id,
+                %company_id,
+                "Listing user whose stored email fails validation"
+            );
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|r| User {
+                id: r.id,
+                company_id: r.company_id,
+                email: Email::new_unchecked(r.email),
+                password_hash: r.password_hash,
+                full_name: r.full_name,
+                is_active: r.is_active,
+                role: r.role,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
             })
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Invalid email from database: {}", e))
+            .collect())
+    }
+
+    async fn find_by_company_paginated(
+        &self,
+        company_id: Uuid,
+        active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, company_id, email, password_hash, full_name, is_active,
+                role as "role: UserRole", created_at, updated_at
+            FROM users
+            WHERE company_id = $1 AND ($2::BOOLEAN IS NULL OR is_active = $2)
+            ORDER BY full_name, id
+            LIMIT $3 OFFSET $4
+            "#,
+            company_id,
+            active,
+            limit,
+            offset
+        )
+        .fetch_all(&self.read_pool)
+        .await
+        .map_err(|e| format!("Failed to find users by company: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| User {
+                id: r.id,
+                company_id: r.company_id,
+                email: Email::new_unchecked(r.email),
+                password_hash: r.password_hash,
+                full_name: r.full_name,
+                is_active: r.is_active,
+                role: r.role,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            })
+            .collect())
+    }
+
+    async fn count_by_company(
+        &self,
+        company_id: Uuid,
+        active: Option<bool>,
+    ) -> Result<i64, String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM users
+            WHERE company_id = $1 AND ($2::BOOLEAN IS NULL OR is_active = $2)
+            "#,
+            company_id,
+            active
+        )
+        .fetch_one(&self.read_pool)
+        .await
+        .map_err(|e| format!("Failed to count users: {}", e))
     }
 
     async fn update_last_login(&self, user_id: Uuid) -> Result<(), String> {
         sqlx::query!(
             r#"
             UPDATE users
-            SET last_login = NOW(), updated_at = NOW()
+            SET last_login = GREATEST(last_login, NOW())
             WHERE id = $1
             "#,
             user_id
@@ -141,4 +279,173 @@ impl UserRepository for PostgresUserRepository {
 
         Ok(())
     }
+
+    async fn update_profile(
+        &self,
+        user_id: Uuid,
+        full_name: &str,
+        email: &Email,
+    ) -> Result<DateTime<Utc>, String> {
+        sqlx::query_scalar!(
+            r#"
+            UPDATE users
+            SET full_name = $2,
+                email = $3,
+                updated_at = GREATEST(updated_at, NOW())
+            WHERE id = $1
+            RETURNING updated_at
+            "#,
+            user_id,
+            full_name,
+            email.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update profile: {}", e))?
+        .ok_or_else(|| format!("User not found: {}", user_id))
+    }
+
+    async fn current_token_version(&self, user_id: Uuid) -> Result<Option<i32>, String> {
+        sqlx::query_scalar!("SELECT token_version FROM users WHERE id = $1", user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to read token version: {}", e))
+    }
+
+    async fn change_company(
+        &self,
+        user_id: Uuid,
+        new_company_id: Uuid,
+        teams: TeamHandling,
+    ) -> Result<(), String> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let old_company_id = sqlx::query_scalar!(
+            "SELECT company_id FROM users WHERE id = $1 FOR UPDATE",
+            user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to find user: {}", e))?
+        .ok_or_else(|| format!("User not found: {}", user_id))?;
+
+        let max_active_teams = sqlx::query_scalar!(
+            "SELECT max_active_teams FROM companies WHERE id = $1",
+            new_company_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to find company: {}", e))?
+        .ok_or_else(|| format!("Company not found: {}", new_company_id))?;
+        if old_company_id == new_company_id {
+            return Ok(());
+        }
+
+        match teams {
+            TeamHandling::Keep => {}
+            TeamHandling::Move => {
+                if let Some(limit) = max_active_teams {
+                    let terminal: Vec<TeamStatus> = TeamStatus::ALL
+                        .into_iter()
+                        .filter(TeamStatus::is_terminal)
+                        .collect();
+                    let counts = sqlx::query!(
+                        r#"
+                        SELECT
+                            COUNT(*) FILTER (WHERE company_id = $2) as "moving!",
+                            COUNT(*) FILTER (WHERE company_id = $3) as "active!"
+                        FROM teams
+                        WHERE (company_id = $3 OR (created_by = $1 AND company_id = $2))
+                          AND status <> ALL($4)
+                        "#,
+                        user_id,
+                        old_company_id,
+                        new_company_id,
+                        &terminal as &[TeamStatus]
+                    )
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Failed to count active teams: {}", e))?;
+                    if counts.moving > 0 && counts.moving + counts.active > i64::from(limit) {
+                        return Err(format!(
+                            "Company {} would exceed its active team limit",
+                            new_company_id
+                        ));
+                    }
+                }
+
+                sqlx::query!(
+                    r#"
+                    UPDATE teams
+                    SET company_id = $3,
+                        enforce_unique_goal = enforce_unique_goal OR $4
+                    WHERE created_by = $1 AND company_id = $2
+                    "#,
+                    user_id,
+                    old_company_id,
+                    new_company_id,
+                    self.enforce_unique_goal
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to move teams: {}", e))?;
+            }
+            TeamHandling::ReassignTo(new_owner) => {
+                let eligible = sqlx::query_scalar!(
+                    r#"
+                    SELECT EXISTS(
+                        SELECT 1 FROM users
+                        WHERE id = $1 AND id <> $2 AND company_id = $3 AND is_active
+                    ) as "exists!"
+                    "#,
+                    new_owner,
+                    user_id,
+                    old_company_id
+                )
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to find user: {}", e))?;
+                if !eligible {
+                    return Err(format!(
+                        "User {} is not a user of company {}",
+                        new_owner, old_company_id
+                    ));
+                }
+
+                sqlx::query!(
+                    "UPDATE teams SET created_by = $3 WHERE created_by = $1 AND company_id = $2",
+                    user_id,
+                    old_company_id,
+                    new_owner
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to reassign teams: {}", e))?;
+            }
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET company_id = $2,
+                role = 'member',
+                token_version = token_version + 1,
+                updated_at = GREATEST(updated_at, NOW())
+            WHERE id = $1
+            "#,
+            user_id,
+            new_company_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to change company: {}", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit company change: {}", e))
+    }
 }
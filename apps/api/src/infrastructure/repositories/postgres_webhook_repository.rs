@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::{Webhook, WebhookRepository};
+
+/// PostgreSQL implementation of WebhookRepository
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookRepository {
+    /// Creates a new PostgresWebhookRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create(&self, webhook: &Webhook) -> Result<(), String> {
+        sqlx::query!(
+            r#"
+            INSERT INTO webhooks (id, company_id, url, secret, event_types, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            webhook.id,
+            webhook.company_id,
+            webhook.url,
+            webhook.secret,
+            &webhook.event_types,
+            webhook.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create webhook: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn find_for_event(
+        &self,
+        company_id: Uuid,
+        event_type: &str,
+    ) -> Result<Vec<Webhook>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, company_id, url, secret, event_types, created_at
+            FROM webhooks
+            WHERE company_id = $1
+              AND (cardinality(event_types) = 0 OR $2 = ANY(event_types))
+            ORDER BY created_at, id
+            "#,
+            company_id,
+            event_type
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find webhooks: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| Webhook {
+                id: r.id,
+                company_id: r.company_id,
+                url: r.url,
+                secret: r.secret,
+                event_types: r.event_types,
+                created_at: r.created_at,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,255 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::agents::types::{Specialization, WorkerStatus};
+use crate::agents::worker::{UNKNOWN_BLOCK_REASON, WORKER_SCHEMA_VERSION};
+use crate::agents::WorkerAgent;
+use crate::domain::repositories::WorkerRepository;
+
+/// PostgreSQL implementation of WorkerRepository
+///
+/// Workers are stored in `team_members` with the `worker` role, using the
+/// agent ID as both the row ID and `agent_id` so tasks can reference
+/// workers directly.
+pub struct PostgresWorkerRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkerRepository {
+    /// Creates a new PostgresWorkerRepository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// `member_status` value stored for a worker status
+///
+/// Block reasons go in the separate `blocked_reason` column.
+fn member_status(status: &WorkerStatus) -> &'static str {
+    match status {
+        WorkerStatus::Idle => "idle",
+        WorkerStatus::Working => "busy",
+        WorkerStatus::Blocked { .. } => "offline",
+    }
+}
+
+/// Reason stored in `blocked_reason` for a worker status
+fn blocked_reason(status: &WorkerStatus) -> Option<String> {
+    match status {
+        WorkerStatus::Blocked { reason } => Some(reason.clone()),
+        _ => None,
+    }
+}
+
+/// Worker status for a stored `member_status` value and block reason
+///
+/// Workers blocked before reasons were stored get [`UNKNOWN_BLOCK_REASON`].
+fn worker_status(status: &str, blocked_reason: Option<String>) -> WorkerStatus {
+    match status {
+        "busy" => WorkerStatus::Working,
+        "offline" => WorkerStatus::Blocked {
+            reason: blocked_reason.unwrap_or_else(|| UNKNOWN_BLOCK_REASON.to_string()),
+        },
+        _ => WorkerStatus::Idle,
+    }
+}
+
+/// Rebuilds a worker from its `team_members` row
+fn worker_from_row(
+    id: Uuid,
+    team_id: Uuid,
+    specialization: Option<&str>,
+    status: &str,
+    blocked_reason: Option<String>,
+    skills: Vec<String>,
+    assigned_task_id: Option<Uuid>,
+) -> Result<WorkerAgent, String> {
+    Ok(WorkerAgent {
+        schema_version: WORKER_SCHEMA_VERSION,
+        id,
+        team_id,
+        specialization: specialization
+            .unwrap_or_default()
+            .parse::<Specialization>()?,
+        skills,
+        responsibilities: vec![],
+        required_tools: vec![],
+        status: worker_status(status, blocked_reason),
+        assigned_task_id,
+    })
+}
+
+/// Upserts `workers` with a single statement on `conn`
+///
+/// Shared with [`super::save_team_formation`] so workers can be saved in
+/// the caller's transaction.
+pub(crate) async fn upsert_workers(
+    conn: &mut PgConnection,
+    workers: &[WorkerAgent],
+) -> Result<(), String> {
+    let ids: Vec<Uuid> = workers.iter().map(|w| w.id).collect();
+    let team_ids: Vec<Uuid> = workers.iter().map(|w| w.team_id).collect();
+    let specializations: Vec<String> = workers
+        .iter()
+        .map(|w| w.specialization.to_string())
+        .collect();
+    let statuses: Vec<String> = workers
+        .iter()
+        .map(|w| member_status(&w.status).to_string())
+        .collect();
+    let blocked_reasons: Vec<Option<String>> =
+        workers.iter().map(|w| blocked_reason(&w.status)).collect();
+    // Skill lists differ in length, so each travels as one JSON array
+    let skills: Vec<serde_json::Value> = workers.iter().map(|w| w.skills.clone().into()).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO team_members (
+            id, team_id, agent_id, role, specialization, status, blocked_reason, skills
+        )
+        SELECT
+            w.id, w.team_id, w.id, 'worker', w.specialization, w.status::member_status,
+            w.blocked_reason, ARRAY(SELECT jsonb_array_elements_text(w.skills))
+        FROM UNNEST($1::uuid[], $2::uuid[], $3::text[], $4::text[], $5::text[], $6::jsonb[])
+            AS w(id, team_id, specialization, status, blocked_reason, skills)
+        ON CONFLICT (id) DO UPDATE SET
+            specialization = EXCLUDED.specialization,
+            status = EXCLUDED.status,
+            blocked_reason = EXCLUDED.blocked_reason,
+            skills = EXCLUDED.skills
+        "#,
+        &ids,
+        &team_ids,
+        &specializations,
+        &statuses,
+        &blocked_reasons as &[Option<String>],
+        &skills
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Failed to save workers: {}", e))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl WorkerRepository for PostgresWorkerRepository {
+    async fn save_many(&self, workers: &[WorkerAgent]) -> Result<(), String> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        upsert_workers(&mut tx, workers).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit workers: {}", e))
+    }
+
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<WorkerAgent>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                m.id, m.team_id, m.specialization,
+                m.status::text as "status!",
+                m.blocked_reason,
+                m.skills,
+                t.id as "assigned_task_id?"
+            FROM team_members m
+            LEFT JOIN LATERAL (
+                SELECT id
+                FROM tasks
+                WHERE assigned_to = m.id
+                  AND status IN ('assigned', 'in_progress', 'review', 'revision_requested')
+                ORDER BY updated_at DESC
+                LIMIT 1
+            ) t ON true
+            WHERE m.team_id = $1 AND m.role = 'worker'
+            ORDER BY m.joined_at, m.id
+            "#,
+            team_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find workers by team: {}", e))?;
+
+        rows.into_iter()
+            .map(|r| {
+                worker_from_row(
+                    r.id,
+                    r.team_id,
+                    r.specialization.as_deref(),
+                    &r.status,
+                    r.blocked_reason,
+                    r.skills,
+                    r.assigned_task_id,
+                )
+            })
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WorkerAgent>, String> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                m.id, m.team_id, m.specialization,
+                m.status::text as "status!",
+                m.blocked_reason,
+                m.skills,
+                t.id as "assigned_task_id?"
+            FROM team_members m
+            LEFT JOIN LATERAL (
+                SELECT id
+                FROM tasks
+                WHERE assigned_to = m.id
+                  AND status IN ('assigned', 'in_progress', 'review', 'revision_requested')
+                ORDER BY updated_at DESC
+                LIMIT 1
+            ) t ON true
+            WHERE m.id = $1 AND m.role = 'worker'
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find worker by id: {}", e))?;
+
+        row.map(|r| {
+            worker_from_row(
+                r.id,
+                r.team_id,
+                r.specialization.as_deref(),
+                &r.status,
+                r.blocked_reason,
+                r.skills,
+                r.assigned_task_id,
+            )
+        })
+        .transpose()
+    }
+
+    async fn update_skills(
+        &self,
+        id: Uuid,
+        expected: &[String],
+        skills: &[String],
+    ) -> Result<bool, String> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE team_members SET skills = $3
+            WHERE id = $1 AND role = 'worker' AND skills = $2
+            "#,
+            id,
+            expected,
+            skills
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update worker skills: {}", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
@@ -0,0 +1,549 @@
+// Webhook delivery
+// POSTs signed TeamEvent payloads to the endpoints companies registered
+
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use sha2::Sha256;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::{Webhook, WebhookRepository};
+use crate::domain::team::events::TeamEvent;
+use crate::infrastructure::repositories::PostgresWebhookRepository;
+
+/// Header carrying `sha256=<hex HMAC of the body keyed by the webhook secret>`
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+/// Header carrying the delivered event's type, e.g. `created`
+pub const EVENT_TYPE_HEADER: &str = "x-webhook-event";
+
+/// Header carrying the event's outbox ID, stable across redeliveries
+pub const DELIVERY_ID_HEADER: &str = "x-webhook-id";
+
+/// How many times one delivery is attempted before it is reported failed
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Wait before the first retry; doubled for each later one
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// How long one delivery attempt may take
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Which addresses webhooks may be delivered to
+///
+/// Webhook URLs come from tenants, so by default every address their
+/// host resolves to must be public: loopback, private, link-local
+/// (including the 169.254.169.254 metadata service), and other reserved
+/// ranges are refused. Hosts on the allowlist skip the check, e.g. a
+/// receiver on the same machine during development.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookUrlPolicy {
+    allowed_hosts: Arc<HashSet<String>>,
+}
+
+impl WebhookUrlPolicy {
+    /// Policy that also accepts URLs whose host is one of `hosts`
+    ///
+    /// Hosts are compared with the URL's host ignoring case; IPv6
+    /// addresses are written in brackets, e.g. `[::1]`.
+    pub fn allowing<I, S>(hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let allowed_hosts = hosts
+            .into_iter()
+            .map(|host| host.as_ref().trim().to_lowercase())
+            .filter(|host| !host.is_empty())
+            .collect();
+        Self {
+            allowed_hosts: Arc::new(allowed_hosts),
+        }
+    }
+
+    /// Allowlist from the comma-separated `WEBHOOK_ALLOWED_HOSTS`
+    pub fn from_env() -> Self {
+        let hosts = std::env::var("WEBHOOK_ALLOWED_HOSTS").unwrap_or_default();
+        Self::allowing(hosts.split(','))
+    }
+
+    fn allows_host(&self, host: &str) -> bool {
+        self.allowed_hosts.contains(&host.to_lowercase())
+    }
+
+    /// Checks that `url` is an absolute `http` or `https` URL the policy
+    /// allows, resolving its host
+    ///
+    /// # Returns
+    /// * `Ok(Url)` - The parsed URL
+    /// * `Err(String)` - If the URL is malformed, cannot be resolved, or
+    ///   reaches a non-public address
+    pub async fn check(&self, url: &str) -> Result<reqwest::Url, String> {
+        let url = reqwest::Url::parse(url.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("Webhook URL must use http or https".to_string());
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| "Webhook URL must have a host".to_string())?;
+        if self.allows_host(host) {
+            return Ok(url);
+        }
+
+        let addresses: Vec<IpAddr> = match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+            Ok(ip) => vec![ip],
+            Err(_) => tokio::net::lookup_host((host, url.port_or_known_default().unwrap_or(80)))
+                .await
+                .map_err(|e| format!("Cannot resolve {}: {}", host, e))?
+                .map(|addr| addr.ip())
+                .collect(),
+        };
+        if addresses.is_empty() || !addresses.into_iter().all(is_public) {
+            return Err(format!(
+                "Webhook URL must resolve to public addresses only: {}",
+                host
+            ));
+        }
+
+        Ok(url)
+    }
+}
+
+/// Whether `ip` is a globally routable unicast address
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                || a == 0
+                || a >= 240
+                // Carrier-grade NAT, IETF protocol assignments, benchmarking
+                || (a == 100 && (64..128).contains(&b))
+                || (a == 192 && b == 0 && c == 0)
+                || (a == 198 && (b == 18 || b == 19)))
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_public(mapped.into());
+            }
+            let [first, second, ..] = ip.segments();
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_multicast()
+                // Unique local, link-local, documentation
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80
+                || (first == 0x2001 && second == 0x0db8))
+        }
+    }
+}
+
+/// Resolver dropping addresses the [`WebhookUrlPolicy`] refuses
+///
+/// Applied to every connection, so a host that passed the check cannot
+/// later resolve to an internal address.
+struct PolicyResolver(WebhookUrlPolicy);
+
+impl Resolve for PolicyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.0.clone();
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let allowed = policy.allows_host(&host);
+            let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| allowed || is_public(addr.ip()))
+                .collect();
+            if addresses.is_empty() {
+                return Err(format!("{} has no public address", host).into());
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Signs a payload for the [`SIGNATURE_HEADER`]
+///
+/// # Example
+/// ```
+/// use ghostpirates_api::infrastructure::webhooks::sign;
+///
+/// let signature = sign("secret", br#"{"type":"started"}"#);
+/// assert!(signature.starts_with("sha256="));
+/// ```
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delivers team events to matching company webhooks
+///
+/// Failed attempts (network errors or non-2xx answers) are retried with
+/// exponential backoff. Events reach it through the outbox relay, so an
+/// event may be delivered more than once; receivers can deduplicate on
+/// the [`DELIVERY_ID_HEADER`]. Every delivery is checked against the
+/// [`WebhookUrlPolicy`] again, and redirects are not followed, so a
+/// registered URL cannot be turned toward internal services later.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    repo: Arc<dyn WebhookRepository>,
+    http: reqwest::Client,
+    policy: WebhookUrlPolicy,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl WebhookDispatcher {
+    /// Creates a dispatcher reading webhooks from `repo`
+    ///
+    /// Only public addresses are accepted until
+    /// [`with_url_policy`](Self::with_url_policy) allows more.
+    pub fn new(repo: Arc<dyn WebhookRepository>) -> Self {
+        let policy = WebhookUrlPolicy::default();
+
+        Self {
+            repo,
+            http: http_client(&policy),
+            policy,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Creates a dispatcher backed by the `webhooks` table, with the
+    /// policy from `WEBHOOK_ALLOWED_HOSTS`
+    pub fn postgres(pool: PgPool) -> Self {
+        Self::new(Arc::new(PostgresWebhookRepository::new(pool)))
+            .with_url_policy(WebhookUrlPolicy::from_env())
+    }
+
+    /// Delivers only to URLs `policy` allows
+    pub fn with_url_policy(mut self, policy: WebhookUrlPolicy) -> Self {
+        self.http = http_client(&policy);
+        self.policy = policy;
+        self
+    }
+
+    /// Sets how often and how soon failed deliveries are retried
+    pub fn with_retry(mut self, max_attempts: u32, retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Delivers one event to the company's webhooks that accept it
+    ///
+    /// `delivery_id` is sent in the [`DELIVERY_ID_HEADER`]. Webhooks are
+    /// delivered to concurrently, so a slow endpoint does not hold up the
+    /// others. Returns an error if the lookup failed or any webhook still
+    /// failed after its retries; the caller decides whether to deliver
+    /// the event again.
+    pub async fn deliver_event(
+        &self,
+        company_id: Uuid,
+        delivery_id: i64,
+        event: &TeamEvent,
+    ) -> Result<(), String> {
+        let event_type = event.event_type();
+        let webhooks = self
+            .repo
+            .find_for_event(company_id, event_type)
+            .await
+            .map_err(|e| format!("Failed to look up webhooks for {}: {}", company_id, e))?;
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let body = serde_json::to_vec(event)
+            .map_err(|e| format!("Failed to serialize {} event: {}", event_type, e))?;
+
+        let results = join_all(webhooks.iter().map(|webhook| async {
+            self.deliver(webhook, delivery_id, event_type, &body)
+                .await
+                .map_err(|e| format!("Webhook {}: {}", webhook.id, e))
+        }))
+        .await;
+        let failures: Vec<String> = results.into_iter().filter_map(Result::err).collect();
+        if !failures.is_empty() {
+            return Err(failures.join("; "));
+        }
+
+        Ok(())
+    }
+
+    /// Posts `body` to `webhook`, retrying until it succeeds or attempts run out
+    async fn deliver(
+        &self,
+        webhook: &Webhook,
+        delivery_id: i64,
+        event_type: &str,
+        body: &[u8],
+    ) -> Result<(), String> {
+        let url = self.policy.check(&webhook.url).await?;
+        let signature = sign(&webhook.secret, body);
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+
+        loop {
+            match self
+                .post(&url, delivery_id, event_type, &signature, body)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt >= self.max_attempts => return Err(e),
+                Err(e) => {
+                    tracing::debug!(
+                        "Webhook {} attempt {} failed, retrying: {}",
+                        webhook.id,
+                        attempt,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn post(
+        &self,
+        url: &reqwest::Url,
+        delivery_id: i64,
+        event_type: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), String> {
+        let response = self
+            .http
+            .post(url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(EVENT_TYPE_HEADER, event_type)
+            .header(DELIVERY_ID_HEADER, delivery_id)
+            .body(body.to_vec())
+            .send()
+            .await
+            .map_err(|e| format!("Webhook request failed: {}", e))?;
+
+        // Redirects are not followed, so a 3xx is a failure too
+        if !response.status().is_success() {
+            return Err(format!("Webhook endpoint answered {}", response.status()));
+        }
+
+        Ok(())
+    }
+}
+
+/// HTTP client that resolves hosts through `policy` and never redirects
+fn http_client(policy: &WebhookUrlPolicy) -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .dns_resolver(Arc::new(PolicyResolver(policy.clone())))
+        .build()
+        .expect("Failed to build webhook HTTP client")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use axum::{http::StatusCode, routing::post, Router};
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Repository returning one fixed webhook for every lookup
+    struct SingleWebhook(Webhook);
+
+    #[async_trait]
+    impl WebhookRepository for SingleWebhook {
+        async fn create(&self, _webhook: &Webhook) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn find_for_event(
+            &self,
+            _company_id: Uuid,
+            event_type: &str,
+        ) -> Result<Vec<Webhook>, String> {
+            Ok(Some(self.0.clone())
+                .filter(|webhook| webhook.accepts(event_type))
+                .into_iter()
+                .collect())
+        }
+    }
+
+    /// Serves `/hook`, failing the first `failures` requests with 503
+    ///
+    /// `/redirect` answers every request with a redirect to `/hook`.
+    async fn flaky_endpoint(failures: u32) -> (String, Arc<AtomicU32>) {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(move || {
+                    let counter = counter.clone();
+                    async move {
+                        if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                            StatusCode::SERVICE_UNAVAILABLE
+                        } else {
+                            StatusCode::NO_CONTENT
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/redirect",
+                post(|| async { axum::response::Redirect::temporary("/hook") }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}/hook", addr), calls)
+    }
+
+    fn dispatcher(url: String, event_types: &[&str]) -> WebhookDispatcher {
+        let webhook = Webhook {
+            id: Uuid::new_v4(),
+            company_id: Uuid::new_v4(),
+            url,
+            secret: "secret".to_string(),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            created_at: Utc::now(),
+        };
+
+        WebhookDispatcher::new(Arc::new(SingleWebhook(webhook)))
+            .with_retry(3, Duration::from_millis(1))
+            .with_url_policy(WebhookUrlPolicy::allowing(["127.0.0.1"]))
+    }
+
+    #[tokio::test]
+    async fn policy_refuses_non_public_addresses() {
+        let policy = WebhookUrlPolicy::default();
+
+        for url in [
+            "http://127.0.0.1/hook",
+            "http://localhost:8080/hook",
+            "http://10.0.0.5/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[::ffff:192.168.1.1]/hook",
+            "http://100.64.0.1/hook",
+        ] {
+            assert!(policy.check(url).await.is_err(), "{} was allowed", url);
+        }
+        assert!(policy.check("https://93.184.216.34/hook").await.is_ok());
+        assert!(policy.check("https://[2606:4700::1111]/hook").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn policy_requires_http_scheme() {
+        let policy = WebhookUrlPolicy::allowing(["example.com"]);
+
+        assert!(policy.check("https://example.com/hook").await.is_ok());
+        assert!(policy.check("ftp://example.com/hook").await.is_err());
+        assert!(policy.check("not a url").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn allowlisted_hosts_skip_the_address_check() {
+        let policy = WebhookUrlPolicy::allowing(["LOCALHOST", "[::1]"]);
+
+        assert!(policy.check("http://localhost:8080/hook").await.is_ok());
+        assert!(policy.check("http://[::1]/hook").await.is_ok());
+        assert!(policy.check("http://127.0.0.1/hook").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn does_not_deliver_to_non_public_addresses() {
+        let (url, calls) = flaky_endpoint(0).await;
+        let team_id = Uuid::new_v4();
+
+        dispatcher(url, &[])
+            .with_url_policy(WebhookUrlPolicy::default())
+            .deliver_event(Uuid::new_v4(), 1, &TeamEvent::Started { team_id })
+            .await
+            .unwrap_err();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn does_not_follow_redirects() {
+        let (url, calls) = flaky_endpoint(0).await;
+        let team_id = Uuid::new_v4();
+
+        dispatcher(url.replace("/hook", "/redirect"), &[])
+            .deliver_event(Uuid::new_v4(), 1, &TeamEvent::Started { team_id })
+            .await
+            .unwrap_err();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn sign_matches_rfc_4231_test_vector() {
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_failed_deliveries() {
+        let (url, calls) = flaky_endpoint(2).await;
+        let team_id = Uuid::new_v4();
+
+        dispatcher(url, &[])
+            .deliver_event(Uuid::new_v4(), 1, &TeamEvent::Started { team_id })
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (url, calls) = flaky_endpoint(u32::MAX).await;
+        let team_id = Uuid::new_v4();
+
+        let result = dispatcher(url, &[])
+            .deliver_event(Uuid::new_v4(), 1, &TeamEvent::Started { team_id })
+            .await;
+
+        assert!(result.is_err());
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn skips_events_the_webhook_does_not_accept() {
+        let (url, calls) = flaky_endpoint(0).await;
+        let team_id = Uuid::new_v4();
+
+        dispatcher(url, &["completed"])
+            .deliver_event(Uuid::new_v4(), 1, &TeamEvent::Started { team_id })
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
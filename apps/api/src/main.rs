@@ -1,13 +1,36 @@
 use axum::{
-    routing::{delete, get, post},
-    Router,
+    middleware,
+    routing::{delete, get, patch, post, put},
+    Extension, Router,
 };
 use sqlx::postgres::PgPoolOptions;
 use std::net::SocketAddr;
+use tokio_util::sync::CancellationToken;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
-use ghostpirates_api::api::handlers::{auth as auth_handlers, teams};
+use ghostpirates_api::agents::cancellation::TeamCancellations;
+use ghostpirates_api::api::handlers::{
+    auth as auth_handlers, events, teams, users, webhooks, workers,
+};
+use ghostpirates_api::api::login_dedup::LoginDeduplicator;
+use ghostpirates_api::api::middleware::{
+    envelope_responses, log_requests, maintenance_mode, negotiate_language, rate_limit,
+    DevAuthBypass, InternalSecret, MaintenanceMode, RateLimiter, SensitiveFields, TrustProxy,
+};
+use ghostpirates_api::api::openapi::openapi_json;
+use ghostpirates_api::auth::lockout::LoginLockout;
+use ghostpirates_api::infrastructure::background_tasks::{
+    BackgroundTasks, DEFAULT_SHUTDOWN_TIMEOUT,
+};
+use ghostpirates_api::infrastructure::captcha::captcha_from_env;
+use ghostpirates_api::infrastructure::database::{
+    connect_with_retry, replica_url_from_env, ReadPool, RetryPolicy,
+};
+use ghostpirates_api::infrastructure::feature_flags::FeatureFlags;
+use ghostpirates_api::infrastructure::migrations;
+use ghostpirates_api::infrastructure::outbox_relay::OutboxRelay;
+use ghostpirates_api::infrastructure::webhooks::WebhookUrlPolicy;
 
 #[tokio::main]
 async fn main() {
@@ -17,6 +40,14 @@ async fn main() {
     // Load environment variables
     dotenv::dotenv().ok();
 
+    let dev_auth_bypass = DevAuthBypass::from_env();
+    if let Some(user_id) = dev_auth_bypass.0 {
+        tracing::warn!(
+            "Dev auth bypass enabled: requests without a token act as user {}",
+            user_id
+        );
+    }
+
     // Get database URL
     let database_url = std::env::var("DATABASE_URL").unwrap_or_else(|_| {
         tracing::warn!("DATABASE_URL not set, using default");
@@ -25,38 +56,170 @@ async fn main() {
 
     // Connect to database
     tracing::info!("Connecting to database...");
-    let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
-        .await
-        .expect("Failed to connect to database");
+    let pool = connect_with_retry(&RetryPolicy::from_env(), || {
+        PgPoolOptions::new()
+            .max_connections(5)
+            .connect(&database_url)
+    })
+    .await
+    .expect("Failed to connect to database");
 
     tracing::info!("Database connected successfully");
 
+    // Serve read-only queries from the replica when one is configured
+    let replica = match replica_url_from_env() {
+        Some(replica_url) => {
+            tracing::info!("Connecting to read replica...");
+            let replica = connect_with_retry(&RetryPolicy::from_env(), || {
+                PgPoolOptions::new()
+                    .max_connections(5)
+                    .connect(&replica_url)
+            })
+            .await
+            .expect("Failed to connect to read replica");
+            Some(replica)
+        }
+        None => None,
+    };
+    let read_pool = ReadPool::new(&pool, replica);
+
+    // Apply pending migrations when opted in
+    if migrations::run_on_startup() {
+        tracing::info!("Running database migrations...");
+        let applied = migrations::run_migrations(&pool)
+            .await
+            .expect("Failed to run database migrations");
+
+        if applied.is_empty() {
+            tracing::info!("Database schema is up to date");
+        } else {
+            tracing::info!("Applied migrations: {:?}", applied);
+        }
+    }
+
+    // Block writes while migrations run
+    let maintenance = MaintenanceMode::from_env();
+    if maintenance.is_enabled() {
+        tracing::warn!("Maintenance mode enabled: write requests are rejected with 503");
+    }
+
     // Configure CORS
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any);
 
+    // Work handlers spawn after responding (e.g. last-login updates)
+    let background_tasks = BackgroundTasks::new();
+
+    // Publish team events queued in the outbox (e.g. webhook deliveries);
+    // stopped on shutdown after its current pass
+    let stop_relay = CancellationToken::new();
+    background_tasks.spawn(OutboxRelay::postgres(pool.clone()).run(stop_relay.clone()));
+
     // Build router
     let app = Router::new()
         // Health check
         .route("/health", get(auth_handlers::health_check))
+        .route("/health/ready", get(auth_handlers::readiness_check))
+        // API description
+        .route("/api/openapi.json", get(openapi_json))
         // Auth routes
-        .route("/api/auth/register", post(auth_handlers::register))
+        .route(
+            "/api/auth/register",
+            post(auth_handlers::register).layer(middleware::from_fn_with_state(
+                RateLimiter::registration_from_env(),
+                rate_limit,
+            )),
+        )
         .route("/api/auth/login", post(auth_handlers::login))
+        .route("/api/auth/introspect", post(auth_handlers::introspect))
+        .route(
+            "/api/auth/forgot-password",
+            post(auth_handlers::forgot_password),
+        )
+        .route(
+            "/api/auth/reset-password",
+            post(auth_handlers::reset_password),
+        )
         // Team routes
-        .route("/api/teams", post(teams::create_team))
+        .route(
+            "/api/teams",
+            post(teams::create_team).get(teams::get_teams_created_between),
+        )
+        .route("/api/teams/batch", post(teams::batch_create_teams))
+        .route("/api/teams/bulk-delete", post(teams::bulk_delete_teams))
+        .route("/api/teams/import", post(teams::import_team))
+        .route("/api/teams/:id/export", get(teams::export_team))
         .route("/api/teams/:id", get(teams::get_team))
+        .route("/api/teams/:id", patch(teams::update_team))
+        .route("/api/teams/:id/tags", put(teams::set_team_tags))
+        .route(
+            "/api/teams/:id/transfer",
+            post(teams::transfer_team_ownership),
+        )
+        .route("/api/teams/:id/cancel", post(teams::cancel_team))
+        .route("/api/teams/:id/events", get(teams::get_team_events))
+        .route("/api/teams/:id/manager", get(teams::get_team_manager))
+        .route("/api/teams/:id/workers", get(teams::get_team_workers))
+        .route(
+            "/api/teams/:id/cost-breakdown",
+            get(teams::get_cost_breakdown),
+        )
         .route("/api/teams/:id", delete(teams::delete_team))
         .route(
             "/api/teams/company/:company_id",
             get(teams::get_teams_by_company),
         )
+        .route(
+            "/api/teams/company/:company_id/stats",
+            get(teams::get_team_stats),
+        )
+        .route("/api/companies/:id/teams.csv", get(teams::export_teams_csv))
+        // Worker routes
+        .route(
+            "/api/workers/:id/skills",
+            patch(workers::update_worker_skills),
+        )
+        // User routes
+        .route("/api/users", get(users::list_users))
+        .route("/api/users/:id", patch(users::update_user))
+        .route("/api/users/:id/company", put(users::change_user_company))
+        // Event log routes
+        .route("/api/events", get(events::list_events))
+        // Webhook routes
+        .route("/api/webhooks", post(webhooks::register_webhook))
         // Middleware
+        .layer(middleware::from_fn_with_state(
+            maintenance,
+            maintenance_mode,
+        ))
+        .layer(middleware::from_fn(negotiate_language))
+        .layer(middleware::from_fn(envelope_responses))
+        .layer(middleware::from_fn_with_state(
+            RateLimiter::general_from_env(),
+            rate_limit,
+        ))
+        .layer(middleware::from_fn_with_state(
+            SensitiveFields::from_env(),
+            log_requests,
+        ))
         .layer(TraceLayer::new_for_http())
         .layer(cors)
+        .layer(Extension(FeatureFlags::postgres(pool.clone())))
+        .layer(Extension(read_pool))
+        .layer(Extension(background_tasks.clone()))
+        .layer(Extension(captcha_from_env()))
+        .layer(Extension(LoginLockout::from_env()))
+        .layer(Extension(LoginDeduplicator::from_env()))
+        .layer(Extension(TrustProxy::from_env()))
+        .layer(Extension(WebhookUrlPolicy::from_env()))
+        .layer(Extension(InternalSecret::from_env()))
+        .layer(Extension(TeamCancellations::default()))
+        .layer(Extension(dev_auth_bypass))
+        .layer(Extension(auth_handlers::ReadinessTimeout::from_env()))
+        .layer(Extension(teams::EnforceUniqueGoal::from_env()))
+        .layer(Extension(teams::MaxTeamBudget::from_env()))
         // Shared state
         .with_state(pool);
 
@@ -68,5 +231,52 @@ async fn main() {
         .await
         .expect("Failed to bind address");
 
-    axum::serve(listener, app).await.expect("Server failed");
+    // Peer addresses identify clients unless TRUST_PROXY allows proxy headers
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await
+    .expect("Server failed");
+
+    // Let in-flight background work finish before exiting
+    stop_relay.cancel();
+    if !background_tasks.shutdown(DEFAULT_SHUTDOWN_TIMEOUT).await {
+        tracing::warn!("Background tasks did not finish before shutdown");
+    }
+}
+
+/// Resolves when the process is asked to stop (Ctrl+C, or SIGTERM from an
+/// orchestrator on Unix)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    tracing::info!("Shutting down");
 }
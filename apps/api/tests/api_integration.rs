@@ -9,28 +9,152 @@
 use axum::{
     body::Body,
     http::{Request, StatusCode},
-    Router,
+    Extension, Router,
 };
-use ghostpirates_api::api::handlers::{auth as auth_handlers, teams};
+use ghostpirates_api::agents::cancellation::TeamCancellations;
+use ghostpirates_api::api::handlers::{
+    auth as auth_handlers, events, teams, users, webhooks, workers,
+};
+use ghostpirates_api::api::login_dedup::LoginDeduplicator;
+use ghostpirates_api::api::middleware::{
+    envelope_responses, log_requests, maintenance_mode, negotiate_language, rate_limit,
+    InternalSecret, MaintenanceMode, RateLimiter, SensitiveFields, TrustProxy,
+};
+use ghostpirates_api::api::openapi::openapi_json;
+use ghostpirates_api::auth::lockout::{LockoutPolicy, LoginLockout};
+use ghostpirates_api::domain::shared::MockClock;
+use ghostpirates_api::domain::user::value_objects::UserRole;
+use ghostpirates_api::infrastructure::background_tasks::BackgroundTasks;
+use ghostpirates_api::infrastructure::captcha::{CaptchaVerifier, NoopCaptchaVerifier};
+use ghostpirates_api::infrastructure::database::ReadPool;
+use ghostpirates_api::infrastructure::feature_flags::{FeatureFlags, UNIQUE_GOAL_PER_COMPANY};
+use ghostpirates_api::infrastructure::outbox_relay::OutboxRelay;
+use ghostpirates_api::infrastructure::repositories::PostgresOutboxRepository;
+use ghostpirates_api::infrastructure::webhooks::{WebhookDispatcher, WebhookUrlPolicy};
 use serde_json::{json, Value};
 use sqlx::PgPool;
+use std::sync::Arc;
 use tower::util::ServiceExt; // for oneshot
 
 /// Setup test application with routes
 async fn setup_app(pool: PgPool) -> Router {
-    use axum::routing::{delete, get, post};
+    setup_app_with_captcha(pool, Arc::new(NoopCaptchaVerifier)).await
+}
+
+/// Setup test application with routes and the given CAPTCHA verifier
+async fn setup_app_with_captcha(pool: PgPool, captcha: Arc<dyn CaptchaVerifier>) -> Router {
+    setup_app_with(
+        pool,
+        captcha,
+        MaintenanceMode::default(),
+        LoginLockout::from_env(),
+        LoginDeduplicator::default(),
+        TeamCancellations::default(),
+    )
+    .await
+}
+
+/// Setup test application with routes, CAPTCHA verifier, maintenance
+/// switch, login lockout, login deduplication, and team cancellations
+async fn setup_app_with(
+    pool: PgPool,
+    captcha: Arc<dyn CaptchaVerifier>,
+    maintenance: MaintenanceMode,
+    lockout: LoginLockout,
+    dedup: LoginDeduplicator,
+    cancellations: TeamCancellations,
+) -> Router {
+    use axum::routing::{delete, get, patch, post, put};
 
     Router::new()
-        .route("/api/auth/register", post(auth_handlers::register))
+        .route(
+            "/api/auth/register",
+            post(auth_handlers::register).layer(axum::middleware::from_fn_with_state(
+                RateLimiter::registration_from_env(),
+                rate_limit,
+            )),
+        )
         .route("/api/auth/login", post(auth_handlers::login))
-        .route("/api/teams", post(teams::create_team))
+        .route("/api/auth/introspect", post(auth_handlers::introspect))
+        .route(
+            "/api/auth/forgot-password",
+            post(auth_handlers::forgot_password),
+        )
+        .route(
+            "/api/auth/reset-password",
+            post(auth_handlers::reset_password),
+        )
+        .route(
+            "/api/teams",
+            post(teams::create_team).get(teams::get_teams_created_between),
+        )
+        .route("/api/teams/batch", post(teams::batch_create_teams))
+        .route("/api/teams/bulk-delete", post(teams::bulk_delete_teams))
+        .route("/api/teams/import", post(teams::import_team))
+        .route("/api/teams/:id/export", get(teams::export_team))
         .route("/api/teams/:id", get(teams::get_team))
+        .route("/api/teams/:id", patch(teams::update_team))
+        .route("/api/teams/:id/tags", put(teams::set_team_tags))
+        .route(
+            "/api/teams/:id/transfer",
+            post(teams::transfer_team_ownership),
+        )
+        .route("/api/teams/:id/cancel", post(teams::cancel_team))
+        .route("/api/teams/:id/events", get(teams::get_team_events))
+        .route("/api/teams/:id/manager", get(teams::get_team_manager))
+        .route("/api/teams/:id/workers", get(teams::get_team_workers))
+        .route(
+            "/api/teams/:id/cost-breakdown",
+            get(teams::get_cost_breakdown),
+        )
         .route(
             "/api/teams/company/:company_id",
             get(teams::get_teams_by_company),
         )
+        .route(
+            "/api/teams/company/:company_id/stats",
+            get(teams::get_team_stats),
+        )
+        .route("/api/companies/:id/teams.csv", get(teams::export_teams_csv))
         .route("/api/teams/:id", delete(teams::delete_team))
+        .route(
+            "/api/workers/:id/skills",
+            patch(workers::update_worker_skills),
+        )
+        .route("/api/users", get(users::list_users))
+        .route("/api/users/:id", patch(users::update_user))
+        .route("/api/users/:id/company", put(users::change_user_company))
+        .route("/api/events", get(events::list_events))
+        .route("/api/webhooks", post(webhooks::register_webhook))
         .route("/health", get(auth_handlers::health_check))
+        .route("/health/ready", get(auth_handlers::readiness_check))
+        .route("/api/openapi.json", get(openapi_json))
+        .layer(axum::middleware::from_fn_with_state(
+            maintenance,
+            maintenance_mode,
+        ))
+        .layer(axum::middleware::from_fn(negotiate_language))
+        .layer(axum::middleware::from_fn(envelope_responses))
+        .layer(axum::middleware::from_fn_with_state(
+            RateLimiter::general_from_env(),
+            rate_limit,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            SensitiveFields::from_env(),
+            log_requests,
+        ))
+        .layer(Extension(FeatureFlags::postgres(pool.clone())))
+        .layer(Extension(ReadPool::new(&pool, None)))
+        .layer(Extension(BackgroundTasks::new()))
+        .layer(Extension(captcha))
+        .layer(Extension(lockout))
+        .layer(Extension(dedup))
+        .layer(Extension(cancellations))
+        // Tests tell clients apart by X-Forwarded-For
+        .layer(Extension(TrustProxy(1)))
+        // Webhook receivers in tests listen on loopback
+        .layer(Extension(local_webhooks()))
+        .layer(Extension(InternalSecret::new(TEST_INTERNAL_SECRET)))
         .with_state(pool)
 }
 
@@ -47,10 +171,11 @@ async fn setup_test_db() -> PgPool {
 /// Create a test company for isolation
 async fn create_test_company(pool: &PgPool) -> uuid::Uuid {
     let company_id = uuid::Uuid::new_v4();
+    // Names are unique, so suffix the ID
     sqlx::query!(
         "INSERT INTO companies (id, name) VALUES ($1, $2)",
         company_id,
-        "E2E Test Company"
+        format!("E2E Test Company {}", company_id)
     )
     .execute(pool)
     .await
@@ -61,6 +186,13 @@ async fn create_test_company(pool: &PgPool) -> uuid::Uuid {
 
 /// Clean up test data
 async fn cleanup_test_company(pool: &PgPool, company_id: uuid::Uuid) {
+    sqlx::query!(
+        "DELETE FROM login_attempts WHERE email IN (SELECT LOWER(email) FROM users WHERE company_id = $1)",
+        company_id
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to cleanup login attempts");
     sqlx::query!("DELETE FROM companies WHERE id = $1", company_id)
         .execute(pool)
         .await
@@ -430,7 +562,7 @@ async fn test_protected_endpoint_requires_authentication() {
     assert_eq!(json["error"], "Missing authorization header");
 
     // Now login and get token
-    let login_payload = json!({
+    let _login_payload = json!({
         "email": "protected-test@test.com",
         "password": "testpass"
     });
@@ -553,3 +685,3826 @@ async fn test_protected_endpoint_with_valid_token() {
     // Cleanup
     cleanup_test_company(&pool, company_id).await;
 }
+
+/// Register a user through the API and return the new user's ID
+async fn register_user(
+    app: &Router,
+    company_id: uuid::Uuid,
+    email: &str,
+    password: &str,
+) -> uuid::Uuid {
+    let register_payload = json!({
+        "email": email,
+        "password": password,
+        "full_name": "Reset Test User",
+        "company_id": company_id.to_string()
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/register")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_string(&register_payload).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    uuid::Uuid::parse_str(json["user_id"].as_str().unwrap()).unwrap()
+}
+
+/// Issue a reset token directly so the test knows its plaintext value
+async fn issue_reset_token(pool: &PgPool, user_id: uuid::Uuid) -> String {
+    use ghostpirates_api::auth::reset_token::{generate_reset_token, hash_reset_token};
+    use ghostpirates_api::domain::repositories::password_reset_repository::{
+        PasswordResetRepository, PasswordResetToken,
+    };
+    use ghostpirates_api::infrastructure::repositories::PostgresPasswordResetRepository;
+
+    let token = generate_reset_token();
+    PostgresPasswordResetRepository::new(pool.clone())
+        .create(PasswordResetToken {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            token_hash: hash_reset_token(&token),
+            expires_at: chrono::Utc::now() + chrono::Duration::minutes(30),
+        })
+        .await
+        .expect("Failed to issue reset token");
+
+    token
+}
+
+#[tokio::test]
+async fn test_password_reset_flow() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-reset-flow@test.com", "oldpassword1").await;
+
+    // Step 1: Request a reset for a registered and an unknown email
+    for email in ["e2e-reset-flow@test.com", "nobody-here@test.com"] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/auth/forgot-password")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "email": email }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let stored = sqlx::query!(
+        "SELECT COUNT(*) as count FROM password_reset_tokens WHERE user_id = $1",
+        user_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(stored.count, Some(1));
+
+    // Step 2: Reset using a known token, right after a login the
+    // deduplicator remembers
+    let login = |password: &str| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/login")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "email": "e2e-reset-flow@test.com", "password": password }).to_string(),
+                ))
+                .unwrap(),
+        )
+    };
+    let response = login("oldpassword1").await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let token = issue_reset_token(&pool, user_id).await;
+    let bearer = bearer_token_with_role(user_id, company_id, UserRole::Admin);
+    let list_users = |bearer: String| {
+        app.clone().oneshot(
+            Request::builder()
+                .uri("/api/users")
+                .header("authorization", bearer)
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+    let response = list_users(bearer.clone()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/reset-password")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "token": token, "new_password": "newpassword2" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Step 3: Old password no longer works, even within the dedup window,
+    // and the new one does
+    for (password, expected) in [
+        ("oldpassword1", StatusCode::UNAUTHORIZED),
+        ("newpassword2", StatusCode::OK),
+    ] {
+        let response = login(password).await.unwrap();
+        assert_eq!(response.status(), expected);
+    }
+
+    // Tokens issued before the reset are revoked
+    let response = list_users(bearer.clone()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_password_reset_token_cannot_be_reused() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-reset-reuse@test.com", "oldpassword1").await;
+    let token = issue_reset_token(&pool, user_id).await;
+
+    let mut statuses = Vec::new();
+    for new_password in ["firstreset1", "secondreset2"] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/auth/reset-password")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({ "token": token, "new_password": new_password }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        statuses.push(response.status());
+    }
+
+    assert_eq!(statuses, vec![StatusCode::OK, StatusCode::BAD_REQUEST]);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// Build a bearer token for a member using the same secret as the middleware
+fn bearer_token(user_id: uuid::Uuid, company_id: uuid::Uuid) -> String {
+    bearer_token_with_role(user_id, company_id, UserRole::Member)
+}
+
+/// Build a bearer token for a user with the given role
+fn bearer_token_with_role(user_id: uuid::Uuid, company_id: uuid::Uuid, role: UserRole) -> String {
+    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+    let token = ghostpirates_api::auth::jwt::create_token(
+        user_id,
+        company_id,
+        role,
+        0,
+        &secret,
+        &ghostpirates_api::domain::shared::SystemClock,
+    )
+    .unwrap();
+    format!("Bearer {}", token)
+}
+
+#[tokio::test]
+async fn test_team_events_keyset_pagination() {
+    use ghostpirates_api::domain::repositories::TeamEventRepository;
+    use ghostpirates_api::domain::team::events::TeamEvent;
+    use ghostpirates_api::infrastructure::repositories::PostgresTeamEventRepository;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-events@test.com", "eventspass1").await;
+
+    // Creating the team records the Created event
+    let team_payload = json!({
+        "goal": "Event feed mission",
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string()
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    let team_id = uuid::Uuid::parse_str(team_json["id"].as_str().unwrap()).unwrap();
+
+    // Add four more events for a total of five
+    let extra_events: Vec<TeamEvent> = (0..4)
+        .map(|i| TeamEvent::Failed {
+            team_id,
+            reason: format!("attempt {}", i),
+        })
+        .collect();
+    PostgresTeamEventRepository::new(pool.clone())
+        .append(&extra_events)
+        .await
+        .unwrap();
+
+    // Page through the feed three events at a time
+    let mut cursor: Option<i64> = None;
+    let mut sequences = Vec::new();
+    let mut pages = Vec::new();
+
+    for _ in 0..2 {
+        let uri = match cursor {
+            Some(after) => format!("/api/teams/{}/events?limit=3&after={}", team_id, after),
+            None => format!("/api/teams/{}/events?limit=3", team_id),
+        };
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .header("authorization", bearer_token(user_id, company_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let page: Value = serde_json::from_slice(&body).unwrap();
+
+        for event in page["events"].as_array().unwrap() {
+            sequences.push(event["sequence"].as_i64().unwrap());
+        }
+        cursor = page["next_cursor"].as_i64();
+        pages.push(page);
+    }
+
+    assert_eq!(pages[0]["events"].as_array().unwrap().len(), 3);
+    assert_eq!(pages[0]["has_more"], true);
+    assert_eq!(pages[0]["events"][0]["event_type"], "created");
+    assert_eq!(pages[1]["events"].as_array().unwrap().len(), 2);
+    assert_eq!(pages[1]["has_more"], false);
+
+    // No overlap and no gaps between pages
+    let all = sqlx::query_scalar!(
+        "SELECT sequence FROM team_events WHERE team_id = $1 ORDER BY sequence",
+        team_id
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(sequences, all);
+
+    // Another company's token cannot read the feed
+    let other_company_id = create_test_company(&pool).await;
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/{}/events", team_id))
+                .header(
+                    "authorization",
+                    bearer_token(uuid::Uuid::new_v4(), other_company_id),
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_event_log_filters_by_event_type() {
+    use ghostpirates_api::domain::repositories::TeamEventRepository;
+    use ghostpirates_api::domain::team::events::TeamEvent;
+    use ghostpirates_api::infrastructure::repositories::PostgresTeamEventRepository;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-event-log@test.com", "eventlog1").await;
+    let other_user_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-event-log-other@test.com",
+        "eventlog1",
+    )
+    .await;
+    let succeeded = create_team_via_api(&app, company_id, user_id, "Succeeding mission").await;
+    let failed = create_team_via_api(&app, company_id, user_id, "Failing mission").await;
+    let other_failed = create_team_via_api(
+        &app,
+        other_company_id,
+        other_user_id,
+        "Other failing mission",
+    )
+    .await;
+
+    PostgresTeamEventRepository::new(pool.clone())
+        .append(&[
+            TeamEvent::Started { team_id: succeeded },
+            TeamEvent::Completed { team_id: succeeded },
+            TeamEvent::Started { team_id: failed },
+            TeamEvent::Failed {
+                team_id: failed,
+                reason: "Out of budget".to_string(),
+            },
+            TeamEvent::Failed {
+                team_id: other_failed,
+                reason: "Not ours".to_string(),
+            },
+        ])
+        .await
+        .unwrap();
+
+    let list_events_as = |bearer: String, query: &str| {
+        let app = app.clone();
+        let request = Request::builder()
+            .uri(format!("/api/events?{}", query))
+            .header("authorization", bearer)
+            .body(Body::empty())
+            .unwrap();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (status, serde_json::from_slice::<Value>(&body).unwrap())
+        }
+    };
+    let admin = bearer_token_with_role(user_id, company_id, UserRole::Admin);
+    let list_events = |query: &str| list_events_as(admin.clone(), query);
+
+    // The log spans every team in the company, so members cannot read it
+    let (status, _) = list_events_as(bearer_token(user_id, company_id), "type=Failed").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Only the caller's company's failure
+    let (status, events) = list_events("type=Failed").await;
+    assert_eq!(status, StatusCode::OK);
+    let events = events.as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["team_id"], failed.to_string());
+    assert_eq!(events[0]["event_type"], "failed");
+    assert_eq!(events[0]["payload"]["reason"], "Out of budget");
+
+    let (status, events) = list_events("type=created").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(events.as_array().unwrap().len(), 2);
+
+    let (status, _) = list_events("type=Exploded").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+/// Create a team through the API and return its ID
+async fn create_team_via_api(
+    app: &Router,
+    company_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    goal: &str,
+) -> uuid::Uuid {
+    let team_payload = json!({
+        "goal": goal,
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string()
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    uuid::Uuid::parse_str(team_json["id"].as_str().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_get_team_manager_before_and_after_formation() {
+    use ghostpirates_api::agents::ManagerAgent;
+    use ghostpirates_api::infrastructure::repositories::save_team_formation;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-manager@test.com", "managerpass1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-manager-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Manager mission").await;
+
+    let get_manager = |caller: uuid::Uuid, caller_company: uuid::Uuid| {
+        Request::builder()
+            .uri(format!("/api/teams/{}/manager", team_id))
+            .header("authorization", bearer_token(caller, caller_company))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Before formation: 404
+    let response = app
+        .clone()
+        .oneshot(get_manager(user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Form the team, which persists its manager
+    let manager = ManagerAgent::new(team_id);
+    save_team_formation(&pool, &manager, &[], &[])
+        .await
+        .expect("Failed to save team formation");
+
+    let response = app
+        .clone()
+        .oneshot(get_manager(user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["id"], manager.id.to_string());
+    assert_eq!(json["model"], manager.model);
+    assert_eq!(json["max_tokens"], manager.max_tokens);
+
+    // Another company's user cannot see it
+    let response = app
+        .clone()
+        .oneshot(get_manager(outsider_id, other_company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_create_team_persists_budget_currency() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-currency@test.com", "currency123").await;
+
+    let create = |currency: &str| {
+        let payload = json!({
+            "goal": "Euro mission",
+            "company_id": company_id.to_string(),
+            "created_by": user_id.to_string(),
+            "budget_limit": 75.25,
+            "budget_currency": currency
+        });
+
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams")
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(create("eur")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(team_json["budget_currency"], "EUR");
+
+    let db_team = sqlx::query!(
+        "SELECT budget_currency FROM teams WHERE id = $1",
+        uuid::Uuid::parse_str(team_json["id"].as_str().unwrap()).unwrap()
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("Team should exist in database");
+    assert_eq!(db_team.budget_currency, "EUR");
+
+    // Unsupported currencies are rejected
+    let response = app.clone().oneshot(create("DOGE")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_company_team_listing_is_tenant_scoped() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-tenant@test.com", "tenantpass1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-tenant-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+    create_team_via_api(&app, company_id, user_id, "Tenant mission").await;
+
+    let list = |token: Option<String>| {
+        let mut builder = Request::builder().uri(format!("/api/teams/company/{}", company_id));
+        if let Some(token) = token {
+            builder = builder.header("authorization", token);
+        }
+        builder.body(Body::empty()).unwrap()
+    };
+
+    // Own company: allowed
+    let response = app
+        .clone()
+        .oneshot(list(Some(bearer_token(user_id, company_id))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let teams_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(teams_json.as_array().unwrap().len(), 1);
+    assert_eq!(teams_json[0]["created_by"], user_id.to_string());
+    assert_eq!(teams_json[0]["created_by_name"], "Reset Test User");
+
+    // Another company's user: denied
+    let response = app
+        .clone()
+        .oneshot(list(Some(bearer_token(outsider_id, other_company_id))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // No token: unauthorized
+    let response = app.clone().oneshot(list(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_company_team_listing_filters_by_status() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-status@test.com", "statuspass1").await;
+    let pending_id = create_team_via_api(&app, company_id, user_id, "Pending mission").await;
+    let active_id = create_team_via_api(&app, company_id, user_id, "Active mission").await;
+    let completed_id = create_team_via_api(&app, company_id, user_id, "Completed mission").await;
+
+    sqlx::query!(
+        "UPDATE teams SET status = 'active' WHERE id = $1",
+        active_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "UPDATE teams SET status = 'completed' WHERE id = $1",
+        completed_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let list = |query: &str| {
+        Request::builder()
+            .uri(format!("/api/teams/company/{}{}", company_id, query))
+            .header("authorization", bearer_token(user_id, company_id))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Comma-separated and repeated forms both return the union
+    for query in ["?status=pending,active", "?status=pending&status=active"] {
+        let response = app.clone().oneshot(list(query)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let teams_json: Value = serde_json::from_slice(&body).unwrap();
+        let mut ids: Vec<String> = teams_json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+
+        let mut expected = vec![pending_id.to_string(), active_id.to_string()];
+        expected.sort();
+        assert_eq!(ids, expected, "query {}", query);
+    }
+
+    // Unknown status is rejected
+    let response = app
+        .clone()
+        .oneshot(list("?status=pending,bogus"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_unique_goal_policy_rejects_duplicate_goal() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    FeatureFlags::postgres(pool.clone())
+        .set(company_id, UNIQUE_GOAL_PER_COMPANY, true)
+        .await
+        .unwrap();
+
+    let user_id = register_user(&app, company_id, "e2e-unique-goal@test.com", "uniquegoal1").await;
+    let first_id = create_team_via_api(&app, company_id, user_id, "Chart the reef").await;
+
+    let create = |goal: &str| {
+        let team_payload = json!({
+            "goal": goal,
+            "company_id": company_id.to_string(),
+            "created_by": user_id.to_string()
+        });
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams")
+            .header("content-type", "application/json")
+            .body(Body::from(team_payload.to_string()))
+            .unwrap()
+    };
+
+    // Same goal (case-insensitive) is rejected
+    let response = app.clone().oneshot(create("chart the REEF")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error_json["code"], "DuplicateGoal");
+
+    // Archiving the original frees the goal
+    sqlx::query!(
+        "UPDATE teams SET status = 'archived' WHERE id = $1",
+        first_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = app.clone().oneshot(create("Chart the reef")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_global_unique_goal_policy_applies_without_flag() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone())
+        .await
+        .layer(Extension(teams::EnforceUniqueGoal(true)));
+
+    let user_id = register_user(&app, company_id, "e2e-global-goal@test.com", "globalgoal1").await;
+    create_team_via_api(&app, company_id, user_id, "Sail the strait").await;
+
+    let team_payload = json!({
+        "goal": "sail the strait",
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string()
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_exhausted_pool_returns_503() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+
+    // A single-connection pool that gives up quickly
+    let database_url = std::env::var("DATABASE_URL").unwrap();
+    let tiny_pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(std::time::Duration::from_millis(200))
+        .connect(&database_url)
+        .await
+        .unwrap();
+    let app = setup_app(tiny_pool.clone()).await;
+
+    // Hold the only connection so handlers time out waiting for one
+    let held = tiny_pool.acquire().await.unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/company/{}", company_id))
+                .header(
+                    "authorization",
+                    bearer_token(uuid::Uuid::new_v4(), company_id),
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response.headers().contains_key("retry-after"));
+
+    // Cleanup
+    drop(held);
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_maintenance_mode_blocks_writes_but_not_reads() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let maintenance = MaintenanceMode::default();
+    let app = setup_app_with(
+        pool.clone(),
+        Arc::new(NoopCaptchaVerifier),
+        maintenance.clone(),
+        LoginLockout::from_env(),
+        LoginDeduplicator::default(),
+        TeamCancellations::default(),
+    )
+    .await;
+    let user_id = register_user(&app, company_id, "e2e-maintenance@test.com", "maintain1").await;
+
+    let create_team = || {
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({
+                    "goal": "Maintenance mission",
+                    "company_id": company_id.to_string(),
+                    "created_by": user_id.to_string()
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+
+    maintenance.set(true);
+
+    let response = app.clone().oneshot(create_team()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response.headers().contains_key("retry-after"));
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error_json["code"], "Maintenance");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/company/{}", company_id))
+                .header("authorization", bearer_token(user_id, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Writes resume once the switch is off
+    maintenance.set(false);
+    let response = app.clone().oneshot(create_team()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_readiness_check_times_out_promptly() {
+    // A single-connection pool whose connection is held, so the probe's
+    // query would otherwise wait out the 30s acquire timeout
+    let database_url = std::env::var("DATABASE_URL").unwrap();
+    let stuck_pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .unwrap();
+    let timeout = auth_handlers::ReadinessTimeout(std::time::Duration::from_millis(100));
+    let app = setup_app(stuck_pool.clone())
+        .await
+        .layer(Extension(timeout));
+    let held = stuck_pool.acquire().await.unwrap();
+
+    let started = std::time::Instant::now();
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/health/ready")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(2),
+        "Probe should give up after the configured timeout"
+    );
+
+    drop(held);
+}
+
+#[tokio::test]
+async fn test_patch_team_updates_only_given_fields() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-patch@test.com", "patchpass1").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Original goal").await;
+
+    let patch = |body: Value| {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/teams/{}", team_id))
+            .header("content-type", "application/json")
+            .header("authorization", bearer_token(user_id, company_id))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+    let read_json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<Value>(&body).unwrap()
+    };
+
+    // Only the budget
+    let response = app
+        .clone()
+        .oneshot(patch(json!({ "budget_limit": "250.00" })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let team_json = read_json(response).await;
+    assert_eq!(team_json["budget_limit"], "250.00");
+    assert_eq!(team_json["goal"], "Original goal");
+
+    // Only the goal
+    let response = app
+        .clone()
+        .oneshot(patch(json!({ "goal": "Revised goal" })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let team_json = read_json(response).await;
+    assert_eq!(team_json["goal"], "Revised goal");
+    assert_eq!(team_json["budget_limit"], "250.00");
+
+    // Explicit null removes the budget
+    let response = app
+        .clone()
+        .oneshot(patch(json!({ "budget_limit": null })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let team_json = read_json(response).await;
+    assert!(team_json["budget_limit"].is_null());
+
+    // Empty body is rejected
+    let response = app.clone().oneshot(patch(json!({}))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Changes were persisted
+    let stored = sqlx::query!(
+        "SELECT goal, budget_limit FROM teams WHERE id = $1",
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(stored.goal, "Revised goal");
+    assert!(stored.budget_limit.is_none());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// POST a team transfer as `caller`
+async fn transfer_team(
+    app: &Router,
+    team_id: uuid::Uuid,
+    caller: uuid::Uuid,
+    company_id: uuid::Uuid,
+    new_owner_id: uuid::Uuid,
+) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/teams/{}/transfer", team_id))
+                .header("content-type", "application/json")
+                .header("authorization", bearer_token(caller, company_id))
+                .body(Body::from(
+                    json!({ "new_owner_id": new_owner_id }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_transfer_team_ownership_to_colleague() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let owner_id =
+        register_user(&app, company_id, "e2e-transfer-owner@test.com", "transfer1").await;
+    let colleague_id = register_user(
+        &app,
+        company_id,
+        "e2e-transfer-colleague@test.com",
+        "transfer2",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, owner_id, "Transferable team").await;
+
+    let response = transfer_team(&app, team_id, owner_id, company_id, colleague_id).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(team_json["created_by"], colleague_id.to_string());
+
+    // The change and its event were persisted
+    let stored_owner = sqlx::query_scalar!("SELECT created_by FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored_owner, colleague_id);
+    let transfers = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM team_events WHERE team_id = $1 AND event_type = 'ownership_transferred'",
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(transfers, Some(1));
+
+    // The former owner is now just a member and cannot take it back
+    let response = transfer_team(&app, team_id, owner_id, company_id, owner_id).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// POST a team cancellation as `caller`
+async fn cancel_team(
+    app: &Router,
+    team_id: uuid::Uuid,
+    caller: uuid::Uuid,
+    company_id: uuid::Uuid,
+) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/teams/{}/cancel", team_id))
+                .header("authorization", bearer_token(caller, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_cancel_team_stops_its_agents() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let cancellations = TeamCancellations::default();
+    let app = setup_app_with(
+        pool.clone(),
+        Arc::new(NoopCaptchaVerifier),
+        MaintenanceMode::default(),
+        LoginLockout::from_env(),
+        LoginDeduplicator::default(),
+        cancellations.clone(),
+    )
+    .await;
+
+    let owner_id = register_user(&app, company_id, "e2e-cancel-owner@test.com", "cancelled1").await;
+    let colleague_id = register_user(
+        &app,
+        company_id,
+        "e2e-cancel-colleague@test.com",
+        "cancelled2",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, owner_id, "Cancellable team").await;
+    let running = cancellations.token(team_id);
+
+    // Only active teams have agents to stop
+    let response = cancel_team(&app, team_id, owner_id, company_id).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    sqlx::query!("UPDATE teams SET status = 'active' WHERE id = $1", team_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Only the owner or an admin may cancel the team
+    let response = cancel_team(&app, team_id, colleague_id, company_id).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(!running.is_cancelled());
+
+    let response = cancel_team(&app, team_id, owner_id, company_id).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(team_json["status"], "Failed");
+    assert!(running.is_cancelled());
+
+    let stored_status =
+        sqlx::query_scalar!("SELECT status::text FROM teams WHERE id = $1", team_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(stored_status.as_deref(), Some("failed"));
+
+    // A finished team cannot be cancelled again
+    let response = cancel_team(&app, team_id, owner_id, company_id).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_transfer_team_ownership_rejects_other_company_user() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let owner_id = register_user(
+        &app,
+        company_id,
+        "e2e-transfer-cross-owner@test.com",
+        "transfer3",
+    )
+    .await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-transfer-cross-outsider@test.com",
+        "transfer4",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, owner_id, "Stays in company").await;
+
+    let response = transfer_team(&app, team_id, owner_id, company_id, outsider_id).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let stored_owner = sqlx::query_scalar!("SELECT created_by FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored_owner, owner_id);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_batch_create_reports_id_collisions_and_rolls_back() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let admin_id = register_user(&app, company_id, "e2e-batch-admin@test.com", "batchadmin1").await;
+    sqlx::query!("UPDATE users SET role = 'admin' WHERE id = $1", admin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let existing = create_team_via_api(&app, company_id, admin_id, "Existing mission").await;
+    let token = bearer_token(admin_id, company_id);
+
+    let batch_create = |teams: Value| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams/batch")
+            .header("content-type", "application/json")
+            .header("authorization", token.clone())
+            .body(Body::from(json!({ "teams": teams }).to_string()))
+            .unwrap()
+    };
+    let error_message = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error_json: Value = serde_json::from_slice(&body).unwrap();
+        error_json["error"].as_str().unwrap().to_string()
+    };
+
+    // Two entries with the same ID
+    let repeated = uuid::Uuid::new_v4();
+    let response = app
+        .clone()
+        .oneshot(batch_create(json!([
+            { "goal": "Batch mission 0" },
+            { "id": repeated, "goal": "Batch mission 1" },
+            { "id": repeated, "goal": "Batch mission 2" },
+        ])))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        error_message(response).await,
+        "Entry 2 reuses the ID of entry 1"
+    );
+
+    // An entry reusing an existing team's ID rolls back the whole batch
+    let fresh = uuid::Uuid::new_v4();
+    let response = app
+        .clone()
+        .oneshot(batch_create(json!([
+            { "id": fresh, "goal": "Batch mission 3" },
+            { "id": existing, "goal": "Batch mission 4" },
+        ])))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        error_message(response).await,
+        "Entry 1 uses the ID of an existing team"
+    );
+
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM teams WHERE company_id = $1"#,
+        company_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(count, 1);
+
+    // Without collisions every team is created with its requested ID
+    let response = app
+        .clone()
+        .oneshot(batch_create(json!([
+            { "id": fresh, "goal": "Batch mission 3" },
+            { "goal": "Batch mission 4", "tags": ["batch"] },
+        ])))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    let teams = result["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 2);
+    assert_eq!(teams[0]["id"], fresh.to_string());
+    assert_eq!(teams[1]["created_by"], admin_id.to_string());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_bulk_delete_only_removes_own_company_teams() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let admin_id = register_user(&app, company_id, "e2e-bulk-admin@test.com", "bulkadmin1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-bulk-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+
+    let own_1 = create_team_via_api(&app, company_id, admin_id, "Bulk mission 1").await;
+    let own_2 = create_team_via_api(&app, company_id, admin_id, "Bulk mission 2").await;
+    let foreign = create_team_via_api(&app, other_company_id, outsider_id, "Foreign mission").await;
+    let missing = uuid::Uuid::new_v4();
+
+    let bulk_delete = |token: String| {
+        let payload = json!({ "team_ids": [own_1, foreign, own_2, missing] });
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams/bulk-delete")
+            .header("content-type", "application/json")
+            .header("authorization", token)
+            .body(Body::from(payload.to_string()))
+            .unwrap()
+    };
+
+    // Members may not bulk delete
+    let response = app
+        .clone()
+        .oneshot(bulk_delete(bearer_token(admin_id, company_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Admins delete only their own company's teams
+    let response = app
+        .clone()
+        .oneshot(bulk_delete(bearer_token_with_role(
+            admin_id,
+            company_id,
+            UserRole::Admin,
+        )))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["deleted"], 2);
+    assert_eq!(result["skipped"], 2);
+    assert_eq!(result["missing"], json!([foreign, missing]));
+
+    let remaining = sqlx::query!(
+        "SELECT id FROM teams WHERE id = ANY($1)",
+        &[own_1, own_2, foreign][..]
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, foreign);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_tags_can_be_set_and_filtered() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-tags@test.com", "tagspass1").await;
+
+    // Tags given at creation are normalized
+    let team_payload = json!({
+        "goal": "Tagged launch mission",
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string(),
+        "tags": ["Marketing", " q3 ", "MARKETING"]
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(team_json["tags"], json!(["marketing", "q3"]));
+    let marketing_id = team_json["id"].as_str().unwrap().to_string();
+
+    // Tags can be replaced later
+    let other_id = create_team_via_api(&app, company_id, user_id, "Untagged tags mission").await;
+    let put_tags = |tags: Value| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/teams/{}/tags", other_id))
+            .header("content-type", "application/json")
+            .header("authorization", bearer_token(user_id, company_id))
+            .body(Body::from(json!({ "tags": tags }).to_string()))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(put_tags(json!(["Q3"]))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Too many tags are rejected
+    let too_many: Vec<String> = (0..11).map(|i| format!("tag{}", i)).collect();
+    let response = app
+        .clone()
+        .oneshot(put_tags(json!(too_many)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let list_ids = |query: &'static str| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri(format!("/api/teams/company/{}{}", company_id, query))
+                        .header("authorization", bearer_token(user_id, company_id))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let teams_json: Value = serde_json::from_slice(&body).unwrap();
+            let mut ids: Vec<String> = teams_json
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["id"].as_str().unwrap().to_string())
+                .collect();
+            ids.sort();
+            ids
+        }
+    };
+
+    // Filtering matches the normalized tag
+    let mut both = vec![marketing_id.clone(), other_id.to_string()];
+    both.sort();
+    assert_eq!(list_ids("?tag=Q3").await, both);
+    assert_eq!(list_ids("?tag=marketing").await, vec![marketing_id]);
+    assert!(list_ids("?tag=sales").await.is_empty());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_list_teams_created_between() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-range@test.com", "rangepass1").await;
+    let inside_id = create_team_via_api(&app, company_id, user_id, "Range inside mission").await;
+    let outside_id = create_team_via_api(&app, company_id, user_id, "Range outside mission").await;
+
+    for (id, created_at) in [
+        (inside_id, "2024-06-15T00:00:00Z"),
+        (outside_id, "2023-01-01T00:00:00Z"),
+    ] {
+        sqlx::query("UPDATE teams SET created_at = $1::timestamptz WHERE id = $2")
+            .bind(created_at)
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let list = |query: &str| {
+        Request::builder()
+            .uri(format!("/api/teams?{}", query))
+            .header("authorization", bearer_token(user_id, company_id))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(list("from=2024-01-01T00:00:00Z&to=2024-12-31T23:59:59Z"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let teams_json: Value = serde_json::from_slice(&body).unwrap();
+    let ids: Vec<&str> = teams_json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec![inside_id.to_string()]);
+
+    // Reversed range is rejected
+    let response = app
+        .clone()
+        .oneshot(list("from=2024-12-31T00:00:00Z&to=2024-01-01T00:00:00Z"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_cost_breakdown_sums_spend_per_specialization() {
+    use ghostpirates_api::agents::types::Specialization;
+    use ghostpirates_api::domain::repositories::cost_repository::CostEntry;
+    use ghostpirates_api::domain::repositories::CostRepository;
+    use ghostpirates_api::infrastructure::repositories::PostgresCostRepository;
+    use rust_decimal::Decimal;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-costs@test.com", "costspass1").await;
+    let team_payload = json!({
+        "goal": "Cost breakdown mission",
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string(),
+        "budget_limit": "100.00"
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let team_json: Value = serde_json::from_slice(&body).unwrap();
+    let team_id: uuid::Uuid = team_json["id"].as_str().unwrap().parse().unwrap();
+
+    // Two workers with different specializations record spend
+    let cost_repo = PostgresCostRepository::new(pool.clone());
+    let coder_id = uuid::Uuid::new_v4();
+    let tester_id = uuid::Uuid::new_v4();
+    for (worker_id, specialization, cost) in [
+        (coder_id, Specialization::Coder, Decimal::new(1250, 2)),
+        (coder_id, Specialization::Coder, Decimal::new(750, 2)),
+        (tester_id, Specialization::Tester, Decimal::new(500, 2)),
+    ] {
+        cost_repo
+            .record(&CostEntry {
+                team_id,
+                task_id: None,
+                worker_id: Some(worker_id),
+                specialization: Some(specialization),
+                model_name: "test-model".to_string(),
+                input_tokens: 100,
+                output_tokens: 50,
+                total_cost: cost,
+            })
+            .await
+            .unwrap();
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/{}/cost-breakdown", team_id))
+                .header("authorization", bearer_token(user_id, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let breakdown: Value = serde_json::from_slice(&body).unwrap();
+    let amount = |value: &Value| value.as_str().unwrap().parse::<Decimal>().unwrap();
+
+    assert_eq!(
+        amount(&breakdown["by_specialization"]["Coder"]),
+        Decimal::from(20)
+    );
+    assert_eq!(
+        amount(&breakdown["by_specialization"]["Tester"]),
+        Decimal::from(5)
+    );
+    assert_eq!(amount(&breakdown["total_spent"]), Decimal::from(25));
+    // Nothing was charged to the team's budget yet
+    assert_eq!(amount(&breakdown["remaining"]), Decimal::from(100));
+
+    // Remaining follows the spend the budget is enforced against, which
+    // includes usage not itemized per worker
+    sqlx::query!("UPDATE teams SET amount_spent = 30 WHERE id = $1", team_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/{}/cost-breakdown", team_id))
+                .header("authorization", bearer_token(user_id, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let breakdown: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(amount(&breakdown["total_spent"]), Decimal::from(25));
+    assert_eq!(amount(&breakdown["remaining"]), Decimal::from(70));
+
+    // Another company cannot see the breakdown
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/{}/cost-breakdown", team_id))
+                .header("authorization", bearer_token(user_id, uuid::Uuid::new_v4()))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_stats_count_by_status() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-stats@test.com", "statspass1").await;
+    create_team_via_api(&app, company_id, user_id, "Stats pending one").await;
+    create_team_via_api(&app, company_id, user_id, "Stats pending two").await;
+    let active_id = create_team_via_api(&app, company_id, user_id, "Stats active").await;
+
+    sqlx::query!(
+        "UPDATE teams SET status = 'active' WHERE id = $1",
+        active_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/teams/company/{}/stats", company_id))
+                .header("authorization", bearer_token(user_id, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stats: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(
+        stats["by_status"],
+        json!({
+            "pending": 2,
+            "planning": 0,
+            "active": 1,
+            "completed": 0,
+            "failed": 0,
+            "archived": 0
+        })
+    );
+    assert_eq!(stats["total"], 3);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_export_teams_csv_quotes_goals() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-csv@test.com", "csvpass123").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Scrape, parse \"fast\"").await;
+    let created_at = sqlx::query_scalar!("SELECT created_at FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    let request = Request::builder()
+        .uri(format!("/api/companies/{}/teams.csv", company_id))
+        .header("authorization", bearer_token(user_id, company_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/csv; charset=utf-8"
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let csv = String::from_utf8(body.to_vec()).unwrap();
+    let expected_row = format!(
+        "{},\"Scrape, parse \"\"fast\"\"\",pending,{},,0",
+        team_id,
+        created_at.to_rfc3339()
+    );
+    assert_eq!(
+        csv.lines().collect::<Vec<_>>(),
+        [
+            "id,goal,status,created_at,budget_limit,spent",
+            expected_row.as_str()
+        ]
+    );
+
+    // Another company's export is forbidden
+    let request = Request::builder()
+        .uri(format!("/api/companies/{}/teams.csv", other_company_id))
+        .header("authorization", bearer_token(user_id, company_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+/// Secret internal callers present to the introspection endpoint
+const TEST_INTERNAL_SECRET: &str = "test-internal-secret";
+
+/// POST a token to the introspection endpoint as an internal service
+async fn introspect_token(app: &Router, token: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/introspect")
+                .header("content-type", "application/json")
+                .header("x-internal-secret", TEST_INTERNAL_SECRET)
+                .body(Body::from(json!({ "token": token }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_introspect_valid_token() {
+    let pool = setup_test_db().await;
+    let app = setup_app(pool).await;
+
+    let user_id = uuid::Uuid::new_v4();
+    let company_id = uuid::Uuid::new_v4();
+    let bearer = bearer_token_with_role(user_id, company_id, UserRole::Admin);
+    let token = bearer.strip_prefix("Bearer ").unwrap();
+
+    let (status, body) = introspect_token(&app, token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], true);
+    assert_eq!(body["sub"], user_id.to_string());
+    assert_eq!(body["company_id"], company_id.to_string());
+    assert_eq!(body["role"], "admin");
+    assert!(body["exp"].as_u64().is_some());
+
+    // Callers without the internal secret are turned away
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/introspect")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "token": token }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_introspect_expired_token_is_inactive() {
+    use chrono::TimeZone;
+
+    let pool = setup_test_db().await;
+    let app = setup_app(pool).await;
+
+    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+    let issued_at = chrono::Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+    let token = ghostpirates_api::auth::jwt::create_token(
+        uuid::Uuid::new_v4(),
+        uuid::Uuid::new_v4(),
+        UserRole::Member,
+        0,
+        &secret,
+        &ghostpirates_api::domain::shared::MockClock::new(issued_at),
+    )
+    .unwrap();
+
+    let (status, body) = introspect_token(&app, &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "active": false }));
+}
+
+#[tokio::test]
+async fn test_error_message_follows_accept_language() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    FeatureFlags::postgres(pool.clone())
+        .set(company_id, UNIQUE_GOAL_PER_COMPANY, true)
+        .await
+        .unwrap();
+
+    let user_id = register_user(&app, company_id, "e2e-locale@test.com", "localepass1").await;
+    create_team_via_api(&app, company_id, user_id, "Sail to Tortuga").await;
+
+    let team_payload = json!({
+        "goal": "Sail to Tortuga",
+        "company_id": company_id.to_string(),
+        "created_by": user_id.to_string()
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/teams")
+                .header("content-type", "application/json")
+                .header("accept-language", "es-ES,es;q=0.9,en;q=0.5")
+                .body(Body::from(team_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error_json["code"], "DuplicateGoal");
+    assert_eq!(error_json["error"], "Ya existe un equipo con este objetivo");
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// PATCH a user's profile as the given caller
+async fn patch_user(
+    app: &Router,
+    bearer: String,
+    user_id: uuid::Uuid,
+    payload: Value,
+) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/api/users/{}", user_id))
+                .header("content-type", "application/json")
+                .header("authorization", bearer)
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_update_user_full_name() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-profile@test.com", "profilepass1").await;
+
+    let response = patch_user(
+        &app,
+        bearer_token(user_id, company_id),
+        user_id,
+        json!({ "full_name": "  Anne   Bonny " }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let user: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(user["full_name"], "Anne Bonny");
+    assert_eq!(user["email"], "e2e-profile@test.com");
+    for field in ["created_at", "updated_at"] {
+        let at = user[field].as_str().unwrap();
+        assert!(at.ends_with('Z'), "{field}: {at}");
+    }
+
+    let stored = sqlx::query!(
+        "SELECT full_name, updated_at FROM users WHERE id = $1",
+        user_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(stored.full_name, "Anne Bonny");
+    let updated_at = chrono::DateTime::parse_from_rfc3339(user["updated_at"].as_str().unwrap());
+    assert_eq!(
+        updated_at.unwrap().timestamp_millis(),
+        stored.updated_at.timestamp_millis(),
+        "The response reports the stored updated_at"
+    );
+
+    // Members cannot edit someone else's profile
+    let other_id = register_user(&app, company_id, "e2e-other@test.com", "profilepass2").await;
+    let response = patch_user(
+        &app,
+        bearer_token(other_id, company_id),
+        user_id,
+        json!({ "full_name": "Mary Read" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_update_user_rejects_duplicate_email() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-email-change@test.com", "emailpass1").await;
+    register_user(&app, company_id, "e2e-email-taken@test.com", "emailpass2").await;
+
+    let response = patch_user(
+        &app,
+        bearer_token(user_id, company_id),
+        user_id,
+        json!({ "email": "e2e-email-taken@test.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error_json["code"], "DuplicateEmail");
+
+    let stored = sqlx::query!("SELECT email FROM users WHERE id = $1", user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored.email, "e2e-email-change@test.com");
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_active_team_quota_limits_creation() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-quota@test.com", "quotapass1").await;
+    sqlx::query!(
+        "UPDATE companies SET max_active_teams = 2 WHERE id = $1",
+        company_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Creation succeeds while under the quota
+    let first_id = create_team_via_api(&app, company_id, user_id, "Quota team one").await;
+    create_team_via_api(&app, company_id, user_id, "Quota team two").await;
+
+    // At the limit, a third team is rejected
+    let create = || {
+        let team_payload = json!({
+            "goal": "Quota team three",
+            "company_id": company_id.to_string(),
+            "created_by": user_id.to_string()
+        });
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams")
+            .header("content-type", "application/json")
+            .body(Body::from(team_payload.to_string()))
+            .unwrap()
+    };
+    let response = app.clone().oneshot(create()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error_json["code"], "TeamQuotaExceeded");
+
+    // Terminal teams no longer count toward the quota
+    sqlx::query!(
+        "UPDATE teams SET status = 'completed' WHERE id = $1",
+        first_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = app.clone().oneshot(create()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_create_team_rejects_invalid_budget() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-budget@test.com", "budgetpass1").await;
+    let create = |budget: Value| {
+        let team_payload = json!({
+            "goal": "Budget validation mission",
+            "company_id": company_id.to_string(),
+            "created_by": user_id.to_string(),
+            "budget_limit": budget
+        });
+        Request::builder()
+            .method("POST")
+            .uri("/api/teams")
+            .header("content-type", "application/json")
+            .body(Body::from(team_payload.to_string()))
+            .unwrap()
+    };
+
+    // Non-numeric input is a 400 naming the field
+    let response = app.clone().oneshot(create(json!("abc"))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let error_json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(error_json["error"]
+        .as_str()
+        .unwrap()
+        .contains("budget_limit must be a decimal number"));
+
+    // Over the maximum
+    let response = app.clone().oneshot(create(json!(5_000_000))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_company_context_loads_company_from_database() {
+    use axum::extract::FromRequestParts;
+    use ghostpirates_api::api::middleware::CompanyContext;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-context@test.com", "contextpass1").await;
+    let parts_with = |authorization: String| {
+        let (parts, _) = Request::builder()
+            .header("authorization", authorization)
+            .body(())
+            .unwrap()
+            .into_parts();
+        parts
+    };
+
+    // The company comes from the user's row, not the token's claim
+    let mut parts = parts_with(bearer_token(user_id, uuid::Uuid::new_v4()));
+    let context = CompanyContext::from_request_parts(&mut parts, &pool)
+        .await
+        .unwrap();
+    assert_eq!(context.user_id, user_id);
+    assert_eq!(context.company_id, company_id);
+    assert_eq!(context.role, UserRole::Member);
+
+    let mut parts = parts_with("Bearer not-a-jwt".to_string());
+    let error = CompanyContext::from_request_parts(&mut parts, &pool)
+        .await
+        .unwrap_err();
+    let response = axum::response::IntoResponse::into_response(error);
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_company_context_is_reloaded_after_a_company_move() {
+    use axum::extract::FromRequestParts;
+    use ghostpirates_api::api::middleware::CompanyContext;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let new_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(
+        &app,
+        company_id,
+        "e2e-context-move@test.com",
+        "contextmove1",
+    )
+    .await;
+    let context_for = |token_version: i32| {
+        let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
+        let token = ghostpirates_api::auth::jwt::create_token(
+            user_id,
+            company_id,
+            UserRole::Member,
+            token_version,
+            &secret,
+            &ghostpirates_api::domain::shared::SystemClock,
+        )
+        .unwrap();
+        let (mut parts, _) = Request::builder()
+            .header("authorization", format!("Bearer {}", token))
+            .body(())
+            .unwrap()
+            .into_parts();
+        let pool = pool.clone();
+        async move { CompanyContext::from_request_parts(&mut parts, &pool).await }
+    };
+
+    // Cache the context before the move
+    assert_eq!(context_for(0).await.unwrap().company_id, company_id);
+
+    let response = app
+        .clone()
+        .oneshot(change_company_request(
+            user_id,
+            json!({ "company_id": new_company_id, "teams": "move" }),
+            Some(TEST_INTERNAL_SECRET),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A token minted after the move sees the new company at once
+    assert_eq!(context_for(1).await.unwrap().company_id, new_company_id);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, new_company_id).await;
+}
+
+#[tokio::test]
+async fn test_login_does_not_wait_for_last_login_update() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-lastlogin@test.com", "lastlogin1").await;
+
+    // Hold the user's row lock so the last-login update cannot proceed
+    let mut lock = pool.begin().await.unwrap();
+    sqlx::query!("SELECT id FROM users WHERE id = $1 FOR UPDATE", user_id)
+        .fetch_one(&mut *lock)
+        .await
+        .unwrap();
+
+    let login = Request::builder()
+        .method("POST")
+        .uri("/api/auth/login")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({ "email": "e2e-lastlogin@test.com", "password": "lastlogin1" }).to_string(),
+        ))
+        .unwrap();
+    let response = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        app.clone().oneshot(login),
+    )
+    .await
+    .expect("login should not wait for the blocked update")
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Once the lock is released the background update lands
+    lock.rollback().await.unwrap();
+    let mut last_login = None;
+    for _ in 0..50 {
+        last_login = sqlx::query_scalar!("SELECT last_login FROM users WHERE id = $1", user_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        if last_login.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert!(last_login.is_some());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// App whose login lockout is judged by the returned clock: three
+/// failures lock the account for ten minutes
+///
+/// Deduplication is off so every login reaches the lockout check.
+async fn setup_app_with_lockout_clock(pool: PgPool) -> (Router, Arc<MockClock>) {
+    let clock = Arc::new(MockClock::new(chrono::Utc::now()));
+    let policy = LockoutPolicy {
+        max_failures: 3,
+        window: chrono::Duration::minutes(15),
+        cooldown: chrono::Duration::minutes(10),
+    };
+    let app = setup_app_with(
+        pool,
+        Arc::new(NoopCaptchaVerifier),
+        MaintenanceMode::default(),
+        LoginLockout::new(policy, clock.clone()),
+        LoginDeduplicator::new(std::time::Duration::ZERO),
+        TeamCancellations::default(),
+    )
+    .await;
+
+    (app, clock)
+}
+
+/// Attempt a login and return the response
+async fn login(app: &Router, email: &str, password: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/login")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "email": email, "password": password }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_login_locks_after_repeated_failures_until_cooldown() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let (app, clock) = setup_app_with_lockout_clock(pool.clone()).await;
+    let email = "e2e-lockout@test.com";
+    register_user(&app, company_id, email, "lockout1").await;
+
+    for _ in 0..3 {
+        let response = login(&app, email, "wrong-password").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // Locked: even the right password is refused
+    clock.advance(chrono::Duration::minutes(4));
+    let response = login(&app, email, "lockout1").await;
+    assert_eq!(response.status(), StatusCode::LOCKED);
+    assert_eq!(response.headers()["retry-after"], "360");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["code"], "AccountLocked");
+
+    // The lock clears once the cooldown has passed
+    clock.advance(chrono::Duration::minutes(6));
+    let response = login(&app, email, "lockout1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_parallel_failed_logins_are_all_counted() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let (app, _clock) = setup_app_with_lockout_clock(pool.clone()).await;
+    let email = "e2e-lockout-parallel@test.com";
+    register_user(&app, company_id, email, "lockout3").await;
+
+    let guesses = (0..3).map(|_| login(&app, email, "wrong-password"));
+    for response in futures::future::join_all(guesses).await {
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let response = login(&app, email, "lockout3").await;
+    assert_eq!(response.status(), StatusCode::LOCKED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_unknown_emails_lock_like_registered_ones() {
+    let pool = setup_test_db().await;
+    let (app, _clock) = setup_app_with_lockout_clock(pool.clone()).await;
+    let email = "e2e-lockout-nobody@test.com";
+    sqlx::query!("DELETE FROM login_attempts WHERE email = $1", email)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let response = login(&app, email, "wrong-password").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = login(&app, &email.to_uppercase(), "wrong-password").await;
+    assert_eq!(response.status(), StatusCode::LOCKED);
+
+    // Cleanup
+    sqlx::query!("DELETE FROM login_attempts WHERE email = $1", email)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_successful_login_resets_failed_login_count() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let (app, _clock) = setup_app_with_lockout_clock(pool.clone()).await;
+    let email = "e2e-lockout-reset@test.com";
+    register_user(&app, company_id, email, "lockout2").await;
+
+    for _ in 0..2 {
+        login(&app, email, "wrong-password").await;
+    }
+    let response = login(&app, email, "lockout2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let remaining = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM login_attempts WHERE email = $1",
+        email
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(remaining, Some(0));
+
+    // Two more failures are not enough to lock the account again
+    for _ in 0..2 {
+        login(&app, email, "wrong-password").await;
+    }
+    let response = login(&app, email, "lockout2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_identical_logins_within_window_share_one_token() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let email = "e2e-login-dedup@test.com";
+    register_user(&app, company_id, email, "dedupe123").await;
+
+    let token = |response: axum::response::Response| async move {
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body).unwrap();
+        json["token"].as_str().unwrap().to_string()
+    };
+
+    // Tokens carry second-resolution expiry, so a freshly minted one
+    // would differ after the pause
+    let first = token(login(&app, email, "dedupe123").await).await;
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let second = token(login(&app, email, "dedupe123").await).await;
+    assert_eq!(first, second);
+
+    // A wrong password is never answered from the cache
+    let response = login(&app, email, "wrong-password").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Nor is a disabled account
+    sqlx::query!(
+        "UPDATE users SET is_active = false WHERE LOWER(email) = $1",
+        email
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = login(&app, email, "dedupe123").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// Log output captured by a test subscriber
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_request_logs_mask_passwords() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    register_user(
+        &app,
+        company_id,
+        "e2e-redaction@test.com",
+        "plaintext-secret-1",
+    )
+    .await;
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains(r#""email":"e2e-redaction@test.com""#));
+    assert!(output.contains(r#""password":"***""#));
+    assert!(!output.contains("plaintext-secret-1"));
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_export_import_round_trip() {
+    let pool = setup_test_db().await;
+    let source_company = create_test_company(&pool).await;
+    let target_company = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let owner_id = register_user(
+        &app,
+        source_company,
+        "e2e-export-owner@test.com",
+        "exportpass1",
+    )
+    .await;
+    let admin_id = register_user(
+        &app,
+        target_company,
+        "e2e-import-admin@test.com",
+        "importpass1",
+    )
+    .await;
+    sqlx::query!("UPDATE users SET role = 'admin' WHERE id = $1", admin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // A team with a manager, one worker, a task assigned to it, and a subtask
+    let team_id = create_team_via_api(&app, source_company, owner_id, "Portable mission").await;
+    let manager_id = uuid::Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO managers (id, team_id, model, temperature, max_tokens)
+        VALUES ($1, $2, 'claude-3-5-sonnet-20241022', 0.7, 4096)
+        "#,
+        manager_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "UPDATE teams SET manager_agent_id = $1 WHERE id = $2",
+        manager_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let worker_id = uuid::Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO team_members (id, team_id, agent_id, role, specialization, status, skills)
+        VALUES ($1, $2, $1, 'worker', 'Coder', 'busy', ARRAY['Rust'])
+        "#,
+        worker_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let parent_id = uuid::Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO tasks (id, team_id, title, description, assigned_to, status)
+        VALUES ($1, $2, 'Parent', 'Top-level task', $3, 'assigned')
+        "#,
+        parent_id,
+        team_id,
+        worker_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query!(
+        r#"
+        INSERT INTO tasks (team_id, parent_task_id, title, description)
+        VALUES ($1, $2, 'Child', 'Subtask')
+        "#,
+        team_id,
+        parent_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let export_request = |team_id: uuid::Uuid, user_id: uuid::Uuid, company_id: uuid::Uuid| {
+        Request::builder()
+            .uri(format!("/api/teams/{}/export", team_id))
+            .header("authorization", bearer_token(user_id, company_id))
+            .body(Body::empty())
+            .unwrap()
+    };
+    let import_request = |uri: &str, export: &Value| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("authorization", bearer_token(admin_id, target_company))
+            .body(Body::from(export.to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(export_request(team_id, owner_id, source_company))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let export: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(export["workers"].as_array().unwrap().len(), 1);
+    assert_eq!(export["workers"][0]["skills"], json!(["Rust"]));
+    assert_eq!(export["manager"]["id"], json!(manager_id));
+    assert_eq!(export["tasks"].as_array().unwrap().len(), 2);
+
+    // Another company cannot export the team
+    let response = app
+        .clone()
+        .oneshot(export_request(team_id, admin_id, target_company))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Import into the other company with new IDs
+    let response = app
+        .clone()
+        .oneshot(import_request("/api/teams/import", &export))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let imported: Value = serde_json::from_slice(&body).unwrap();
+    let imported_id = uuid::Uuid::parse_str(imported["id"].as_str().unwrap()).unwrap();
+    assert_ne!(imported_id, team_id);
+    assert_eq!(imported["company_id"], target_company.to_string());
+    assert_eq!(imported["goal"], "Portable mission");
+
+    let response = app
+        .clone()
+        .oneshot(export_request(imported_id, admin_id, target_company))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let copy: Value = serde_json::from_slice(&body).unwrap();
+    let copy_worker = copy["workers"][0]["id"].clone();
+    let copy_parent = &copy["tasks"][0];
+    assert_ne!(copy_worker, json!(worker_id));
+    assert_eq!(copy["workers"][0]["skills"], json!(["Rust"]));
+    assert_ne!(copy["manager"]["id"], json!(manager_id));
+    assert_eq!(copy["team"]["manager_agent_id"], copy["manager"]["id"]);
+    assert_eq!(copy_parent["title"], "Parent");
+    assert_eq!(copy_parent["assigned_to"], copy_worker);
+    assert_eq!(copy["tasks"][1]["parent_task_id"], copy_parent["id"]);
+    assert_eq!(
+        copy["events"].as_array().unwrap().len(),
+        export["events"].as_array().unwrap().len()
+    );
+
+    // Preserving IDs of a team that still exists is a conflict
+    let response = app
+        .clone()
+        .oneshot(import_request(
+            "/api/teams/import?preserve_ids=true",
+            &export,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // So is a new team ID whose worker and task IDs are taken
+    let mut colliding = export.clone();
+    colliding["team"]["id"] = json!(uuid::Uuid::new_v4());
+    colliding["manager"]["id"] = json!(uuid::Uuid::new_v4());
+    colliding["team"]["manager_agent_id"] = colliding["manager"]["id"].clone();
+    let response = app
+        .clone()
+        .oneshot(import_request(
+            "/api/teams/import?preserve_ids=true",
+            &colliding,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Invariants are checked before anything is stored
+    let mut invalid = export.clone();
+    invalid["team"]["goal"] = json!("   ");
+    let response = app
+        .clone()
+        .oneshot(import_request("/api/teams/import", &invalid))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let mut invalid = export.clone();
+    invalid["workers"][0]["status"] = json!("sleeping");
+    let response = app
+        .clone()
+        .oneshot(import_request("/api/teams/import", &invalid))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Imports count toward the active team quota
+    sqlx::query!(
+        "UPDATE companies SET max_active_teams = 1 WHERE id = $1",
+        target_company
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = app
+        .clone()
+        .oneshot(import_request("/api/teams/import", &export))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Cleanup
+    cleanup_test_company(&pool, source_company).await;
+    cleanup_test_company(&pool, target_company).await;
+}
+
+/// Builds a registration request from `ip` with the given JSON body
+fn register_request_from(ip: &str, payload: &Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/api/auth/register")
+        .header("content-type", "application/json")
+        .header("x-forwarded-for", ip)
+        .body(Body::from(serde_json::to_string(payload).unwrap()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_register_is_rate_limited_per_ip() {
+    let pool = setup_test_db().await;
+    let app = setup_app(pool).await;
+
+    // Rejected payloads still count towards the limit
+    for _ in 0..5 {
+        let response = app
+            .clone()
+            .oneshot(register_request_from("203.0.113.7", &json!({})))
+            .await
+            .unwrap();
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(register_request_from("203.0.113.7", &json!({})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+
+    // Other clients keep their own budget
+    let response = app
+        .clone()
+        .oneshot(register_request_from("198.51.100.2", &json!({})))
+        .await
+        .unwrap();
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+/// CAPTCHA verifier that rejects every token
+struct RejectingCaptcha;
+
+#[async_trait::async_trait]
+impl CaptchaVerifier for RejectingCaptcha {
+    async fn verify(&self, _token: Option<&str>) -> Result<bool, String> {
+        Ok(false)
+    }
+}
+
+#[tokio::test]
+async fn test_register_rejects_failed_captcha() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app_with_captcha(pool.clone(), Arc::new(RejectingCaptcha)).await;
+    let email = format!("captcha-{}@example.com", uuid::Uuid::new_v4());
+
+    let payload = json!({
+        "email": email,
+        "password": "securepass456",
+        "full_name": "Captcha Test User",
+        "company_id": company_id.to_string(),
+        "captcha_token": "bot-token"
+    });
+    let response = app
+        .clone()
+        .oneshot(register_request_from("203.0.113.8", &payload))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], "CAPTCHA verification failed");
+
+    // No user was created
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE email = $1")
+        .bind(&email)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_get_team_workers_returns_persisted_roster() {
+    use ghostpirates_api::agents::{ManagerAgent, WorkerAgent, WorkerSpec};
+    use ghostpirates_api::domain::repositories::TaskAssignment;
+    use ghostpirates_api::infrastructure::repositories::save_team_formation;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-roster@test.com", "rosterpass1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-roster-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Roster mission").await;
+
+    let get_workers = |caller: uuid::Uuid, caller_company: uuid::Uuid| {
+        Request::builder()
+            .uri(format!("/api/teams/{}/workers", team_id))
+            .header("authorization", bearer_token(caller, caller_company))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Before formation the roster is empty
+    let response = app
+        .clone()
+        .oneshot(get_workers(user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(serde_json::from_slice::<Value>(&body).unwrap(), json!([]));
+
+    // Form a coder working on a task and a blocked tester
+    let spec = |specialization: &str| WorkerSpec {
+        specialization: specialization.to_string(),
+        skills: vec!["Rust".to_string()],
+        responsibilities: vec![],
+        required_tools: vec![],
+    };
+    let task_id = uuid::Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO tasks (id, team_id, title, description) VALUES ($1, $2, 'Build', 'Build it')",
+        task_id,
+        team_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let mut coder = WorkerAgent::from_spec(team_id, &spec("Coder")).unwrap();
+    coder.assign_task(task_id).unwrap();
+    let mut tester = WorkerAgent::from_spec(team_id, &spec("Tester")).unwrap();
+    tester.block("waiting on fixtures");
+    save_team_formation(
+        &pool,
+        &ManagerAgent::new(team_id),
+        &[coder.clone(), tester.clone()],
+        &[TaskAssignment {
+            task_id,
+            worker_id: coder.id,
+        }],
+    )
+    .await
+    .expect("Failed to save team formation");
+
+    let response = app
+        .clone()
+        .oneshot(get_workers(user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let workers: Value = serde_json::from_slice(&body).unwrap();
+    let workers = workers.as_array().unwrap();
+    assert_eq!(workers.len(), 2);
+    let coder_json = workers.iter().find(|w| w["id"] == json!(coder.id)).unwrap();
+    assert_eq!(coder_json["specialization"], "Coder");
+    assert_eq!(
+        coder_json["status"],
+        json!({ "state": "Working", "reason": null })
+    );
+    assert_eq!(coder_json["assigned_task_id"], json!(task_id));
+    let tester_json = workers
+        .iter()
+        .find(|w| w["id"] == json!(tester.id))
+        .unwrap();
+    assert_eq!(tester_json["specialization"], "Tester");
+    assert_eq!(
+        tester_json["status"],
+        json!({ "state": "Blocked", "reason": "waiting on fixtures" })
+    );
+    assert!(tester_json["assigned_task_id"].is_null());
+    // Internal fields are not exposed
+    assert!(coder_json.get("skills").is_none());
+    assert!(coder_json.get("schema_version").is_none());
+
+    // Another company's user cannot see the roster
+    let response = app
+        .clone()
+        .oneshot(get_workers(outsider_id, other_company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_patch_worker_skills_persists_changes() {
+    use ghostpirates_api::agents::{WorkerAgent, WorkerSpec};
+    use ghostpirates_api::domain::repositories::WorkerRepository;
+    use ghostpirates_api::infrastructure::repositories::PostgresWorkerRepository;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-skills@test.com", "skillspass1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-skills-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Skills mission").await;
+
+    let worker_repo = PostgresWorkerRepository::new(pool.clone());
+    let worker = WorkerAgent::from_spec(
+        team_id,
+        &WorkerSpec {
+            specialization: "Coder".to_string(),
+            skills: vec!["Rust".to_string(), "Python".to_string()],
+            responsibilities: vec![],
+            required_tools: vec![],
+        },
+    )
+    .unwrap();
+    worker_repo
+        .save_many(std::slice::from_ref(&worker))
+        .await
+        .unwrap();
+
+    let patch_skills = |caller: uuid::Uuid, caller_company: uuid::Uuid, body: Value| {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/workers/{}/skills", worker.id))
+            .header("content-type", "application/json")
+            .header("authorization", bearer_token(caller, caller_company))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(patch_skills(
+            user_id,
+            company_id,
+            json!({ "add": ["rust", "SQL"], "remove": ["python"] }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["skills"], json!(["Rust", "SQL"]));
+
+    let stored = worker_repo.find_by_id(worker.id).await.unwrap().unwrap();
+    assert_eq!(stored.skills, vec!["Rust".to_string(), "SQL".to_string()]);
+
+    // Concurrent updates both land
+    let (first, second) = tokio::join!(
+        app.clone()
+            .oneshot(patch_skills(user_id, company_id, json!({ "add": ["Go"] }))),
+        app.clone()
+            .oneshot(patch_skills(user_id, company_id, json!({ "add": ["C"] }))),
+    );
+    assert_eq!(first.unwrap().status(), StatusCode::OK);
+    assert_eq!(second.unwrap().status(), StatusCode::OK);
+    let mut stored = worker_repo.find_by_id(worker.id).await.unwrap().unwrap();
+    stored.skills.sort();
+    assert_eq!(stored.skills, vec!["C", "Go", "Rust", "SQL"]);
+
+    // A coder cannot be left without skills
+    let response = app
+        .clone()
+        .oneshot(patch_skills(
+            user_id,
+            company_id,
+            json!({ "remove": ["rust", "sql", "go", "c"] }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let stored = worker_repo.find_by_id(worker.id).await.unwrap().unwrap();
+    assert_eq!(stored.skills.len(), 4);
+
+    // No changes, and another company's caller, are rejected
+    let response = app
+        .clone()
+        .oneshot(patch_skills(user_id, company_id, json!({})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(patch_skills(
+            outsider_id,
+            other_company_id,
+            json!({ "add": ["Go"] }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_delete_nonexistent_team_returns_404() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let user_id = register_user(
+        &app,
+        company_id,
+        "e2e-delete-missing@test.com",
+        "deletepass1",
+    )
+    .await;
+    let missing_id = uuid::Uuid::new_v4();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/teams/{}", missing_id))
+                .header("authorization", bearer_token(user_id, company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"], format!("Team not found: {}", missing_id));
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_success_envelope_only_when_requested() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let user_id = register_user(&app, company_id, "e2e-envelope@test.com", "envelope1").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Envelope mission").await;
+
+    let get_team = |team: uuid::Uuid, envelope: Option<&str>| {
+        let mut builder = Request::builder()
+            .method("GET")
+            .uri(format!("/api/teams/{}", team))
+            .header("authorization", bearer_token(user_id, company_id));
+        if let Some(envelope) = envelope {
+            builder = builder.header("x-envelope", envelope);
+        }
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(builder.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (status, serde_json::from_slice::<Value>(&body).unwrap())
+        }
+    };
+
+    // Default: the bare object
+    for envelope in [None, Some("false")] {
+        let (status, json) = get_team(team_id, envelope).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["id"], team_id.to_string());
+        assert!(json.get("data").is_none());
+    }
+
+    // Requested: wrapped with metadata
+    let (status, json) = get_team(team_id, Some("true")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["id"], team_id.to_string());
+    assert_eq!(json["meta"]["status"], 200);
+
+    // Errors keep their own envelope
+    let (status, json) = get_team(uuid::Uuid::new_v4(), Some("true")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(json.get("error").is_some());
+    assert!(json.get("data").is_none());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_lookup_is_404_for_missing_and_cross_tenant_teams() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let user_id = register_user(&app, company_id, "e2e-lookup@test.com", "lookuppass1").await;
+    let outsider_id = register_user(
+        &app,
+        other_company_id,
+        "e2e-lookup-outsider@test.com",
+        "outsider1",
+    )
+    .await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Lookup mission").await;
+
+    let request = |method: &str, team: uuid::Uuid, caller: uuid::Uuid, company: uuid::Uuid| {
+        Request::builder()
+            .method(method)
+            .uri(format!("/api/teams/{}", team))
+            .header("authorization", bearer_token(caller, company))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    for method in ["GET", "DELETE"] {
+        // Missing team
+        let response = app
+            .clone()
+            .oneshot(request(method, uuid::Uuid::new_v4(), user_id, company_id))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{method} missing");
+
+        // Another company's team
+        let response = app
+            .clone()
+            .oneshot(request(method, team_id, outsider_id, other_company_id))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.status(),
+            StatusCode::NOT_FOUND,
+            "{method} cross-tenant"
+        );
+    }
+
+    // The owning company can read and delete it
+    let response = app
+        .clone()
+        .oneshot(request("GET", team_id, user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(request("DELETE", team_id, user_id, company_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+/// Build an internal request moving `user_id` to another company
+fn change_company_request(user_id: uuid::Uuid, body: Value, secret: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/users/{}/company", user_id))
+        .header("content-type", "application/json");
+    if let Some(secret) = secret {
+        builder = builder.header("x-internal-secret", secret);
+    }
+    builder.body(Body::from(body.to_string())).unwrap()
+}
+
+#[tokio::test]
+async fn test_change_user_company_moves_user_and_teams() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let new_company_id = create_test_company(&pool).await;
+    // Login deduplication stays on: the revoked token must not be reused
+    let app = setup_app(pool.clone()).await;
+    let secret = Some(TEST_INTERNAL_SECRET);
+
+    let user_id = register_user(&app, company_id, "e2e-mover@test.com", "moverpass1").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Moving mission").await;
+    sqlx::query!("UPDATE users SET role = 'admin' WHERE id = $1", user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let response = login(&app, "e2e-mover@test.com", "moverpass1").await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let old_token = serde_json::from_slice::<Value>(&body).unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (status, _) = list_users(&app, format!("Bearer {}", old_token), "").await;
+    assert_eq!(status, StatusCode::OK);
+    let body = json!({ "company_id": new_company_id, "teams": "move" });
+
+    // Only internal callers may move users
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, body.clone(), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Reassigning to a user outside the old company is rejected
+    let invalid = json!({ "company_id": new_company_id, "teams": { "reassign_to": user_id } });
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, invalid, secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, body, secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["company_id"], new_company_id.to_string());
+    assert_eq!(json["role"], "member");
+
+    // The user's team moved with them
+    let team_company = sqlx::query_scalar!("SELECT company_id FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(team_company, new_company_id);
+
+    // Tokens carrying the old company and role are revoked
+    let (status, _) = list_users(&app, format!("Bearer {}", old_token), "").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (_, body) = introspect_token(&app, &old_token).await;
+    assert_eq!(body["active"], false);
+
+    // A fresh login carries the new company
+    let response = login(&app, "e2e-mover@test.com", "moverpass1").await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let new_token = serde_json::from_slice::<Value>(&body).unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (_, body) = introspect_token(&app, &new_token).await;
+    assert_eq!(body["active"], true);
+    assert_eq!(body["company_id"], new_company_id.to_string());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, new_company_id).await;
+}
+
+#[tokio::test]
+async fn test_change_user_company_checks_quota_and_unique_goals() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let new_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let secret = Some(TEST_INTERNAL_SECRET);
+
+    let user_id = register_user(&app, company_id, "e2e-crowded@test.com", "crowdedpass1").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Shared mission").await;
+    let resident_id = register_user(
+        &app,
+        new_company_id,
+        "e2e-resident@test.com",
+        "residentpass1",
+    )
+    .await;
+    FeatureFlags::postgres(pool.clone())
+        .set(new_company_id, UNIQUE_GOAL_PER_COMPANY, true)
+        .await
+        .unwrap();
+    let body = json!({ "company_id": new_company_id, "teams": "move" });
+
+    // The moved team would push the new company past its quota
+    create_team_via_api(&app, new_company_id, resident_id, "Resident mission").await;
+    sqlx::query!(
+        "UPDATE companies SET max_active_teams = 1 WHERE id = $1",
+        new_company_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, body.clone(), secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // The moved team's goal is already taken in the new company
+    sqlx::query!(
+        "UPDATE companies SET max_active_teams = NULL WHERE id = $1",
+        new_company_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    create_team_via_api(&app, new_company_id, resident_id, "Shared mission").await;
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, body, secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Neither attempt moved anything
+    let user_company = sqlx::query_scalar!("SELECT company_id FROM users WHERE id = $1", user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(user_company, company_id);
+    let team_company = sqlx::query_scalar!("SELECT company_id FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(team_company, company_id);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, new_company_id).await;
+}
+
+#[tokio::test]
+async fn test_change_user_company_rejects_unknown_company() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let secret = Some(TEST_INTERNAL_SECRET);
+
+    let user_id = register_user(&app, company_id, "e2e-stayer@test.com", "stayerpass1").await;
+    let team_id = create_team_via_api(&app, company_id, user_id, "Staying mission").await;
+
+    let body = json!({ "company_id": uuid::Uuid::new_v4(), "teams": "move" });
+    let response = app
+        .clone()
+        .oneshot(change_company_request(user_id, body, secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Nothing changed
+    let user_company = sqlx::query_scalar!("SELECT company_id FROM users WHERE id = $1", user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(user_company, company_id);
+    let team_company = sqlx::query_scalar!("SELECT company_id FROM teams WHERE id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(team_company, company_id);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// List users as `bearer`, returning the status and JSON body
+async fn list_users(app: &Router, bearer: String, query: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/users?{}", query))
+                .header("authorization", bearer)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+/// IDs of the users in a `Paginated<UserResponse>` body
+fn listed_ids(page: &Value) -> Vec<uuid::Uuid> {
+    page["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["id"].as_str().unwrap().parse().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_users_pages_through_company_users() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let other_company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let mut user_ids = Vec::new();
+    for n in 0..3 {
+        let email = format!("e2e-list-{n}@test.com");
+        user_ids.push(register_user(&app, company_id, &email, "listpass1").await);
+    }
+    register_user(
+        &app,
+        other_company_id,
+        "e2e-list-other@test.com",
+        "listpass1",
+    )
+    .await;
+    let admin = || bearer_token_with_role(user_ids[0], company_id, UserRole::Admin);
+
+    // Members cannot list users
+    let (status, _) = list_users(&app, bearer_token(user_ids[1], company_id), "").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, first) = list_users(&app, admin(), "limit=2&offset=0").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["limit"], 2);
+    assert_eq!(first["offset"], 0);
+    assert!(first["items"][0].get("password_hash").is_none());
+
+    let (status, second) = list_users(&app, admin(), "limit=2&offset=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(second["total"], 3);
+
+    // Paging defaults apply and out-of-range values are rejected
+    let (_, defaults) = list_users(&app, admin(), "").await;
+    assert_eq!(defaults["limit"], 20);
+    assert_eq!(defaults["offset"], 0);
+    let (status, _) = list_users(&app, admin(), "limit=101").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = list_users(&app, admin(), "offset=-1").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // The pages cover the company's users exactly once
+    let mut listed = [listed_ids(&first), listed_ids(&second)].concat();
+    assert_eq!(listed.len(), 3);
+    listed.sort();
+    user_ids.sort();
+    assert_eq!(listed, user_ids);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+    cleanup_test_company(&pool, other_company_id).await;
+}
+
+#[tokio::test]
+async fn test_list_users_filters_by_active() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let admin_id =
+        register_user(&app, company_id, "e2e-active-admin@test.com", "activepass1").await;
+    let inactive_id = register_user(&app, company_id, "e2e-inactive@test.com", "activepass1").await;
+    sqlx::query!(
+        "UPDATE users SET is_active = FALSE WHERE id = $1",
+        inactive_id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let admin = || bearer_token_with_role(admin_id, company_id, UserRole::Admin);
+
+    let (status, active) = list_users(&app, admin(), "active=true").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(active["total"], 1);
+    assert_eq!(listed_ids(&active), vec![admin_id]);
+
+    let (_, inactive) = list_users(&app, admin(), "active=false").await;
+    assert_eq!(inactive["total"], 1);
+    assert_eq!(listed_ids(&inactive), vec![inactive_id]);
+
+    let (_, everyone) = list_users(&app, admin(), "").await;
+    assert_eq!(everyone["total"], 2);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+/// Collects every `$ref` target in a JSON document
+fn schema_refs(value: &Value, refs: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(target)) = map.get("$ref") {
+                refs.push(target.clone());
+            }
+            map.values().for_each(|v| schema_refs(v, refs));
+        }
+        Value::Array(items) => items.iter().for_each(|v| schema_refs(v, refs)),
+        _ => {}
+    }
+}
+
+#[tokio::test]
+async fn test_openapi_spec_describes_team_creation() {
+    let pool = setup_test_db().await;
+    let app = setup_app(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/openapi.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let spec: Value = serde_json::from_slice(&body).unwrap();
+
+    let create = &spec["paths"]["/api/teams"]["post"];
+    assert_eq!(
+        create["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/CreateTeamRequest"
+    );
+    assert!(create["responses"]["201"].is_object());
+    let schema = &spec["components"]["schemas"]["CreateTeamRequest"];
+    for field in ["goal", "company_id", "created_by", "budget_limit"] {
+        assert!(schema["properties"][field].is_object(), "missing {field}");
+    }
+
+    // Event feed, export, and import are described too
+    let events = &spec["paths"]["/api/teams/{id}/events"]["get"];
+    assert_eq!(
+        events["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/TeamEventsResponse"
+    );
+    assert!(spec["paths"]["/api/teams/{id}/export"]["get"].is_object());
+    assert!(spec["paths"]["/api/teams/import"]["post"]["requestBody"].is_object());
+
+    // Every referenced schema is defined
+    let mut refs = Vec::new();
+    schema_refs(&spec, &mut refs);
+    for target in refs {
+        let name = target.trim_start_matches("#/components/schemas/");
+        assert!(
+            spec["components"]["schemas"][name].is_object(),
+            "dangling {target}"
+        );
+    }
+}
+
+/// Webhook policy admitting the local receivers tests start
+fn local_webhooks() -> WebhookUrlPolicy {
+    WebhookUrlPolicy::allowing(["127.0.0.1"])
+}
+
+/// Serves `POST /hook` locally, forwarding each request's signature,
+/// event type, delivery ID, and body to the returned channel
+async fn webhook_receiver() -> (
+    String,
+    tokio::sync::mpsc::UnboundedReceiver<(String, String, String, Vec<u8>)>,
+) {
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let receiver = Router::new().route(
+        "/hook",
+        post(
+            move |headers: HeaderMap, body: axum::body::Bytes| async move {
+                let header = |name: &str| {
+                    headers
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                tx.send((
+                    header("x-webhook-signature"),
+                    header("x-webhook-event"),
+                    header("x-webhook-id"),
+                    body.to_vec(),
+                ))
+                .unwrap();
+                StatusCode::NO_CONTENT
+            },
+        ),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    (format!("http://{}/hook", addr), rx)
+}
+
+#[tokio::test]
+async fn test_webhook_receives_signed_created_event() {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let (url, mut deliveries) = webhook_receiver().await;
+
+    let user_id = register_user(&app, company_id, "e2e-webhook@test.com", "hookpass1").await;
+
+    // Register a webhook for creations only
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/webhooks")
+                .header("content-type", "application/json")
+                .header(
+                    "authorization",
+                    bearer_token_with_role(user_id, company_id, UserRole::Admin),
+                )
+                .body(Body::from(
+                    json!({ "url": url, "event_types": ["created"] }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let webhook: Value = serde_json::from_slice(&body).unwrap();
+    let secret = webhook["secret"].as_str().unwrap().to_string();
+    assert_eq!(secret.len(), 64);
+
+    let team_id = create_team_via_api(&app, company_id, user_id, "Webhook goal").await;
+
+    // Drain the outbox the team's events were queued in
+    let relay = OutboxRelay::new(
+        Arc::new(PostgresOutboxRepository::new(pool.clone())),
+        WebhookDispatcher::postgres(pool.clone()).with_url_policy(local_webhooks()),
+    );
+    while relay.relay_once().await.unwrap() > 0 {}
+
+    let (signature, event_type, delivery_id, body) =
+        tokio::time::timeout(std::time::Duration::from_secs(5), deliveries.recv())
+            .await
+            .expect("webhook was not delivered")
+            .unwrap();
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(&body);
+    assert_eq!(
+        signature,
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    );
+    assert_eq!(event_type, "created");
+    assert!(delivery_id.parse::<i64>().is_ok());
+    let payload: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(payload["type"], "created");
+    assert_eq!(payload["team_id"], team_id.to_string());
+    assert_eq!(payload["goal"], "Webhook goal");
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_register_webhook_rejects_internal_urls() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+    let admin = bearer_token_with_role(uuid::Uuid::new_v4(), company_id, UserRole::Admin);
+
+    for url in [
+        "http://169.254.169.254/latest/meta-data",
+        "http://10.0.0.5/hook",
+        "http://[::1]/hook",
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/webhooks")
+                    .header("content-type", "application/json")
+                    .header("authorization", admin.clone())
+                    .body(Body::from(json!({ "url": url }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", url);
+    }
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_register_webhook_requires_admin() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let app = setup_app(pool.clone()).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/webhooks")
+                .header("content-type", "application/json")
+                .header(
+                    "authorization",
+                    bearer_token(uuid::Uuid::new_v4(), company_id),
+                )
+                .body(Body::from(
+                    json!({ "url": "https://example.com/hook" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
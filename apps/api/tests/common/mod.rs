@@ -0,0 +1,98 @@
+//! Shared helpers for integration tests
+//!
+//! [`with_test_db`] runs a test against its own freshly migrated schema,
+//! so tests using it see no rows from other tests and can run in parallel
+//! without cleaning up after themselves.
+
+// Each test binary uses a different subset of these helpers
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+
+use futures::FutureExt;
+use ghostpirates_api::infrastructure::migrations::run_migrations;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::{Executor, PgPool};
+
+/// Connections per isolated test pool
+const TEST_POOL_CONNECTIONS: u32 = 5;
+
+/// Create `schema` and a pool whose connections resolve unqualified names
+/// in it
+///
+/// `public` stays on the search path for extension functions such as
+/// `uuid_generate_v4`.
+pub async fn scratch_pool(schema: &str, max_connections: u32) -> PgPool {
+    let database_url =
+        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set for integration tests");
+
+    let admin = PgPool::connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+    admin
+        .execute(format!("CREATE SCHEMA {}", schema).as_str())
+        .await
+        .expect("Failed to create scratch schema");
+    admin.close().await;
+
+    let search_path = format!("SET search_path TO {}, public", schema);
+    PgPoolOptions::new()
+        .max_connections(max_connections)
+        .after_connect(move |conn, _meta| {
+            let search_path = search_path.clone();
+            Box::pin(async move {
+                conn.execute(search_path.as_str()).await?;
+                Ok(())
+            })
+        })
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect scratch pool")
+}
+
+/// Drop the scratch schema and everything in it, then close the pool
+pub async fn drop_scratch_schema(pool: &PgPool, schema: &str) {
+    pool.execute(format!("DROP SCHEMA {} CASCADE", schema).as_str())
+        .await
+        .expect("Failed to drop scratch schema");
+    pool.close().await;
+}
+
+/// Run `test` against a pool on a new, fully migrated schema
+///
+/// The schema is dropped afterwards even if the test panics; the panic is
+/// then re-raised so the test still fails.
+///
+/// Usage:
+/// ```ignore
+/// #[tokio::test]
+/// async fn test_something() {
+///     with_test_db(|pool| async move {
+///         let company_id = create_test_company(&pool).await;
+///         // ...
+///     })
+///     .await;
+/// }
+/// ```
+pub async fn with_test_db<F, Fut, T>(test: F) -> T
+where
+    F: FnOnce(PgPool) -> Fut,
+    Fut: Future<Output = T>,
+{
+    let schema = format!("test_{}", uuid::Uuid::new_v4().simple());
+    let pool = scratch_pool(&schema, TEST_POOL_CONNECTIONS).await;
+
+    if let Err(e) = run_migrations(&pool).await {
+        drop_scratch_schema(&pool, &schema).await;
+        panic!("Failed to migrate test schema: {}", e);
+    }
+
+    let outcome = AssertUnwindSafe(test(pool.clone())).catch_unwind().await;
+    drop_scratch_schema(&pool, &schema).await;
+
+    match outcome {
+        Ok(value) => value,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
@@ -0,0 +1,108 @@
+//! Integration tests for embedded migrations
+//!
+//! These tests apply the full migration set to throwaway schemas so they
+//! never touch the tables used by the other integration tests.
+
+mod common;
+
+use common::{drop_scratch_schema, scratch_pool, with_test_db};
+use ghostpirates_api::infrastructure::migrations::{run_migrations, MIGRATOR};
+use sqlx::{Executor, PgPool};
+
+#[tokio::test]
+async fn test_migrations_are_idempotent() {
+    let schema = format!("migrate_test_{}", uuid::Uuid::new_v4().simple());
+    let pool = scratch_pool(&schema, 1).await;
+
+    // First run applies every embedded migration
+    let applied = run_migrations(&pool).await.expect("First run failed");
+    let expected: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+    assert_eq!(applied, expected);
+
+    // Second run is a no-op
+    let applied = run_migrations(&pool).await.expect("Second run failed");
+    assert!(applied.is_empty(), "Nothing should be re-applied");
+
+    // Cleanup
+    drop_scratch_schema(&pool, &schema).await;
+}
+
+#[tokio::test]
+async fn test_with_test_db_uses_a_fresh_schema_and_drops_it() {
+    let schema = with_test_db(|pool| async move {
+        let companies: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM companies")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to count companies");
+        assert_eq!(companies, 0, "A fresh schema has no rows");
+
+        sqlx::query_scalar::<_, String>("SELECT current_schema()")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to read current schema")
+    })
+    .await;
+    assert!(schema.starts_with("test_"));
+
+    let database_url =
+        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set for integration tests");
+    let pool = PgPool::connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+    let remaining: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = $1",
+    )
+    .bind(&schema)
+    .fetch_one(&pool)
+    .await
+    .expect("Failed to look up schema");
+    assert_eq!(remaining, 0, "The schema is dropped afterwards");
+}
+
+#[tokio::test]
+async fn test_unique_company_name_migration_renames_existing_duplicates() {
+    const UNIQUE_NAME_VERSION: i64 = 20251108000021;
+    let schema = format!("migrate_test_{}", uuid::Uuid::new_v4().simple());
+    let pool = scratch_pool(&schema, 1).await;
+    let (earlier, later): (Vec<_>, Vec<_>) = MIGRATOR
+        .iter()
+        .partition(|m| m.version < UNIQUE_NAME_VERSION);
+
+    // Schema as it was before names were unique, with a duplicated name
+    for migration in earlier {
+        pool.execute(&*migration.sql)
+            .await
+            .expect("Failed to apply migration");
+    }
+    for (name, age_minutes) in [("Acme", 3), ("Acme", 2), ("Acme", 1), ("Other", 0)] {
+        sqlx::query(
+            "INSERT INTO companies (name, created_at)
+             VALUES ($1, NOW() - make_interval(mins => $2))",
+        )
+        .bind(name)
+        .bind(age_minutes)
+        .execute(&pool)
+        .await
+        .expect("Failed to create company");
+    }
+
+    for migration in later {
+        pool.execute(&*migration.sql)
+            .await
+            .expect("Failed to apply migration");
+    }
+
+    // The oldest company keeps the name; the others are told apart by ID
+    let names: Vec<(String, String)> =
+        sqlx::query_as("SELECT name, id::text FROM companies ORDER BY created_at")
+            .fetch_all(&pool)
+            .await
+            .expect("Failed to list companies");
+    assert_eq!(names[0].0, "Acme");
+    assert_eq!(names[1].0, format!("Acme ({})", names[1].1));
+    assert_eq!(names[2].0, format!("Acme ({})", names[2].1));
+    assert_eq!(names[3].0, "Other");
+
+    // Cleanup
+    drop_scratch_schema(&pool, &schema).await;
+}
@@ -4,13 +4,28 @@
 //! with the PostgreSQL database, including CRUD operations, tenant isolation,
 //! and transaction handling.
 
+mod common;
+
+use common::with_test_db;
+use ghostpirates_api::agents::types::WorkerSpec;
+use ghostpirates_api::agents::{ManagerAgent, WorkerAgent};
 use ghostpirates_api::auth::password::hash_password;
+use ghostpirates_api::domain::repositories::password_reset_repository::{
+    PasswordResetRepository, PasswordResetToken,
+};
+use ghostpirates_api::domain::repositories::task_repository::TaskAssignment;
 use ghostpirates_api::domain::repositories::team_repository::TeamRepository;
 use ghostpirates_api::domain::repositories::user_repository::{User, UserRepository};
+use ghostpirates_api::domain::repositories::{RepositoryError, WorkerRepository};
+use ghostpirates_api::domain::shared::{Currency, MockClock, Money, SystemClock};
+use ghostpirates_api::domain::team::value_objects::TeamStatus;
 use ghostpirates_api::domain::team::Team;
-use ghostpirates_api::domain::user::value_objects::Email;
+use ghostpirates_api::domain::user::value_objects::{Email, UserRole};
+use ghostpirates_api::infrastructure::feature_flags::{FeatureFlags, UNIQUE_GOAL_PER_COMPANY};
+use ghostpirates_api::infrastructure::outbox_relay::OutboxRelay;
 use ghostpirates_api::infrastructure::repositories::{
-    PostgresTeamRepository, PostgresUserRepository,
+    save_team_formation, PostgresPasswordResetRepository, PostgresTeamRepository,
+    PostgresUserRepository, PostgresWorkerRepository,
 };
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -25,13 +40,19 @@ async fn setup_test_db() -> PgPool {
         .expect("Failed to connect to test database")
 }
 
+/// Build a USD budget from a scaled integer (e.g. `usd(10000, 2)` is $100.00)
+fn usd(num: i64, scale: u32) -> Money {
+    Money::new(rust_decimal::Decimal::new(num, scale), Currency::Usd).expect("valid money")
+}
+
 /// Create a test company for isolation
 async fn create_test_company(pool: &PgPool) -> Uuid {
     let company_id = Uuid::new_v4();
+    // Names are unique, so suffix the ID
     sqlx::query!(
         "INSERT INTO companies (id, name) VALUES ($1, $2)",
         company_id,
-        "Test Company"
+        format!("Test Company {}", company_id)
     )
     .execute(pool)
     .await
@@ -89,6 +110,9 @@ async fn test_user_repository_create_and_find_by_email() {
         password_hash,
         full_name: "Test User".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     // Test: Create user
@@ -117,7 +141,7 @@ async fn test_user_repository_create_and_find_by_email() {
         found_user.full_name, "Test User",
         "Full names should match"
     );
-    assert_eq!(found_user.is_active, true, "User should be active");
+    assert!(found_user.is_active, "User should be active");
 
     // Cleanup
     cleanup_test_company(&pool, company_id).await;
@@ -141,6 +165,9 @@ async fn test_user_repository_duplicate_email_fails() {
         password_hash: password_hash.clone(),
         full_name: "User One".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     user_repo
@@ -156,6 +183,9 @@ async fn test_user_repository_duplicate_email_fails() {
         password_hash,
         full_name: "User Two".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     let result = user_repo.create(user2).await;
@@ -177,6 +207,88 @@ async fn test_user_repository_duplicate_email_fails() {
     cleanup_test_company(&pool, company_id).await;
 }
 
+#[tokio::test]
+async fn test_user_repository_find_by_ids() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user1_id = create_test_user(&pool, company_id, "batch-one@test.com").await;
+    let user2_id = create_test_user(&pool, company_id, "batch-two@test.com").await;
+    let user3_id = create_test_user(&pool, company_id, "batch-three@test.com").await;
+
+    let user_repo = PostgresUserRepository::new(pool.clone());
+
+    // Test: One query resolves several users, skipping unknown IDs
+    let missing_id = Uuid::new_v4();
+    let users = user_repo
+        .find_by_ids(&[user3_id, missing_id, user1_id, user2_id])
+        .await
+        .expect("Failed to find users by ids");
+
+    assert_eq!(users.len(), 3, "Should find every existing user");
+    for id in [user1_id, user2_id, user3_id] {
+        assert!(users.iter().any(|u| u.id == id), "Should contain {}", id);
+    }
+    assert!(!users.iter().any(|u| u.id == missing_id));
+
+    // Test: Empty input needs no lookup
+    let users = user_repo.find_by_ids(&[]).await.expect("Empty lookup");
+    assert!(users.is_empty());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_user_repository_loads_legacy_email() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let legacy_email = format!("legacy-{}", Uuid::new_v4().simple());
+    let user_id = create_test_user(&pool, company_id, &legacy_email).await;
+
+    let user_repo = PostgresUserRepository::new(pool.clone());
+
+    // Test: A stored email that fails validation still loads
+    assert!(Email::new(legacy_email.as_str()).is_err());
+    let user = user_repo
+        .find_by_email(&Email::new_unchecked(legacy_email.clone()))
+        .await
+        .expect("Legacy email should not error")
+        .expect("User should exist");
+    assert_eq!(user.id, user_id);
+    assert_eq!(user.email.as_str(), legacy_email);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_user_repository_company_listing_survives_malformed_email() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let valid_id = create_test_user(
+        &pool,
+        company_id,
+        &format!("valid-{}@example.com", Uuid::new_v4().simple()),
+    )
+    .await;
+    let malformed_email = format!("not an email {}", Uuid::new_v4().simple());
+    let malformed_id = create_test_user(&pool, company_id, &malformed_email).await;
+    assert!(Email::new(malformed_email.as_str()).is_err());
+
+    let users = PostgresUserRepository::new(pool.clone())
+        .find_by_company(company_id)
+        .await
+        .expect("A malformed email should not fail the listing");
+
+    assert!(users.iter().any(|u| u.id == valid_id));
+    assert!(users
+        .iter()
+        .any(|u| u.id == malformed_id && u.email.as_str() == malformed_email));
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
 #[tokio::test]
 async fn test_user_repository_update_last_login() {
     let pool = setup_test_db().await;
@@ -195,6 +307,9 @@ async fn test_user_repository_update_last_login() {
         password_hash,
         full_name: "Login Test User".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     user_repo
@@ -223,6 +338,114 @@ async fn test_user_repository_update_last_login() {
     cleanup_test_company(&pool, company_id).await;
 }
 
+#[tokio::test]
+async fn test_user_repository_login_does_not_advance_updated_at() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "updated-at@example.com").await;
+
+    let user_repo = PostgresUserRepository::new(pool.clone());
+
+    let before = sqlx::query!("SELECT updated_at FROM users WHERE id = $1", user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch user");
+
+    // Login only records last_login
+    user_repo
+        .update_last_login(user_id)
+        .await
+        .expect("Failed to update last login");
+
+    let after_login = sqlx::query!(
+        "SELECT updated_at, last_login FROM users WHERE id = $1",
+        user_id
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("Failed to fetch user");
+
+    assert!(after_login.last_login.is_some(), "last_login should be set");
+    assert_eq!(
+        after_login.updated_at, before.updated_at,
+        "Login must not advance updated_at"
+    );
+
+    // A profile change does advance updated_at
+    let email = Email::new("updated-at@example.com").expect("valid email");
+    let updated_at = user_repo
+        .update_profile(user_id, "Renamed User", &email)
+        .await
+        .expect("Failed to update profile");
+
+    let after_update = sqlx::query!("SELECT updated_at FROM users WHERE id = $1", user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch user");
+
+    assert!(
+        after_update.updated_at > before.updated_at,
+        "Profile change should advance updated_at"
+    );
+    assert_eq!(updated_at, after_update.updated_at);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_password_reset_sets_the_password_and_burns_the_token_together() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "reset-together@test.com").await;
+    let reset_repo = PostgresPasswordResetRepository::new(pool.clone());
+    let password_hash = || async {
+        sqlx::query_scalar!("SELECT password_hash FROM users WHERE id = $1", user_id)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to read password hash")
+    };
+    for (token_hash, expires_in) in [("live-token", 30), ("expired-token", -30)] {
+        reset_repo
+            .create(PasswordResetToken {
+                id: Uuid::new_v4(),
+                user_id,
+                token_hash: format!("{}-{}", token_hash, user_id),
+                expires_at: chrono::Utc::now() + chrono::Duration::minutes(expires_in),
+            })
+            .await
+            .expect("Failed to create reset token");
+    }
+    let original = password_hash().await;
+
+    // An expired token changes nothing
+    let reset = reset_repo
+        .reset_password(&format!("expired-token-{}", user_id), "expired-hash")
+        .await
+        .expect("Failed to reset password");
+    assert_eq!(reset, None);
+    assert_eq!(password_hash().await, original);
+
+    // A live token changes the password once
+    let live = format!("live-token-{}", user_id);
+    let reset = reset_repo
+        .reset_password(&live, "new-hash")
+        .await
+        .expect("Failed to reset password");
+    assert_eq!(reset, Some(user_id));
+    assert_eq!(password_hash().await, "new-hash");
+
+    let reset = reset_repo
+        .reset_password(&live, "replayed-hash")
+        .await
+        .expect("Failed to reset password");
+    assert_eq!(reset, None);
+    assert_eq!(password_hash().await, "new-hash");
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
 #[tokio::test]
 async fn test_team_repository_save_and_find_by_id() {
     let pool = setup_test_db().await;
@@ -236,7 +459,8 @@ async fn test_team_repository_save_and_find_by_id() {
         company_id,
         "Test Mission".to_string(),
         user_id,
-        Some(rust_decimal::Decimal::new(10000, 2)), // $100.00
+        Some(usd(10000, 2)), // $100.00
+        &SystemClock,
     )
     .expect("Valid team");
 
@@ -264,48 +488,485 @@ async fn test_team_repository_save_and_find_by_id() {
 }
 
 #[tokio::test]
-async fn test_team_repository_find_by_company() {
+async fn test_team_repository_transaction_rollback_and_commit() {
     let pool = setup_test_db().await;
     let company_id = create_test_company(&pool).await;
-    let user_id = create_test_user(&pool, company_id, "team-creator@test.com").await;
+    let user_id = create_test_user(&pool, company_id, "tx-owner@test.com").await;
 
     let team_repo = PostgresTeamRepository::new(pool.clone());
+    let new_team = |goal: &str| {
+        Team::new(company_id, goal.to_string(), user_id, None, &SystemClock)
+            .expect("Valid team")
+            .0
+    };
 
-    // Create multiple teams
-    let (team1, _) = Team::new(
-        company_id,
-        "Mission Alpha".to_string(),
-        user_id,
-        Some(rust_decimal::Decimal::new(5000, 2)),
-    )
-    .expect("Valid team");
+    // Rolled back: visible inside the transaction, gone afterwards
+    let team = new_team("Rolled back mission");
+    let mut tx = team_repo.begin().await.expect("Failed to begin");
+    tx.save(&team).await.expect("Failed to save team");
+    assert!(tx.find_by_id(team.id()).await.unwrap().is_some());
+    assert!(team_repo.find_by_id(team.id()).await.unwrap().is_none());
+    tx.rollback().await.expect("Failed to roll back");
 
-    let (team2, _) = Team::new(
+    assert!(team_repo.find_by_id(team.id()).await.unwrap().is_none());
+
+    // Dropped without commit: also rolled back
+    let team = new_team("Abandoned mission");
+    let mut tx = team_repo.begin().await.expect("Failed to begin");
+    tx.save(&team).await.expect("Failed to save team");
+    drop(tx);
+
+    assert!(team_repo.find_by_id(team.id()).await.unwrap().is_none());
+
+    // Committed: persisted
+    let team = new_team("Committed mission");
+    let mut tx = team_repo.begin().await.expect("Failed to begin");
+    tx.save(&team).await.expect("Failed to save team");
+    assert_eq!(tx.find_by_company(company_id).await.unwrap().len(), 1);
+    tx.commit().await.expect("Failed to commit");
+
+    let teams = team_repo.find_by_company(company_id).await.unwrap();
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0].id(), team.id());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_repositories_route_reads_to_the_read_pool() {
+    // The scratch schema stands in for a replica that has not caught up
+    with_test_db(|replica| async move {
+        let pool = setup_test_db().await;
+        let company_id = create_test_company(&pool).await;
+        let user_id = create_test_user(&pool, company_id, "read-pool-owner@test.com").await;
+        let (team, _events) = Team::new(
+            company_id,
+            "Primary mission".to_string(),
+            user_id,
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+
+        // No replica: reads see the primary
+        let team_repo = PostgresTeamRepository::new(pool.clone());
+        team_repo.save(&team).await.expect("Failed to save team");
+        assert!(team_repo.find_by_id(team.id()).await.unwrap().is_some());
+
+        // Replica: writes still reach the primary, reads come from the replica
+        let routed = PostgresTeamRepository::new(pool.clone()).with_read_pool(replica.clone());
+        routed.save(&team).await.expect("Failed to save team");
+        assert!(routed.find_by_id(team.id()).await.unwrap().is_none());
+        assert!(team_repo.find_by_id(team.id()).await.unwrap().is_some());
+
+        let replica_company_id = create_test_company(&replica).await;
+        let replica_user_id =
+            create_test_user(&replica, replica_company_id, "replica-owner@test.com").await;
+        let (replica_team, _events) = Team::new(
+            replica_company_id,
+            "Replica mission".to_string(),
+            replica_user_id,
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+        PostgresTeamRepository::new(replica.clone())
+            .save(&replica_team)
+            .await
+            .expect("Failed to save replica team");
+
+        let teams = routed.find_by_company(replica_company_id).await.unwrap();
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].id(), replica_team.id());
+        assert!(team_repo
+            .find_by_company(replica_company_id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let user_repo = PostgresUserRepository::new(pool.clone()).with_read_pool(replica.clone());
+        assert!(user_repo.find_by_ids(&[user_id]).await.unwrap().is_empty());
+        assert_eq!(
+            user_repo.find_by_ids(&[replica_user_id]).await.unwrap().len(),
+            1
+        );
+
+        cleanup_test_company(&pool, company_id).await;
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_teams_missing_backfilled_columns_still_load() {
+    with_test_db(|db| async move {
+        // Mimic a table whose `amount_spent` / `tags` backfill has not run
+        sqlx::query(
+            "ALTER TABLE teams ALTER COLUMN amount_spent DROP NOT NULL, \
+             ALTER COLUMN tags DROP NOT NULL",
+        )
+        .execute(&db)
+        .await
+        .expect("Failed to relax team columns");
+
+        let company_id = create_test_company(&db).await;
+        let user_id = create_test_user(&db, company_id, "backfill-owner@test.com").await;
+        let (team, _events) = Team::new(
+            company_id,
+            "Unbackfilled mission".to_string(),
+            user_id,
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+        let team_repo = PostgresTeamRepository::new(db.clone());
+        team_repo.save(&team).await.expect("Failed to save team");
+        sqlx::query("UPDATE teams SET amount_spent = NULL, tags = NULL WHERE id = $1")
+            .bind(team.id())
+            .execute(&db)
+            .await
+            .expect("Failed to clear team columns");
+
+        let loaded = team_repo
+            .find_by_id(team.id())
+            .await
+            .unwrap()
+            .expect("Team should load");
+        assert!(loaded.amount_spent().is_zero());
+        assert!(loaded.tags().is_empty());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_team_events_reach_outbox_with_team_and_relay_marks_them_sent() {
+    with_test_db(|pool| async move {
+        let company_id = create_test_company(&pool).await;
+        let user_id = create_test_user(&pool, company_id, "outbox-owner@test.com").await;
+        let team_repo = PostgresTeamRepository::new(pool.clone());
+        let outbox_rows = |pool: PgPool| async move {
+            sqlx::query!("SELECT event_type, sent_at FROM outbox ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap()
+        };
+
+        // Rolled back: neither the team nor its event is kept
+        let (team, events) = Team::new(
+            company_id,
+            "Rolled back mission".to_string(),
+            user_id,
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+        let mut tx = team_repo.begin().await.unwrap();
+        tx.save(&team).await.unwrap();
+        tx.record_events(company_id, &events).await.unwrap();
+        tx.rollback().await.unwrap();
+
+        assert!(outbox_rows(pool.clone()).await.is_empty());
+
+        // Committed: the event becomes visible together with the team
+        let (team, events) = Team::new(
+            company_id,
+            "Committed mission".to_string(),
+            user_id,
+            None,
+            &SystemClock,
+        )
+        .unwrap();
+        let mut tx = team_repo.begin().await.unwrap();
+        tx.save(&team).await.unwrap();
+        tx.record_events(company_id, &events).await.unwrap();
+        assert!(outbox_rows(pool.clone()).await.is_empty());
+        tx.commit().await.unwrap();
+
+        assert!(team_repo.find_by_id(team.id()).await.unwrap().is_some());
+        let rows = outbox_rows(pool.clone()).await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].event_type, "created");
+        assert!(rows[0].sent_at.is_none());
+
+        // The relay publishes it once and marks it sent
+        let relay = OutboxRelay::postgres(pool.clone());
+        assert_eq!(relay.relay_once().await.unwrap(), 1);
+        assert_eq!(relay.relay_once().await.unwrap(), 0);
+        assert!(outbox_rows(pool.clone()).await[0].sent_at.is_some());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_outbox_claims_are_exclusive_and_failures_are_retried() {
+    use chrono::{Duration as ChronoDuration, Utc};
+    use ghostpirates_api::domain::repositories::OutboxRepository;
+    use ghostpirates_api::infrastructure::repositories::PostgresOutboxRepository;
+    use std::time::Duration;
+
+    with_test_db(|pool| async move {
+        let company_id = create_test_company(&pool).await;
+        for _ in 0..4 {
+            sqlx::query!(
+                "INSERT INTO outbox (company_id, event_type, payload) VALUES ($1, 'created', '{}')",
+                company_id
+            )
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let outbox = PostgresOutboxRepository::new(pool.clone());
+        let lease = Duration::from_secs(60);
+
+        // Concurrent relays never claim the same message
+        let (first, second) =
+            tokio::join!(outbox.claim_unsent(2, lease), outbox.claim_unsent(2, lease));
+        let mut ids: Vec<i64> = first.unwrap().into_iter().map(|m| m.id).collect();
+        let second: Vec<i64> = second.unwrap().into_iter().map(|m| m.id).collect();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(second.len(), 2);
+        ids.extend(&second);
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 4);
+
+        // Leased messages are not handed out again
+        assert!(outbox.claim_unsent(10, lease).await.unwrap().is_empty());
+
+        // A retry makes the message due again at the given time
+        outbox
+            .record_failure(ids[0], "timed out", Some(Utc::now()))
+            .await
+            .unwrap();
+        let retried = outbox.claim_unsent(10, lease).await.unwrap();
+        assert_eq!(retried.len(), 1);
+        assert_eq!((retried[0].id, retried[0].attempts), (ids[0], 1));
+
+        // Giving up leaves the message failed, not sent
+        outbox
+            .record_failure(ids[0], "timed out", None)
+            .await
+            .unwrap();
+        let row = sqlx::query!(
+            "SELECT attempts, last_error, sent_at, failed_at FROM outbox WHERE id = $1",
+            ids[0]
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(row.attempts, 2);
+        assert_eq!(row.last_error.as_deref(), Some("timed out"));
+        assert!(row.sent_at.is_none());
+        assert!(row.failed_at.is_some());
+
+        // Only messages sent before the cutoff are pruned
+        outbox.mark_sent(&ids[1..]).await.unwrap();
+        assert_eq!(
+            outbox
+                .prune_sent(Utc::now() - ChronoDuration::hours(1))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            outbox
+                .prune_sent(Utc::now() + ChronoDuration::seconds(1))
+                .await
+                .unwrap(),
+            3
+        );
+
+        cleanup_test_company(&pool, company_id).await;
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_team_repository_snapshot_matches_aggregate() {
+    use ghostpirates_api::api::handlers::teams::TeamResponse;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "snapshot-owner@test.com").await;
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+
+    let (team, _events) = Team::new(
         company_id,
-        "Mission Beta".to_string(),
+        "Snapshot Mission".to_string(),
         user_id,
-        Some(rust_decimal::Decimal::new(7500, 2)),
+        Some(usd(4250, 2)), // $42.50
+        &SystemClock,
     )
     .expect("Valid team");
+    team_repo.save(&team).await.expect("Failed to save team");
 
-    team_repo.save(&team1).await.expect("Failed to save team1");
-    team_repo.save(&team2).await.expect("Failed to save team2");
+    let aggregate = team_repo
+        .find_by_id(team.id())
+        .await
+        .expect("Failed to find team")
+        .expect("Team should be found");
+    let snapshot = team_repo
+        .find_snapshot_by_id(team.id())
+        .await
+        .expect("Failed to find snapshot")
+        .expect("Snapshot should be found");
 
-    // Test: Find all teams by company
+    assert_eq!(
+        serde_json::to_value(TeamResponse::from(&snapshot)).unwrap(),
+        serde_json::to_value(TeamResponse::from(&aggregate)).unwrap(),
+        "Snapshot response should match aggregate response"
+    );
+
+    // Missing teams yield no snapshot
+    let missing = team_repo
+        .find_snapshot_by_id(Uuid::new_v4())
+        .await
+        .expect("Query should succeed");
+    assert!(missing.is_none());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_by_company() {
+    with_test_db(|pool| async move {
+        let company_id = create_test_company(&pool).await;
+        let user_id = create_test_user(&pool, company_id, "team-creator@test.com").await;
+
+        let team_repo = PostgresTeamRepository::new(pool.clone());
+
+        // Create multiple teams
+        let (team1, _) = Team::new(
+            company_id,
+            "Mission Alpha".to_string(),
+            user_id,
+            Some(usd(5000, 2)),
+            &SystemClock,
+        )
+        .expect("Valid team");
+
+        let (team2, _) = Team::new(
+            company_id,
+            "Mission Beta".to_string(),
+            user_id,
+            Some(usd(7500, 2)),
+            &SystemClock,
+        )
+        .expect("Valid team");
+
+        team_repo.save(&team1).await.expect("Failed to save team1");
+        team_repo.save(&team2).await.expect("Failed to save team2");
+
+        // Test: Find all teams by company
+        let teams = team_repo
+            .find_by_company(company_id)
+            .await
+            .expect("Failed to find teams by company");
+
+        assert_eq!(teams.len(), 2, "Should find 2 teams");
+        assert!(
+            teams.iter().any(|t| t.id() == team1.id()),
+            "Should contain team1"
+        );
+        assert!(
+            teams.iter().any(|t| t.id() == team2.id()),
+            "Should contain team2"
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_by_ids() {
+    with_test_db(|pool| async move {
+        let company_id = create_test_company(&pool).await;
+        let other_company_id = create_test_company(&pool).await;
+        let user_id = create_test_user(&pool, company_id, "batch-lookup@test.com").await;
+        let other_user_id = create_test_user(&pool, other_company_id, "batch-other@test.com").await;
+
+        let team_repo = PostgresTeamRepository::new(pool.clone());
+
+        let mut ids = Vec::new();
+        for goal in ["Mission Alpha", "Mission Beta"] {
+            let (team, _) = Team::new(company_id, goal.to_string(), user_id, None, &SystemClock)
+                .expect("Valid team");
+            team_repo.save(&team).await.expect("Failed to save team");
+            ids.push(team.id());
+        }
+        let (foreign, _) = Team::new(
+            other_company_id,
+            "Mission Gamma".to_string(),
+            other_user_id,
+            None,
+            &SystemClock,
+        )
+        .expect("Valid team");
+        team_repo.save(&foreign).await.expect("Failed to save team");
+
+        // Test: Missing and cross-tenant IDs are left out, order is kept
+        let teams = team_repo
+            .find_by_ids(company_id, &[ids[1], Uuid::new_v4(), foreign.id(), ids[0]])
+            .await
+            .expect("Failed to find teams by ids");
+
+        let found: Vec<Uuid> = teams.iter().map(|t| t.id()).collect();
+        assert_eq!(found, vec![ids[1], ids[0]]);
+
+        let none = team_repo
+            .find_by_ids(company_id, &[])
+            .await
+            .expect("Failed to find teams by ids");
+        assert!(none.is_empty());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_by_statuses() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "status-filter@test.com").await;
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+
+    // One team per status of interest
+    let mut ids = Vec::new();
+    for status in [
+        TeamStatus::Pending,
+        TeamStatus::Active,
+        TeamStatus::Completed,
+    ] {
+        let team = Team::from_persistence(
+            Uuid::new_v4(),
+            company_id,
+            format!("Mission {}", status),
+            status,
+            None,
+            user_id,
+            chrono::Utc::now(),
+            None,
+            None,
+            None,
+            rust_decimal::Decimal::ZERO,
+            Vec::new(),
+            None,
+        );
+        team_repo.save(&team).await.expect("Failed to save team");
+        ids.push(team.id());
+    }
+
+    // Test: Filter by two statuses returns their union
     let teams = team_repo
-        .find_by_company(company_id)
+        .find_by_statuses(company_id, &[TeamStatus::Pending, TeamStatus::Active])
         .await
-        .expect("Failed to find teams by company");
+        .expect("Failed to find teams by status");
 
-    assert_eq!(teams.len(), 2, "Should find 2 teams");
-    assert!(
-        teams.iter().any(|t| t.id() == team1.id()),
-        "Should contain team1"
-    );
-    assert!(
-        teams.iter().any(|t| t.id() == team2.id()),
-        "Should contain team2"
-    );
+    assert_eq!(teams.len(), 2, "Should find pending and active teams");
+    assert!(teams.iter().any(|t| t.id() == ids[0]));
+    assert!(teams.iter().any(|t| t.id() == ids[1]));
+    assert!(!teams.iter().any(|t| t.id() == ids[2]));
 
     // Cleanup
     cleanup_test_company(&pool, company_id).await;
@@ -325,6 +986,7 @@ async fn test_team_repository_delete() {
         "Mission to Delete".to_string(),
         user_id,
         None,
+        &SystemClock,
     )
     .expect("Valid team");
 
@@ -350,6 +1012,62 @@ async fn test_team_repository_delete() {
         .expect("Failed to find team after delete");
     assert!(found.is_none(), "Team should not exist after delete");
 
+    // Test: Deleting it again reports it as missing
+    let error = team_repo
+        .delete(team.id())
+        .await
+        .expect_err("Second delete should fail");
+    assert_eq!(
+        error,
+        RepositoryError::NotFound {
+            entity: "Team",
+            id: team.id()
+        }
+    );
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_deleted_after_being_loaded_is_reported_missing() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "team-delete-race@test.com").await;
+    let (team, _) = Team::new(
+        company_id,
+        "Contested mission".to_string(),
+        user_id,
+        None,
+        &SystemClock,
+    )
+    .expect("Valid team");
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+    team_repo.save(&team).await.expect("Failed to save team");
+
+    // One caller loads the team, then another deletes it first
+    let loaded = team_repo
+        .find_by_id(team.id())
+        .await
+        .expect("Failed to find team")
+        .expect("Team should exist");
+    PostgresTeamRepository::new(pool.clone())
+        .delete(team.id())
+        .await
+        .expect("Failed to delete team");
+
+    let error = team_repo
+        .delete(loaded.id())
+        .await
+        .expect_err("Losing delete should fail");
+    assert_eq!(
+        error,
+        RepositoryError::NotFound {
+            entity: "Team",
+            id: team.id()
+        }
+    );
+
     // Cleanup
     cleanup_test_company(&pool, company_id).await;
 }
@@ -367,7 +1085,8 @@ async fn test_team_repository_upsert_updates_existing() {
         company_id,
         "Initial Goal".to_string(),
         user_id,
-        Some(rust_decimal::Decimal::new(10000, 2)),
+        Some(usd(10000, 2)),
+        &SystemClock,
     )
     .expect("Valid team");
 
@@ -400,6 +1119,44 @@ async fn test_team_repository_upsert_updates_existing() {
     cleanup_test_company(&pool, company_id).await;
 }
 
+#[tokio::test]
+async fn test_team_repository_persists_amount_spent() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "team-spender@test.com").await;
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+
+    let (mut team, _) = Team::new(
+        company_id,
+        "Spend Mission".to_string(),
+        user_id,
+        Some(usd(10000, 2)),
+        &SystemClock,
+    )
+    .expect("Valid team");
+    team_repo.save(&team).await.expect("Failed to save team");
+
+    // Test: Recorded spend survives an upsert
+    team.record_spend(rust_decimal::Decimal::new(1250, 2), &SystemClock)
+        .expect("Valid spend");
+    team_repo.save(&team).await.expect("Failed to update team");
+
+    let found_team = team_repo
+        .find_by_id(team.id())
+        .await
+        .expect("Failed to find team")
+        .expect("Team should be found");
+
+    assert_eq!(
+        found_team.amount_spent(),
+        rust_decimal::Decimal::new(1250, 2)
+    );
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
 #[tokio::test]
 async fn test_tenant_isolation_users() {
     let pool = setup_test_db().await;
@@ -421,6 +1178,9 @@ async fn test_tenant_isolation_users() {
         password_hash: hash_password("password1").expect("hash"),
         full_name: "User One".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     user_repo
@@ -438,6 +1198,9 @@ async fn test_tenant_isolation_users() {
         password_hash: hash_password("password2").expect("hash"),
         full_name: "User Two".to_string(),
         is_active: true,
+        role: UserRole::Member,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
     };
 
     user_repo
@@ -488,6 +1251,7 @@ async fn test_tenant_isolation_teams() {
         "Company 1 Mission".to_string(),
         user1_id,
         None,
+        &SystemClock,
     )
     .expect("Valid team");
 
@@ -499,6 +1263,7 @@ async fn test_tenant_isolation_teams() {
         "Company 2 Mission".to_string(),
         user2_id,
         None,
+        &SystemClock,
     )
     .expect("Valid team");
 
@@ -527,3 +1292,513 @@ async fn test_tenant_isolation_teams() {
     cleanup_test_company(&pool, company1_id).await;
     cleanup_test_company(&pool, company2_id).await;
 }
+
+#[tokio::test]
+async fn test_feature_flag_is_scoped_to_company() {
+    let pool = setup_test_db().await;
+    let company1_id = create_test_company(&pool).await;
+    let company2_id = create_test_company(&pool).await;
+
+    let flags = FeatureFlags::postgres(pool.clone());
+
+    // Test: Flags are disabled until set
+    assert!(!flags
+        .is_enabled(company1_id, UNIQUE_GOAL_PER_COMPANY)
+        .await
+        .expect("Failed to read flag"));
+
+    // Test: Enabling for one company leaves the other untouched
+    flags
+        .set(company1_id, UNIQUE_GOAL_PER_COMPANY, true)
+        .await
+        .expect("Failed to set flag");
+
+    // A fresh instance has no cache, so this reads the database
+    let fresh = FeatureFlags::postgres(pool.clone());
+    assert!(fresh
+        .is_enabled(company1_id, UNIQUE_GOAL_PER_COMPANY)
+        .await
+        .expect("Failed to read flag"));
+    assert!(!fresh
+        .is_enabled(company2_id, UNIQUE_GOAL_PER_COMPANY)
+        .await
+        .expect("Failed to read flag"));
+
+    // Test: Disabling overwrites the stored value
+    flags
+        .set(company1_id, UNIQUE_GOAL_PER_COMPANY, false)
+        .await
+        .expect("Failed to set flag");
+    let fresh = FeatureFlags::postgres(pool.clone());
+    assert!(!fresh
+        .is_enabled(company1_id, UNIQUE_GOAL_PER_COMPANY)
+        .await
+        .expect("Failed to read flag"));
+
+    // Cleanup
+    cleanup_test_company(&pool, company1_id).await;
+    cleanup_test_company(&pool, company2_id).await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_by_tag() {
+    let pool = setup_test_db().await;
+    let company1_id = create_test_company(&pool).await;
+    let company2_id = create_test_company(&pool).await;
+    let user1_id = create_test_user(&pool, company1_id, "tags1@test.com").await;
+    let user2_id = create_test_user(&pool, company2_id, "tags2@test.com").await;
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+
+    let (mut tagged, _) = Team::new(
+        company1_id,
+        "Tagged Mission".to_string(),
+        user1_id,
+        None,
+        &SystemClock,
+    )
+    .expect("Valid team");
+    tagged
+        .set_tags(vec!["growth".to_string(), "q3".to_string()])
+        .expect("Valid tags");
+    team_repo.save(&tagged).await.expect("Failed to save team");
+
+    let (untagged, _) = Team::new(
+        company1_id,
+        "Untagged Mission".to_string(),
+        user1_id,
+        None,
+        &SystemClock,
+    )
+    .expect("Valid team");
+    team_repo
+        .save(&untagged)
+        .await
+        .expect("Failed to save team");
+
+    let (mut other_company, _) = Team::new(
+        company2_id,
+        "Other Company Mission".to_string(),
+        user2_id,
+        None,
+        &SystemClock,
+    )
+    .expect("Valid team");
+    other_company
+        .set_tags(vec!["q3".to_string()])
+        .expect("Valid tags");
+    team_repo
+        .save(&other_company)
+        .await
+        .expect("Failed to save team");
+
+    // Test: Only the company's teams with the tag are returned
+    let teams = team_repo
+        .find_by_tag(company1_id, "q3")
+        .await
+        .expect("Failed to find teams by tag");
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0].id(), tagged.id());
+    assert_eq!(teams[0].tags(), ["growth".to_string(), "q3".to_string()]);
+
+    // Test: Tags round-trip through the snapshot query
+    let snapshot = team_repo
+        .find_snapshot_by_id(tagged.id())
+        .await
+        .expect("Failed to find snapshot")
+        .expect("Snapshot should exist");
+    assert_eq!(snapshot.tags, vec!["growth".to_string(), "q3".to_string()]);
+
+    // Cleanup
+    cleanup_test_company(&pool, company1_id).await;
+    cleanup_test_company(&pool, company2_id).await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_created_between() {
+    use chrono::{TimeZone, Utc};
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "range@test.com").await;
+
+    let team_repo = PostgresTeamRepository::new(pool.clone());
+
+    // Teams created on Jan 1, Feb 1, and Mar 1
+    let mut ids = Vec::new();
+    for month in 1..=3 {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2024, month, 1, 12, 0, 0).unwrap());
+        let (team, _) = Team::new(
+            company_id,
+            format!("Range Mission {}", month),
+            user_id,
+            None,
+            &clock,
+        )
+        .expect("Valid team");
+        team_repo.save(&team).await.expect("Failed to save team");
+        ids.push(team.id());
+    }
+
+    // Test: Only teams inside the range are returned
+    let teams = team_repo
+        .find_created_between(
+            company_id,
+            Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        )
+        .await
+        .expect("Failed to find teams by creation time");
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0].id(), ids[1]);
+
+    // Test: Bounds are inclusive
+    let teams = team_repo
+        .find_created_between(
+            company_id,
+            Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+        )
+        .await
+        .expect("Failed to find teams by creation time");
+    assert_eq!(teams.len(), 3);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_repository_find_stale() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    // An isolated schema, since stale teams are looked up across companies
+    with_test_db(|pool| async move {
+        let company_id = create_test_company(&pool).await;
+        let user_id = create_test_user(&pool, company_id, "stale@test.com").await;
+        let team_repo = PostgresTeamRepository::new(pool.clone());
+
+        // A team pending since 2020 and one created just now
+        let old_clock = MockClock::new(Utc.with_ymd_and_hms(2020, 1, 1, 12, 0, 0).unwrap());
+        let (old_team, _) = Team::new(
+            company_id,
+            "Stuck mission".to_string(),
+            user_id,
+            None,
+            &old_clock,
+        )
+        .expect("Valid team");
+        team_repo
+            .save(&old_team)
+            .await
+            .expect("Failed to save team");
+
+        let (recent_team, _) = Team::new(
+            company_id,
+            "Fresh mission".to_string(),
+            user_id,
+            None,
+            &SystemClock,
+        )
+        .expect("Valid team");
+        team_repo
+            .save(&recent_team)
+            .await
+            .expect("Failed to save team");
+
+        // Test: Only the team created before the cutoff is stale
+        let stale = team_repo
+            .find_stale(TeamStatus::Pending, Utc::now() - Duration::hours(1))
+            .await
+            .expect("Failed to find stale teams");
+        let stale_ids: Vec<Uuid> = stale.iter().map(|team| team.id()).collect();
+        assert_eq!(stale_ids, vec![old_team.id()]);
+
+        // Test: Teams in other statuses are not returned
+        let stale = team_repo
+            .find_stale(TeamStatus::Planning, Utc::now())
+            .await
+            .expect("Failed to find stale teams");
+        assert!(stale.is_empty());
+    })
+    .await;
+}
+
+/// Saves a team and inserts a pending task for it, returning (team ID, task ID)
+async fn create_team_with_task(pool: &PgPool, company_id: Uuid, user_id: Uuid) -> (Uuid, Uuid) {
+    let (team, _) = Team::new(
+        company_id,
+        "Formation mission".to_string(),
+        user_id,
+        None,
+        &SystemClock,
+    )
+    .expect("Valid team");
+    PostgresTeamRepository::new(pool.clone())
+        .save(&team)
+        .await
+        .expect("Failed to save team");
+
+    let task_id = Uuid::new_v4();
+    sqlx::query!(
+        "INSERT INTO tasks (id, team_id, title, description) VALUES ($1, $2, $3, $4)",
+        task_id,
+        team.id(),
+        "Write the scraper",
+        "Fetch and parse listings"
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to create task");
+
+    (team.id(), task_id)
+}
+
+/// Builds a coder worker for `team_id`
+fn coder(team_id: Uuid) -> WorkerAgent {
+    let spec = WorkerSpec {
+        specialization: "Coder".to_string(),
+        skills: vec!["Rust".to_string()],
+        responsibilities: vec![],
+        required_tools: vec![],
+    };
+    WorkerAgent::from_spec(team_id, &spec).unwrap()
+}
+
+#[tokio::test]
+async fn test_team_formation_persists_workers_and_assignments() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "formation-ok@test.com").await;
+    let (team_id, task_id) = create_team_with_task(&pool, company_id, user_id).await;
+
+    let workers = vec![coder(team_id), coder(team_id)];
+    let assignments = [TaskAssignment {
+        task_id,
+        worker_id: workers[1].id,
+    }];
+    let manager = ManagerAgent::new(team_id);
+    save_team_formation(&pool, &manager, &workers, &assignments)
+        .await
+        .expect("Failed to save team formation");
+
+    let members = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM team_members WHERE team_id = $1 AND role = 'worker'",
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(members, Some(2));
+    let manager_id = sqlx::query_scalar!("SELECT id FROM managers WHERE team_id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(manager_id, manager.id);
+
+    let task = sqlx::query!(
+        "SELECT assigned_to, status::text AS status FROM tasks WHERE id = $1",
+        task_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(task.assigned_to, Some(workers[1].id));
+    assert_eq!(task.status.as_deref(), Some("assigned"));
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_formation_failure_persists_no_workers() {
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "formation-fail@test.com").await;
+    let (team_id, task_id) = create_team_with_task(&pool, company_id, user_id).await;
+
+    // The second assignment names a task that does not exist
+    let workers = vec![coder(team_id), coder(team_id)];
+    let assignments = [
+        TaskAssignment {
+            task_id,
+            worker_id: workers[0].id,
+        },
+        TaskAssignment {
+            task_id: Uuid::new_v4(),
+            worker_id: workers[1].id,
+        },
+    ];
+    let result =
+        save_team_formation(&pool, &ManagerAgent::new(team_id), &workers, &assignments).await;
+    assert!(result.is_err());
+
+    let members = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM team_members WHERE team_id = $1",
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(members, Some(0));
+
+    let task = sqlx::query!("SELECT assigned_to FROM tasks WHERE id = $1", task_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(task.assigned_to.is_none());
+    let managers = sqlx::query_scalar!("SELECT COUNT(*) FROM managers WHERE team_id = $1", team_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(managers, Some(0));
+
+    // A batch with a duplicate worker is rejected as a whole too
+    let duplicate = coder(team_id);
+    let result = PostgresWorkerRepository::new(pool.clone())
+        .save_many(&[coder(team_id), duplicate.clone(), duplicate])
+        .await;
+    assert!(result.is_err());
+    let members = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM team_members WHERE team_id = $1",
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(members, Some(0));
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_manager_repository_persists_worker_and_task_limits() {
+    use ghostpirates_api::agents::ManagerAgent;
+    use ghostpirates_api::domain::repositories::ManagerRepository;
+    use ghostpirates_api::infrastructure::repositories::PostgresManagerRepository;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "manager-limits@test.com").await;
+    let (team_id, _) = create_team_with_task(&pool, company_id, user_id).await;
+    let manager_repo = PostgresManagerRepository::new(pool.clone());
+
+    let manager = ManagerAgent::new(team_id)
+        .with_worker_limits(2, 7)
+        .unwrap()
+        .with_max_concurrent_tasks(6)
+        .unwrap();
+    manager_repo
+        .save(&manager)
+        .await
+        .expect("Failed to save manager");
+
+    let found = manager_repo
+        .find_by_team(team_id)
+        .await
+        .expect("Failed to find manager")
+        .expect("Manager should exist");
+    assert_eq!(found.worker_limits, manager.worker_limits);
+    assert_eq!(found.max_concurrent_tasks, 6);
+
+    // Limits the builder would reject cannot be stored either
+    let result = sqlx::query!(
+        "UPDATE managers SET min_workers = 0 WHERE id = $1",
+        manager.id
+    )
+    .execute(&pool)
+    .await;
+    assert!(result.is_err());
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_team_delete_leaves_no_orphan_rows() {
+    use ghostpirates_api::domain::repositories::TeamEventRepository;
+    use ghostpirates_api::domain::team::events::TeamEvent;
+    use ghostpirates_api::infrastructure::repositories::PostgresTeamEventRepository;
+
+    let pool = setup_test_db().await;
+    let company_id = create_test_company(&pool).await;
+    let user_id = create_test_user(&pool, company_id, "delete-cascade@test.com").await;
+    let (team_id, task_id) = create_team_with_task(&pool, company_id, user_id).await;
+
+    let workers = vec![coder(team_id), coder(team_id)];
+    let assignments = [TaskAssignment {
+        task_id,
+        worker_id: workers[0].id,
+    }];
+    save_team_formation(&pool, &ManagerAgent::new(team_id), &workers, &assignments)
+        .await
+        .expect("Failed to save team formation");
+    PostgresTeamEventRepository::new(pool.clone())
+        .append(&[TeamEvent::Started { team_id }])
+        .await
+        .expect("Failed to append event");
+
+    // Removing one worker clears its assignment instead of failing
+    sqlx::query!("DELETE FROM team_members WHERE id = $1", workers[0].id)
+        .execute(&pool)
+        .await
+        .expect("Failed to delete worker");
+    let task = sqlx::query!("SELECT assigned_to FROM tasks WHERE id = $1", task_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(task.assigned_to.is_none());
+
+    PostgresTeamRepository::new(pool.clone())
+        .delete(team_id)
+        .await
+        .expect("Failed to delete team");
+
+    let orphans = sqlx::query!(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM team_members WHERE team_id = $1) AS "members!",
+            (SELECT COUNT(*) FROM tasks WHERE team_id = $1) AS "tasks!",
+            (SELECT COUNT(*) FROM team_events WHERE team_id = $1) AS "events!"
+        "#,
+        team_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(orphans.members, 0);
+    assert_eq!(orphans.tasks, 0);
+    assert_eq!(orphans.events, 0);
+
+    // Cleanup
+    cleanup_test_company(&pool, company_id).await;
+}
+
+#[tokio::test]
+async fn test_company_find_or_create_by_name_is_stable() {
+    use ghostpirates_api::domain::repositories::CompanyRepository;
+    use ghostpirates_api::infrastructure::repositories::PostgresCompanyRepository;
+
+    let pool = setup_test_db().await;
+    let company_repo = PostgresCompanyRepository::new(pool.clone());
+    let name = format!("Find Or Create {}", Uuid::new_v4());
+
+    let first = company_repo
+        .find_or_create_by_name(&name)
+        .await
+        .expect("Failed to create company");
+    let second = company_repo
+        .find_or_create_by_name(&name)
+        .await
+        .expect("Failed to find company");
+
+    assert_eq!(first, second);
+    let count = sqlx::query_scalar!("SELECT COUNT(*) FROM companies WHERE name = $1", name)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, Some(1));
+
+    // Cleanup
+    cleanup_test_company(&pool, first).await;
+}